@@ -20,7 +20,12 @@ export type Soar = {
   instructions: [
     {
       name: "initializeGame";
-      docs: ["Initialize a new [Game] and register its [LeaderBoard]."];
+      docs: [
+        "Initialize a new [Game] and register its [LeaderBoard].",
+        "",
+        "The game is derived from the `creator` and a `nonce` unique among their games. The",
+        "`creator` becomes the game's owner. A `nonce` whose game was closed can't be reused."
+      ];
       accounts: [
         {
           name: "creator";
@@ -30,12 +35,27 @@ export type Soar = {
         {
           name: "game";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
+        },
+        {
+          name: "closedGame";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -48,14 +68,26 @@ export type Soar = {
         {
           name: "gameAuth";
           type: {
-            vec: "publicKey";
+            vec: {
+              defined: "Authority";
+            };
           };
+        },
+        {
+          name: "nonce";
+          type: "u64";
         }
       ];
     },
     {
       name: "updateGame";
-      docs: ["Update a [Game]'s meta-information or authority list."];
+      docs: [
+        "Update a [Game]'s meta-information or its authorities and their roles.",
+        "",
+        "`new_auth` can remove authorities or change their roles, but new keys are added with",
+        "`propose_authority`. Only the game's owner can change who holds the admin role.",
+        "Rent freed by shrinking the authority list is refunded to `payer`."
+      ];
       accounts: [
         {
           name: "authority";
@@ -76,6 +108,16 @@ export type Soar = {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -91,16 +133,22 @@ export type Soar = {
           name: "newAuth";
           type: {
             option: {
-              vec: "publicKey";
+              vec: {
+                defined: "Authority";
+              };
             };
           };
         }
       ];
     },
     {
-      name: "addAchievement";
+      name: "proposeAuthority";
       docs: [
-        "Add a new [Achievement] that can be attained for a particular [Game]."
+        "Propose adding `authority` to a [Game], or replacing one of its existing authorities",
+        "with it.",
+        "",
+        "The change is applied once the proposed key accepts it, no earlier than `timelock`",
+        "seconds from now. Only the game's owner can propose changes involving the admin role."
       ];
       accounts: [
         {
@@ -115,11 +163,11 @@ export type Soar = {
         },
         {
           name: "game";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "newAchievement";
+          name: "proposal";
           isMut: true;
           isSigner: false;
         },
@@ -127,75 +175,49 @@ export type Soar = {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "title";
-          type: "string";
-        },
-        {
-          name: "description";
-          type: "string";
-        },
-        {
-          name: "nftMeta";
-          type: "publicKey";
-        }
-      ];
-    },
-    {
-      name: "updateAchievement";
-      docs: ["Update an [Achievement]'s meta information."];
-      accounts: [
-        {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
         },
         {
-          name: "game";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "achievement";
-          isMut: true;
+          name: "program";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "newTitle";
+          name: "authority";
           type: {
-            option: "string";
+            defined: "Authority";
           };
         },
         {
-          name: "newDescription";
+          name: "replaces";
           type: {
-            option: "string";
+            option: "publicKey";
           };
         },
         {
-          name: "nftMeta";
-          type: {
-            option: "publicKey";
-          };
+          name: "timelock";
+          type: "i64";
         }
       ];
     },
     {
-      name: "addLeaderboard";
-      docs: ["Overwrite the active [LeaderBoard] and set a newly created one."];
+      name: "acceptAuthority";
+      docs: [
+        "Accept an [AuthorityProposal] as its proposed key, applying it to the game's",
+        "authority list.",
+        "",
+        "Fails if the proposer is no longer an admin, or no longer the owner for changes",
+        "involving the admin role."
+      ];
       accounts: [
         {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "payer";
+          name: "newAuthority";
           isMut: true;
           isSigner: true;
         },
@@ -205,36 +227,37 @@ export type Soar = {
           isSigner: false;
         },
         {
-          name: "leaderboard";
+          name: "proposal";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "topEntries";
+          name: "proposer";
           isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "input";
-          type: {
-            defined: "RegisterLeaderBoardInput";
-          };
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "updateLeaderboard";
+      name: "cancelAuthorityProposal";
       docs: [
-        "Update's a leaderboard's description, nft metadata information, min/max score, or whether",
-        "or not multiple scores are allowed for a single player."
+        "Withdraw an [AuthorityProposal], either as a game admin or as the proposed key."
       ];
       accounts: [
         {
@@ -248,306 +271,240 @@ export type Soar = {
           isSigner: false;
         },
         {
-          name: "leaderboard";
+          name: "proposal";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "topEntries";
+          name: "proposer";
           isMut: true;
           isSigner: false;
-          isOptional: true;
-        }
-      ];
-      args: [
-        {
-          name: "newDescription";
-          type: {
-            option: "string";
-          };
-        },
-        {
-          name: "newNftMeta";
-          type: {
-            option: "publicKey";
-          };
         },
         {
-          name: "newMinScore";
-          type: {
-            option: "u64";
-          };
-        },
-        {
-          name: "newMaxScore";
-          type: {
-            option: "u64";
-          };
-        },
-        {
-          name: "newIsAscending";
-          type: {
-            option: "bool";
-          };
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "newAllowMultipleScores";
-          type: {
-            option: "bool";
-          };
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "initializePlayer";
-      docs: ["Create a [Player] account for a particular user."];
+      name: "transferGameOwnership";
+      docs: [
+        "Start transferring ownership of a [Game] to `new_owner`, or withdraw a pending",
+        "transfer if [None]."
+      ];
       accounts: [
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "user";
+          name: "owner";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "playerAccount";
+          name: "game";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "username";
-          type: "string";
-        },
-        {
-          name: "nftMeta";
-          type: "publicKey";
+          name: "newOwner";
+          type: {
+            option: "publicKey";
+          };
         }
       ];
     },
     {
-      name: "updatePlayer";
-      docs: ["Update the username or nft_meta for a [Player] account."];
+      name: "acceptGameOwnership";
+      docs: ["Accept ownership of a [Game] as its pending owner."];
       accounts: [
         {
-          name: "user";
+          name: "newOwner";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "playerAccount";
+          name: "game";
           isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "username";
-          type: {
-            option: "string";
-          };
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "nftMeta";
-          type: {
-            option: "publicKey";
-          };
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "registerPlayer";
+      name: "setGamePaused";
       docs: [
-        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
-        "created [PlayerEntryList] account."
+        "Pause or resume a [Game].",
+        "",
+        "A paused game rejects score submissions, player registrations, achievement unlocks",
+        "and reward claims, while its authorities can still manage it."
       ];
       accounts: [
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "user";
+          name: "authority";
           isMut: false;
           isSigner: true;
         },
-        {
-          name: "playerAccount";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "game";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "leaderboard";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "newList";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "systemProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
-      args: [];
+      args: [
+        {
+          name: "paused";
+          type: "bool";
+        }
+      ];
     },
     {
-      name: "submitScore";
+      name: "addAchievement";
       docs: [
-        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
-        "Optionally increase the player's rank if needed.",
-        "",
-        "This instruction automatically resizes the [PlayerScoresList] account if needed."
+        "Add a new [Achievement] that can be attained for a particular [Game]."
       ];
       accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
         {
           name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
+          name: "game";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "playerAccount";
-          isMut: false;
+          name: "newAchievement";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "game";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "leaderboard";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerScores";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "topEntries";
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-        },
-        {
-          name: "systemProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "score";
-          type: "u64";
+          name: "title";
+          type: "string";
+        },
+        {
+          name: "description";
+          type: "string";
+        },
+        {
+          name: "nftMeta";
+          type: "publicKey";
         }
       ];
     },
     {
-      name: "initiateMerge";
-      docs: [
-        "Initialize a new merge account and await approval from the verified users of all the",
-        "specified [Player] accounts.",
-        "",
-        "A merge is complete when all the users of the [Player] account keys referenced in it",
-        "have signed to set their approval to `true`."
-      ];
+      name: "updateAchievement";
+      docs: ["Update an [Achievement]'s meta information."];
       accounts: [
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "user";
+          name: "authority";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "playerAccount";
+          name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "mergeAccount";
+          name: "achievement";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "keys";
+          name: "newTitle";
           type: {
-            vec: "publicKey";
+            option: "string";
           };
-        }
-      ];
-    },
-    {
-      name: "approveMerge";
-      docs: [
-        "Register merge confirmation for a particular [Player] account included in a [Merged]."
-      ];
-      accounts: [
-        {
-          name: "user";
-          isMut: false;
-          isSigner: true;
         },
         {
-          name: "playerAccount";
-          isMut: false;
-          isSigner: false;
+          name: "newDescription";
+          type: {
+            option: "string";
+          };
         },
         {
-          name: "mergeAccount";
-          isMut: true;
-          isSigner: false;
+          name: "nftMeta";
+          type: {
+            option: "publicKey";
+          };
         }
       ];
-      args: [];
     },
     {
-      name: "unlockPlayerAchievement";
-      docs: [
-        "Unlock a [PlayerAchievement] account without minting a reward.",
-        "",
-        "Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that",
-        "can serve as a gated verification-method for claims.",
-        "",
-        "Since claim instructions like [claim_ft_reward] and [claim_nft_reward] for reward types",
-        "defined by this program try to initialize this account and will fail if it already exists,",
-        "calling this means opting out of using these functions."
-      ];
+      name: "addLeaderboard";
+      docs: ["Overwrite the active [LeaderBoard] and set a newly created one."];
       accounts: [
         {
           name: "authority";
@@ -560,39 +517,54 @@ export type Soar = {
           isSigner: true;
         },
         {
-          name: "playerAccount";
-          isMut: false;
+          name: "game";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "game";
-          isMut: false;
+          name: "leaderboard";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "achievement";
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAchievement";
-          isMut: true;
+          name: "eventAuthority";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
-      args: [];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "RegisterLeaderBoardInput";
+          };
+        }
+      ];
     },
     {
-      name: "addFtReward";
+      name: "updateLeaderboard";
       docs: [
-        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
+        "Update's a leaderboard's description, nft metadata information, min/max score, whether",
+        "or not multiple scores are allowed for a single player, or its score retention policy.",
         "",
-        "Overwrites the current reward if one exists."
+        "Changing the order or score bounds, or disallowing multiple scores, re-ranks the",
+        "leaderboard's [LeaderTopEntries], which must then be passed in."
       ];
       accounts: [
         {
@@ -600,67 +572,84 @@ export type Soar = {
           isMut: false;
           isSigner: true;
         },
-        {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
         {
           name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "achievement";
+          name: "leaderboard";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "newReward";
+          name: "topEntries";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: "rewardTokenMint";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "delegateFromTokenAccount";
-          isMut: true;
+          name: "program";
+          isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "newDescription";
+          type: {
+            option: "string";
+          };
         },
         {
-          name: "tokenAccountOwner";
-          isMut: false;
-          isSigner: true;
+          name: "newNftMeta";
+          type: {
+            option: "publicKey";
+          };
         },
         {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "newMinScore";
+          type: {
+            option: "u64";
+          };
         },
         {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
+          name: "newMaxScore";
+          type: {
+            option: "u64";
+          };
+        },
         {
-          name: "input";
+          name: "newIsAscending";
           type: {
-            defined: "AddNewRewardInput";
+            option: "bool";
+          };
+        },
+        {
+          name: "newAllowMultipleScores";
+          type: {
+            option: "bool";
+          };
+        },
+        {
+          name: "newRetention";
+          type: {
+            option: {
+              defined: "ScoreRetention";
+            };
           };
         }
       ];
     },
     {
-      name: "addNftReward";
+      name: "setLeaderboardPaused";
       docs: [
-        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists."
+        "Pause or resume score submissions and player registrations for a [LeaderBoard]."
       ];
       accounts: [
         {
@@ -668,153 +657,140 @@ export type Soar = {
           isMut: false;
           isSigner: true;
         },
-        {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
         {
           name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "achievement";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "newReward";
+          name: "leaderboard";
           isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "rewardCollectionMint";
-          isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: "collectionUpdateAuth";
+          name: "eventAuthority";
           isMut: false;
-          isSigner: true;
-          isOptional: true;
-        },
-        {
-          name: "collectionMetadata";
-          isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
-          isOptional: true;
         }
       ];
       args: [
         {
-          name: "input";
-          type: {
-            defined: "AddNewRewardInput";
-          };
+          name: "paused";
+          type: "bool";
         }
       ];
     },
     {
-      name: "claimFtReward";
+      name: "archiveSeason";
       docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
-        "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
+        "Snapshot a seasonal [LeaderBoard]'s [LeaderTopEntries] into a [SeasonTopEntries] account",
+        "once its season is over, and reset the top entries for the current season.",
         "",
-        "Relevant `ONLY` if an FT reward is specified for that achievement."
+        "Anyone can archive a season. Until then, scores submitted in a later season are",
+        "rejected so the finished season's standings can't be lost; a season that ended",
+        "without any entries needs no archive."
       ];
       accounts: [
-        {
-          name: "user";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
-        },
         {
           name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "game";
-          isMut: false;
+          name: "leaderboard";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "achievement";
-          isMut: false;
+          name: "topEntries";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "reward";
+          name: "archive";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "playerAccount";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAchievement";
-          isMut: true;
+          name: "eventAuthority";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "sourceTokenAccount";
-          isMut: true;
+          name: "program";
+          isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "growTopEntries";
+      docs: [
+        "Allocate more of a [LeaderTopEntries] account's slots, up to its `scores_to_retain`.",
+        "",
+        "Accounts can only be created or grown by about 10KB per instruction, so large top lists",
+        "start out partially allocated and rank only the slots allocated so far. Anyone can pay",
+        "to grow them."
+      ];
+      accounts: [
         {
-          name: "userTokenAccount";
+          name: "payer";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenProgram";
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "claimNftReward";
+      name: "rebuildTopEntries";
       docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
-        "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
+        "Recompute a [LeaderBoard]'s [LeaderTopEntries] from the [PlayerScoresList] accounts",
+        "passed as remaining accounts, to repair a corrupted top list. Each list is followed by",
+        "the address of its player's [PlayerBan], and players banned from the leaderboard are",
+        "left out.",
         "",
-        "Relevant `ONLY` if an NFT reward is specified for that achievement."
+        "The top entries are cleared first if `reset`, which also discards a finished season's",
+        "standings that haven't been archived. Lists are read in place, but a board with",
+        "many players still needs several transactions: reset in the first, then pass the rest",
+        "of the lists in batches."
       ];
       accounts: [
-        {
-          name: "user";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "authority";
           isMut: false;
@@ -826,91 +802,155 @@ export type Soar = {
           isSigner: false;
         },
         {
-          name: "payer";
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "achievement";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "reward";
-          isMut: true;
+          name: "program";
+          isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "reset";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "addTopEntries";
+      docs: [
+        "Create a [LeaderTopEntries] account for a [LeaderBoard] that was added without one.",
+        "",
+        "Scores submitted before then can be ranked by passing their players' [PlayerScoresList]",
+        "accounts as remaining accounts, paired with their [PlayerBan] addresses as in",
+        "`rebuild_top_entries`, or later through `rebuild_top_entries` itself."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "playerAccount";
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAchievement";
+          name: "leaderboard";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "claim";
+          name: "topEntries";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "newMint";
-          isMut: true;
-          isSigner: true;
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "newMetadata";
-          isMut: true;
+          name: "eventAuthority";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "newMasterEdition";
-          isMut: true;
+          name: "program";
+          isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "scoresToRetain";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "resizeTopEntries";
+      docs: [
+        "Change the number of scores a [LeaderBoard]'s [LeaderTopEntries] retain, reallocating",
+        "the account.",
+        "",
+        "Shrinking drops the lowest-ranked entries and refunds the freed rent to the payer, and",
+        "makes the dropped ranks unclaimable for the leaderboard's [RankReward]s. New slots from",
+        "growing are filled from the [PlayerScoresList] accounts passed as remaining accounts,",
+        "paired with their [PlayerBan] addresses as in `rebuild_top_entries`; a list too large",
+        "to allocate at once keeps growing through `grow_top_entries`."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "mintTo";
+          name: "payer";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "rent";
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
-      args: [];
+      args: [
+        {
+          name: "scoresToRetain";
+          type: "u16";
+        }
+      ];
     },
     {
-      name: "verifyNftReward";
-      docs: [
-        "Verify NFT reward as belonging to a particular collection.",
-        "",
-        "Optional: Only relevant if an NFT reward is specified and the reward's",
-        "`collection_mint` is Some(...)"
-      ];
+      name: "initializePlayer";
+      docs: ["Create a [Player] account for a particular user."];
       accounts: [
         {
           name: "payer";
@@ -918,20 +958,46 @@ export type Soar = {
           isSigner: true;
         },
         {
-          name: "game";
+          name: "user";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "achievement";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "reward";
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "username";
+          type: "string";
         },
+        {
+          name: "nftMeta";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "updatePlayer";
+      docs: ["Update the username or nft_meta for a [Player] account."];
+      accounts: [
         {
           name: "user";
           isMut: false;
@@ -939,2522 +1005,13425 @@ export type Soar = {
         },
         {
           name: "playerAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "claim";
+          name: "program";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "username";
+          type: {
+            option: "string";
+          };
         },
         {
-          name: "playerAchievement";
+          name: "nftMeta";
+          type: {
+            option: "publicKey";
+          };
+        }
+      ];
+    },
+    {
+      name: "registerPlayer";
+      docs: [
+        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
+        "created [PlayerEntryList] account."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "metadataToVerify";
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "newList";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "collectionMint";
+          name: "playerBan";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "collectionMetadata";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "collectionEdition";
+          name: "eventAuthority";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "program";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
+    },
     {
-      name: "achievement";
+      name: "submitScore";
       docs: [
-        "Represents an achievement(with optional rewards) for this game",
-        "that can be attained by players.",
-        "",
-        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
+        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
+        "Optionally increase the player's rank if needed.",
         "",
-        "`id` is an incrementing index stored in the game account."
+        "This instruction automatically resizes the [PlayerScoresList] account if needed."
       ];
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "game";
-            docs: [
-              "Public key of the game account this achievement is derived from."
-            ];
-            type: "publicKey";
-          },
-          {
-            name: "id";
-            docs: [
-              "The achievement_count of the game account when this account was",
-              "created, also used as a seed for its PDA."
-            ];
-            type: "u64";
-          },
-          {
-            name: "title";
-            docs: ["Achievement title."];
-            type: "string";
-          },
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerBan";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "score";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "submitScoreWithTiebreak";
+      docs: [
+        "Like [submit_score], with a secondary `tiebreak` value used to rank the score against",
+        "equal ones on leaderboards with a [TieBreak::HighestValue] or [TieBreak::LowestValue]",
+        "policy."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerBan";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "score";
+          type: "u64";
+        },
+        {
+          name: "tiebreak";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "submitAttestedScore";
+      docs: [
+        "Submit a score signed for and paid by the player, carrying an attestation from one",
+        "of the [Game]'s authorities.",
+        "",
+        "The transaction must include an ed25519 program instruction right before this one that",
+        "verifies an authority's signature over the attestation's",
+        "[message][ScoreAttestation::message]."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerBan";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "score";
+          type: "u64";
+        },
+        {
+          name: "tiebreak";
+          type: "u64";
+        },
+        {
+          name: "nonce";
+          type: "u64";
+        },
+        {
+          name: "issuedAt";
+          type: "i64";
+        },
+        {
+          name: "expiry";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "removePlayerScore";
+      docs: [
+        "Remove the score at `index` from a [PlayerScoresList], also evicting it from the",
+        "[LeaderTopEntries] if given."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "evictPlayer";
+      docs: [
+        "Remove every entry held by a [Player] from a [LeaderBoard]'s [LeaderTopEntries]."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "banPlayer";
+      docs: [
+        "Ban a [Player] from registering for or submitting scores to a [LeaderBoard].",
+        "",
+        "The ban is held by a [PlayerBan] account, so players can be banned before they",
+        "register and stay banned if they close their [PlayerScoresList]."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ban";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unbanPlayer";
+      docs: [
+        "Lift a [Player]'s ban from a [LeaderBoard], closing its [PlayerBan] account."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ban";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initiateMerge";
+      docs: [
+        "Initialize a new merge account and await approval from the verified users of all the",
+        "specified [Player] accounts.",
+        "",
+        "A merge is complete when all the users of the [Player] account keys referenced in it",
+        "have signed to set their approval to `true`.",
+        "",
+        "The [Merged] account is derived from the initiating user and a `nonce` unique among",
+        "their merges."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergeAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "keys";
+          type: {
+            vec: "publicKey";
+          };
+        },
+        {
+          name: "nonce";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "approveMerge";
+      docs: [
+        "Register merge confirmation for a particular [Player] account included in a [Merged]."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergeAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mergePlayerScores";
+      docs: [
+        "Move a merged [Player]'s scores for a [LeaderBoard] into the initiator's [PlayerScoresList]",
+        "once the [Merged] account is complete.",
+        "",
+        "Rows in the leaderboard's [LeaderTopEntries] that belong to the merged player are",
+        "reassigned to the initiator's [Player] account.",
+        "",
+        "The merged player's [PlayerScoresList] is closed, refunding its rent to their wallet."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergeAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergedPlayerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mergedPlayerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mergedUser";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mergedPlayerBan";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mergePlayerAchievement";
+      docs: [
+        "Carry over a merged [Player]'s [PlayerAchievement] to the initiator's [Player] account",
+        "once the [Merged] account is complete.",
+        "",
+        "The merged player's status is [marked as merged][PlayerAchievement::mark_merged], so",
+        "neither it nor the merged player can claim the achievement's rewards anymore. If the",
+        "merged player claimed any, the [RewardClaim]s of both players are passed as remaining",
+        "accounts for each of the achievement's rewards, in order, so the initiator can't claim",
+        "them again."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergeAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergedPlayerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mergedPlayerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unlockPlayerAchievement";
+      docs: [
+        "Unlock a [PlayerAchievement] account without paying out a reward.",
+        "",
+        "The achievement's rewards can be claimed later with [claim_ft_reward] and",
+        "[claim_nft_reward]. The account can also serve as a gated verification-method for",
+        "custom rewards mechanisms."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "addFtReward";
+      docs: [
+        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "The reward's mint may belong to either SPL Token or Token-2022. `deposit` tokens are",
+        "moved into a vault owned by the achievement, from which claims are paid out.",
+        "",
+        "An achievement can hold up to [Achievement::MAX_REWARDS] rewards, each paid out",
+        "independently while it has spots left. The reward is derived from the achievement",
+        "and [Achievement::next_reward]."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newReward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccountOwner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddNewRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "topUpFtReward";
+      docs: [
+        "Deposit more tokens into the vault of a fungible token [Reward]."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccountOwner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "withdrawFtReward";
+      docs: [
+        "Withdraw the tokens in a fungible token [Reward]'s vault that aren't owed to any of its",
+        "remaining spots."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destinationTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "addNftReward";
+      docs: [
+        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "An achievement can hold up to [Achievement::MAX_REWARDS] rewards, each paid out",
+        "independently while it has spots left. The reward is derived from the achievement",
+        "and [Achievement::next_reward]."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newReward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardCollectionMint";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "collectionUpdateAuth";
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: "collectionMetadata";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddNewRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "updateReward";
+      docs: [
+        "Update a [Reward]'s available spots, or the amount given out by a fungible token reward,",
+        "or the uri, name or symbol of NFTs minted by an NFT reward.",
+        "",
+        "Only affects claims made after the update. Cancelled rewards can't be updated, and a",
+        "fungible token reward's vault must already hold any increase in what its remaining",
+        "spots are owed."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "The reward's vault, checked in the handler. Only needed when an FT reward's",
+            "remaining spots are owed more."
+          ];
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "newAvailableSpots";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "newAmount";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "newUri";
+          type: {
+            option: "string";
+          };
+        },
+        {
+          name: "newName";
+          type: {
+            option: "string";
+          };
+        },
+        {
+          name: "newSymbol";
+          type: {
+            option: "string";
+          };
+        }
+      ];
+    },
+    {
+      name: "setRewardPaused";
+      docs: ["Pause or resume claims of a [Reward]."];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "paused";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "cancelReward";
+      docs: [
+        "Cancel a [Reward], detaching it from its [Achievement].",
+        "",
+        "The whole vault of a fungible token reward is refunded to `destination_token_account`.",
+        "The reward account itself is left for `close_reward`."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "destinationTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "redeemClaimVoucher";
+      docs: [
+        "Redeem a [ClaimVoucher] signed by one of the [Game]'s authorities for a fungible token",
+        "[Reward], unlocking its [Achievement] for the signing player if needed and paying the",
+        "reward out to them.",
+        "",
+        "The transaction must include an ed25519 program instruction right before this one that",
+        "verifies the signature over the voucher's [message][ClaimVoucher::message]. The signing",
+        "authority must be allowed to both unlock achievements and manage rewards.",
+        "",
+        "For Token-2022 mints with a transfer hook, the accounts the hook requires are passed",
+        "as remaining accounts."
+      ];
+      accounts: [
+        {
+          name: "claim";
+          accounts: [
+            {
+              name: "user";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "payer";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "game";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "achievement";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "reward";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "playerAccount";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "playerAchievement";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardClaim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardTokenMint";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "rewardVault";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "userTokenAccount";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "tokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "associatedTokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "systemProgram";
+              isMut: false;
+              isSigner: false;
+            }
+          ];
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "expiry";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "redeemNftClaimVoucher";
+      docs: ["Same as [redeem_claim_voucher], but for an NFT [Reward]."];
+      accounts: [
+        {
+          name: "claim";
+          accounts: [
+            {
+              name: "user";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "payer";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "game";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "achievement";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "reward";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "playerAccount";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "playerAchievement";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardClaim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "claim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "newMint";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "newMetadata";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "newMasterEdition";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "mintTo";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "tokenMetadataProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "associatedTokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "systemProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "tokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "rent";
+              isMut: false;
+              isSigner: false;
+            }
+          ];
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "expiry";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "claimFtReward";
+      docs: [
+        "Pay out one of an [Achievement]'s fungible token [Reward]s to a player who has already",
+        "unlocked it.",
+        "",
+        "Signed by either one of the [Game]'s authorities or the player themselves.",
+        "",
+        "Each reward can be claimed once per player, tracked by a [RewardClaim] account. To pay",
+        "out every reward of an achievement at once, use [claim_ft_rewards].",
+        "",
+        "Marks the [PlayerAchievement] as `claimed`, which only records that at least one of",
+        "the achievement's rewards was claimed.",
+        "",
+        "Relevant `ONLY` if an FT reward is specified for that achievement. The user's associated",
+        "token account is created if it doesn't exist yet.",
+        "",
+        "For Token-2022 mints with a transfer hook, the accounts the hook requires are passed",
+        "as remaining accounts."
+      ];
+      accounts: [
+        {
+          name: "claim";
+          accounts: [
+            {
+              name: "user";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "payer";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "game";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "achievement";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "reward";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "playerAccount";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "playerAchievement";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardClaim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardTokenMint";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "rewardVault";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "userTokenAccount";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "tokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "associatedTokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "systemProgram";
+              isMut: false;
+              isSigner: false;
+            }
+          ];
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimFtRewards";
+      docs: [
+        "Pay out several of an [Achievement]'s fungible token [Reward]s to a player who has",
+        "already unlocked it, in one instruction.",
+        "",
+        "Each reward is passed as a group of remaining accounts: the reward (mut), its",
+        "[RewardClaim] (mut), its mint, its vault (mut) and the user's token account for the",
+        "mint (mut), which must already exist. All rewards must use `token_program`.",
+        "",
+        "Mints with a transfer hook aren't supported here and are claimed with",
+        "[claim_ft_reward] instead."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimNftReward";
+      docs: [
+        "Mint one of an [Achievement]'s NFT [Reward]s to a player who has already unlocked it.",
+        "",
+        "Signed by either one of the [Game]'s authorities or the player themselves.",
+        "",
+        "Each reward can be claimed once per player, tracked by a [RewardClaim] account.",
+        "",
+        "Relevant `ONLY` if an NFT reward is specified for that achievement."
+      ];
+      accounts: [
+        {
+          name: "claim";
+          accounts: [
+            {
+              name: "user";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "payer";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "game";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "achievement";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "reward";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "playerAccount";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "playerAchievement";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardClaim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "claim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "newMint";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "newMetadata";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "newMasterEdition";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "mintTo";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "tokenMetadataProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "associatedTokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "systemProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "tokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "rent";
+              isMut: false;
+              isSigner: false;
+            }
+          ];
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unlockAndClaimFtReward";
+      docs: [
+        "Same as [claim_ft_reward], but first unlocks the achievement for the player if they",
+        "haven't unlocked it yet."
+      ];
+      accounts: [
+        {
+          name: "claim";
+          accounts: [
+            {
+              name: "user";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "payer";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "game";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "achievement";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "reward";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "playerAccount";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "playerAchievement";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardClaim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardTokenMint";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "rewardVault";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "userTokenAccount";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "tokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "associatedTokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "systemProgram";
+              isMut: false;
+              isSigner: false;
+            }
+          ];
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unlockAndClaimNftReward";
+      docs: [
+        "Same as [claim_nft_reward], but first unlocks the achievement for the player if they",
+        "haven't unlocked it yet."
+      ];
+      accounts: [
+        {
+          name: "claim";
+          accounts: [
+            {
+              name: "user";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "payer";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "game";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "achievement";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "reward";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "playerAccount";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "playerAchievement";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "rewardClaim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "claim";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "newMint";
+              isMut: true;
+              isSigner: true;
+            },
+            {
+              name: "newMetadata";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "newMasterEdition";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "mintTo";
+              isMut: true;
+              isSigner: false;
+            },
+            {
+              name: "tokenMetadataProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "associatedTokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "systemProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "tokenProgram";
+              isMut: false;
+              isSigner: false;
+            },
+            {
+              name: "rent";
+              isMut: false;
+              isSigner: false;
+            }
+          ];
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "verifyNftReward";
+      docs: [
+        "Verify NFT reward as belonging to a particular collection.",
+        "",
+        "Optional: Only relevant if an NFT reward is specified and the reward's",
+        "`collection_mint` is Some(...)"
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadataToVerify";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "collectionMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionEdition";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "addFtRankReward";
+      docs: [
+        "Add a fungible token [RankReward] to a [LeaderBoard], paid out to players who finish",
+        "within a range of ranks in its [LeaderTopEntries].",
+        "",
+        "`deposit` tokens are moved into a vault owned by the leaderboard PDA, which claims are",
+        "paid from. On seasonal leaderboards the reward names the season it pays out for;",
+        "otherwise the leaderboard must have an end time."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "newReward";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccountOwner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddRankRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "addNftRankReward";
+      docs: [
+        "Add a nft [RankReward] to a [LeaderBoard], minted to players who finish within a range",
+        "of ranks in its [LeaderTopEntries].",
+        "",
+        "As with [add_ft_rank_reward], the reward names its season on seasonal leaderboards."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "newReward";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddRankRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "claimFtRankReward";
+      docs: [
+        "Claim an FT [RankReward] for the player holding `rank` in a closed [LeaderBoard], or",
+        "in the [SeasonTopEntries] archive of the reward's season.",
+        "",
+        "Ranks start from `1`. Each player can claim a reward once. For Token-2022 mints with a",
+        "transfer hook, the accounts the hook requires are passed as remaining accounts."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "seasonTopEntries";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rankReward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rank";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "claimNftRankReward";
+      docs: [
+        "Claim an NFT [RankReward] for the player holding `rank` in a closed [LeaderBoard], or",
+        "in the [SeasonTopEntries] archive of the reward's season.",
+        "",
+        "Ranks start from `1`. Each player can claim a reward once."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "seasonTopEntries";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rankReward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newMint";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "newMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newMasterEdition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintTo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rank";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "closeGame";
+      docs: [
+        "Close a [Game] account, sending its lamports to `receiver`.",
+        "",
+        "Fails if any of the game's leaderboards or achievements are still open. Leaves a",
+        "[ClosedGame] account behind, so the game's address can't be initialized again."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "closedGame";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeLeaderboard";
+      docs: [
+        "Close a [LeaderBoard] along with its [LeaderTopEntries] if it has one.",
+        "",
+        "Fails while any of the leaderboard's [RankReward]s or [SeasonTopEntries] are still",
+        "open, as they can't be closed without it."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeSeasonTopEntries";
+      docs: ["Close an archived [SeasonTopEntries] account."];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeAchievement";
+      docs: [
+        "Close an [Achievement]. Fails if it still has a [Reward] attached."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeReward";
+      docs: [
+        "Close a [Reward], detaching it from its [Achievement].",
+        "",
+        "For FT rewards, the reward's vault must be emptied first and is closed along with it.",
+        "For mints with transfer fees, fees withheld in the vault must be harvested too.",
+        "Close the reward's [NftClaim]s first, as they can't be closed without it."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeRankReward";
+      docs: [
+        "Close a [RankReward]. For FT rewards, whatever is left in the reward's vault is",
+        "refunded to `destination_token_account` and the vault is closed along with it."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rankReward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "destinationTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closePlayer";
+      docs: ["Close a user's [Player] account."];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closePlayerScores";
+      docs: ["Close a user's [PlayerScoresList]."];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closePlayerAchievement";
+      docs: [
+        "Close a user's [PlayerAchievement].",
+        "",
+        "Claimed achievements can only be closed once the [Achievement] itself is closed, so",
+        "the record that the player claimed from it lasts as long as the achievement."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeMerged";
+      docs: [
+        "Close a [Merged] account. Only the merge initiator can close it."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "mergeAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeNftClaim";
+      docs: [
+        "Close an [NftClaim], after which the minted nft can no longer be verified."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeRewardClaim";
+      docs: ["Close a user's [RewardClaim] once its [Reward] is closed."];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeRankRewardClaim";
+      docs: [
+        "Close a user's [RankRewardClaim] once its [RankReward] is closed."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rankReward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateGame";
+      docs: [
+        "Convert a [Game] created under the legacy layout, reallocating it. Its authorities keep",
+        "every role, and the signing authority, which must be one of them, becomes its owner."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateLeaderboard";
+      docs: [
+        "Convert a [LeaderBoard] created under the legacy layout, along with its",
+        "[LeaderTopEntries], reallocating both. The leaderboard takes its order from its top",
+        "entries, and its newer settings are left at their defaults."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migratePlayerScores";
+      docs: [
+        "Convert a [PlayerScoresList] created under the legacy layout, reallocating it for its",
+        "larger score entries."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateAchievement";
+      docs: [
+        "Convert an [Achievement] created under the legacy layout, reallocating it to hold",
+        "several rewards. Its reward, if any, becomes the first of them."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateFtReward";
+      docs: [
+        "Move a fungible token [Reward] created under the legacy layout to a vault.",
+        "",
+        "Legacy rewards were paid from a token account the [Achievement] was approved to spend",
+        "from. What the remaining spots are owed is moved from it into a new vault, as far as",
+        "the approval and balance cover, and the reward is paid from the vault from then on."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "legacyTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "achievement";
+      docs: [
+        "Represents an achievement(with optional rewards) for this game",
+        "that can be attained by players.",
+        "",
+        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
+        "",
+        "`id` is an incrementing index stored in the game account."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "game";
+            docs: [
+              "Public key of the game account this achievement is derived from."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "id";
+            docs: [
+              "The achievement_count of the game account when this account was",
+              "created, also used as a seed for its PDA."
+            ];
+            type: "u64";
+          },
+          {
+            name: "title";
+            docs: ["Achievement title."];
+            type: "string";
+          },
+          {
+            name: "description";
+            docs: ["Achievement description."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: [
+              "Public key of a nft metadata account describing this achievement."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "rewards";
+            docs: [
+              "Rewards given out to players for unlocking this achievement, each with its own spots."
+            ];
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "rewardCount";
+            docs: [
+              "Number of rewards ever added to this achievement. Also used to determine the u64 seed",
+              "for the next reward."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "authorityProposal";
+      docs: [
+        "A pending change to a [Game][super::Game]'s authority list, applied once the",
+        "proposed key accepts it.",
+        "",
+        'Seeds = `[b"authority-proposal", game.key().as_ref(), authority.key.as_ref()]`.'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "game";
+            docs: ["The game whose authority list is being changed."];
+            type: "publicKey";
+          },
+          {
+            name: "proposer";
+            docs: [
+              "The account that paid for this proposal, refunded when it's closed."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "proposedBy";
+            docs: [
+              "The admin or owner that made the proposal, who must still be allowed to make it when",
+              "it's accepted."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            docs: ["The proposed authority and the roles it will hold."];
+            type: {
+              defined: "Authority";
+            };
+          },
+          {
+            name: "replaces";
+            docs: [
+              "An existing authority to be replaced by the proposed one. The proposed authority",
+              "is added alongside the existing ones if [None]."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "unlockTime";
+            docs: ["Unix timestamp from which the proposal can be accepted."];
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "game";
+      docs: [
+        "An account representing a single game.",
+        "",
+        'PDA with seeds = `[b"game", creator.key().as_ref(), &nonce.to_le_bytes()]`, where `nonce`',
+        "tells apart games by the same creator. Games created before seeded addresses live at",
+        "keypair addresses, which every other instruction still accepts."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "meta";
+            docs: ["Game meta-information."];
+            type: {
+              defined: "GameAttributes";
+            };
+          },
+          {
+            name: "leaderboardCount";
+            docs: [
+              "Number of leaderboards this game has created. Used both",
+              "in determining the most recent leaderboard address, and",
+              "as a seed for the next leaderboard."
+            ];
+            type: "u64";
+          },
+          {
+            name: "achievementCount";
+            docs: [
+              "Number of achievements that exist for this game. Also",
+              "used to determine the u64 seed for the next achievement."
+            ];
+            type: "u64";
+          },
+          {
+            name: "liveLeaderboards";
+            docs: [
+              "Number of this game's leaderboards that haven't been closed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "liveAchievements";
+            docs: [
+              "Number of this game's achievements that haven't been closed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "owner";
+            docs: [
+              "The key that alone can grant or revoke the admin role, and transfer ownership."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "pendingOwner";
+            docs: [
+              "The key ownership is being transferred to, once it accepts."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "paused";
+            docs: [
+              "Whether score submissions, registrations, unlocks and reward claims are suspended."
+            ];
+            type: "bool";
+          },
+          {
+            name: "auth";
+            docs: [
+              "The game's authorities, each limited to the roles it was granted."
+            ];
+            type: {
+              vec: {
+                defined: "Authority";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "closedGame";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "leaderBoard";
+      docs: [
+        "Represents a [Game][super::Game]'s leaderboard.",
+        "",
+        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            docs: [
+              "The leaderboard's id, used in deriving its address from the game."
+            ];
+            type: "u64";
+          },
+          {
+            name: "game";
+            docs: ["The game this leaderboard belongs to and is derived from."];
+            type: "publicKey";
+          },
+          {
+            name: "description";
+            docs: ["Leaderboard description."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: [
+              "Pubkey of an nft metadata account that describes this leaderboard."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "decimals";
+            docs: ["Used to contextualize scores for this leaderboard."];
+            type: "u8";
+          },
+          {
+            name: "minScore";
+            docs: ["Minimum possible score for this leaderboard."];
+            type: "u64";
+          },
+          {
+            name: "maxScore";
+            docs: ["Maximum possible score for this leaderboard."];
+            type: "u64";
+          },
+          {
+            name: "topEntries";
+            docs: ["Top [entries](ScoreEntry) for a leaderboard."];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "allowMultipleScores";
+            docs: [
+              "Whether or not multiple scores are allowed for a single player."
+            ];
+            type: "bool";
+          },
+          {
+            name: "startTime";
+            docs: ["Unix timestamp before which scores are rejected."];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "endTime";
+            docs: ["Unix timestamp from which scores are rejected."];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "seasonLength";
+            docs: [
+              "Length of a recurring season in seconds, counted from `start_time`."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "isAscending";
+            docs: [
+              "Order by which scores are ranked. `true` for ascending, `false` for descending."
+            ];
+            type: "bool";
+          },
+          {
+            name: "retention";
+            docs: [
+              "Which of a player's scores are kept in their [PlayerScoresList][super::PlayerScoresList]."
+            ];
+            type: {
+              defined: "ScoreRetention";
+            };
+          },
+          {
+            name: "tieBreak";
+            docs: ["How equal scores are ranked against each other."];
+            type: {
+              defined: "TieBreak";
+            };
+          },
+          {
+            name: "paused";
+            docs: [
+              "Whether score submissions and registrations are suspended."
+            ];
+            type: "bool";
+          },
+          {
+            name: "liveRankRewards";
+            docs: [
+              "Number of this leaderboard's [RankReward][super::RankReward]s that haven't been closed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "liveArchives";
+            docs: [
+              "Number of this leaderboard's [SeasonTopEntries][super::SeasonTopEntries] archives that",
+              "haven't been closed."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "merged";
+      docs: [
+        "An account that represents a single user's ownership of",
+        "multiple [Player][super::Player] accounts.",
+        "",
+        'PDA with seeds = `[b"merged", initiator.as_ref(), &nonce.to_le_bytes()]`, where `nonce`',
+        "tells apart merges by the same user. Only `initiate_merge` derives the address, so",
+        "merges already open at keypair addresses can still be approved and carried out."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "initiator";
+            docs: ["The user that initialized this merge."];
+            type: "publicKey";
+          },
+          {
+            name: "approvals";
+            docs: [
+              "Details of all the player accounts to be merged with the main_user's."
+            ];
+            type: {
+              vec: {
+                defined: "MergeApproval";
+              };
+            };
+          },
+          {
+            name: "mergeComplete";
+            docs: [
+              "Set to true when every user in `others` has registered their approval."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "playerAchievement";
+      docs: [
+        "Represents a player's status for a particular [Achievement](super::Achievement).",
+        "",
+        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The user's [player][super::Player] account."];
+            type: "publicKey";
+          },
+          {
+            name: "achievement";
+            docs: ["The key of the achievement unlocked for this player."];
+            type: "publicKey";
+          },
+          {
+            name: "timestamp";
+            docs: ["Timestamp showing when this achievement was unlocked."];
+            type: "i64";
+          },
+          {
+            name: "unlocked";
+            docs: [
+              "A player's unlock status for this achievement.",
+              "",
+              "Cleared, with `claimed` set, once merged into another player's account. See",
+              "[PlayerAchievement::is_merged]."
+            ];
+            type: "bool";
+          },
+          {
+            name: "claimed";
+            docs: [
+              "Whether this player has claimed any of the achievement's rewards.",
+              "",
+              "Set by the first claim, so with several rewards it doesn't mean all of them were",
+              "claimed. Which rewards were claimed is recorded by their",
+              "[RewardClaim](super::RewardClaim) accounts, which also keep each from being claimed",
+              "twice."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "playerBan";
+      docs: [
+        "Bars a [Player][super::Player] from registering for or submitting scores to a",
+        "[LeaderBoard][super::LeaderBoard] for as long as it exists.",
+        "",
+        'Seeds: `[b"player-ban", leaderboard.key().as_ref(), player_account.key().as_ref()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The banned player."];
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard the player is banned from."];
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "playerScoresList";
+      docs: [
+        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
+        "for a particular [LeaderBoard].",
+        "",
+        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: [
+              "The player[super::Player] account this entry is derived from"
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            docs: ["The id of the specific leaderboard."];
+            type: "publicKey";
+          },
+          {
+            name: "allocCount";
+            docs: [
+              "Max number of [scores][ScoreEntry] the current space allocation supports."
+            ];
+            type: "u16";
+          },
+          {
+            name: "submissionCount";
+            docs: [
+              "Number of scores ever submitted to this list, including removed ones."
+            ];
+            type: "u64";
+          },
+          {
+            name: "createdAt";
+            docs: [
+              "Unix timestamp the list was registered at. Score attestations issued no later than",
+              "this can't be submitted to it."
+            ];
+            type: "i64";
+          },
+          {
+            name: "topEntriesGeneration";
+            docs: [
+              "The [LeaderTopEntries][super::LeaderTopEntries] generation `top_entry` was recorded",
+              "in, or `0` if it never was."
+            ];
+            type: "u32";
+          },
+          {
+            name: "topEntry";
+            docs: [
+              "The player's best entry in the leaderboard's top entries as of",
+              "`top_entries_generation`. The default entry if the player had none."
+            ];
+            type: {
+              defined: "ScoreEntry";
+            };
+          },
+          {
+            name: "scores";
+            docs: ["Collection of [scores][ScoreEntry]."];
+            type: {
+              vec: {
+                defined: "ScoreEntry";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "player";
+      docs: [
+        "An account representing a player.",
+        "",
+        'Seeds: `[b"player", user.key().as_ref()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "user";
+            docs: ["The wallet that owns this player-info account"];
+            type: "publicKey";
+          },
+          {
+            name: "username";
+            docs: ["The player's username."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: ["Metadata to represent this player."];
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "nftClaim";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "reward";
+      docs: [
+        "An account representing a reward for a given achievement.",
+        "",
+        'PDA with seeds = `[b"reward", achievement.key().as_ref(), &index.to_le_bytes()]`, where',
+        "`index` is the 1-based position of the reward in the order rewards were added to the",
+        "achievement, i.e. its [reward_count](super::Achievement::reward_count) after adding it.",
+        "Older rewards at keypair addresses keep working, as only `add_*_reward` checks the seeds.",
+        "A migrated achievement counts its legacy reward, so its first derived reward has index 2."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "achievement";
+            docs: ["The achievement this reward is given for."];
+            type: "publicKey";
+          },
+          {
+            name: "availableSpots";
+            docs: ["Number of available reward spots."];
+            type: "u64";
+          },
+          {
+            name: "reward";
+            docs: [
+              "The reward kind. Current supports Nft and Ft rewards only."
+            ];
+            type: {
+              defined: "RewardKind";
+            };
+          },
+          {
+            name: "paused";
+            docs: ["Whether claims are currently suspended."];
+            type: "bool";
+          },
+          {
+            name: "cancelled";
+            docs: [
+              "Whether the reward was cancelled. Unlike a reward whose spots ran out, a cancelled",
+              "reward can't be updated or topped up again."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "rankReward";
+      docs: [
+        "An account representing a reward given out to players that finish within a range of",
+        "ranks on a leaderboard.",
+        "",
+        "Fungible token rank rewards are paid out of a vault owned by the leaderboard, at",
+        'seeds = `[b"reward-vault", rank_reward.key().as_ref()]`.'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard this reward is given for."];
+            type: "publicKey";
+          },
+          {
+            name: "minRank";
+            docs: ["Highest rank eligible for this reward, starting from `1`."];
+            type: "u32";
+          },
+          {
+            name: "maxRank";
+            docs: ["Lowest rank eligible for this reward, inclusive."];
+            type: "u32";
+          },
+          {
+            name: "season";
+            docs: [
+              "The season whose [SeasonTopEntries][super::SeasonTopEntries] archive decides the",
+              "ranks, on seasonal leaderboards. Otherwise ranks are read from the leaderboard's",
+              "[LeaderTopEntries][super::LeaderTopEntries] once it closes."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "reward";
+            docs: [
+              "The reward kind. Current supports Nft and Ft rewards only."
+            ];
+            type: {
+              defined: "RewardKind";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "rewardClaim";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "rankRewardClaim";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The player account that claimed the reward."];
+            type: "publicKey";
+          },
+          {
+            name: "rank";
+            docs: ["The rank the reward was claimed for."];
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "leaderTopEntries";
+      docs: [
+        "Keeps track of a sorted list of top scores for a leaderboard.",
+        "",
+        "Zero-copy: this header is followed in the account data by `capacity` [LeaderBoardScore]",
+        "slots, sorted from best to worst under the [LeaderBoard]'s order and tie-break policy.",
+        "Use [LeaderTopEntries::load_scores] and [LeaderTopEntries::load_scores_mut] to access them.",
+        "",
+        'Seeds = [b"top-scores", leaderboard.key().as_ref()]'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "season";
+            docs: ["The leaderboard season these top scores are for."];
+            type: "u64";
+          },
+          {
+            name: "scoresToRetain";
+            docs: ["Number of top scores to keep once fully allocated."];
+            type: "u16";
+          },
+          {
+            name: "capacity";
+            docs: ["Number of score slots currently allocated."];
+            type: "u16";
+          },
+          {
+            name: "generation";
+            docs: [
+              "Bumped whenever entries are moved other than by inserting a submitted score, which",
+              "invalidates the top entry recorded in each [PlayerScoresList][super::PlayerScoresList].",
+              "Never `0`, which lists use before recording one."
+            ];
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "seasonTopEntries";
+      docs: [
+        "A snapshot of a [LeaderTopEntries] account taken at the end of a leaderboard season.",
+        "",
+        'Seeds = [b"season-top-scores", leaderboard.key().as_ref(), &season.to_le_bytes()]'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard these top scores were recorded for."];
+            type: "publicKey";
+          },
+          {
+            name: "season";
+            docs: ["The archived season."];
+            type: "u64";
+          },
+          {
+            name: "isAscending";
+            docs: ["Arrangement order."];
+            type: "bool";
+          },
+          {
+            name: "topScores";
+            docs: ["Top scores at the end of the season."];
+            type: {
+              vec: {
+                defined: "LeaderBoardScore";
+              };
+            };
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "ScoreAttestation";
+      docs: [
+        "The message a [Game][super::Game] authority signs to let a player submit a score",
+        "on their own.",
+        "",
+        "Signed as its [message][ScoreAttestation::message] and verified through an ed25519",
+        "program instruction placed right before the submission in the same transaction."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The [Player][super::Player] account the score is for."];
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            docs: [
+              "The [LeaderBoard][super::LeaderBoard] the score is submitted to."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "score";
+            docs: ["The attested score."];
+            type: "u64";
+          },
+          {
+            name: "tiebreak";
+            docs: ["The attested secondary value used to rank equal scores."];
+            type: "u64";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Must equal the `submission_count` of the player's",
+              "[PlayerScoresList][super::PlayerScoresList] when submitted, so the attestation",
+              "can't be replayed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "issuedAt";
+            docs: [
+              "Unix timestamp the attestation was issued at. Must be later than the list's",
+              "`created_at`, so the attestation can't be replayed once the list is closed and",
+              "registered again with its `submission_count` starting over."
+            ];
+            type: "i64";
+          },
+          {
+            name: "expiry";
+            docs: [
+              "Unix timestamp after which the attestation is no longer valid."
+            ];
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ClaimVoucher";
+      docs: [
+        "The message a [Game][super::Game] authority signs to let a player unlock an",
+        "[Achievement][super::Achievement] and claim one of its [Reward][super::Reward]s on their",
+        "own.",
+        "",
+        "Signed as its [message][ClaimVoucher::message] and verified through an ed25519 program",
+        "instruction placed right before the redemption in the same transaction. Redeeming it",
+        "claims the reward, so the reward's [RewardClaim][super::RewardClaim] keeps it from being",
+        "redeemed twice."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The [Player][super::Player] account the voucher is for."];
+            type: "publicKey";
+          },
+          {
+            name: "achievement";
+            docs: ["The achievement the player is allowed to unlock."];
+            type: "publicKey";
+          },
+          {
+            name: "reward";
+            docs: [
+              "The reward of `achievement` the player is allowed to claim."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "expiry";
+            docs: [
+              "Unix timestamp after which the voucher is no longer valid."
+            ];
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Authority";
+      docs: [
+        "A [Game] authority and the roles it was granted, as a bitmask of [Authority] role flags."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "key";
+            docs: ["The authority's pubkey."];
+            type: "publicKey";
+          },
+          {
+            name: "roles";
+            docs: ["The roles granted to `key`."];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "GameAttributes";
+      docs: ["A type that represents game-specific information."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "title";
+            docs: ["The title of the game, max length = 30 bytes."];
+            type: "string";
+          },
+          {
+            name: "description";
+            docs: ["The game description, max length = 200 bytes."];
+            type: "string";
+          },
+          {
+            name: "genre";
+            docs: ["The game's [genre](super::Genre), as a u8."];
+            type: "u8";
+          },
+          {
+            name: "gameType";
+            docs: ["The game's [type](super::GameType), as a u8."];
+            type: "u8";
+          },
+          {
+            name: "nftMeta";
+            docs: ["An nft metadata account describing the game."];
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "ScoreEntry";
+      docs: ["A single score entry for a player."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "score";
+            docs: ["The player's score."];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            docs: ["When this entry was made."];
+            type: "i64";
+          },
+          {
+            name: "tiebreak";
+            docs: [
+              "Secondary value used to rank equal scores under [TieBreak::HighestValue] and",
+              "[TieBreak::LowestValue]."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyScoreEntry";
+      docs: ["A [ScoreEntry] without a tiebreak value."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "score";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyLeaderBoard";
+      docs: [
+        "A [LeaderBoard] without seasons, ranking rules, retention or live account counts. Its",
+        "order was held by its [LegacyLeaderTopEntries]."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "game";
+            type: "publicKey";
+          },
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            type: "publicKey";
+          },
+          {
+            name: "decimals";
+            type: "u8";
+          },
+          {
+            name: "minScore";
+            type: "u64";
+          },
+          {
+            name: "maxScore";
+            type: "u64";
+          },
+          {
+            name: "topEntries";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "allowMultipleScores";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyLeaderBoardScore";
+      docs: ["A [LeaderBoardScore] holding a [LegacyScoreEntry]."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "player";
+            type: "publicKey";
+          },
+          {
+            name: "entry";
+            type: {
+              defined: "LegacyScoreEntry";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyLeaderTopEntries";
+      docs: [
+        "A borsh-serialized [LeaderTopEntries][super::LeaderTopEntries] holding only the scores",
+        "submitted so far, and the leaderboard's order."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "isAscending";
+            type: "bool";
+          },
+          {
+            name: "topScores";
+            type: {
+              vec: {
+                defined: "LegacyLeaderBoardScore";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyPlayerScoresList";
+      docs: [
+        "A [PlayerScoresList] of [LegacyScoreEntry]s, without a submission count or recorded top",
+        "entry."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            type: "publicKey";
+          },
+          {
+            name: "allocCount";
+            type: "u16";
+          },
+          {
+            name: "scores";
+            type: {
+              vec: {
+                defined: "LegacyScoreEntry";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyAchievement";
+      docs: [
+        "An [Achievement] with at most one reward, created from a keypair rather than derived",
+        "from the achievement."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "game";
+            type: "publicKey";
+          },
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            type: "publicKey";
+          },
+          {
+            name: "reward";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyGame";
+      docs: [
+        "A [Game] whose authorities all held every role, without an owner, pause flag or live",
+        "account counts."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "meta";
+            type: {
+              defined: "GameAttributes";
+            };
+          },
+          {
+            name: "leaderboardCount";
+            type: "u64";
+          },
+          {
+            name: "achievementCount";
+            type: "u64";
+          },
+          {
+            name: "auth";
+            type: {
+              vec: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "MergeApproval";
+      docs: [
+        "Represents a [Player][super::Player] account involved in a merge",
+        "and if that account's user/authority has granted approval."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "key";
+            docs: ["The player_account pubkey."];
+            type: "publicKey";
+          },
+          {
+            name: "approved";
+            docs: ["User's approval status."];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "LeaderBoardScore";
+      docs: ["An single entry to a [LeaderTopEntries]."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "player";
+            docs: ["The player"];
+            type: "publicKey";
+          },
+          {
+            name: "entry";
+            docs: ["The user's [score][super::ScoreEntry]."];
+            type: {
+              defined: "ScoreEntry";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "RegisterLeaderBoardInput";
+      docs: ["Parameters needed when registering a leaderboard."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "description";
+            docs: ["Leaderboard description."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: ["Nft metadata representing the leaderboard."];
+            type: "publicKey";
+          },
+          {
+            name: "decimals";
+            docs: [
+              "Specify the decimals score values are represented in. Defaults to `0` if [None]."
+            ];
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "minScore";
+            docs: [
+              "Specifies minimum allowed score. Defaults to `u64::MIN` if [None]."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxScore";
+            docs: [
+              "Specifies maximum allowed score. Defaults to `u64::MAX` if [None]."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "scoresToRetain";
+            docs: [
+              "Number of top scores to store on-chain.",
+              "",
+              "Lists too large to allocate on creation are filled in with `grow_top_entries`."
+            ];
+            type: "u16";
+          },
+          {
+            name: "isAscending";
+            docs: [
+              "Order by which scores are stored. `true` for ascending, `false` for descending."
+            ];
+            type: "bool";
+          },
+          {
+            name: "allowMultipleScores";
+            docs: [
+              "Whether or not multiple scores are kept in the leaderboard for a single player."
+            ];
+            type: "bool";
+          },
+          {
+            name: "startTime";
+            docs: [
+              "Unix timestamp before which scores are rejected. Open from creation if [None]."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "endTime";
+            docs: [
+              "Unix timestamp from which scores are rejected. Never closes if [None]."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "seasonLength";
+            docs: [
+              "Length of a recurring season in seconds, counted from `start_time`. The top entries",
+              "are reset at the start of every season. Requires `start_time` to be set."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "retention";
+            docs: [
+              "Which of a player's scores are kept. Defaults to keeping every score."
+            ];
+            type: {
+              defined: "ScoreRetention";
+            };
+          },
+          {
+            name: "tieBreak";
+            docs: [
+              "How equal scores are ranked against each other. Defaults to the earliest score",
+              "ranking higher."
+            ];
+            type: {
+              defined: "TieBreak";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AddNewRewardInput";
+      docs: ["Input to add a new reward for an achievement."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "availableSpots";
+            docs: ["Number of rewards to be given out."];
+            type: "u64";
+          },
+          {
+            name: "kind";
+            docs: ["Specific reward kind."];
+            type: {
+              defined: "RewardKindInput";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AddRankRewardInput";
+      docs: [
+        "Input to add a new reward for a range of ranks on a leaderboard."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "minRank";
+            docs: ["Highest rank eligible for the reward, starting from `1`."];
+            type: "u32";
+          },
+          {
+            name: "maxRank";
+            docs: ["Lowest rank eligible for the reward, inclusive."];
+            type: "u32";
+          },
+          {
+            name: "season";
+            docs: [
+              "The season whose archived top entries decide the ranks. Required on seasonal",
+              "leaderboards, and must be [None] otherwise."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "kind";
+            docs: ["Specific reward kind."];
+            type: {
+              defined: "RewardKindInput";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "GameType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Mobile";
+          },
+          {
+            name: "Desktop";
+          },
+          {
+            name: "Web";
+          },
+          {
+            name: "Unspecified";
+          }
+        ];
+      };
+    },
+    {
+      name: "Genre";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Rpg";
+          },
+          {
+            name: "Mmo";
+          },
+          {
+            name: "Action";
+          },
+          {
+            name: "Adventure";
+          },
+          {
+            name: "Puzzle";
+          },
+          {
+            name: "Casual";
+          },
+          {
+            name: "Unspecified";
+          }
+        ];
+      };
+    },
+    {
+      name: "ScoreRetention";
+      docs: [
+        "Policy for the scores a [PlayerScoresList][super::PlayerScoresList] keeps once it",
+        "holds a set number of entries."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unbounded";
+          },
+          {
+            name: "KeepLatest";
+            fields: ["u16"];
+          },
+          {
+            name: "KeepBest";
+            fields: ["u16"];
+          },
+          {
+            name: "BestOnly";
+          }
+        ];
+      };
+    },
+    {
+      name: "TieBreak";
+      docs: ["Policy for ranking equal scores."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "EarliestFirst";
+          },
+          {
+            name: "LatestFirst";
+          },
+          {
+            name: "HighestValue";
+          },
+          {
+            name: "LowestValue";
+          }
+        ];
+      };
+    },
+    {
+      name: "RewardKind";
+      docs: ["The kind of reward to be given out."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "FungibleToken";
+            fields: [
+              {
+                name: "mint";
+                docs: ["The mint of the token to be given out."];
+                type: "publicKey";
+              },
+              {
+                name: "account";
+                docs: ["The token account to withdraw from."];
+                type: "publicKey";
+              },
+              {
+                name: "amount";
+                docs: ["Reward amount per user."];
+                type: "u64";
+              },
+              {
+                name: "tokenProgram";
+                docs: [
+                  "The token program `mint` belongs to, either SPL Token or Token-2022."
+                ];
+                type: "publicKey";
+              }
+            ];
+          },
+          {
+            name: "NonFungibleToken";
+            fields: [
+              {
+                name: "uri";
+                docs: ["URI of the NFT to be minted."];
+                type: "string";
+              },
+              {
+                name: "name";
+                docs: ["Name of the NFT to be minted."];
+                type: "string";
+              },
+              {
+                name: "symbol";
+                docs: ["Symbol of the NFT to be minted."];
+                type: "string";
+              },
+              {
+                name: "minted";
+                docs: ["Total NFTs minted so far."];
+                type: "u64";
+              },
+              {
+                name: "collection";
+                docs: [
+                  "Optional field for a collection mint used for",
+                  "verifying minted rewards."
+                ];
+                type: {
+                  option: "publicKey";
+                };
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "RewardKindInput";
+      docs: ["Specific variant of [AddNewRewardInput]."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Ft";
+            fields: [
+              {
+                name: "deposit";
+                docs: [
+                  "Amount to be delegated to this program's PDA",
+                  "so it can spend for reward claims."
+                ];
+                type: "u64";
+              },
+              {
+                name: "amount";
+                docs: ["Amount given to a single user."];
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "Nft";
+            fields: [
+              {
+                name: "uri";
+                docs: ["Uri of the minted nft."];
+                type: "string";
+              },
+              {
+                name: "name";
+                docs: ["Name of the minted nft."];
+                type: "string";
+              },
+              {
+                name: "symbol";
+                docs: ["Symbol of the minted nft."];
+                type: "string";
+              }
+            ];
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "GameInitialized";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "auth";
+          type: {
+            vec: {
+              defined: "Authority";
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GameUpdated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "auth";
+          type: {
+            vec: {
+              defined: "Authority";
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GamePauseUpdated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "paused";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AuthorityProposed";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: {
+            defined: "Authority";
+          };
+          index: false;
+        },
+        {
+          name: "replaces";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "unlockTime";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AuthorityAccepted";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: {
+            defined: "Authority";
+          };
+          index: false;
+        },
+        {
+          name: "replaced";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AuthorityProposalCancelled";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "OwnershipTransferProposed";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "pendingOwner";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "OwnershipTransferred";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousOwner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementAdded";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "id";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementUpdated";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderboardAdded";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "id";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderboardUpdated";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderboardPauseUpdated";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "paused";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SeasonArchived";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "season";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "archive";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesGrown";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "capacity";
+          type: "u16";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesRebuilt";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lists";
+          type: "u32";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesAdded";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "scoresToRetain";
+          type: "u16";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesResized";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "scoresToRetain";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "capacity";
+          type: "u16";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerInitialized";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerUpdated";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerRegistered";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerScores";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ScoreSubmitted";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "entry";
+          type: {
+            defined: "ScoreEntry";
+          };
+          index: false;
+        },
+        {
+          name: "oldRank";
+          type: {
+            option: "u32";
+          };
+          index: false;
+        },
+        {
+          name: "newRank";
+          type: {
+            option: "u32";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ScoreRemoved";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "entry";
+          type: {
+            defined: "ScoreEntry";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerEvicted";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "removed";
+          type: "u32";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerBanUpdated";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "banned";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeInitiated";
+      fields: [
+        {
+          name: "merged";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "initiator";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "keys";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeApproved";
+      fields: [
+        {
+          name: "merged";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mergeComplete";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerScoresMerged";
+      fields: [
+        {
+          name: "merged";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mergedPlayer";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerAchievementMerged";
+      fields: [
+        {
+          name: "merged";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mergedPlayer";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementUnlocked";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardAdded";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "availableSpots";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardFunded";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardWithdrawn";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "destination";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardUpdated";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "availableSpots";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardPauseUpdated";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "paused";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardCancelled";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "refunded";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FtRewardClaimed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "userTokenAccount";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "NftRewardClaimed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "NftRewardVerified";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RankRewardAdded";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "minRank";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "maxRank";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "season";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FtRankRewardClaimed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rank";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "NftRankRewardClaimed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rank";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GameClosed";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderboardClosed";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SeasonTopEntriesClosed";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "season";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementClosed";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardClosed";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RankRewardClosed";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerClosed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerScoresClosed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerAchievementClosed";
+      fields: [
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergedClosed";
+      fields: [
+        {
+          name: "merged";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "NftClaimClosed";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardClaimClosed";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RankRewardClaimClosed";
+      fields: [
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "player";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AccountMigrated";
+      fields: [
+        {
+          name: "account";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidFieldLength";
+      msg: "Exceeded max length for field.";
+    },
+    {
+      code: 6001;
+      name: "InvalidAuthority";
+      msg: "Invalid authority for instruction";
+    },
+    {
+      code: 6002;
+      name: "MissingSignature";
+      msg: "An expected signature isn't present";
+    },
+    {
+      code: 6003;
+      name: "NoRewardForAchievement";
+      msg: "Reward not specified for this achievement";
+    },
+    {
+      code: 6004;
+      name: "AccountNotPartOfMerge";
+      msg: "The merge account does not include this player account";
+    },
+    {
+      code: 6005;
+      name: "ScoreNotWithinBounds";
+      msg: "Tried to input score that is below the minimum or above the maximum";
+    },
+    {
+      code: 6006;
+      name: "MissingExpectedAccount";
+      msg: "An optional but expected account is missing";
+    },
+    {
+      code: 6007;
+      name: "InvalidRewardKind";
+      msg: "Invalid reward kind for this instruction";
+    },
+    {
+      code: 6008;
+      name: "NoAvailableRewards";
+      msg: "No more rewards are being given out for this game";
+    },
+    {
+      code: 6009;
+      name: "MergeNotComplete";
+      msg: "Not all player accounts in this merge have approved it";
+    },
+    {
+      code: 6010;
+      name: "InvalidAttestation";
+      msg: "Missing or invalid ed25519 attestation";
+    },
+    {
+      code: 6011;
+      name: "AttestationExpired";
+      msg: "The attestation has expired";
+    },
+    {
+      code: 6012;
+      name: "ScoreOutsideWindow";
+      msg: "Score submitted outside of the leaderboard's active time window";
+    },
+    {
+      code: 6013;
+      name: "InvalidTimeWindow";
+      msg: "Invalid leaderboard start time, end time or season length";
+    },
+    {
+      code: 6014;
+      name: "SeasonNotOver";
+      msg: "The leaderboard's current season isn't over yet";
+    },
+    {
+      code: 6015;
+      name: "InvalidRankRange";
+      msg: "Invalid rank range for this leaderboard";
+    },
+    {
+      code: 6016;
+      name: "LeaderboardNotClosed";
+      msg: "The leaderboard hasn't closed yet";
+    },
+    {
+      code: 6017;
+      name: "RankNotEligible";
+      msg: "The player doesn't hold this rank or it isn't covered by the reward";
+    },
+    {
+      code: 6018;
+      name: "PlayerBanned";
+      msg: "The player is banned from this leaderboard";
+    },
+    {
+      code: 6019;
+      name: "ScoreNotFound";
+      msg: "No score exists at the given index";
+    },
+    {
+      code: 6020;
+      name: "AccountsStillLive";
+      msg: "The game still has leaderboards or achievements that haven't been closed";
+    },
+    {
+      code: 6021;
+      name: "RewardStillAttached";
+      msg: "The achievement still has a reward attached";
+    },
+    {
+      code: 6022;
+      name: "DelegationNotRevoked";
+      msg: "The reward's token delegation hasn't been revoked";
+    },
+    {
+      code: 6023;
+      name: "RewardStillClaimable";
+      msg: "This account still guards a reward that can be claimed";
+    },
+    {
+      code: 6024;
+      name: "InvalidRetention";
+      msg: "A bounded score retention policy must keep at least one score";
+    },
+    {
+      code: 6025;
+      name: "TopEntriesFullyAllocated";
+      msg: "The top entries account already holds all its scores";
+    },
+    {
+      code: 6026;
+      name: "InvalidTokenProgram";
+      msg: "The token program doesn't match the reward's mint";
+    },
+    {
+      code: 6027;
+      name: "NothingToWithdraw";
+      msg: "The reward vault holds no tokens beyond what its remaining spots are owed";
+    },
+    {
+      code: 6028;
+      name: "RewardVaultNotEmpty";
+      msg: "The reward vault still holds tokens";
+    },
+    {
+      code: 6029;
+      name: "RewardPaused";
+      msg: "Claims for this reward are paused";
+    },
+    {
+      code: 6030;
+      name: "TooManyRewards";
+      msg: "The achievement already has the maximum number of rewards";
+    },
+    {
+      code: 6031;
+      name: "AchievementNotUnlocked";
+      msg: "The player hasn't unlocked this achievement";
+    },
+    {
+      code: 6032;
+      name: "MissingAdmin";
+      msg: "A game must keep at least one authority with the admin role";
+    },
+    {
+      code: 6033;
+      name: "NotGameOwner";
+      msg: "Only the game's owner can grant or revoke the admin role";
+    },
+    {
+      code: 6034;
+      name: "UnconfirmedAuthority";
+      msg: "New authorities must accept a proposal before they are added";
+    },
+    {
+      code: 6035;
+      name: "AuthorityAlreadyExists";
+      msg: "The key is already one of the game's authorities";
+    },
+    {
+      code: 6036;
+      name: "AuthorityNotFound";
+      msg: "The key isn't one of the game's authorities";
+    },
+    {
+      code: 6037;
+      name: "TimelockActive";
+      msg: "The proposal's timelock hasn't expired yet";
+    },
+    {
+      code: 6038;
+      name: "InvalidTimelock";
+      msg: "The timelock can't be negative";
+    },
+    {
+      code: 6039;
+      name: "NoPendingOwner";
+      msg: "No ownership transfer to this key is pending";
+    },
+    {
+      code: 6040;
+      name: "GamePaused";
+      msg: "The game is paused";
+    },
+    {
+      code: 6041;
+      name: "LeaderboardPaused";
+      msg: "The leaderboard is paused";
+    },
+    {
+      code: 6042;
+      name: "InvalidPlayerScoresList";
+      msg: "The account isn't a scores list for this leaderboard";
+    },
+    {
+      code: 6043;
+      name: "TopEntriesAlreadyExist";
+      msg: "The leaderboard already has a top entries account";
+    },
+    {
+      code: 6044;
+      name: "InvalidScoresToRetain";
+      msg: "A top entries account must retain at least one score";
+    },
+    {
+      code: 6045;
+      name: "SeasonNotArchived";
+      msg: "The previous season's top entries must be archived first";
+    },
+    {
+      code: 6046;
+      name: "InvalidRankRewardSeason";
+      msg: "Rank rewards must name a season on seasonal leaderboards, and need an end time otherwise";
+    },
+    {
+      code: 6047;
+      name: "TopEntriesFrozen";
+      msg: "The leaderboard has closed and its top entries can no longer change";
+    },
+    {
+      code: 6048;
+      name: "LeaderboardAccountsStillLive";
+      msg: "The leaderboard still has rank rewards or season archives that haven't been closed";
+    },
+    {
+      code: 6049;
+      name: "TooManySeasonScores";
+      msg: "Seasonal leaderboards can't retain more top scores than a season archive holds";
+    },
+    {
+      code: 6050;
+      name: "NotLegacyAccount";
+      msg: "The account doesn't have a legacy layout";
+    },
+    {
+      code: 6051;
+      name: "RewardVaultUnderfunded";
+      msg: "The reward vault doesn't hold what the reward's remaining spots are owed";
+    },
+    {
+      code: 6052;
+      name: "PlayerAchievementMerged";
+      msg: "The player's achievement was merged into another player's";
+    },
+    {
+      code: 6053;
+      name: "CancelledReward";
+      msg: "The reward was cancelled";
+    },
+    {
+      code: 6054;
+      name: "GameClosed";
+      msg: "A game closed at this address can't be initialized again";
+    },
+    {
+      code: 6055;
+      name: "StaleAttestation";
+      msg: "The attestation was issued before the player's scores list was registered";
+    }
+  ];
+};
+
+export const IDL: Soar = {
+  version: "0.1.1",
+  name: "soar",
+  constants: [
+    {
+      name: "MAX_TITLE_LEN",
+      type: {
+        defined: "usize",
+      },
+      value: "30",
+    },
+    {
+      name: "MAX_DESCRIPTION_LEN",
+      type: {
+        defined: "usize",
+      },
+      value: "200",
+    },
+  ],
+  instructions: [
+    {
+      name: "initializeGame",
+      docs: [
+        "Initialize a new [Game] and register its [LeaderBoard].",
+        "",
+        "The game is derived from the `creator` and a `nonce` unique among their games. The",
+        "`creator` becomes the game's owner. A `nonce` whose game was closed can't be reused.",
+      ],
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closedGame",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "gameMeta",
+          type: {
+            defined: "GameAttributes",
+          },
+        },
+        {
+          name: "gameAuth",
+          type: {
+            vec: {
+              defined: "Authority",
+            },
+          },
+        },
+        {
+          name: "nonce",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "updateGame",
+      docs: [
+        "Update a [Game]'s meta-information or its authorities and their roles.",
+        "",
+        "`new_auth` can remove authorities or change their roles, but new keys are added with",
+        "`propose_authority`. Only the game's owner can change who holds the admin role.",
+        "Rent freed by shrinking the authority list is refunded to `payer`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newMeta",
+          type: {
+            option: {
+              defined: "GameAttributes",
+            },
+          },
+        },
+        {
+          name: "newAuth",
+          type: {
+            option: {
+              vec: {
+                defined: "Authority",
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "proposeAuthority",
+      docs: [
+        "Propose adding `authority` to a [Game], or replacing one of its existing authorities",
+        "with it.",
+        "",
+        "The change is applied once the proposed key accepts it, no earlier than `timelock`",
+        "seconds from now. Only the game's owner can propose changes involving the admin role.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "authority",
+          type: {
+            defined: "Authority",
+          },
+        },
+        {
+          name: "replaces",
+          type: {
+            option: "publicKey",
+          },
+        },
+        {
+          name: "timelock",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "acceptAuthority",
+      docs: [
+        "Accept an [AuthorityProposal] as its proposed key, applying it to the game's",
+        "authority list.",
+        "",
+        "Fails if the proposer is no longer an admin, or no longer the owner for changes",
+        "involving the admin role.",
+      ],
+      accounts: [
+        {
+          name: "newAuthority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "cancelAuthorityProposal",
+      docs: [
+        "Withdraw an [AuthorityProposal], either as a game admin or as the proposed key.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "transferGameOwnership",
+      docs: [
+        "Start transferring ownership of a [Game] to `new_owner`, or withdraw a pending",
+        "transfer if [None].",
+      ],
+      accounts: [
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newOwner",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "acceptGameOwnership",
+      docs: ["Accept ownership of a [Game] as its pending owner."],
+      accounts: [
+        {
+          name: "newOwner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setGamePaused",
+      docs: [
+        "Pause or resume a [Game].",
+        "",
+        "A paused game rejects score submissions, player registrations, achievement unlocks",
+        "and reward claims, while its authorities can still manage it.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "paused",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "addAchievement",
+      docs: [
+        "Add a new [Achievement] that can be attained for a particular [Game].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "title",
+          type: "string",
+        },
+        {
+          name: "description",
+          type: "string",
+        },
+        {
+          name: "nftMeta",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "updateAchievement",
+      docs: ["Update an [Achievement]'s meta information."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newTitle",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "newDescription",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "nftMeta",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "addLeaderboard",
+      docs: ["Overwrite the active [LeaderBoard] and set a newly created one."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "RegisterLeaderBoardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateLeaderboard",
+      docs: [
+        "Update's a leaderboard's description, nft metadata information, min/max score, whether",
+        "or not multiple scores are allowed for a single player, or its score retention policy.",
+        "",
+        "Changing the order or score bounds, or disallowing multiple scores, re-ranks the",
+        "leaderboard's [LeaderTopEntries], which must then be passed in.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newDescription",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "newNftMeta",
+          type: {
+            option: "publicKey",
+          },
+        },
+        {
+          name: "newMinScore",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "newMaxScore",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "newIsAscending",
+          type: {
+            option: "bool",
+          },
+        },
+        {
+          name: "newAllowMultipleScores",
+          type: {
+            option: "bool",
+          },
+        },
+        {
+          name: "newRetention",
+          type: {
+            option: {
+              defined: "ScoreRetention",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "setLeaderboardPaused",
+      docs: [
+        "Pause or resume score submissions and player registrations for a [LeaderBoard].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "paused",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "archiveSeason",
+      docs: [
+        "Snapshot a seasonal [LeaderBoard]'s [LeaderTopEntries] into a [SeasonTopEntries] account",
+        "once its season is over, and reset the top entries for the current season.",
+        "",
+        "Anyone can archive a season. Until then, scores submitted in a later season are",
+        "rejected so the finished season's standings can't be lost; a season that ended",
+        "without any entries needs no archive.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "growTopEntries",
+      docs: [
+        "Allocate more of a [LeaderTopEntries] account's slots, up to its `scores_to_retain`.",
+        "",
+        "Accounts can only be created or grown by about 10KB per instruction, so large top lists",
+        "start out partially allocated and rank only the slots allocated so far. Anyone can pay",
+        "to grow them.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "rebuildTopEntries",
+      docs: [
+        "Recompute a [LeaderBoard]'s [LeaderTopEntries] from the [PlayerScoresList] accounts",
+        "passed as remaining accounts, to repair a corrupted top list. Each list is followed by",
+        "the address of its player's [PlayerBan], and players banned from the leaderboard are",
+        "left out.",
+        "",
+        "The top entries are cleared first if `reset`, which also discards a finished season's",
+        "standings that haven't been archived. Lists are read in place, but a board with",
+        "many players still needs several transactions: reset in the first, then pass the rest",
+        "of the lists in batches.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "reset",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "addTopEntries",
+      docs: [
+        "Create a [LeaderTopEntries] account for a [LeaderBoard] that was added without one.",
+        "",
+        "Scores submitted before then can be ranked by passing their players' [PlayerScoresList]",
+        "accounts as remaining accounts, paired with their [PlayerBan] addresses as in",
+        "`rebuild_top_entries`, or later through `rebuild_top_entries` itself.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "scoresToRetain",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "resizeTopEntries",
+      docs: [
+        "Change the number of scores a [LeaderBoard]'s [LeaderTopEntries] retain, reallocating",
+        "the account.",
+        "",
+        "Shrinking drops the lowest-ranked entries and refunds the freed rent to the payer, and",
+        "makes the dropped ranks unclaimable for the leaderboard's [RankReward]s. New slots from",
+        "growing are filled from the [PlayerScoresList] accounts passed as remaining accounts,",
+        "paired with their [PlayerBan] addresses as in `rebuild_top_entries`; a list too large",
+        "to allocate at once keeps growing through `grow_top_entries`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "scoresToRetain",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "initializePlayer",
+      docs: ["Create a [Player] account for a particular user."],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "username",
+          type: "string",
+        },
+        {
+          name: "nftMeta",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "updatePlayer",
+      docs: ["Update the username or nft_meta for a [Player] account."],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "username",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "nftMeta",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "registerPlayer",
+      docs: [
+        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
+        "created [PlayerEntryList] account.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "newList",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerBan",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "submitScore",
+      docs: [
+        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
+        "Optionally increase the player's rank if needed.",
+        "",
+        "This instruction automatically resizes the [PlayerScoresList] account if needed.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerBan",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "score",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "submitScoreWithTiebreak",
+      docs: [
+        "Like [submit_score], with a secondary `tiebreak` value used to rank the score against",
+        "equal ones on leaderboards with a [TieBreak::HighestValue] or [TieBreak::LowestValue]",
+        "policy.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerBan",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "score",
+          type: "u64",
+        },
+        {
+          name: "tiebreak",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "submitAttestedScore",
+      docs: [
+        "Submit a score signed for and paid by the player, carrying an attestation from one",
+        "of the [Game]'s authorities.",
+        "",
+        "The transaction must include an ed25519 program instruction right before this one that",
+        "verifies an authority's signature over the attestation's",
+        "[message][ScoreAttestation::message].",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerBan",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "score",
+          type: "u64",
+        },
+        {
+          name: "tiebreak",
+          type: "u64",
+        },
+        {
+          name: "nonce",
+          type: "u64",
+        },
+        {
+          name: "issuedAt",
+          type: "i64",
+        },
+        {
+          name: "expiry",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "removePlayerScore",
+      docs: [
+        "Remove the score at `index` from a [PlayerScoresList], also evicting it from the",
+        "[LeaderTopEntries] if given.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "evictPlayer",
+      docs: [
+        "Remove every entry held by a [Player] from a [LeaderBoard]'s [LeaderTopEntries].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "banPlayer",
+      docs: [
+        "Ban a [Player] from registering for or submitting scores to a [LeaderBoard].",
+        "",
+        "The ban is held by a [PlayerBan] account, so players can be banned before they",
+        "register and stay banned if they close their [PlayerScoresList].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ban",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unbanPlayer",
+      docs: [
+        "Lift a [Player]'s ban from a [LeaderBoard], closing its [PlayerBan] account.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ban",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initiateMerge",
+      docs: [
+        "Initialize a new merge account and await approval from the verified users of all the",
+        "specified [Player] accounts.",
+        "",
+        "A merge is complete when all the users of the [Player] account keys referenced in it",
+        "have signed to set their approval to `true`.",
+        "",
+        "The [Merged] account is derived from the initiating user and a `nonce` unique among",
+        "their merges.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "keys",
+          type: {
+            vec: "publicKey",
+          },
+        },
+        {
+          name: "nonce",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "approveMerge",
+      docs: [
+        "Register merge confirmation for a particular [Player] account included in a [Merged].",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mergePlayerScores",
+      docs: [
+        "Move a merged [Player]'s scores for a [LeaderBoard] into the initiator's [PlayerScoresList]",
+        "once the [Merged] account is complete.",
+        "",
+        "Rows in the leaderboard's [LeaderTopEntries] that belong to the merged player are",
+        "reassigned to the initiator's [Player] account.",
+        "",
+        "The merged player's [PlayerScoresList] is closed, refunding its rent to their wallet.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mergedUser",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerBan",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mergePlayerAchievement",
+      docs: [
+        "Carry over a merged [Player]'s [PlayerAchievement] to the initiator's [Player] account",
+        "once the [Merged] account is complete.",
+        "",
+        "The merged player's status is [marked as merged][PlayerAchievement::mark_merged], so",
+        "neither it nor the merged player can claim the achievement's rewards anymore. If the",
+        "merged player claimed any, the [RewardClaim]s of both players are passed as remaining",
+        "accounts for each of the achievement's rewards, in order, so the initiator can't claim",
+        "them again.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unlockPlayerAchievement",
+      docs: [
+        "Unlock a [PlayerAchievement] account without paying out a reward.",
+        "",
+        "The achievement's rewards can be claimed later with [claim_ft_reward] and",
+        "[claim_nft_reward]. The account can also serve as a gated verification-method for",
+        "custom rewards mechanisms.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "addFtReward",
+      docs: [
+        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "The reward's mint may belong to either SPL Token or Token-2022. `deposit` tokens are",
+        "moved into a vault owned by the achievement, from which claims are paid out.",
+        "",
+        "An achievement can hold up to [Achievement::MAX_REWARDS] rewards, each paid out",
+        "independently while it has spots left. The reward is derived from the achievement",
+        "and [Achievement::next_reward].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newReward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccountOwner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddNewRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "topUpFtReward",
+      docs: [
+        "Deposit more tokens into the vault of a fungible token [Reward].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccountOwner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "withdrawFtReward",
+      docs: [
+        "Withdraw the tokens in a fungible token [Reward]'s vault that aren't owed to any of its",
+        "remaining spots.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destinationTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "addNftReward",
+      docs: [
+        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "An achievement can hold up to [Achievement::MAX_REWARDS] rewards, each paid out",
+        "independently while it has spots left. The reward is derived from the achievement",
+        "and [Achievement::next_reward].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newReward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardCollectionMint",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "collectionUpdateAuth",
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddNewRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateReward",
+      docs: [
+        "Update a [Reward]'s available spots, or the amount given out by a fungible token reward,",
+        "or the uri, name or symbol of NFTs minted by an NFT reward.",
+        "",
+        "Only affects claims made after the update. Cancelled rewards can't be updated, and a",
+        "fungible token reward's vault must already hold any increase in what its remaining",
+        "spots are owed.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "The reward's vault, checked in the handler. Only needed when an FT reward's",
+            "remaining spots are owed more.",
+          ],
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newAvailableSpots",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "newAmount",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "newUri",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "newName",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "newSymbol",
+          type: {
+            option: "string",
+          },
+        },
+      ],
+    },
+    {
+      name: "setRewardPaused",
+      docs: ["Pause or resume claims of a [Reward]."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "paused",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "cancelReward",
+      docs: [
+        "Cancel a [Reward], detaching it from its [Achievement].",
+        "",
+        "The whole vault of a fungible token reward is refunded to `destination_token_account`.",
+        "The reward account itself is left for `close_reward`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "destinationTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "redeemClaimVoucher",
+      docs: [
+        "Redeem a [ClaimVoucher] signed by one of the [Game]'s authorities for a fungible token",
+        "[Reward], unlocking its [Achievement] for the signing player if needed and paying the",
+        "reward out to them.",
+        "",
+        "The transaction must include an ed25519 program instruction right before this one that",
+        "verifies the signature over the voucher's [message][ClaimVoucher::message]. The signing",
+        "authority must be allowed to both unlock achievements and manage rewards.",
+        "",
+        "For Token-2022 mints with a transfer hook, the accounts the hook requires are passed",
+        "as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "claim",
+          accounts: [
+            {
+              name: "user",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "payer",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "game",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "achievement",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "reward",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "playerAccount",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "playerAchievement",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardClaim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardTokenMint",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "rewardVault",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "associatedTokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "systemProgram",
+              isMut: false,
+              isSigner: false,
+            },
+          ],
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "expiry",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "redeemNftClaimVoucher",
+      docs: ["Same as [redeem_claim_voucher], but for an NFT [Reward]."],
+      accounts: [
+        {
+          name: "claim",
+          accounts: [
+            {
+              name: "user",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "payer",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "game",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "achievement",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "reward",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "playerAccount",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "playerAchievement",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardClaim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "claim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "newMint",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "newMetadata",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "newMasterEdition",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "mintTo",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "tokenMetadataProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "associatedTokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "systemProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "rent",
+              isMut: false,
+              isSigner: false,
+            },
+          ],
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "expiry",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "claimFtReward",
+      docs: [
+        "Pay out one of an [Achievement]'s fungible token [Reward]s to a player who has already",
+        "unlocked it.",
+        "",
+        "Signed by either one of the [Game]'s authorities or the player themselves.",
+        "",
+        "Each reward can be claimed once per player, tracked by a [RewardClaim] account. To pay",
+        "out every reward of an achievement at once, use [claim_ft_rewards].",
+        "",
+        "Marks the [PlayerAchievement] as `claimed`, which only records that at least one of",
+        "the achievement's rewards was claimed.",
+        "",
+        "Relevant `ONLY` if an FT reward is specified for that achievement. The user's associated",
+        "token account is created if it doesn't exist yet.",
+        "",
+        "For Token-2022 mints with a transfer hook, the accounts the hook requires are passed",
+        "as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "claim",
+          accounts: [
+            {
+              name: "user",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "payer",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "game",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "achievement",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "reward",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "playerAccount",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "playerAchievement",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardClaim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardTokenMint",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "rewardVault",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "associatedTokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "systemProgram",
+              isMut: false,
+              isSigner: false,
+            },
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimFtRewards",
+      docs: [
+        "Pay out several of an [Achievement]'s fungible token [Reward]s to a player who has",
+        "already unlocked it, in one instruction.",
+        "",
+        "Each reward is passed as a group of remaining accounts: the reward (mut), its",
+        "[RewardClaim] (mut), its mint, its vault (mut) and the user's token account for the",
+        "mint (mut), which must already exist. All rewards must use `token_program`.",
+        "",
+        "Mints with a transfer hook aren't supported here and are claimed with",
+        "[claim_ft_reward] instead.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimNftReward",
+      docs: [
+        "Mint one of an [Achievement]'s NFT [Reward]s to a player who has already unlocked it.",
+        "",
+        "Signed by either one of the [Game]'s authorities or the player themselves.",
+        "",
+        "Each reward can be claimed once per player, tracked by a [RewardClaim] account.",
+        "",
+        "Relevant `ONLY` if an NFT reward is specified for that achievement.",
+      ],
+      accounts: [
+        {
+          name: "claim",
+          accounts: [
+            {
+              name: "user",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "payer",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "game",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "achievement",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "reward",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "playerAccount",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "playerAchievement",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardClaim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "claim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "newMint",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "newMetadata",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "newMasterEdition",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "mintTo",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "tokenMetadataProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "associatedTokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "systemProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "rent",
+              isMut: false,
+              isSigner: false,
+            },
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unlockAndClaimFtReward",
+      docs: [
+        "Same as [claim_ft_reward], but first unlocks the achievement for the player if they",
+        "haven't unlocked it yet.",
+      ],
+      accounts: [
+        {
+          name: "claim",
+          accounts: [
+            {
+              name: "user",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "payer",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "game",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "achievement",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "reward",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "playerAccount",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "playerAchievement",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardClaim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardTokenMint",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "rewardVault",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "associatedTokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "systemProgram",
+              isMut: false,
+              isSigner: false,
+            },
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unlockAndClaimNftReward",
+      docs: [
+        "Same as [claim_nft_reward], but first unlocks the achievement for the player if they",
+        "haven't unlocked it yet.",
+      ],
+      accounts: [
+        {
+          name: "claim",
+          accounts: [
+            {
+              name: "user",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "payer",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "game",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "achievement",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "reward",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "playerAccount",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "playerAchievement",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "rewardClaim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "claim",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "newMint",
+              isMut: true,
+              isSigner: true,
+            },
+            {
+              name: "newMetadata",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "newMasterEdition",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "mintTo",
+              isMut: true,
+              isSigner: false,
+            },
+            {
+              name: "tokenMetadataProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "associatedTokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "systemProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+            },
+            {
+              name: "rent",
+              isMut: false,
+              isSigner: false,
+            },
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "verifyNftReward",
+      docs: [
+        "Verify NFT reward as belonging to a particular collection.",
+        "",
+        "Optional: Only relevant if an NFT reward is specified and the reward's",
+        "`collection_mint` is Some(...)",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadataToVerify",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "addFtRankReward",
+      docs: [
+        "Add a fungible token [RankReward] to a [LeaderBoard], paid out to players who finish",
+        "within a range of ranks in its [LeaderTopEntries].",
+        "",
+        "`deposit` tokens are moved into a vault owned by the leaderboard PDA, which claims are",
+        "paid from. On seasonal leaderboards the reward names the season it pays out for;",
+        "otherwise the leaderboard must have an end time.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "newReward",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccountOwner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddRankRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "addNftRankReward",
+      docs: [
+        "Add a nft [RankReward] to a [LeaderBoard], minted to players who finish within a range",
+        "of ranks in its [LeaderTopEntries].",
+        "",
+        "As with [add_ft_rank_reward], the reward names its season on seasonal leaderboards.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "newReward",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddRankRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "claimFtRankReward",
+      docs: [
+        "Claim an FT [RankReward] for the player holding `rank` in a closed [LeaderBoard], or",
+        "in the [SeasonTopEntries] archive of the reward's season.",
+        "",
+        "Ranks start from `1`. Each player can claim a reward once. For Token-2022 mints with a",
+        "transfer hook, the accounts the hook requires are passed as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "seasonTopEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rankReward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rank",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "claimNftRankReward",
+      docs: [
+        "Claim an NFT [RankReward] for the player holding `rank` in a closed [LeaderBoard], or",
+        "in the [SeasonTopEntries] archive of the reward's season.",
+        "",
+        "Ranks start from `1`. Each player can claim a reward once.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "seasonTopEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rankReward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "newMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newMasterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintTo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rank",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "closeGame",
+      docs: [
+        "Close a [Game] account, sending its lamports to `receiver`.",
+        "",
+        "Fails if any of the game's leaderboards or achievements are still open. Leaves a",
+        "[ClosedGame] account behind, so the game's address can't be initialized again.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closedGame",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeLeaderboard",
+      docs: [
+        "Close a [LeaderBoard] along with its [LeaderTopEntries] if it has one.",
+        "",
+        "Fails while any of the leaderboard's [RankReward]s or [SeasonTopEntries] are still",
+        "open, as they can't be closed without it.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeSeasonTopEntries",
+      docs: ["Close an archived [SeasonTopEntries] account."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeAchievement",
+      docs: [
+        "Close an [Achievement]. Fails if it still has a [Reward] attached.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeReward",
+      docs: [
+        "Close a [Reward], detaching it from its [Achievement].",
+        "",
+        "For FT rewards, the reward's vault must be emptied first and is closed along with it.",
+        "For mints with transfer fees, fees withheld in the vault must be harvested too.",
+        "Close the reward's [NftClaim]s first, as they can't be closed without it.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeRankReward",
+      docs: [
+        "Close a [RankReward]. For FT rewards, whatever is left in the reward's vault is",
+        "refunded to `destination_token_account` and the vault is closed along with it.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rankReward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "destinationTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePlayer",
+      docs: ["Close a user's [Player] account."],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePlayerScores",
+      docs: ["Close a user's [PlayerScoresList]."],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePlayerAchievement",
+      docs: [
+        "Close a user's [PlayerAchievement].",
+        "",
+        "Claimed achievements can only be closed once the [Achievement] itself is closed, so",
+        "the record that the player claimed from it lasts as long as the achievement.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeMerged",
+      docs: [
+        "Close a [Merged] account. Only the merge initiator can close it.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeNftClaim",
+      docs: [
+        "Close an [NftClaim], after which the minted nft can no longer be verified.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeRewardClaim",
+      docs: ["Close a user's [RewardClaim] once its [Reward] is closed."],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeRankRewardClaim",
+      docs: [
+        "Close a user's [RankRewardClaim] once its [RankReward] is closed.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rankReward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateGame",
+      docs: [
+        "Convert a [Game] created under the legacy layout, reallocating it. Its authorities keep",
+        "every role, and the signing authority, which must be one of them, becomes its owner.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateLeaderboard",
+      docs: [
+        "Convert a [LeaderBoard] created under the legacy layout, along with its",
+        "[LeaderTopEntries], reallocating both. The leaderboard takes its order from its top",
+        "entries, and its newer settings are left at their defaults.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migratePlayerScores",
+      docs: [
+        "Convert a [PlayerScoresList] created under the legacy layout, reallocating it for its",
+        "larger score entries.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateAchievement",
+      docs: [
+        "Convert an [Achievement] created under the legacy layout, reallocating it to hold",
+        "several rewards. Its reward, if any, becomes the first of them.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateFtReward",
+      docs: [
+        "Move a fungible token [Reward] created under the legacy layout to a vault.",
+        "",
+        "Legacy rewards were paid from a token account the [Achievement] was approved to spend",
+        "from. What the remaining spots are owed is moved from it into a new vault, as far as",
+        "the approval and balance cover, and the reward is paid from the vault from then on.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "legacyTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "achievement",
+      docs: [
+        "Represents an achievement(with optional rewards) for this game",
+        "that can be attained by players.",
+        "",
+        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
+        "",
+        "`id` is an incrementing index stored in the game account.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "game",
+            docs: [
+              "Public key of the game account this achievement is derived from.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "id",
+            docs: [
+              "The achievement_count of the game account when this account was",
+              "created, also used as a seed for its PDA.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "title",
+            docs: ["Achievement title."],
+            type: "string",
+          },
+          {
+            name: "description",
+            docs: ["Achievement description."],
+            type: "string",
+          },
+          {
+            name: "nftMeta",
+            docs: [
+              "Public key of a nft metadata account describing this achievement.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "rewards",
+            docs: [
+              "Rewards given out to players for unlocking this achievement, each with its own spots.",
+            ],
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "rewardCount",
+            docs: [
+              "Number of rewards ever added to this achievement. Also used to determine the u64 seed",
+              "for the next reward.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "authorityProposal",
+      docs: [
+        "A pending change to a [Game][super::Game]'s authority list, applied once the",
+        "proposed key accepts it.",
+        "",
+        'Seeds = `[b"authority-proposal", game.key().as_ref(), authority.key.as_ref()]`.',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "game",
+            docs: ["The game whose authority list is being changed."],
+            type: "publicKey",
+          },
+          {
+            name: "proposer",
+            docs: [
+              "The account that paid for this proposal, refunded when it's closed.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "proposedBy",
+            docs: [
+              "The admin or owner that made the proposal, who must still be allowed to make it when",
+              "it's accepted.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "authority",
+            docs: ["The proposed authority and the roles it will hold."],
+            type: {
+              defined: "Authority",
+            },
+          },
+          {
+            name: "replaces",
+            docs: [
+              "An existing authority to be replaced by the proposed one. The proposed authority",
+              "is added alongside the existing ones if [None].",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "unlockTime",
+            docs: ["Unix timestamp from which the proposal can be accepted."],
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "game",
+      docs: [
+        "An account representing a single game.",
+        "",
+        'PDA with seeds = `[b"game", creator.key().as_ref(), &nonce.to_le_bytes()]`, where `nonce`',
+        "tells apart games by the same creator. Games created before seeded addresses live at",
+        "keypair addresses, which every other instruction still accepts.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "meta",
+            docs: ["Game meta-information."],
+            type: {
+              defined: "GameAttributes",
+            },
+          },
+          {
+            name: "leaderboardCount",
+            docs: [
+              "Number of leaderboards this game has created. Used both",
+              "in determining the most recent leaderboard address, and",
+              "as a seed for the next leaderboard.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "achievementCount",
+            docs: [
+              "Number of achievements that exist for this game. Also",
+              "used to determine the u64 seed for the next achievement.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "liveLeaderboards",
+            docs: [
+              "Number of this game's leaderboards that haven't been closed.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "liveAchievements",
+            docs: [
+              "Number of this game's achievements that haven't been closed.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "owner",
+            docs: [
+              "The key that alone can grant or revoke the admin role, and transfer ownership.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "pendingOwner",
+            docs: [
+              "The key ownership is being transferred to, once it accepts.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "paused",
+            docs: [
+              "Whether score submissions, registrations, unlocks and reward claims are suspended.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "auth",
+            docs: [
+              "The game's authorities, each limited to the roles it was granted.",
+            ],
+            type: {
+              vec: {
+                defined: "Authority",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "closedGame",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "leaderBoard",
+      docs: [
+        "Represents a [Game][super::Game]'s leaderboard.",
+        "",
+        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "id",
+            docs: [
+              "The leaderboard's id, used in deriving its address from the game.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "game",
+            docs: ["The game this leaderboard belongs to and is derived from."],
+            type: "publicKey",
+          },
+          {
+            name: "description",
+            docs: ["Leaderboard description."],
+            type: "string",
+          },
+          {
+            name: "nftMeta",
+            docs: [
+              "Pubkey of an nft metadata account that describes this leaderboard.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "decimals",
+            docs: ["Used to contextualize scores for this leaderboard."],
+            type: "u8",
+          },
+          {
+            name: "minScore",
+            docs: ["Minimum possible score for this leaderboard."],
+            type: "u64",
+          },
+          {
+            name: "maxScore",
+            docs: ["Maximum possible score for this leaderboard."],
+            type: "u64",
+          },
+          {
+            name: "topEntries",
+            docs: ["Top [entries](ScoreEntry) for a leaderboard."],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "allowMultipleScores",
+            docs: [
+              "Whether or not multiple scores are allowed for a single player.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "startTime",
+            docs: ["Unix timestamp before which scores are rejected."],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "endTime",
+            docs: ["Unix timestamp from which scores are rejected."],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "seasonLength",
+            docs: [
+              "Length of a recurring season in seconds, counted from `start_time`.",
+            ],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "isAscending",
+            docs: [
+              "Order by which scores are ranked. `true` for ascending, `false` for descending.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "retention",
+            docs: [
+              "Which of a player's scores are kept in their [PlayerScoresList][super::PlayerScoresList].",
+            ],
+            type: {
+              defined: "ScoreRetention",
+            },
+          },
+          {
+            name: "tieBreak",
+            docs: ["How equal scores are ranked against each other."],
+            type: {
+              defined: "TieBreak",
+            },
+          },
+          {
+            name: "paused",
+            docs: [
+              "Whether score submissions and registrations are suspended.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "liveRankRewards",
+            docs: [
+              "Number of this leaderboard's [RankReward][super::RankReward]s that haven't been closed.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "liveArchives",
+            docs: [
+              "Number of this leaderboard's [SeasonTopEntries][super::SeasonTopEntries] archives that",
+              "haven't been closed.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "merged",
+      docs: [
+        "An account that represents a single user's ownership of",
+        "multiple [Player][super::Player] accounts.",
+        "",
+        'PDA with seeds = `[b"merged", initiator.as_ref(), &nonce.to_le_bytes()]`, where `nonce`',
+        "tells apart merges by the same user. Only `initiate_merge` derives the address, so",
+        "merges already open at keypair addresses can still be approved and carried out.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "initiator",
+            docs: ["The user that initialized this merge."],
+            type: "publicKey",
+          },
+          {
+            name: "approvals",
+            docs: [
+              "Details of all the player accounts to be merged with the main_user's.",
+            ],
+            type: {
+              vec: {
+                defined: "MergeApproval",
+              },
+            },
+          },
+          {
+            name: "mergeComplete",
+            docs: [
+              "Set to true when every user in `others` has registered their approval.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "playerAchievement",
+      docs: [
+        "Represents a player's status for a particular [Achievement](super::Achievement).",
+        "",
+        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: ["The user's [player][super::Player] account."],
+            type: "publicKey",
+          },
+          {
+            name: "achievement",
+            docs: ["The key of the achievement unlocked for this player."],
+            type: "publicKey",
+          },
+          {
+            name: "timestamp",
+            docs: ["Timestamp showing when this achievement was unlocked."],
+            type: "i64",
+          },
+          {
+            name: "unlocked",
+            docs: [
+              "A player's unlock status for this achievement.",
+              "",
+              "Cleared, with `claimed` set, once merged into another player's account. See",
+              "[PlayerAchievement::is_merged].",
+            ],
+            type: "bool",
+          },
+          {
+            name: "claimed",
+            docs: [
+              "Whether this player has claimed any of the achievement's rewards.",
+              "",
+              "Set by the first claim, so with several rewards it doesn't mean all of them were",
+              "claimed. Which rewards were claimed is recorded by their",
+              "[RewardClaim](super::RewardClaim) accounts, which also keep each from being claimed",
+              "twice.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "playerBan",
+      docs: [
+        "Bars a [Player][super::Player] from registering for or submitting scores to a",
+        "[LeaderBoard][super::LeaderBoard] for as long as it exists.",
+        "",
+        'Seeds: `[b"player-ban", leaderboard.key().as_ref(), player_account.key().as_ref()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: ["The banned player."],
+            type: "publicKey",
+          },
+          {
+            name: "leaderboard",
+            docs: ["The leaderboard the player is banned from."],
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "playerScoresList",
+      docs: [
+        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
+        "for a particular [LeaderBoard].",
+        "",
+        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: [
+              "The player[super::Player] account this entry is derived from",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "leaderboard",
+            docs: ["The id of the specific leaderboard."],
+            type: "publicKey",
+          },
+          {
+            name: "allocCount",
+            docs: [
+              "Max number of [scores][ScoreEntry] the current space allocation supports.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "submissionCount",
+            docs: [
+              "Number of scores ever submitted to this list, including removed ones.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "createdAt",
+            docs: [
+              "Unix timestamp the list was registered at. Score attestations issued no later than",
+              "this can't be submitted to it.",
+            ],
+            type: "i64",
+          },
+          {
+            name: "topEntriesGeneration",
+            docs: [
+              "The [LeaderTopEntries][super::LeaderTopEntries] generation `top_entry` was recorded",
+              "in, or `0` if it never was.",
+            ],
+            type: "u32",
+          },
+          {
+            name: "topEntry",
+            docs: [
+              "The player's best entry in the leaderboard's top entries as of",
+              "`top_entries_generation`. The default entry if the player had none.",
+            ],
+            type: {
+              defined: "ScoreEntry",
+            },
+          },
+          {
+            name: "scores",
+            docs: ["Collection of [scores][ScoreEntry]."],
+            type: {
+              vec: {
+                defined: "ScoreEntry",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "player",
+      docs: [
+        "An account representing a player.",
+        "",
+        'Seeds: `[b"player", user.key().as_ref()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "user",
+            docs: ["The wallet that owns this player-info account"],
+            type: "publicKey",
+          },
+          {
+            name: "username",
+            docs: ["The player's username."],
+            type: "string",
+          },
+          {
+            name: "nftMeta",
+            docs: ["Metadata to represent this player."],
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "nftClaim",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "reward",
+      docs: [
+        "An account representing a reward for a given achievement.",
+        "",
+        'PDA with seeds = `[b"reward", achievement.key().as_ref(), &index.to_le_bytes()]`, where',
+        "`index` is the 1-based position of the reward in the order rewards were added to the",
+        "achievement, i.e. its [reward_count](super::Achievement::reward_count) after adding it.",
+        "Older rewards at keypair addresses keep working, as only `add_*_reward` checks the seeds.",
+        "A migrated achievement counts its legacy reward, so its first derived reward has index 2.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "achievement",
+            docs: ["The achievement this reward is given for."],
+            type: "publicKey",
+          },
+          {
+            name: "availableSpots",
+            docs: ["Number of available reward spots."],
+            type: "u64",
+          },
+          {
+            name: "reward",
+            docs: [
+              "The reward kind. Current supports Nft and Ft rewards only.",
+            ],
+            type: {
+              defined: "RewardKind",
+            },
+          },
+          {
+            name: "paused",
+            docs: ["Whether claims are currently suspended."],
+            type: "bool",
+          },
+          {
+            name: "cancelled",
+            docs: [
+              "Whether the reward was cancelled. Unlike a reward whose spots ran out, a cancelled",
+              "reward can't be updated or topped up again.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "rankReward",
+      docs: [
+        "An account representing a reward given out to players that finish within a range of",
+        "ranks on a leaderboard.",
+        "",
+        "Fungible token rank rewards are paid out of a vault owned by the leaderboard, at",
+        'seeds = `[b"reward-vault", rank_reward.key().as_ref()]`.',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "leaderboard",
+            docs: ["The leaderboard this reward is given for."],
+            type: "publicKey",
+          },
+          {
+            name: "minRank",
+            docs: ["Highest rank eligible for this reward, starting from `1`."],
+            type: "u32",
+          },
+          {
+            name: "maxRank",
+            docs: ["Lowest rank eligible for this reward, inclusive."],
+            type: "u32",
+          },
+          {
+            name: "season",
+            docs: [
+              "The season whose [SeasonTopEntries][super::SeasonTopEntries] archive decides the",
+              "ranks, on seasonal leaderboards. Otherwise ranks are read from the leaderboard's",
+              "[LeaderTopEntries][super::LeaderTopEntries] once it closes.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "reward",
+            docs: [
+              "The reward kind. Current supports Nft and Ft rewards only.",
+            ],
+            type: {
+              defined: "RewardKind",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "rewardClaim",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "rankRewardClaim",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: ["The player account that claimed the reward."],
+            type: "publicKey",
+          },
+          {
+            name: "rank",
+            docs: ["The rank the reward was claimed for."],
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "leaderTopEntries",
+      docs: [
+        "Keeps track of a sorted list of top scores for a leaderboard.",
+        "",
+        "Zero-copy: this header is followed in the account data by `capacity` [LeaderBoardScore]",
+        "slots, sorted from best to worst under the [LeaderBoard]'s order and tie-break policy.",
+        "Use [LeaderTopEntries::load_scores] and [LeaderTopEntries::load_scores_mut] to access them.",
+        "",
+        'Seeds = [b"top-scores", leaderboard.key().as_ref()]',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "season",
+            docs: ["The leaderboard season these top scores are for."],
+            type: "u64",
+          },
+          {
+            name: "scoresToRetain",
+            docs: ["Number of top scores to keep once fully allocated."],
+            type: "u16",
+          },
+          {
+            name: "capacity",
+            docs: ["Number of score slots currently allocated."],
+            type: "u16",
+          },
+          {
+            name: "generation",
+            docs: [
+              "Bumped whenever entries are moved other than by inserting a submitted score, which",
+              "invalidates the top entry recorded in each [PlayerScoresList][super::PlayerScoresList].",
+              "Never `0`, which lists use before recording one.",
+            ],
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "seasonTopEntries",
+      docs: [
+        "A snapshot of a [LeaderTopEntries] account taken at the end of a leaderboard season.",
+        "",
+        'Seeds = [b"season-top-scores", leaderboard.key().as_ref(), &season.to_le_bytes()]',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "leaderboard",
+            docs: ["The leaderboard these top scores were recorded for."],
+            type: "publicKey",
+          },
+          {
+            name: "season",
+            docs: ["The archived season."],
+            type: "u64",
+          },
+          {
+            name: "isAscending",
+            docs: ["Arrangement order."],
+            type: "bool",
+          },
+          {
+            name: "topScores",
+            docs: ["Top scores at the end of the season."],
+            type: {
+              vec: {
+                defined: "LeaderBoardScore",
+              },
+            },
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "ScoreAttestation",
+      docs: [
+        "The message a [Game][super::Game] authority signs to let a player submit a score",
+        "on their own.",
+        "",
+        "Signed as its [message][ScoreAttestation::message] and verified through an ed25519",
+        "program instruction placed right before the submission in the same transaction.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: ["The [Player][super::Player] account the score is for."],
+            type: "publicKey",
+          },
+          {
+            name: "leaderboard",
+            docs: [
+              "The [LeaderBoard][super::LeaderBoard] the score is submitted to.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "score",
+            docs: ["The attested score."],
+            type: "u64",
+          },
+          {
+            name: "tiebreak",
+            docs: ["The attested secondary value used to rank equal scores."],
+            type: "u64",
+          },
+          {
+            name: "nonce",
+            docs: [
+              "Must equal the `submission_count` of the player's",
+              "[PlayerScoresList][super::PlayerScoresList] when submitted, so the attestation",
+              "can't be replayed.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "issuedAt",
+            docs: [
+              "Unix timestamp the attestation was issued at. Must be later than the list's",
+              "`created_at`, so the attestation can't be replayed once the list is closed and",
+              "registered again with its `submission_count` starting over.",
+            ],
+            type: "i64",
+          },
+          {
+            name: "expiry",
+            docs: [
+              "Unix timestamp after which the attestation is no longer valid.",
+            ],
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "ClaimVoucher",
+      docs: [
+        "The message a [Game][super::Game] authority signs to let a player unlock an",
+        "[Achievement][super::Achievement] and claim one of its [Reward][super::Reward]s on their",
+        "own.",
+        "",
+        "Signed as its [message][ClaimVoucher::message] and verified through an ed25519 program",
+        "instruction placed right before the redemption in the same transaction. Redeeming it",
+        "claims the reward, so the reward's [RewardClaim][super::RewardClaim] keeps it from being",
+        "redeemed twice.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "description";
-            docs: ["Achievement description."];
-            type: "string";
+            name: "playerAccount",
+            docs: ["The [Player][super::Player] account the voucher is for."],
+            type: "publicKey",
           },
           {
-            name: "nftMeta";
+            name: "achievement",
+            docs: ["The achievement the player is allowed to unlock."],
+            type: "publicKey",
+          },
+          {
+            name: "reward",
             docs: [
-              "Public key of a nft metadata account describing this achievement."
-            ];
-            type: "publicKey";
+              "The reward of `achievement` the player is allowed to claim.",
+            ],
+            type: "publicKey",
           },
           {
-            name: "reward";
+            name: "expiry",
             docs: [
-              "Optional: Specify a reward to players for unlocking this achievement."
-            ];
-            type: {
-              option: "publicKey";
-            };
-          }
-        ];
-      };
+              "Unix timestamp after which the voucher is no longer valid.",
+            ],
+            type: "i64",
+          },
+        ],
+      },
     },
     {
-      name: "game";
-      docs: ["An account representing a single game."];
+      name: "Authority",
+      docs: [
+        "A [Game] authority and the roles it was granted, as a bitmask of [Authority] role flags.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "meta";
-            docs: ["Game meta-information."];
-            type: {
-              defined: "GameAttributes";
-            };
+            name: "key",
+            docs: ["The authority's pubkey."],
+            type: "publicKey",
           },
           {
-            name: "leaderboardCount";
-            docs: [
-              "Number of leaderboards this game has created. Used both",
-              "in determining the most recent leaderboard address, and",
-              "as a seed for the next leaderboard."
-            ];
-            type: "u64";
+            name: "roles",
+            docs: ["The roles granted to `key`."],
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "GameAttributes",
+      docs: ["A type that represents game-specific information."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "title",
+            docs: ["The title of the game, max length = 30 bytes."],
+            type: "string",
           },
           {
-            name: "achievementCount";
-            docs: [
-              "Number of achievements that exist for this game. Also",
-              "used to determine the u64 seed for the next achievement."
-            ];
-            type: "u64";
+            name: "description",
+            docs: ["The game description, max length = 200 bytes."],
+            type: "string",
           },
           {
-            name: "auth";
+            name: "genre",
+            docs: ["The game's [genre](super::Genre), as a u8."],
+            type: "u8",
+          },
+          {
+            name: "gameType",
+            docs: ["The game's [type](super::GameType), as a u8."],
+            type: "u8",
+          },
+          {
+            name: "nftMeta",
+            docs: ["An nft metadata account describing the game."],
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "ScoreEntry",
+      docs: ["A single score entry for a player."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "score",
+            docs: ["The player's score."],
+            type: "u64",
+          },
+          {
+            name: "timestamp",
+            docs: ["When this entry was made."],
+            type: "i64",
+          },
+          {
+            name: "tiebreak",
             docs: [
-              "A collection of pubkeys which each represent a valid",
-              "authority for this game."
-            ];
-            type: {
-              vec: "publicKey";
-            };
-          }
-        ];
-      };
+              "Secondary value used to rank equal scores under [TieBreak::HighestValue] and",
+              "[TieBreak::LowestValue].",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "leaderBoard";
+      name: "LegacyScoreEntry",
+      docs: ["A [ScoreEntry] without a tiebreak value."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "score",
+            type: "u64",
+          },
+          {
+            name: "timestamp",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LegacyLeaderBoard",
       docs: [
-        "Represents a [Game][super::Game]'s leaderboard.",
-        "",
-        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`'
-      ];
+        "A [LeaderBoard] without seasons, ranking rules, retention or live account counts. Its",
+        "order was held by its [LegacyLeaderTopEntries].",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "id";
-            docs: [
-              "The leaderboard's id, used in deriving its address from the game."
-            ];
-            type: "u64";
+            name: "id",
+            type: "u64",
           },
           {
-            name: "game";
-            docs: ["The game this leaderboard belongs to and is derived from."];
-            type: "publicKey";
+            name: "game",
+            type: "publicKey",
           },
           {
-            name: "description";
-            docs: ["Leaderboard description."];
-            type: "string";
+            name: "description",
+            type: "string",
           },
           {
-            name: "nftMeta";
-            docs: [
-              "Pubkey of an nft metadata account that describes this leaderboard."
-            ];
-            type: "publicKey";
+            name: "nftMeta",
+            type: "publicKey",
           },
           {
-            name: "decimals";
-            docs: ["Used to contextualize scores for this leaderboard."];
-            type: "u8";
+            name: "decimals",
+            type: "u8",
           },
           {
-            name: "minScore";
-            docs: ["Minimum possible score for this leaderboard."];
-            type: "u64";
+            name: "minScore",
+            type: "u64",
           },
           {
-            name: "maxScore";
-            docs: ["Maximum possible score for this leaderboard."];
-            type: "u64";
+            name: "maxScore",
+            type: "u64",
           },
           {
-            name: "topEntries";
-            docs: ["Top [entries](ScoreEntry) for a leaderboard."];
+            name: "topEntries",
             type: {
-              option: "publicKey";
-            };
+              option: "publicKey",
+            },
           },
           {
-            name: "allowMultipleScores";
-            docs: [
-              "Whether or not multiple scores are allowed for a single player."
-            ];
-            type: "bool";
-          }
-        ];
-      };
+            name: "allowMultipleScores",
+            type: "bool",
+          },
+        ],
+      },
     },
     {
-      name: "merged";
+      name: "LegacyLeaderBoardScore",
+      docs: ["A [LeaderBoardScore] holding a [LegacyScoreEntry]."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "player",
+            type: "publicKey",
+          },
+          {
+            name: "entry",
+            type: {
+              defined: "LegacyScoreEntry",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "LegacyLeaderTopEntries",
       docs: [
-        "An account that represents a single user's ownership of",
-        "multiple [Player][super::Player] accounts."
-      ];
+        "A borsh-serialized [LeaderTopEntries][super::LeaderTopEntries] holding only the scores",
+        "submitted so far, and the leaderboard's order.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
+        fields: [
+          {
+            name: "isAscending",
+            type: "bool",
+          },
+          {
+            name: "topScores",
+            type: {
+              vec: {
+                defined: "LegacyLeaderBoardScore",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "LegacyPlayerScoresList",
+      docs: [
+        "A [PlayerScoresList] of [LegacyScoreEntry]s, without a submission count or recorded top",
+        "entry.",
+      ],
+      type: {
+        kind: "struct",
         fields: [
           {
-            name: "initiator";
-            docs: ["The user that initialized this merge."];
-            type: "publicKey";
+            name: "playerAccount",
+            type: "publicKey",
+          },
+          {
+            name: "leaderboard",
+            type: "publicKey",
           },
           {
-            name: "approvals";
-            docs: [
-              "Details of all the player accounts to be merged with the main_user's."
-            ];
+            name: "allocCount",
+            type: "u16",
+          },
+          {
+            name: "scores",
             type: {
               vec: {
-                defined: "MergeApproval";
-              };
-            };
+                defined: "LegacyScoreEntry",
+              },
+            },
           },
-          {
-            name: "mergeComplete";
-            docs: [
-              "Set to true when every user in `others` has registered their approval."
-            ];
-            type: "bool";
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "playerAchievement";
+      name: "LegacyAchievement",
       docs: [
-        "Represents a player's status for a particular [Achievement](super::Achievement).",
-        "",
-        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.'
-      ];
+        "An [Achievement] with at most one reward, created from a keypair rather than derived",
+        "from the achievement.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "playerAccount";
-            docs: ["The user's [player][super::Player] account."];
-            type: "publicKey";
+            name: "game",
+            type: "publicKey",
           },
           {
-            name: "achievement";
-            docs: ["The key of the achievement unlocked for this player."];
-            type: "publicKey";
+            name: "id",
+            type: "u64",
           },
           {
-            name: "timestamp";
-            docs: ["Timestamp showing when this achievement was unlocked."];
-            type: "i64";
+            name: "title",
+            type: "string",
           },
           {
-            name: "unlocked";
-            docs: ["A player's unlock status for this achievement."];
-            type: "bool";
+            name: "description",
+            type: "string",
           },
           {
-            name: "claimed";
-            docs: ["Whether or not this player has claimed their reward."];
-            type: "bool";
-          }
-        ];
-      };
+            name: "nftMeta",
+            type: "publicKey",
+          },
+          {
+            name: "reward",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "playerScoresList";
+      name: "LegacyGame",
       docs: [
-        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
-        "for a particular [LeaderBoard].",
-        "",
-        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`'
-      ];
+        "A [Game] whose authorities all held every role, without an owner, pause flag or live",
+        "account counts.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "playerAccount";
-            docs: [
-              "The player[super::Player] account this entry is derived from"
-            ];
-            type: "publicKey";
+            name: "meta",
+            type: {
+              defined: "GameAttributes",
+            },
           },
           {
-            name: "leaderboard";
-            docs: ["The id of the specific leaderboard."];
-            type: "publicKey";
+            name: "leaderboardCount",
+            type: "u64",
           },
           {
-            name: "allocCount";
-            docs: [
-              "Max number of [scores][ScoreEntry] the current space allocation supports."
-            ];
-            type: "u16";
+            name: "achievementCount",
+            type: "u64",
           },
           {
-            name: "scores";
-            docs: ["Collection of [scores][ScoreEntry]."];
+            name: "auth",
             type: {
-              vec: {
-                defined: "ScoreEntry";
-              };
-            };
-          }
-        ];
-      };
+              vec: "publicKey",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "player";
+      name: "MergeApproval",
       docs: [
-        "An account representing a player.",
-        "",
-        'Seeds: `[b"player", user.key().as_ref()]`'
-      ];
+        "Represents a [Player][super::Player] account involved in a merge",
+        "and if that account's user/authority has granted approval.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "user";
-            docs: ["The wallet that owns this player-info account"];
-            type: "publicKey";
+            name: "key",
+            docs: ["The player_account pubkey."],
+            type: "publicKey",
           },
           {
-            name: "username";
-            docs: ["The player's username."];
-            type: "string";
+            name: "approved",
+            docs: ["User's approval status."],
+            type: "bool",
           },
-          {
-            name: "nftMeta";
-            docs: ["Metadata to represent this player."];
-            type: "publicKey";
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "nftClaim";
+      name: "LeaderBoardScore",
+      docs: ["An single entry to a [LeaderTopEntries]."],
       type: {
-        kind: "struct";
-        fields: [];
-      };
+        kind: "struct",
+        fields: [
+          {
+            name: "player",
+            docs: ["The player"],
+            type: "publicKey",
+          },
+          {
+            name: "entry",
+            docs: ["The user's [score][super::ScoreEntry]."],
+            type: {
+              defined: "ScoreEntry",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "reward";
-      docs: ["An account representing a reward for a given achievement."];
+      name: "RegisterLeaderBoardInput",
+      docs: ["Parameters needed when registering a leaderboard."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "achievement";
-            docs: ["The achievement this reward is given for."];
-            type: "publicKey";
+            name: "description",
+            docs: ["Leaderboard description."],
+            type: "string",
           },
           {
-            name: "availableSpots";
-            docs: ["Number of available reward spots."];
-            type: "u64";
+            name: "nftMeta",
+            docs: ["Nft metadata representing the leaderboard."],
+            type: "publicKey",
           },
           {
-            name: "reward";
+            name: "decimals",
             docs: [
-              "The reward kind. Current supports Nft and Ft rewards only."
-            ];
+              "Specify the decimals score values are represented in. Defaults to `0` if [None].",
+            ],
             type: {
-              defined: "RewardKind";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "leaderTopEntries";
-      docs: [
-        "Keeps track of a sorted list of top scores for a leaderboard.",
-        "",
-        'Seeds = [b"top-scores", leaderboard.key().as_ref()]'
-      ];
-      type: {
-        kind: "struct";
-        fields: [
+              option: "u8",
+            },
+          },
           {
-            name: "isAscending";
-            docs: ["Arrangement order."];
-            type: "bool";
+            name: "minScore",
+            docs: [
+              "Specifies minimum allowed score. Defaults to `u64::MIN` if [None].",
+            ],
+            type: {
+              option: "u64",
+            },
           },
           {
-            name: "topScores";
-            docs: ["Top scores."];
+            name: "maxScore",
+            docs: [
+              "Specifies maximum allowed score. Defaults to `u64::MAX` if [None].",
+            ],
             type: {
-              vec: {
-                defined: "LeaderBoardScore";
-              };
-            };
-          }
-        ];
-      };
-    }
-  ];
-  types: [
-    {
-      name: "GameAttributes";
-      docs: ["A type that represents game-specific information."];
-      type: {
-        kind: "struct";
-        fields: [
+              option: "u64",
+            },
+          },
           {
-            name: "title";
-            docs: ["The title of the game, max length = 30 bytes."];
-            type: "string";
+            name: "scoresToRetain",
+            docs: [
+              "Number of top scores to store on-chain.",
+              "",
+              "Lists too large to allocate on creation are filled in with `grow_top_entries`.",
+            ],
+            type: "u16",
           },
           {
-            name: "description";
-            docs: ["The game description, max length = 200 bytes."];
-            type: "string";
+            name: "isAscending",
+            docs: [
+              "Order by which scores are stored. `true` for ascending, `false` for descending.",
+            ],
+            type: "bool",
           },
           {
-            name: "genre";
-            docs: ["The game's [genre](super::Genre), as a u8."];
-            type: "u8";
+            name: "allowMultipleScores",
+            docs: [
+              "Whether or not multiple scores are kept in the leaderboard for a single player.",
+            ],
+            type: "bool",
           },
           {
-            name: "gameType";
-            docs: ["The game's [type](super::GameType), as a u8."];
-            type: "u8";
+            name: "startTime",
+            docs: [
+              "Unix timestamp before which scores are rejected. Open from creation if [None].",
+            ],
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "nftMeta";
-            docs: ["An nft metadata account describing the game."];
-            type: "publicKey";
-          }
-        ];
-      };
-    },
-    {
-      name: "ScoreEntry";
-      docs: ["A single score entry for a player."];
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "score";
-            docs: ["The player's score."];
-            type: "u64";
+            name: "endTime",
+            docs: [
+              "Unix timestamp from which scores are rejected. Never closes if [None].",
+            ],
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "timestamp";
-            docs: ["When this entry was made."];
-            type: "i64";
-          }
-        ];
-      };
-    },
-    {
-      name: "MergeApproval";
-      docs: [
-        "Represents a [Player][super::Player] account involved in a merge",
-        "and if that account's user/authority has granted approval."
-      ];
-      type: {
-        kind: "struct";
-        fields: [
+            name: "seasonLength",
+            docs: [
+              "Length of a recurring season in seconds, counted from `start_time`. The top entries",
+              "are reset at the start of every season. Requires `start_time` to be set.",
+            ],
+            type: {
+              option: "i64",
+            },
+          },
           {
-            name: "key";
-            docs: ["The player_account pubkey."];
-            type: "publicKey";
+            name: "retention",
+            docs: [
+              "Which of a player's scores are kept. Defaults to keeping every score.",
+            ],
+            type: {
+              defined: "ScoreRetention",
+            },
           },
           {
-            name: "approved";
-            docs: ["User's approval status."];
-            type: "bool";
-          }
-        ];
-      };
+            name: "tieBreak",
+            docs: [
+              "How equal scores are ranked against each other. Defaults to the earliest score",
+              "ranking higher.",
+            ],
+            type: {
+              defined: "TieBreak",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "LeaderBoardScore";
-      docs: ["An single entry to a [LeaderTopEntries]."];
+      name: "AddNewRewardInput",
+      docs: ["Input to add a new reward for an achievement."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "player";
-            docs: ["The player"];
-            type: "publicKey";
+            name: "availableSpots",
+            docs: ["Number of rewards to be given out."],
+            type: "u64",
           },
           {
-            name: "entry";
-            docs: ["The user's [score][super::ScoreEntry]."];
+            name: "kind",
+            docs: ["Specific reward kind."],
             type: {
-              defined: "ScoreEntry";
-            };
-          }
-        ];
-      };
+              defined: "RewardKindInput",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "RegisterLeaderBoardInput";
-      docs: ["Parameters needed when registering a leaderboard."];
+      name: "AddRankRewardInput",
+      docs: [
+        "Input to add a new reward for a range of ranks on a leaderboard.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "description";
-            docs: ["Leaderboard description."];
-            type: "string";
+            name: "minRank",
+            docs: ["Highest rank eligible for the reward, starting from `1`."],
+            type: "u32",
           },
           {
-            name: "nftMeta";
-            docs: ["Nft metadata representing the leaderboard."];
-            type: "publicKey";
+            name: "maxRank",
+            docs: ["Lowest rank eligible for the reward, inclusive."],
+            type: "u32",
           },
           {
-            name: "decimals";
+            name: "season",
             docs: [
-              "Specify the decimals score values are represented in. Defaults to `0` if [None]."
-            ];
+              "The season whose archived top entries decide the ranks. Required on seasonal",
+              "leaderboards, and must be [None] otherwise.",
+            ],
             type: {
-              option: "u8";
-            };
+              option: "u64",
+            },
           },
           {
-            name: "minScore";
-            docs: [
-              "Specifies minimum allowed score. Defaults to `u64::MIN` if [None]."
-            ];
+            name: "kind",
+            docs: ["Specific reward kind."],
             type: {
-              option: "u64";
-            };
+              defined: "RewardKindInput",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "GameType",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "maxScore";
-            docs: [
-              "Specifies maximum allowed score. Defaults to `u64::MAX` if [None]."
-            ];
-            type: {
-              option: "u64";
-            };
+            name: "Mobile",
           },
           {
-            name: "scoresToRetain";
-            docs: ["Number of top scores to store on-chain."];
-            type: "u8";
+            name: "Desktop",
           },
           {
-            name: "isAscending";
-            docs: [
-              "Order by which scores are stored. `true` for ascending, `false` for descending."
-            ];
-            type: "bool";
+            name: "Web",
           },
           {
-            name: "allowMultipleScores";
-            docs: [
-              "Whether or not multiple scores are kept in the leaderboard for a single player."
-            ];
-            type: "bool";
-          }
-        ];
-      };
+            name: "Unspecified",
+          },
+        ],
+      },
     },
     {
-      name: "AddNewRewardInput";
-      docs: ["Input to add a new reward for an achievement."];
+      name: "Genre",
       type: {
-        kind: "struct";
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "availableSpots";
-            docs: ["Number of rewards to be given out."];
-            type: "u64";
+            name: "Rpg",
           },
           {
-            name: "kind";
-            docs: ["Specific reward kind."];
-            type: {
-              defined: "RewardKindInput";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "GameType";
-      type: {
-        kind: "enum";
-        variants: [
+            name: "Mmo",
+          },
           {
-            name: "Mobile";
+            name: "Action",
           },
           {
-            name: "Desktop";
+            name: "Adventure",
           },
           {
-            name: "Web";
+            name: "Puzzle",
           },
           {
-            name: "Unspecified";
-          }
-        ];
-      };
+            name: "Casual",
+          },
+          {
+            name: "Unspecified",
+          },
+        ],
+      },
     },
     {
-      name: "Genre";
+      name: "ScoreRetention",
+      docs: [
+        "Policy for the scores a [PlayerScoresList][super::PlayerScoresList] keeps once it",
+        "holds a set number of entries.",
+      ],
       type: {
-        kind: "enum";
+        kind: "enum",
         variants: [
           {
-            name: "Rpg";
+            name: "Unbounded",
           },
           {
-            name: "Mmo";
+            name: "KeepLatest",
+            fields: ["u16"],
           },
           {
-            name: "Action";
+            name: "KeepBest",
+            fields: ["u16"],
           },
           {
-            name: "Adventure";
+            name: "BestOnly",
           },
+        ],
+      },
+    },
+    {
+      name: "TieBreak",
+      docs: ["Policy for ranking equal scores."],
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "Puzzle";
+            name: "EarliestFirst",
           },
           {
-            name: "Casual";
+            name: "LatestFirst",
           },
           {
-            name: "Unspecified";
-          }
-        ];
-      };
+            name: "HighestValue",
+          },
+          {
+            name: "LowestValue",
+          },
+        ],
+      },
     },
     {
-      name: "RewardKind";
-      docs: ["The kind of reward to be given out."];
+      name: "RewardKind",
+      docs: ["The kind of reward to be given out."],
       type: {
-        kind: "enum";
+        kind: "enum",
         variants: [
           {
-            name: "FungibleToken";
+            name: "FungibleToken",
             fields: [
               {
-                name: "mint";
-                docs: ["The mint of the token to be given out."];
-                type: "publicKey";
+                name: "mint",
+                docs: ["The mint of the token to be given out."],
+                type: "publicKey",
               },
               {
-                name: "account";
-                docs: ["The token account to withdraw from."];
-                type: "publicKey";
+                name: "account",
+                docs: ["The token account to withdraw from."],
+                type: "publicKey",
               },
               {
-                name: "amount";
-                docs: ["Reward amount per user."];
-                type: "u64";
-              }
-            ];
+                name: "amount",
+                docs: ["Reward amount per user."],
+                type: "u64",
+              },
+              {
+                name: "tokenProgram",
+                docs: [
+                  "The token program `mint` belongs to, either SPL Token or Token-2022.",
+                ],
+                type: "publicKey",
+              },
+            ],
           },
           {
-            name: "NonFungibleToken";
+            name: "NonFungibleToken",
             fields: [
               {
-                name: "uri";
-                docs: ["URI of the NFT to be minted."];
-                type: "string";
+                name: "uri",
+                docs: ["URI of the NFT to be minted."],
+                type: "string",
               },
               {
-                name: "name";
-                docs: ["Name of the NFT to be minted."];
-                type: "string";
+                name: "name",
+                docs: ["Name of the NFT to be minted."],
+                type: "string",
               },
               {
-                name: "symbol";
-                docs: ["Symbol of the NFT to be minted."];
-                type: "string";
+                name: "symbol",
+                docs: ["Symbol of the NFT to be minted."],
+                type: "string",
               },
               {
-                name: "minted";
-                docs: ["Total NFTs minted so far."];
-                type: "u64";
+                name: "minted",
+                docs: ["Total NFTs minted so far."],
+                type: "u64",
               },
               {
-                name: "collection";
+                name: "collection",
                 docs: [
                   "Optional field for a collection mint used for",
-                  "verifying minted rewards."
-                ];
+                  "verifying minted rewards.",
+                ],
                 type: {
-                  option: "publicKey";
-                };
-              }
-            ];
-          }
-        ];
-      };
+                  option: "publicKey",
+                },
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      name: "RewardKindInput";
-      docs: ["Specific variant of [AddNewRewardInput]."];
+      name: "RewardKindInput",
+      docs: ["Specific variant of [AddNewRewardInput]."],
       type: {
-        kind: "enum";
+        kind: "enum",
         variants: [
           {
-            name: "Ft";
+            name: "Ft",
             fields: [
               {
-                name: "deposit";
+                name: "deposit",
                 docs: [
                   "Amount to be delegated to this program's PDA",
-                  "so it can spend for reward claims."
-                ];
-                type: "u64";
-              },
-              {
-                name: "amount";
-                docs: ["Amount given to a single user."];
-                type: "u64";
-              }
-            ];
-          },
-          {
-            name: "Nft";
-            fields: [
-              {
-                name: "uri";
-                docs: ["Uri of the minted nft."];
-                type: "string";
-              },
-              {
-                name: "name";
-                docs: ["Name of the minted nft."];
-                type: "string";
+                  "so it can spend for reward claims.",
+                ],
+                type: "u64",
               },
-              {
-                name: "symbol";
-                docs: ["Symbol of the minted nft."];
-                type: "string";
-              }
-            ];
-          }
-        ];
-      };
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "InvalidFieldLength";
-      msg: "Exceeded max length for field.";
-    },
-    {
-      code: 6001;
-      name: "InvalidAuthority";
-      msg: "Invalid authority for instruction";
-    },
-    {
-      code: 6002;
-      name: "MissingSignature";
-      msg: "An expected signature isn't present";
-    },
-    {
-      code: 6003;
-      name: "NoRewardForAchievement";
-      msg: "Reward not specified for this achievement";
-    },
-    {
-      code: 6004;
-      name: "AccountNotPartOfMerge";
-      msg: "The merge account does not include this player account";
-    },
-    {
-      code: 6005;
-      name: "ScoreNotWithinBounds";
-      msg: "Tried to input score that is below the minimum or above the maximum";
-    },
-    {
-      code: 6006;
-      name: "MissingExpectedAccount";
-      msg: "An optional but expected account is missing";
-    },
-    {
-      code: 6007;
-      name: "InvalidRewardKind";
-      msg: "Invalid reward kind for this instruction";
-    },
-    {
-      code: 6008;
-      name: "NoAvailableRewards";
-      msg: "No more rewards are being given out for this game";
-    }
-  ];
-};
-
-export const IDL: Soar = {
-  version: "0.1.1",
-  name: "soar",
-  constants: [
-    {
-      name: "MAX_TITLE_LEN",
-      type: {
-        defined: "usize",
-      },
-      value: "30",
-    },
-    {
-      name: "MAX_DESCRIPTION_LEN",
-      type: {
-        defined: "usize",
-      },
-      value: "200",
-    },
-  ],
-  instructions: [
-    {
-      name: "initializeGame",
-      docs: ["Initialize a new [Game] and register its [LeaderBoard]."],
-      accounts: [
-        {
-          name: "creator",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "gameMeta",
-          type: {
-            defined: "GameAttributes",
-          },
-        },
-        {
-          name: "gameAuth",
-          type: {
-            vec: "publicKey",
-          },
-        },
-      ],
-    },
-    {
-      name: "updateGame",
-      docs: ["Update a [Game]'s meta-information or authority list."],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "newMeta",
-          type: {
-            option: {
-              defined: "GameAttributes",
-            },
+              {
+                name: "amount",
+                docs: ["Amount given to a single user."],
+                type: "u64",
+              },
+            ],
           },
-        },
-        {
-          name: "newAuth",
-          type: {
-            option: {
-              vec: "publicKey",
-            },
+          {
+            name: "Nft",
+            fields: [
+              {
+                name: "uri",
+                docs: ["Uri of the minted nft."],
+                type: "string",
+              },
+              {
+                name: "name",
+                docs: ["Name of the minted nft."],
+                type: "string",
+              },
+              {
+                name: "symbol",
+                docs: ["Symbol of the minted nft."],
+                type: "string",
+              },
+            ],
           },
-        },
-      ],
+        ],
+      },
     },
+  ],
+  events: [
     {
-      name: "addAchievement",
-      docs: [
-        "Add a new [Achievement] that can be attained for a particular [Game].",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "GameInitialized",
+      fields: [
         {
           name: "game",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "newAchievement",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "title",
-          type: "string",
-        },
-        {
-          name: "description",
-          type: "string",
-        },
-        {
-          name: "nftMeta",
           type: "publicKey",
-        },
-      ],
-    },
-    {
-      name: "updateAchievement",
-      docs: ["Update an [Achievement]'s meta information."],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "achievement",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "newTitle",
-          type: {
-            option: "string",
-          },
+          index: false,
         },
         {
-          name: "newDescription",
-          type: {
-            option: "string",
-          },
+          name: "owner",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "nftMeta",
+          name: "auth",
           type: {
-            option: "publicKey",
+            vec: {
+              defined: "Authority",
+            },
           },
+          index: false,
         },
       ],
     },
     {
-      name: "addLeaderboard",
-      docs: ["Overwrite the active [LeaderBoard] and set a newly created one."],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "GameUpdated",
+      fields: [
         {
           name: "game",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "topEntries",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "input",
+          name: "auth",
           type: {
-            defined: "RegisterLeaderBoardInput",
+            vec: {
+              defined: "Authority",
+            },
           },
+          index: false,
         },
       ],
     },
     {
-      name: "updateLeaderboard",
-      docs: [
-        "Update's a leaderboard's description, nft metadata information, min/max score, or whether",
-        "or not multiple scores are allowed for a single player.",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
+      name: "GamePauseUpdated",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "topEntries",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-      ],
-      args: [
-        {
-          name: "newDescription",
-          type: {
-            option: "string",
-          },
-        },
-        {
-          name: "newNftMeta",
-          type: {
-            option: "publicKey",
-          },
-        },
-        {
-          name: "newMinScore",
-          type: {
-            option: "u64",
-          },
-        },
-        {
-          name: "newMaxScore",
-          type: {
-            option: "u64",
-          },
-        },
-        {
-          name: "newIsAscending",
-          type: {
-            option: "bool",
-          },
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newAllowMultipleScores",
-          type: {
-            option: "bool",
-          },
+          name: "paused",
+          type: "bool",
+          index: false,
         },
       ],
     },
     {
-      name: "initializePlayer",
-      docs: ["Create a [Player] account for a particular user."],
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "username",
-          type: "string",
-        },
+      name: "AuthorityProposed",
+      fields: [
         {
-          name: "nftMeta",
+          name: "game",
           type: "publicKey",
-        },
-      ],
-    },
-    {
-      name: "updatePlayer",
-      docs: ["Update the username or nft_meta for a [Player] account."],
-      accounts: [
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
+          index: false,
         },
         {
-          name: "playerAccount",
-          isMut: true,
-          isSigner: false,
+          name: "proposal",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "username",
+          name: "authority",
           type: {
-            option: "string",
+            defined: "Authority",
           },
+          index: false,
         },
         {
-          name: "nftMeta",
+          name: "replaces",
           type: {
             option: "publicKey",
           },
+          index: false,
+        },
+        {
+          name: "unlockTime",
+          type: "i64",
+          index: false,
         },
       ],
     },
     {
-      name: "registerPlayer",
-      docs: [
-        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
-        "created [PlayerEntryList] account.",
-      ],
-      accounts: [
+      name: "AuthorityAccepted",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "game",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "user",
-          isMut: false,
-          isSigner: true,
+          name: "authority",
+          type: {
+            defined: "Authority",
+          },
+          index: false,
         },
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "replaced",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "AuthorityProposalCancelled",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "newList",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "proposal",
+          type: "publicKey",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "submitScore",
-      docs: [
-        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
-        "Optionally increase the player's rank if needed.",
-        "",
-        "This instruction automatically resizes the [PlayerScoresList] account if needed.",
-      ],
-      accounts: [
+      name: "OwnershipTransferProposed",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "game",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
+          name: "owner",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "pendingOwner",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "OwnershipTransferred",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "leaderboard",
-          isMut: false,
-          isSigner: false,
+          name: "previousOwner",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerScores",
-          isMut: true,
-          isSigner: false,
+          name: "owner",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "AchievementAdded",
+      fields: [
         {
-          name: "topEntries",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
+          name: "game",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "score",
+          name: "id",
           type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "initiateMerge",
-      docs: [
-        "Initialize a new merge account and await approval from the verified users of all the",
-        "specified [Player] accounts.",
-        "",
-        "A merge is complete when all the users of the [Player] account keys referenced in it",
-        "have signed to set their approval to `true`.",
-      ],
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "AchievementUpdated",
+      fields: [
         {
-          name: "user",
-          isMut: false,
-          isSigner: true,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "LeaderboardAdded",
+      fields: [
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "game",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mergeAccount",
-          isMut: true,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "id",
+          type: "u64",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "keys",
+          name: "topEntries",
           type: {
-            vec: "publicKey",
+            option: "publicKey",
           },
+          index: false,
         },
       ],
     },
     {
-      name: "approveMerge",
-      docs: [
-        "Register merge confirmation for a particular [Player] account included in a [Merged].",
-      ],
-      accounts: [
+      name: "LeaderboardUpdated",
+      fields: [
         {
-          name: "user",
-          isMut: false,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "LeaderboardPauseUpdated",
+      fields: [
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mergeAccount",
-          isMut: true,
-          isSigner: false,
+          name: "paused",
+          type: "bool",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "unlockPlayerAchievement",
-      docs: [
-        "Unlock a [PlayerAchievement] account without minting a reward.",
-        "",
-        "Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that",
-        "can serve as a gated verification-method for claims.",
-        "",
-        "Since claim instructions like [claim_ft_reward] and [claim_nft_reward] for reward types",
-        "defined by this program try to initialize this account and will fail if it already exists,",
-        "calling this means opting out of using these functions.",
-      ],
-      accounts: [
+      name: "SeasonArchived",
+      fields: [
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "season",
+          type: "u64",
+          index: false,
         },
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "archive",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "TopEntriesGrown",
+      fields: [
         {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAchievement",
-          isMut: true,
-          isSigner: false,
+          name: "topEntries",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "capacity",
+          type: "u16",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "addFtReward",
-      docs: [
-        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists.",
-      ],
-      accounts: [
+      name: "TopEntriesRebuilt",
+      fields: [
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "topEntries",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "lists",
+          type: "u32",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "TopEntriesAdded",
+      fields: [
         {
-          name: "achievement",
-          isMut: true,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newReward",
-          isMut: true,
-          isSigner: true,
+          name: "topEntries",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "rewardTokenMint",
-          isMut: false,
-          isSigner: false,
+          name: "scoresToRetain",
+          type: "u16",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TopEntriesResized",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "topEntries",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "delegateFromTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "scoresToRetain",
+          type: "u16",
+          index: false,
         },
         {
-          name: "tokenAccountOwner",
-          isMut: false,
-          isSigner: true,
+          name: "capacity",
+          type: "u16",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "PlayerInitialized",
+      fields: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "user",
+          type: "publicKey",
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "PlayerUpdated",
+      fields: [
         {
-          name: "input",
-          type: {
-            defined: "AddNewRewardInput",
-          },
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
       ],
     },
     {
-      name: "addNftReward",
-      docs: [
-        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists.",
-      ],
-      accounts: [
+      name: "PlayerRegistered",
+      fields: [
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "playerScores",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "ScoreSubmitted",
+      fields: [
         {
-          name: "achievement",
-          isMut: true,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newReward",
-          isMut: true,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "entry",
+          type: {
+            defined: "ScoreEntry",
+          },
+          index: false,
         },
         {
-          name: "rewardCollectionMint",
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: "oldRank",
+          type: {
+            option: "u32",
+          },
+          index: false,
         },
         {
-          name: "collectionUpdateAuth",
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
+          name: "newRank",
+          type: {
+            option: "u32",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "ScoreRemoved",
+      fields: [
         {
-          name: "collectionMetadata",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "input",
+          name: "entry",
           type: {
-            defined: "AddNewRewardInput",
+            defined: "ScoreEntry",
           },
+          index: false,
         },
       ],
     },
     {
-      name: "claimFtReward",
-      docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
-        "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
-        "",
-        "Relevant `ONLY` if an FT reward is specified for that achievement.",
-      ],
-      accounts: [
+      name: "PlayerEvicted",
+      fields: [
         {
-          name: "user",
-          isMut: false,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "removed",
+          type: "u32",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "PlayerBanUpdated",
+      fields: [
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "reward",
-          isMut: true,
-          isSigner: false,
+          name: "banned",
+          type: "bool",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "MergeInitiated",
+      fields: [
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "merged",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAchievement",
-          isMut: true,
-          isSigner: false,
+          name: "initiator",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "sourceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "keys",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "MergeApproved",
+      fields: [
         {
-          name: "userTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "merged",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "mergeComplete",
+          type: "bool",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "claimNftReward",
-      docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
-        "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
-        "",
-        "Relevant `ONLY` if an NFT reward is specified for that achievement.",
-      ],
-      accounts: [
+      name: "PlayerScoresMerged",
+      fields: [
         {
-          name: "user",
-          isMut: false,
-          isSigner: false,
+          name: "merged",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "mergedPlayer",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PlayerAchievementMerged",
+      fields: [
+        {
+          name: "merged",
+          type: "publicKey",
+          index: false,
         },
         {
           name: "achievement",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "reward",
-          isMut: true,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "mergedPlayer",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "AchievementUnlocked",
+      fields: [
         {
-          name: "playerAchievement",
-          isMut: true,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "claim",
-          isMut: true,
-          isSigner: false,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newMint",
-          isMut: true,
-          isSigner: true,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "RewardAdded",
+      fields: [
         {
-          name: "newMetadata",
-          isMut: true,
-          isSigner: false,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newMasterEdition",
-          isMut: true,
-          isSigner: false,
+          name: "reward",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mintTo",
-          isMut: true,
-          isSigner: false,
+          name: "availableSpots",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "RewardFunded",
+      fields: [
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "reward",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "vault",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RewardWithdrawn",
+      fields: [
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "vault",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "destination",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "amount",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "verifyNftReward",
-      docs: [
-        "Verify NFT reward as belonging to a particular collection.",
-        "",
-        "Optional: Only relevant if an NFT reward is specified and the reward's",
-        "`collection_mint` is Some(...)",
-      ],
-      accounts: [
+      name: "RewardUpdated",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "reward",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
+          name: "availableSpots",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "RewardPauseUpdated",
+      fields: [
         {
           name: "reward",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "user",
-          isMut: false,
-          isSigner: true,
+          name: "paused",
+          type: "bool",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "RewardCancelled",
+      fields: [
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "claim",
-          isMut: false,
-          isSigner: false,
+          name: "reward",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAchievement",
-          isMut: false,
-          isSigner: false,
+          name: "refunded",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "FtRewardClaimed",
+      fields: [
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "player",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "metadataToVerify",
-          isMut: true,
-          isSigner: false,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "collectionMint",
-          isMut: false,
-          isSigner: false,
+          name: "reward",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "collectionMetadata",
-          isMut: true,
-          isSigner: false,
+          name: "mint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "collectionEdition",
-          isMut: false,
-          isSigner: false,
+          name: "amount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
-    },
-  ],
-  accounts: [
-    {
-      name: "achievement",
-      docs: [
-        "Represents an achievement(with optional rewards) for this game",
-        "that can be attained by players.",
-        "",
-        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
-        "",
-        "`id` is an incrementing index stored in the game account.",
-      ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "game",
-            docs: [
-              "Public key of the game account this achievement is derived from.",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "id",
-            docs: [
-              "The achievement_count of the game account when this account was",
-              "created, also used as a seed for its PDA.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "title",
-            docs: ["Achievement title."],
-            type: "string",
-          },
-          {
-            name: "description",
-            docs: ["Achievement description."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: [
-              "Public key of a nft metadata account describing this achievement.",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "reward",
-            docs: [
-              "Optional: Specify a reward to players for unlocking this achievement.",
-            ],
-            type: {
-              option: "publicKey",
-            },
-          },
-        ],
-      },
-    },
-    {
-      name: "game",
-      docs: ["An account representing a single game."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "meta",
-            docs: ["Game meta-information."],
-            type: {
-              defined: "GameAttributes",
-            },
-          },
-          {
-            name: "leaderboardCount",
-            docs: [
-              "Number of leaderboards this game has created. Used both",
-              "in determining the most recent leaderboard address, and",
-              "as a seed for the next leaderboard.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "achievementCount",
-            docs: [
-              "Number of achievements that exist for this game. Also",
-              "used to determine the u64 seed for the next achievement.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "auth",
-            docs: [
-              "A collection of pubkeys which each represent a valid",
-              "authority for this game.",
-            ],
-            type: {
-              vec: "publicKey",
-            },
-          },
-        ],
-      },
-    },
-    {
-      name: "leaderBoard",
-      docs: [
-        "Represents a [Game][super::Game]'s leaderboard.",
-        "",
-        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`',
-      ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "id",
-            docs: [
-              "The leaderboard's id, used in deriving its address from the game.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "game",
-            docs: ["The game this leaderboard belongs to and is derived from."],
-            type: "publicKey",
-          },
-          {
-            name: "description",
-            docs: ["Leaderboard description."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: [
-              "Pubkey of an nft metadata account that describes this leaderboard.",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "decimals",
-            docs: ["Used to contextualize scores for this leaderboard."],
-            type: "u8",
-          },
-          {
-            name: "minScore",
-            docs: ["Minimum possible score for this leaderboard."],
-            type: "u64",
-          },
-          {
-            name: "maxScore",
-            docs: ["Maximum possible score for this leaderboard."],
-            type: "u64",
-          },
-          {
-            name: "topEntries",
-            docs: ["Top [entries](ScoreEntry) for a leaderboard."],
-            type: {
-              option: "publicKey",
-            },
-          },
-          {
-            name: "allowMultipleScores",
-            docs: [
-              "Whether or not multiple scores are allowed for a single player.",
-            ],
-            type: "bool",
-          },
-        ],
-      },
+          name: "userTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "merged",
-      docs: [
-        "An account that represents a single user's ownership of",
-        "multiple [Player][super::Player] accounts.",
+      name: "NftRewardClaimed",
+      fields: [
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "initiator",
-            docs: ["The user that initialized this merge."],
-            type: "publicKey",
-          },
-          {
-            name: "approvals",
-            docs: [
-              "Details of all the player accounts to be merged with the main_user's.",
-            ],
-            type: {
-              vec: {
-                defined: "MergeApproval",
-              },
-            },
-          },
-          {
-            name: "mergeComplete",
-            docs: [
-              "Set to true when every user in `others` has registered their approval.",
-            ],
-            type: "bool",
-          },
-        ],
-      },
     },
     {
-      name: "playerAchievement",
-      docs: [
-        "Represents a player's status for a particular [Achievement](super::Achievement).",
-        "",
-        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.',
+      name: "NftRewardVerified",
+      fields: [
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "playerAccount",
-            docs: ["The user's [player][super::Player] account."],
-            type: "publicKey",
-          },
-          {
-            name: "achievement",
-            docs: ["The key of the achievement unlocked for this player."],
-            type: "publicKey",
-          },
-          {
-            name: "timestamp",
-            docs: ["Timestamp showing when this achievement was unlocked."],
-            type: "i64",
-          },
-          {
-            name: "unlocked",
-            docs: ["A player's unlock status for this achievement."],
-            type: "bool",
-          },
-          {
-            name: "claimed",
-            docs: ["Whether or not this player has claimed their reward."],
-            type: "bool",
+    },
+    {
+      name: "RankRewardAdded",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "minRank",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "maxRank",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "season",
+          type: {
+            option: "u64",
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "playerScoresList",
-      docs: [
-        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
-        "for a particular [LeaderBoard].",
-        "",
-        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`',
+      name: "FtRankRewardClaimed",
+      fields: [
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rank",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "playerAccount",
-            docs: [
-              "The player[super::Player] account this entry is derived from",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "leaderboard",
-            docs: ["The id of the specific leaderboard."],
-            type: "publicKey",
-          },
-          {
-            name: "allocCount",
-            docs: [
-              "Max number of [scores][ScoreEntry] the current space allocation supports.",
-            ],
-            type: "u16",
-          },
-          {
-            name: "scores",
-            docs: ["Collection of [scores][ScoreEntry]."],
-            type: {
-              vec: {
-                defined: "ScoreEntry",
-              },
-            },
-          },
-        ],
-      },
     },
     {
-      name: "player",
-      docs: [
-        "An account representing a player.",
-        "",
-        'Seeds: `[b"player", user.key().as_ref()]`',
+      name: "NftRankRewardClaimed",
+      fields: [
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rank",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "user",
-            docs: ["The wallet that owns this player-info account"],
-            type: "publicKey",
-          },
-          {
-            name: "username",
-            docs: ["The player's username."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: ["Metadata to represent this player."],
-            type: "publicKey",
-          },
-        ],
-      },
     },
     {
-      name: "nftClaim",
-      type: {
-        kind: "struct",
-        fields: [],
-      },
+      name: "GameClosed",
+      fields: [
+        {
+          name: "game",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "reward",
-      docs: ["An account representing a reward for a given achievement."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "achievement",
-            docs: ["The achievement this reward is given for."],
-            type: "publicKey",
-          },
-          {
-            name: "availableSpots",
-            docs: ["Number of available reward spots."],
-            type: "u64",
-          },
-          {
-            name: "reward",
-            docs: [
-              "The reward kind. Current supports Nft and Ft rewards only.",
-            ],
-            type: {
-              defined: "RewardKind",
-            },
-          },
-        ],
-      },
+      name: "LeaderboardClosed",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SeasonTopEntriesClosed",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "season",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "leaderTopEntries",
-      docs: [
-        "Keeps track of a sorted list of top scores for a leaderboard.",
-        "",
-        'Seeds = [b"top-scores", leaderboard.key().as_ref()]',
+      name: "AchievementClosed",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "isAscending",
-            docs: ["Arrangement order."],
-            type: "bool",
-          },
-          {
-            name: "topScores",
-            docs: ["Top scores."],
-            type: {
-              vec: {
-                defined: "LeaderBoardScore",
-              },
-            },
-          },
-        ],
-      },
     },
-  ],
-  types: [
     {
-      name: "GameAttributes",
-      docs: ["A type that represents game-specific information."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "title",
-            docs: ["The title of the game, max length = 30 bytes."],
-            type: "string",
-          },
-          {
-            name: "description",
-            docs: ["The game description, max length = 200 bytes."],
-            type: "string",
-          },
-          {
-            name: "genre",
-            docs: ["The game's [genre](super::Genre), as a u8."],
-            type: "u8",
-          },
-          {
-            name: "gameType",
-            docs: ["The game's [type](super::GameType), as a u8."],
-            type: "u8",
-          },
-          {
-            name: "nftMeta",
-            docs: ["An nft metadata account describing the game."],
-            type: "publicKey",
-          },
-        ],
-      },
+      name: "RewardClosed",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "ScoreEntry",
-      docs: ["A single score entry for a player."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "score",
-            docs: ["The player's score."],
-            type: "u64",
-          },
-          {
-            name: "timestamp",
-            docs: ["When this entry was made."],
-            type: "i64",
-          },
-        ],
-      },
+      name: "RankRewardClosed",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "MergeApproval",
-      docs: [
-        "Represents a [Player][super::Player] account involved in a merge",
-        "and if that account's user/authority has granted approval.",
+      name: "PlayerClosed",
+      fields: [
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "key",
-            docs: ["The player_account pubkey."],
-            type: "publicKey",
-          },
-          {
-            name: "approved",
-            docs: ["User's approval status."],
-            type: "bool",
-          },
-        ],
-      },
     },
     {
-      name: "LeaderBoardScore",
-      docs: ["An single entry to a [LeaderTopEntries]."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "player",
-            docs: ["The player"],
-            type: "publicKey",
-          },
-          {
-            name: "entry",
-            docs: ["The user's [score][super::ScoreEntry]."],
-            type: {
-              defined: "ScoreEntry",
-            },
-          },
-        ],
-      },
+      name: "PlayerScoresClosed",
+      fields: [
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RegisterLeaderBoardInput",
-      docs: ["Parameters needed when registering a leaderboard."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "description",
-            docs: ["Leaderboard description."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: ["Nft metadata representing the leaderboard."],
-            type: "publicKey",
-          },
-          {
-            name: "decimals",
-            docs: [
-              "Specify the decimals score values are represented in. Defaults to `0` if [None].",
-            ],
-            type: {
-              option: "u8",
-            },
-          },
-          {
-            name: "minScore",
-            docs: [
-              "Specifies minimum allowed score. Defaults to `u64::MIN` if [None].",
-            ],
-            type: {
-              option: "u64",
-            },
-          },
-          {
-            name: "maxScore",
-            docs: [
-              "Specifies maximum allowed score. Defaults to `u64::MAX` if [None].",
-            ],
-            type: {
-              option: "u64",
-            },
-          },
-          {
-            name: "scoresToRetain",
-            docs: ["Number of top scores to store on-chain."],
-            type: "u8",
-          },
-          {
-            name: "isAscending",
-            docs: [
-              "Order by which scores are stored. `true` for ascending, `false` for descending.",
-            ],
-            type: "bool",
-          },
-          {
-            name: "allowMultipleScores",
-            docs: [
-              "Whether or not multiple scores are kept in the leaderboard for a single player.",
-            ],
-            type: "bool",
-          },
-        ],
-      },
+      name: "PlayerAchievementClosed",
+      fields: [
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "AddNewRewardInput",
-      docs: ["Input to add a new reward for an achievement."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "availableSpots",
-            docs: ["Number of rewards to be given out."],
-            type: "u64",
-          },
-          {
-            name: "kind",
-            docs: ["Specific reward kind."],
-            type: {
-              defined: "RewardKindInput",
-            },
-          },
-        ],
-      },
+      name: "MergedClosed",
+      fields: [
+        {
+          name: "merged",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "GameType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Mobile",
-          },
-          {
-            name: "Desktop",
-          },
-          {
-            name: "Web",
-          },
-          {
-            name: "Unspecified",
-          },
-        ],
-      },
+      name: "NftClaimClosed",
+      fields: [
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "Genre",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Rpg",
-          },
-          {
-            name: "Mmo",
-          },
-          {
-            name: "Action",
-          },
-          {
-            name: "Adventure",
-          },
-          {
-            name: "Puzzle",
-          },
-          {
-            name: "Casual",
-          },
-          {
-            name: "Unspecified",
-          },
-        ],
-      },
+      name: "RewardClaimClosed",
+      fields: [
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RewardKind",
-      docs: ["The kind of reward to be given out."],
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "FungibleToken",
-            fields: [
-              {
-                name: "mint",
-                docs: ["The mint of the token to be given out."],
-                type: "publicKey",
-              },
-              {
-                name: "account",
-                docs: ["The token account to withdraw from."],
-                type: "publicKey",
-              },
-              {
-                name: "amount",
-                docs: ["Reward amount per user."],
-                type: "u64",
-              },
-            ],
-          },
-          {
-            name: "NonFungibleToken",
-            fields: [
-              {
-                name: "uri",
-                docs: ["URI of the NFT to be minted."],
-                type: "string",
-              },
-              {
-                name: "name",
-                docs: ["Name of the NFT to be minted."],
-                type: "string",
-              },
-              {
-                name: "symbol",
-                docs: ["Symbol of the NFT to be minted."],
-                type: "string",
-              },
-              {
-                name: "minted",
-                docs: ["Total NFTs minted so far."],
-                type: "u64",
-              },
-              {
-                name: "collection",
-                docs: [
-                  "Optional field for a collection mint used for",
-                  "verifying minted rewards.",
-                ],
-                type: {
-                  option: "publicKey",
-                },
-              },
-            ],
-          },
-        ],
-      },
+      name: "RankRewardClaimClosed",
+      fields: [
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "player",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RewardKindInput",
-      docs: ["Specific variant of [AddNewRewardInput]."],
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Ft",
-            fields: [
-              {
-                name: "deposit",
-                docs: [
-                  "Amount to be delegated to this program's PDA",
-                  "so it can spend for reward claims.",
-                ],
-                type: "u64",
-              },
-              {
-                name: "amount",
-                docs: ["Amount given to a single user."],
-                type: "u64",
-              },
-            ],
-          },
-          {
-            name: "Nft",
-            fields: [
-              {
-                name: "uri",
-                docs: ["Uri of the minted nft."],
-                type: "string",
-              },
-              {
-                name: "name",
-                docs: ["Name of the minted nft."],
-                type: "string",
-              },
-              {
-                name: "symbol",
-                docs: ["Symbol of the minted nft."],
-                type: "string",
-              },
-            ],
-          },
-        ],
-      },
+      name: "AccountMigrated",
+      fields: [
+        {
+          name: "account",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
   ],
   errors: [
@@ -3503,5 +14472,240 @@ export const IDL: Soar = {
       name: "NoAvailableRewards",
       msg: "No more rewards are being given out for this game",
     },
+    {
+      code: 6009,
+      name: "MergeNotComplete",
+      msg: "Not all player accounts in this merge have approved it",
+    },
+    {
+      code: 6010,
+      name: "InvalidAttestation",
+      msg: "Missing or invalid ed25519 attestation",
+    },
+    {
+      code: 6011,
+      name: "AttestationExpired",
+      msg: "The attestation has expired",
+    },
+    {
+      code: 6012,
+      name: "ScoreOutsideWindow",
+      msg: "Score submitted outside of the leaderboard's active time window",
+    },
+    {
+      code: 6013,
+      name: "InvalidTimeWindow",
+      msg: "Invalid leaderboard start time, end time or season length",
+    },
+    {
+      code: 6014,
+      name: "SeasonNotOver",
+      msg: "The leaderboard's current season isn't over yet",
+    },
+    {
+      code: 6015,
+      name: "InvalidRankRange",
+      msg: "Invalid rank range for this leaderboard",
+    },
+    {
+      code: 6016,
+      name: "LeaderboardNotClosed",
+      msg: "The leaderboard hasn't closed yet",
+    },
+    {
+      code: 6017,
+      name: "RankNotEligible",
+      msg: "The player doesn't hold this rank or it isn't covered by the reward",
+    },
+    {
+      code: 6018,
+      name: "PlayerBanned",
+      msg: "The player is banned from this leaderboard",
+    },
+    {
+      code: 6019,
+      name: "ScoreNotFound",
+      msg: "No score exists at the given index",
+    },
+    {
+      code: 6020,
+      name: "AccountsStillLive",
+      msg: "The game still has leaderboards or achievements that haven't been closed",
+    },
+    {
+      code: 6021,
+      name: "RewardStillAttached",
+      msg: "The achievement still has a reward attached",
+    },
+    {
+      code: 6022,
+      name: "DelegationNotRevoked",
+      msg: "The reward's token delegation hasn't been revoked",
+    },
+    {
+      code: 6023,
+      name: "RewardStillClaimable",
+      msg: "This account still guards a reward that can be claimed",
+    },
+    {
+      code: 6024,
+      name: "InvalidRetention",
+      msg: "A bounded score retention policy must keep at least one score",
+    },
+    {
+      code: 6025,
+      name: "TopEntriesFullyAllocated",
+      msg: "The top entries account already holds all its scores",
+    },
+    {
+      code: 6026,
+      name: "InvalidTokenProgram",
+      msg: "The token program doesn't match the reward's mint",
+    },
+    {
+      code: 6027,
+      name: "NothingToWithdraw",
+      msg: "The reward vault holds no tokens beyond what its remaining spots are owed",
+    },
+    {
+      code: 6028,
+      name: "RewardVaultNotEmpty",
+      msg: "The reward vault still holds tokens",
+    },
+    {
+      code: 6029,
+      name: "RewardPaused",
+      msg: "Claims for this reward are paused",
+    },
+    {
+      code: 6030,
+      name: "TooManyRewards",
+      msg: "The achievement already has the maximum number of rewards",
+    },
+    {
+      code: 6031,
+      name: "AchievementNotUnlocked",
+      msg: "The player hasn't unlocked this achievement",
+    },
+    {
+      code: 6032,
+      name: "MissingAdmin",
+      msg: "A game must keep at least one authority with the admin role",
+    },
+    {
+      code: 6033,
+      name: "NotGameOwner",
+      msg: "Only the game's owner can grant or revoke the admin role",
+    },
+    {
+      code: 6034,
+      name: "UnconfirmedAuthority",
+      msg: "New authorities must accept a proposal before they are added",
+    },
+    {
+      code: 6035,
+      name: "AuthorityAlreadyExists",
+      msg: "The key is already one of the game's authorities",
+    },
+    {
+      code: 6036,
+      name: "AuthorityNotFound",
+      msg: "The key isn't one of the game's authorities",
+    },
+    {
+      code: 6037,
+      name: "TimelockActive",
+      msg: "The proposal's timelock hasn't expired yet",
+    },
+    {
+      code: 6038,
+      name: "InvalidTimelock",
+      msg: "The timelock can't be negative",
+    },
+    {
+      code: 6039,
+      name: "NoPendingOwner",
+      msg: "No ownership transfer to this key is pending",
+    },
+    {
+      code: 6040,
+      name: "GamePaused",
+      msg: "The game is paused",
+    },
+    {
+      code: 6041,
+      name: "LeaderboardPaused",
+      msg: "The leaderboard is paused",
+    },
+    {
+      code: 6042,
+      name: "InvalidPlayerScoresList",
+      msg: "The account isn't a scores list for this leaderboard",
+    },
+    {
+      code: 6043,
+      name: "TopEntriesAlreadyExist",
+      msg: "The leaderboard already has a top entries account",
+    },
+    {
+      code: 6044,
+      name: "InvalidScoresToRetain",
+      msg: "A top entries account must retain at least one score",
+    },
+    {
+      code: 6045,
+      name: "SeasonNotArchived",
+      msg: "The previous season's top entries must be archived first",
+    },
+    {
+      code: 6046,
+      name: "InvalidRankRewardSeason",
+      msg: "Rank rewards must name a season on seasonal leaderboards, and need an end time otherwise",
+    },
+    {
+      code: 6047,
+      name: "TopEntriesFrozen",
+      msg: "The leaderboard has closed and its top entries can no longer change",
+    },
+    {
+      code: 6048,
+      name: "LeaderboardAccountsStillLive",
+      msg: "The leaderboard still has rank rewards or season archives that haven't been closed",
+    },
+    {
+      code: 6049,
+      name: "TooManySeasonScores",
+      msg: "Seasonal leaderboards can't retain more top scores than a season archive holds",
+    },
+    {
+      code: 6050,
+      name: "NotLegacyAccount",
+      msg: "The account doesn't have a legacy layout",
+    },
+    {
+      code: 6051,
+      name: "RewardVaultUnderfunded",
+      msg: "The reward vault doesn't hold what the reward's remaining spots are owed",
+    },
+    {
+      code: 6052,
+      name: "PlayerAchievementMerged",
+      msg: "The player's achievement was merged into another player's",
+    },
+    {
+      code: 6053,
+      name: "CancelledReward",
+      msg: "The reward was cancelled",
+    },
+    {
+      code: 6054,
+      name: "GameClosed",
+      msg: "A game closed at this address can't be initialized again",
+    },
+    {
+      code: 6055,
+      name: "StaleAttestation",
+      msg: "The attestation was issued before the player's scores list was registered",
+    },
   ],
 };
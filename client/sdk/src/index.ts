@@ -1,5 +1,6 @@
 export * from "./idl/soar";
 export * from "./instructions";
-export { Genre, GameType } from "./state";
+export { AuthorityRole, Genre, GameType, type Authority } from "./state";
+export { ScoreRetention, TieBreak } from "./types";
 export * from "./soar.game";
 export * from "./soar.program";
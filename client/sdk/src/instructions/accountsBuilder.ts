@@ -1,17 +1,28 @@
 import { IDL, type Soar } from "../idl/soar";
 import { type AnchorProvider, Program } from "@coral-xyz/anchor";
 import {
+  type AccountMeta,
   type PublicKey,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { TOKEN_METADATA_PROGRAM_ID } from "../constants";
+import type BN from "bn.js";
 import { Utils } from "../utils";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import { AchievementAccount, RewardAccount } from "../state";
+import {
+  RankRewardAccount,
+  RewardAccount,
+  type FungibleTokenReward,
+} from "../state";
+import {
+  type ClaimFtRewardAccounts,
+  type ClaimNftRewardAccounts,
+} from "../types";
 
 export class AccountsBuilder {
   readonly program: Program<Soar>;
@@ -28,15 +39,121 @@ export class AccountsBuilder {
   ): Promise<{
     creator: PublicKey;
     game: PublicKey;
+    closedGame: PublicKey;
     systemProgram: PublicKey;
   }> => {
     return {
       creator: creator != null ? creator : this.provider.publicKey,
       game,
+      closedGame: this.utils.deriveClosedGameAddress(game)[0],
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  proposeAuthorityAccounts = async (
+    game: PublicKey,
+    authority: PublicKey,
+    proposed: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    proposal: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    return {
+      authority,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game,
+      proposal: this.utils.deriveAuthorityProposalAddress(game, proposed)[0],
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  acceptAuthorityAccounts = async (
+    game: PublicKey,
+    newAuthority: PublicKey
+  ): Promise<{
+    newAuthority: PublicKey;
+    game: PublicKey;
+    proposal: PublicKey;
+    proposer: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const proposal = this.utils.deriveAuthorityProposalAddress(
+      game,
+      newAuthority
+    )[0];
+    const proposalAccount = await this.program.account.authorityProposal.fetch(
+      proposal
+    );
+
+    return {
+      newAuthority,
+      game,
+      proposal,
+      proposer: proposalAccount.proposer,
       systemProgram: SystemProgram.programId,
     };
   };
 
+  cancelAuthorityProposalAccounts = async (
+    game: PublicKey,
+    authority: PublicKey,
+    proposed: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    proposal: PublicKey;
+    proposer: PublicKey;
+  }> => {
+    const proposal = this.utils.deriveAuthorityProposalAddress(
+      game,
+      proposed
+    )[0];
+    const proposalAccount = await this.program.account.authorityProposal.fetch(
+      proposal
+    );
+
+    return {
+      authority,
+      game,
+      proposal,
+      proposer: proposalAccount.proposer,
+    };
+  };
+
+  transferGameOwnershipAccounts = async (
+    game: PublicKey,
+    owner: PublicKey
+  ): Promise<{
+    owner: PublicKey;
+    game: PublicKey;
+  }> => {
+    return { owner, game };
+  };
+
+  acceptGameOwnershipAccounts = async (
+    game: PublicKey,
+    newOwner: PublicKey
+  ): Promise<{
+    newOwner: PublicKey;
+    game: PublicKey;
+  }> => {
+    return { newOwner, game };
+  };
+
+  setGamePausedAccounts = async (
+    game: PublicKey,
+    authority: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+  }> => {
+    return { authority, game };
+  };
+
   initializePlayerAccounts = async (
     user: PublicKey,
     payer?: PublicKey
@@ -142,93 +259,170 @@ export class AccountsBuilder {
     };
   };
 
-  addFtRewardAccounts = async (
+  setLeaderboardPausedAccounts = async (
     authority: PublicKey,
-    newReward: PublicKey,
-    achievement: PublicKey,
-    sourceTokenAccount: PublicKey,
-    tokenAccountOwner: PublicKey,
-    mint: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
+
+    return { authority, game: gameAddress, leaderboard };
+  };
+
+  archiveSeasonAccounts = async (
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    archive: PublicKey;
+    systemProgram: PublicKey;
+    season: BN;
+  }> => {
+    const topEntries = await this.leaderboardTopEntries(leaderboard);
+    const { season } = await this.program.account.leaderTopEntries.fetch(
+      topEntries
+    );
+
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      leaderboard,
+      topEntries,
+      archive: this.utils.deriveSeasonTopEntriesAddress(leaderboard, season)[0],
+      systemProgram: SystemProgram.programId,
+      season,
+    };
+  };
+
+  growTopEntriesAccounts = async (
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      leaderboard,
+      topEntries: await this.leaderboardTopEntries(leaderboard),
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  rebuildTopEntriesAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+  }> => {
+    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
+      leaderboard
+    );
+    if (leaderboardAccount.topEntries === null) {
+      throw new Error("Leaderboard has no top entries");
+    }
+
+    return {
+      authority,
+      game: game ?? leaderboardAccount.game,
+      leaderboard,
+      topEntries: leaderboardAccount.topEntries,
+    };
+  };
+
+  addTopEntriesAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
     game?: PublicKey,
     payer?: PublicKey
   ): Promise<{
     authority: PublicKey;
     payer: PublicKey;
     game: PublicKey;
-    achievement: PublicKey;
-    newReward: PublicKey;
-    rewardTokenMint: PublicKey;
-    delegateFromTokenAccount: PublicKey;
-    tokenAccountOwner: PublicKey;
-    tokenProgram: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
     systemProgram: PublicKey;
   }> => {
     const gameAddress =
-      game ?? (await this.program.account.achievement.fetch(achievement)).game;
+      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
 
     return {
       authority,
       payer: payer != null ? payer : this.provider.publicKey,
       game: gameAddress,
-      achievement,
-      newReward,
-      rewardTokenMint: mint,
-      delegateFromTokenAccount: sourceTokenAccount,
-      tokenAccountOwner,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      leaderboard,
+      topEntries: this.utils.deriveLeaderTopEntriesAddress(leaderboard)[0],
       systemProgram: SystemProgram.programId,
     };
   };
 
-  addNftRewardAccounts = async (
+  resizeTopEntriesAccounts = async (
     authority: PublicKey,
-    newReward: PublicKey,
-    achievement: PublicKey,
-    collectionMint?: PublicKey,
-    collectionUpdateAuthority?: PublicKey,
+    leaderboard: PublicKey,
     game?: PublicKey,
     payer?: PublicKey
   ): Promise<{
     authority: PublicKey;
     payer: PublicKey;
     game: PublicKey;
-    achievement: PublicKey;
-    newReward: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
     systemProgram: PublicKey;
-    rewardCollectionMint: PublicKey | null;
-    collectionUpdateAuth: PublicKey | null;
-    collectionMetadata: PublicKey | null;
-    tokenMetadataProgram: PublicKey | null;
   }> => {
-    const gameAddress =
-      game ?? (await this.program.account.achievement.fetch(achievement)).game;
-
-    let collectionMetadata: PublicKey | null = null;
-    let metadataProgram: PublicKey | null = null;
-
-    if (collectionMint !== undefined) {
-      if (collectionUpdateAuthority === undefined) {
-        throw new Error("Collection update authority should be defined");
-      }
-
-      collectionMetadata = this.utils.deriveMetadataAddress(collectionMint)[0];
-      metadataProgram = TOKEN_METADATA_PROGRAM_ID;
-    }
+    const accounts = await this.rebuildTopEntriesAccounts(
+      authority,
+      leaderboard,
+      game
+    );
 
     return {
-      authority,
+      ...accounts,
       payer: payer != null ? payer : this.provider.publicKey,
-      game: gameAddress,
-      achievement,
-      newReward,
       systemProgram: SystemProgram.programId,
-      rewardCollectionMint: collectionMint ?? null,
-      collectionUpdateAuth: collectionUpdateAuthority ?? null,
-      collectionMetadata,
-      tokenMetadataProgram: metadataProgram ?? null,
     };
   };
 
+  /**
+   * Remaining accounts for the instructions that rank a leaderboard's scores
+   * lists into its top entries: each player's scores list followed by their
+   * ban address.
+   */
+  topEntriesRemainingAccounts = (
+    leaderboard: PublicKey,
+    playerAccounts: PublicKey[]
+  ): AccountMeta[] => {
+    return playerAccounts.flatMap((playerAccount) => [
+      {
+        pubkey: this.utils.derivePlayerScoresListAddressForPlayer(
+          playerAccount,
+          leaderboard
+        )[0],
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: this.utils.derivePlayerBanAddressForPlayer(
+          playerAccount,
+          leaderboard
+        )[0],
+        isSigner: false,
+        isWritable: false,
+      },
+    ]);
+  };
+
   registerMergeApprovalAccounts = async (
     user: PublicKey,
     mergeAccount: PublicKey
@@ -244,426 +438,1608 @@ export class AccountsBuilder {
     };
   };
 
-  claimFtRewardAccounts = async (
-    authority: PublicKey,
-    achievement: PublicKey,
+  mergePlayerScoresAccounts = async (
     user: PublicKey,
-    reward?: PublicKey,
-    game?: PublicKey
+    mergeAccount: PublicKey,
+    mergedPlayerAccount: PublicKey,
+    leaderboard: PublicKey,
+    payer?: PublicKey
   ): Promise<{
+    payer: PublicKey;
     user: PublicKey;
-    authority: PublicKey;
     playerAccount: PublicKey;
-    game: PublicKey;
-    achievement: PublicKey;
-    reward: PublicKey;
-    playerAchievement: PublicKey;
-    sourceTokenAccount: PublicKey;
-    userTokenAccount: PublicKey;
-    tokenProgram: PublicKey;
-    mint: PublicKey;
+    mergeAccount: PublicKey;
+    mergedPlayerAccount: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    mergedPlayerScores: PublicKey;
+    mergedUser: PublicKey;
+    mergedPlayerBan: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
   }> => {
-    let rewardAddress: PublicKey;
-    let gameAddress: PublicKey;
-
-    if (reward === undefined || game === undefined) {
-      const account = await this.program.account.achievement.fetch(achievement);
-      const achievementAccount = AchievementAccount.fromIdlAccount(
-        account,
-        achievement
-      );
-      if (achievementAccount.reward === null) {
-        throw new Error("No reward for achievement");
-      }
-
-      rewardAddress = achievementAccount.reward;
-      gameAddress = achievementAccount.game;
-    } else {
-      rewardAddress = reward;
-      gameAddress = game;
-    }
-
-    const playerAccount = this.utils.derivePlayerAddress(user)[0];
-    const playerAchievement = this.utils.derivePlayerAchievementAddress(
-      user,
-      achievement
-    )[0];
-
-    const idlAccount = await this.program.account.reward.fetch(rewardAddress);
-    const rewardAccount = RewardAccount.fromIdlAccount(
-      idlAccount,
-      rewardAddress
+    const mergedPlayer = await this.program.account.player.fetch(
+      mergedPlayerAccount
+    );
+    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
+      leaderboard
     );
-
-    if (rewardAccount.FungibleToken === undefined) {
-      throw new Error("Not a fungible-token reward");
-    }
-    const mint = rewardAccount.FungibleToken.mint;
 
     return {
+      payer: payer != null ? payer : this.provider.publicKey,
       user,
-      authority,
-      playerAccount,
-      game: gameAddress,
-      achievement,
-      reward: rewardAddress,
-      playerAchievement,
-      sourceTokenAccount: rewardAccount.FungibleToken.account,
-      userTokenAccount: this.utils.deriveAssociatedTokenAddress(mint, user),
-      tokenProgram: TOKEN_PROGRAM_ID,
-      mint,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      mergeAccount,
+      mergedPlayerAccount,
+      leaderboard,
+      playerScores: this.utils.derivePlayerScoresListAddress(
+        user,
+        leaderboard
+      )[0],
+      mergedPlayerScores: this.utils.derivePlayerScoresListAddressForPlayer(
+        mergedPlayerAccount,
+        leaderboard
+      )[0],
+      mergedUser: mergedPlayer.user,
+      mergedPlayerBan: this.utils.derivePlayerBanAddressForPlayer(
+        mergedPlayerAccount,
+        leaderboard
+      )[0],
+      topEntries: leaderboardAccount.topEntries,
+      systemProgram: SystemProgram.programId,
     };
   };
 
-  claimNftRewardAccounts = async (
-    authority: PublicKey,
-    achievement: PublicKey,
-    mint: PublicKey,
+  mergePlayerAchievementAccounts = async (
     user: PublicKey,
-    reward?: PublicKey,
-    game?: PublicKey,
+    mergeAccount: PublicKey,
+    mergedPlayerAccount: PublicKey,
+    achievement: PublicKey,
     payer?: PublicKey
   ): Promise<{
+    payer: PublicKey;
     user: PublicKey;
-    authority: PublicKey;
     playerAccount: PublicKey;
-    game: PublicKey;
+    mergeAccount: PublicKey;
+    mergedPlayerAccount: PublicKey;
     achievement: PublicKey;
-    reward: PublicKey;
+    mergedPlayerAchievement: PublicKey;
     playerAchievement: PublicKey;
-    payer: PublicKey;
-    claim: PublicKey;
-    newMint: PublicKey;
-    newMetadata: PublicKey;
-    newMasterEdition: PublicKey;
-    mintTo: PublicKey;
-    tokenMetadataProgram: PublicKey;
-    associatedTokenProgram: PublicKey;
     systemProgram: PublicKey;
-    tokenProgram: PublicKey;
-    rent: PublicKey;
   }> => {
-    let rewardAddress: PublicKey;
-    let gameAddress: PublicKey;
-
-    if (reward === undefined || game === undefined) {
-      const account = await this.program.account.achievement.fetch(achievement);
-      const achievementAccount = AchievementAccount.fromIdlAccount(
-        account,
-        achievement
-      );
-      if (achievementAccount.reward === null) {
-        throw new Error("No reward for achievement");
-      }
-
-      rewardAddress = achievementAccount.reward;
-      gameAddress = achievementAccount.game;
-    } else {
-      rewardAddress = reward;
-      gameAddress = game;
-    }
-
-    const playerAccount = this.utils.derivePlayerAddress(user)[0];
-    const playerAchievement = this.utils.derivePlayerAchievementAddress(
-      user,
-      achievement
-    )[0];
-
-    const metadata = this.utils.deriveMetadataAddress(mint)[0];
-    const masterEdition = this.utils.deriveEditionAddress(mint)[0];
-    const userAta = this.utils.deriveAssociatedTokenAddress(mint, user);
-
-    const claim = this.utils.deriveNftClaimAddress(rewardAddress, mint)[0];
-
     return {
+      payer: payer != null ? payer : this.provider.publicKey,
       user,
-      authority,
-      playerAccount,
-      game: gameAddress,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      mergeAccount,
+      mergedPlayerAccount,
       achievement,
-      reward: rewardAddress,
-      playerAchievement,
-      payer: payer != null ? payer : this.provider.publicKey,
-      claim,
-      newMint: mint,
-      newMetadata: metadata,
-      newMasterEdition: masterEdition,
-      mintTo: userAta,
-      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
-      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      mergedPlayerAchievement:
+        this.utils.derivePlayerAchievementAddressForPlayer(
+          mergedPlayerAccount,
+          achievement
+        )[0],
+      playerAchievement: this.utils.derivePlayerAchievementAddress(
+        user,
+        achievement
+      )[0],
       systemProgram: SystemProgram.programId,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      rent: SYSVAR_RENT_PUBKEY,
     };
   };
 
-  registerPlayerEntryAccounts = async (
+  /**
+   * Remaining accounts carrying a merged player's reward claims over to
+   * `user`: for each of the achievement's rewards, the merged player's claim
+   * followed by `user`'s.
+   *
+   * Only needed if the merged player has claimed the achievement's rewards.
+   */
+  mergePlayerAchievementRemainingAccounts = async (
     user: PublicKey,
-    leaderboard: PublicKey,
+    mergedPlayerAccount: PublicKey,
+    achievement: PublicKey
+  ): Promise<AccountMeta[]> => {
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+    const mergedPlayerAchievement = await this.program.account.playerAchievement
+      .fetch(
+        this.utils.derivePlayerAchievementAddressForPlayer(
+          mergedPlayerAccount,
+          achievement
+        )[0]
+      );
+    if (!mergedPlayerAchievement.claimed) {
+      return [];
+    }
+
+    const { rewards } = await this.program.account.achievement.fetch(
+      achievement
+    );
+    return rewards.flatMap((reward) => [
+      {
+        pubkey: this.utils.deriveRewardClaimAddressForPlayer(
+          mergedPlayerAccount,
+          reward
+        )[0],
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: this.utils.deriveRewardClaimAddressForPlayer(
+          playerAccount,
+          reward
+        )[0],
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+  };
+
+  addFtRewardAccounts = async (
+    authority: PublicKey,
+    achievement: PublicKey,
+    sourceTokenAccount: PublicKey,
+    tokenAccountOwner: PublicKey,
+    mint: PublicKey,
+    nextReward?: PublicKey,
     game?: PublicKey,
     payer?: PublicKey
   ): Promise<{
-    user: PublicKey;
+    authority: PublicKey;
     payer: PublicKey;
-    playerAccount: PublicKey;
-    newList: PublicKey;
     game: PublicKey;
-    leaderboard: PublicKey;
+    achievement: PublicKey;
+    newReward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    sourceTokenAccount: PublicKey;
+    tokenAccountOwner: PublicKey;
+    tokenProgram: PublicKey;
     systemProgram: PublicKey;
   }> => {
-    const gameAddress =
-      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
-    const playerAccount = this.utils.derivePlayerAddress(user)[0];
-    const newList = this.utils.derivePlayerScoresListAddress(
-      user,
-      leaderboard
-    )[0];
+    const [gameAddress, newReward] = await this.nextReward(
+      achievement,
+      nextReward,
+      game
+    );
 
     return {
-      user,
+      authority,
       payer: payer != null ? payer : this.provider.publicKey,
-      playerAccount,
-      newList,
       game: gameAddress,
-      leaderboard,
+      achievement,
+      newReward,
+      rewardTokenMint: mint,
+      rewardVault: this.utils.deriveRewardVaultAddress(newReward)[0],
+      sourceTokenAccount,
+      tokenAccountOwner,
+      tokenProgram: await this.mintTokenProgram(mint),
       systemProgram: SystemProgram.programId,
     };
   };
 
-  submitScoreAccounts = async (
-    user: PublicKey,
+  addNftRewardAccounts = async (
     authority: PublicKey,
-    leaderboard: PublicKey,
+    achievement: PublicKey,
+    collectionMint?: PublicKey,
+    collectionUpdateAuthority?: PublicKey,
+    nextReward?: PublicKey,
     game?: PublicKey,
     payer?: PublicKey
   ): Promise<{
-    payer: PublicKey;
-    playerAccount: PublicKey;
     authority: PublicKey;
+    payer: PublicKey;
     game: PublicKey;
-    leaderboard: PublicKey;
-    playerScores: PublicKey;
-    topEntries: PublicKey | null;
+    achievement: PublicKey;
+    newReward: PublicKey;
     systemProgram: PublicKey;
+    rewardCollectionMint: PublicKey | null;
+    collectionUpdateAuth: PublicKey | null;
+    collectionMetadata: PublicKey | null;
+    tokenMetadataProgram: PublicKey | null;
   }> => {
-    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
-      leaderboard
+    const [gameAddress, newReward] = await this.nextReward(
+      achievement,
+      nextReward,
+      game
     );
-    const gameAddress = game ?? leaderboardAccount.game;
-    const playerAccount = this.utils.derivePlayerAddress(user)[0];
-    const playerScores = this.utils.derivePlayerScoresListAddress(
-      user,
-      leaderboard
-    )[0];
-    const topEntries = leaderboardAccount.topEntries;
+
+    let collectionMetadata: PublicKey | null = null;
+    let metadataProgram: PublicKey | null = null;
+
+    if (collectionMint !== undefined) {
+      if (collectionUpdateAuthority === undefined) {
+        throw new Error("Collection update authority should be defined");
+      }
+
+      collectionMetadata = this.utils.deriveMetadataAddress(collectionMint)[0];
+      metadataProgram = TOKEN_METADATA_PROGRAM_ID;
+    }
 
     return {
-      payer: payer != null ? payer : this.provider.publicKey,
-      playerAccount,
       authority,
+      payer: payer != null ? payer : this.provider.publicKey,
       game: gameAddress,
-      leaderboard,
-      playerScores,
-      topEntries,
+      achievement,
+      newReward,
       systemProgram: SystemProgram.programId,
+      rewardCollectionMint: collectionMint ?? null,
+      collectionUpdateAuth: collectionUpdateAuthority ?? null,
+      collectionMetadata,
+      tokenMetadataProgram: metadataProgram ?? null,
     };
   };
 
-  updateAchievementAccounts = async (
+  topUpFtRewardAccounts = async (
     authority: PublicKey,
-    achievement: PublicKey,
-    game?: PublicKey
+    reward: PublicKey,
+    sourceTokenAccount: PublicKey,
+    tokenAccountOwner: PublicKey
   ): Promise<{
     authority: PublicKey;
     game: PublicKey;
     achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    sourceTokenAccount: PublicKey;
+    tokenAccountOwner: PublicKey;
+    tokenProgram: PublicKey;
   }> => {
-    const gameAddress =
-      game ?? (await this.program.account.achievement.fetch(achievement)).game;
+    const { game, achievement, ft } = await this.ftReward(reward);
 
     return {
       authority,
-      game: gameAddress,
+      game,
       achievement,
+      reward,
+      rewardTokenMint: ft.mint,
+      rewardVault: ft.account,
+      sourceTokenAccount,
+      tokenAccountOwner,
+      tokenProgram: this.utils.rewardTokenProgram(ft.tokenProgram),
     };
   };
 
-  unlockPlayerAchievementAccounts = async (
-    user: PublicKey,
+  withdrawFtRewardAccounts = async (
     authority: PublicKey,
-    achievement: PublicKey,
-    leaderboard: PublicKey,
-    game?: PublicKey,
-    payer?: PublicKey
+    reward: PublicKey,
+    destinationTokenAccount: PublicKey
   ): Promise<{
-    payer: PublicKey;
-    playerAccount: PublicKey;
-    playerScores: PublicKey;
+    authority: PublicKey;
     game: PublicKey;
     achievement: PublicKey;
-    authority: PublicKey;
-    playerAchievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    destinationTokenAccount: PublicKey;
+    tokenProgram: PublicKey;
   }> => {
-    const playerAccount = this.utils.derivePlayerAddress(user)[0];
-
-    const gameAddress =
-      game ?? (await this.program.account.achievement.fetch(achievement)).game;
-    const playerEntryList = this.utils.derivePlayerScoresListAddress(
-      user,
-      leaderboard
-    )[0];
-    const newPlayerAchievement = this.utils.derivePlayerAchievementAddress(
-      user,
-      achievement
-    )[0];
+    const { game, achievement, ft } = await this.ftReward(reward);
 
     return {
-      payer: payer != null ? payer : this.provider.publicKey,
-      playerAccount,
-      playerScores: playerEntryList,
-      game: gameAddress,
-      achievement,
       authority,
-      playerAchievement: newPlayerAchievement,
+      game,
+      achievement,
+      reward,
+      rewardTokenMint: ft.mint,
+      rewardVault: ft.account,
+      destinationTokenAccount,
+      tokenProgram: this.utils.rewardTokenProgram(ft.tokenProgram),
     };
   };
 
-  updateGameAccounts = async (
-    game: PublicKey,
+  updateRewardAccounts = async (
     authority: PublicKey,
-    payer?: PublicKey
+    reward: PublicKey
   ): Promise<{
-    payer: PublicKey;
-    game: PublicKey;
     authority: PublicKey;
-    systemProgram: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardVault: PublicKey | null;
   }> => {
+    const rewardAccount = await this.fetchReward(reward);
+    const { game } = await this.program.account.achievement.fetch(
+      rewardAccount.achievement
+    );
+
     return {
-      payer: payer != null ? payer : this.provider.publicKey,
-      game,
       authority,
-      systemProgram: SystemProgram.programId,
+      game,
+      achievement: rewardAccount.achievement,
+      reward,
+      rewardVault:
+        rewardAccount.FungibleToken !== undefined
+          ? this.utils.deriveRewardVaultAddress(reward)[0]
+          : null,
     };
   };
 
-  updateLeaderboardAccounts = async (
+  setRewardPausedAccounts = async (
     authority: PublicKey,
-    leaderboard: PublicKey,
-    game?: PublicKey,
-    topEntries?: PublicKey
+    reward: PublicKey
   ): Promise<{
     authority: PublicKey;
     game: PublicKey;
-    leaderboard: PublicKey;
-    topEntries: PublicKey | null;
+    achievement: PublicKey;
+    reward: PublicKey;
   }> => {
-    const gameAddress =
-      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
+    const { achievement } = await this.program.account.reward.fetch(reward);
+    const { game } = await this.program.account.achievement.fetch(achievement);
+
+    return { authority, game, achievement, reward };
+  };
+
+  cancelRewardAccounts = async (
+    authority: PublicKey,
+    reward: PublicKey,
+    destinationTokenAccount?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey | null;
+    rewardVault: PublicKey | null;
+    destinationTokenAccount: PublicKey | null;
+    tokenProgram: PublicKey | null;
+  }> => {
+    const rewardAccount = await this.fetchReward(reward);
+    const { game } = await this.program.account.achievement.fetch(
+      rewardAccount.achievement
+    );
+
+    const ft = rewardAccount.FungibleToken;
+    const tokenProgram =
+      ft !== undefined ? this.utils.rewardTokenProgram(ft.tokenProgram) : null;
 
     return {
       authority,
-      game: gameAddress,
-      leaderboard,
-      topEntries: topEntries ?? null,
+      game,
+      achievement: rewardAccount.achievement,
+      reward,
+      rewardTokenMint: ft !== undefined ? ft.mint : null,
+      rewardVault: ft !== undefined ? ft.account : null,
+      destinationTokenAccount:
+        ft !== undefined
+          ? destinationTokenAccount ??
+            this.utils.deriveAssociatedTokenAddress(
+              ft.mint,
+              authority,
+              tokenProgram
+            )
+          : null,
+      tokenProgram,
     };
   };
 
-  updatePlayerAccounts = async (
-    user: PublicKey
-  ): Promise<{
-    user: PublicKey;
-    playerAccount: PublicKey;
-  }> => {
+  /**
+   * Accounts paying out a fungible-token reward to `user`'s associated token
+   * account, which is created if it doesn't exist.
+   */
+  claimFtRewardAccounts = async (
+    user: PublicKey,
+    reward: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<ClaimFtRewardAccounts> => {
+    const { achievement, ft } = await this.ftReward(reward, game);
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+    const tokenProgram = this.utils.rewardTokenProgram(ft.tokenProgram);
+
     return {
       user,
-      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      payer: payer != null ? payer : this.provider.publicKey,
+      game: game ?? (await this.achievementGame(achievement)),
+      achievement,
+      reward,
+      playerAccount,
+      playerAchievement: this.utils.derivePlayerAchievementAddress(
+        user,
+        achievement
+      )[0],
+      rewardClaim: this.utils.deriveRewardClaimAddressForPlayer(
+        playerAccount,
+        reward
+      )[0],
+      rewardTokenMint: ft.mint,
+      rewardVault: ft.account,
+      userTokenAccount: this.utils.deriveAssociatedTokenAddress(
+        ft.mint,
+        user,
+        tokenProgram
+      ),
+      tokenProgram,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
     };
   };
 
-  verifyNftRewardAccounts = async (
+  /** Accounts minting a non-fungible-token reward to `user` as `mint`. */
+  claimNftRewardAccounts = async (
     user: PublicKey,
-    achievement: PublicKey,
+    reward: PublicKey,
     mint: PublicKey,
-    reward?: PublicKey,
     game?: PublicKey,
     payer?: PublicKey
-  ): Promise<{
+  ): Promise<ClaimNftRewardAccounts> => {
+    const { achievement } = await this.program.account.reward.fetch(reward);
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+
+    return {
+      user,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game: game ?? (await this.achievementGame(achievement)),
+      achievement,
+      reward,
+      playerAccount,
+      playerAchievement: this.utils.derivePlayerAchievementAddress(
+        user,
+        achievement
+      )[0],
+      rewardClaim: this.utils.deriveRewardClaimAddressForPlayer(
+        playerAccount,
+        reward
+      )[0],
+      claim: this.utils.deriveNftClaimAddress(reward, mint)[0],
+      newMint: mint,
+      newMetadata: this.utils.deriveMetadataAddress(mint)[0],
+      newMasterEdition: this.utils.deriveEditionAddress(mint)[0],
+      mintTo: this.utils.deriveAssociatedTokenAddress(mint, user),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    };
+  };
+
+  claimFtRewardsAccounts = async (
+    authority: PublicKey,
+    user: PublicKey,
+    achievement: PublicKey,
+    tokenProgram?: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    playerAccount: PublicKey;
+    playerAchievement: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.achievement.fetch(achievement)).game;
+
+    return {
+      user,
+      authority,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game: gameAddress,
+      achievement,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      playerAchievement: this.utils.derivePlayerAchievementAddress(
+        user,
+        achievement
+      )[0],
+      tokenProgram: tokenProgram ?? TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  /**
+   * Remaining accounts paying out each of `rewards` at once: the reward,
+   * `user`'s claim on it, its mint, its vault and `user`'s associated token
+   * account, which must exist.
+   */
+  claimFtRewardsRemainingAccounts = async (
+    user: PublicKey,
+    rewards: PublicKey[]
+  ): Promise<AccountMeta[]> => {
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+    const accounts = await Promise.all(
+      rewards.map(async (reward) => {
+        const { ft } = await this.ftReward(reward);
+        const tokenProgram = this.utils.rewardTokenProgram(ft.tokenProgram);
+        return [
+          reward,
+          this.utils.deriveRewardClaimAddressForPlayer(
+            playerAccount,
+            reward
+          )[0],
+          ft.mint,
+          ft.account,
+          this.utils.deriveAssociatedTokenAddress(ft.mint, user, tokenProgram),
+        ];
+      })
+    );
+
+    return accounts.flatMap(([reward, claim, mint, vault, tokenAccount]) => [
+      { pubkey: reward, isSigner: false, isWritable: true },
+      { pubkey: claim, isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: vault, isSigner: false, isWritable: true },
+      { pubkey: tokenAccount, isSigner: false, isWritable: true },
+    ]);
+  };
+
+  registerPlayerEntryAccounts = async (
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    payer: PublicKey;
+    playerAccount: PublicKey;
+    newList: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerBan: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+    const newList = this.utils.derivePlayerScoresListAddress(
+      user,
+      leaderboard
+    )[0];
+
+    return {
+      user,
+      payer: payer != null ? payer : this.provider.publicKey,
+      playerAccount,
+      newList,
+      game: gameAddress,
+      leaderboard,
+      playerBan: this.utils.derivePlayerBanAddress(user, leaderboard)[0],
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  submitScoreAccounts = async (
+    user: PublicKey,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    playerAccount: PublicKey;
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    playerBan: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
+  }> => {
+    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
+      leaderboard
+    );
+    const gameAddress = game ?? leaderboardAccount.game;
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+    const playerScores = this.utils.derivePlayerScoresListAddress(
+      user,
+      leaderboard
+    )[0];
+    const topEntries = leaderboardAccount.topEntries;
+
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      playerAccount,
+      authority,
+      game: gameAddress,
+      leaderboard,
+      playerScores,
+      playerBan: this.utils.derivePlayerBanAddress(user, leaderboard)[0],
+      topEntries,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  submitAttestedScoreAccounts = async (
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    playerAccount: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    playerBan: PublicKey;
+    topEntries: PublicKey | null;
+    instructions: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const accounts = await this.submitScoreAccounts(
+      user,
+      user,
+      leaderboard,
+      game
+    );
+
+    return {
+      user,
+      playerAccount: accounts.playerAccount,
+      game: accounts.game,
+      leaderboard,
+      playerScores: accounts.playerScores,
+      playerBan: accounts.playerBan,
+      topEntries: accounts.topEntries,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  removePlayerScoreAccounts = async (
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    topEntries: PublicKey | null;
+  }> => {
+    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
+      leaderboard
+    );
+
+    return {
+      authority,
+      game: game ?? leaderboardAccount.game,
+      leaderboard,
+      playerScores: this.utils.derivePlayerScoresListAddress(
+        user,
+        leaderboard
+      )[0],
+      topEntries: leaderboardAccount.topEntries,
+    };
+  };
+
+  evictPlayerAccounts = async (
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    playerAccount: PublicKey;
+  }> => {
+    const accounts = await this.rebuildTopEntriesAccounts(
+      authority,
+      leaderboard,
+      game
+    );
+
+    return {
+      ...accounts,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+    };
+  };
+
+  banPlayerAccounts = async (
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerAccount: PublicKey;
+    ban: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
+
+    return {
+      authority,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game: gameAddress,
+      leaderboard,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      ban: this.utils.derivePlayerBanAddress(user, leaderboard)[0],
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  unbanPlayerAccounts = async (
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    ban: PublicKey;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
+
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game: gameAddress,
+      leaderboard,
+      ban: this.utils.derivePlayerBanAddress(user, leaderboard)[0],
+    };
+  };
+
+  updateAchievementAccounts = async (
+    authority: PublicKey,
+    achievement: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.achievement.fetch(achievement)).game;
+
+    return {
+      authority,
+      game: gameAddress,
+      achievement,
+    };
+  };
+
+  unlockPlayerAchievementAccounts = async (
+    user: PublicKey,
+    authority: PublicKey,
+    achievement: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    playerAccount: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    playerAchievement: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+
+    const gameAddress =
+      game ?? (await this.program.account.achievement.fetch(achievement)).game;
+    const newPlayerAchievement = this.utils.derivePlayerAchievementAddress(
+      user,
+      achievement
+    )[0];
+
+    return {
+      authority,
+      payer: payer != null ? payer : this.provider.publicKey,
+      playerAccount,
+      game: gameAddress,
+      achievement,
+      playerAchievement: newPlayerAchievement,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  updateGameAccounts = async (
+    game: PublicKey,
+    authority: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    game: PublicKey;
+    authority: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      game,
+      authority,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  updateLeaderboardAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    topEntries?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+  }> => {
+    const gameAddress =
+      game ?? (await this.program.account.leaderBoard.fetch(leaderboard)).game;
+
+    return {
+      authority,
+      game: gameAddress,
+      leaderboard,
+      topEntries: topEntries ?? null,
+    };
+  };
+
+  updatePlayerAccounts = async (
+    user: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    playerAccount: PublicKey;
+  }> => {
+    return {
+      user,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+    };
+  };
+
+  verifyNftRewardAccounts = async (
+    user: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    user: PublicKey;
+    playerAccount: PublicKey;
+    achievement: PublicKey;
+    game: PublicKey;
+    reward: PublicKey;
+    playerAchievement: PublicKey;
+    mint: PublicKey;
+    claim: PublicKey;
+    metadataToVerify: PublicKey;
+    collectionMint: PublicKey;
+    collectionMetadata: PublicKey;
+    collectionEdition: PublicKey;
+    tokenMetadataProgram: PublicKey;
+  }> => {
+    const rewardAccount = await this.fetchReward(reward);
+    if (
+      rewardAccount.NonFungibleToken === undefined ||
+      rewardAccount.NonFungibleToken?.collection === null
+    ) {
+      throw new Error("No collection to verify rewards for.");
+    }
+    const achievement = rewardAccount.achievement;
+    const gameAddress =
+      game ?? (await this.program.account.achievement.fetch(achievement)).game;
+
+    const playerAccount = this.utils.derivePlayerAddress(user)[0];
+    const playerAchievement = this.utils.derivePlayerAchievementAddress(
+      user,
+      achievement
+    )[0];
+
+    const claim = this.utils.deriveNftClaimAddress(reward, mint)[0];
+    const metadata = this.utils.deriveMetadataAddress(mint)[0];
+
+    const collectionMint = rewardAccount.NonFungibleToken.collection;
+    const collectionMetadata =
+      this.utils.deriveMetadataAddress(collectionMint)[0];
+    const collectionEdition =
+      this.utils.deriveEditionAddress(collectionMint)[0];
+
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      user,
+      playerAccount,
+      achievement,
+      game: gameAddress,
+      reward,
+      playerAchievement,
+      mint,
+      claim,
+      metadataToVerify: metadata,
+      collectionMint,
+      collectionEdition,
+      collectionMetadata,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+    };
+  };
+
+  addFtRankRewardAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    newReward: PublicKey,
+    sourceTokenAccount: PublicKey,
+    tokenAccountOwner: PublicKey,
+    mint: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    newReward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    sourceTokenAccount: PublicKey;
+    tokenAccountOwner: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const accounts = await this.addNftRankRewardAccounts(
+      authority,
+      leaderboard,
+      newReward,
+      game,
+      payer
+    );
+
+    return {
+      ...accounts,
+      rewardTokenMint: mint,
+      rewardVault: this.utils.deriveRewardVaultAddress(newReward)[0],
+      sourceTokenAccount,
+      tokenAccountOwner,
+      tokenProgram: await this.mintTokenProgram(mint),
+    };
+  };
+
+  addNftRankRewardAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    newReward: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    newReward: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const accounts = await this.rebuildTopEntriesAccounts(
+      authority,
+      leaderboard,
+      game
+    );
+
+    return {
+      ...accounts,
+      payer: payer != null ? payer : this.provider.publicKey,
+      newReward,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  claimFtRankRewardAccounts = async (
+    user: PublicKey,
+    rankReward: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+    seasonTopEntries: PublicKey | null;
+    rankReward: PublicKey;
+    playerAccount: PublicKey;
+    claim: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    userTokenAccount: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const [rankRewardAccount, ranking] = await this.rankRewardRanking(
+      rankReward
+    );
+    const ft = rankRewardAccount.FungibleToken;
+    if (ft === undefined) {
+      throw new Error("Not a fungible-token reward");
+    }
+    const tokenProgram = this.utils.rewardTokenProgram(ft.tokenProgram);
+
+    return {
+      user,
+      payer: payer != null ? payer : this.provider.publicKey,
+      ...ranking,
+      rankReward,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      claim: this.utils.deriveRankRewardClaimAddress(user, rankReward)[0],
+      rewardTokenMint: ft.mint,
+      rewardVault: ft.account,
+      userTokenAccount: this.utils.deriveAssociatedTokenAddress(
+        ft.mint,
+        user,
+        tokenProgram
+      ),
+      tokenProgram,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  claimNftRankRewardAccounts = async (
+    user: PublicKey,
+    rankReward: PublicKey,
+    mint: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+    seasonTopEntries: PublicKey | null;
+    rankReward: PublicKey;
+    playerAccount: PublicKey;
+    claim: PublicKey;
+    newMint: PublicKey;
+    newMetadata: PublicKey;
+    newMasterEdition: PublicKey;
+    mintTo: PublicKey;
+    tokenMetadataProgram: PublicKey;
+    associatedTokenProgram: PublicKey;
+    systemProgram: PublicKey;
+    tokenProgram: PublicKey;
+    rent: PublicKey;
+  }> => {
+    const ranking = (await this.rankRewardRanking(rankReward))[1];
+
+    return {
+      user,
+      payer: payer != null ? payer : this.provider.publicKey,
+      ...ranking,
+      rankReward,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      claim: this.utils.deriveRankRewardClaimAddress(user, rankReward)[0],
+      newMint: mint,
+      newMetadata: this.utils.deriveMetadataAddress(mint)[0],
+      newMasterEdition: this.utils.deriveEditionAddress(mint)[0],
+      mintTo: this.utils.deriveAssociatedTokenAddress(mint, user),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    };
+  };
+
+  closeGameAccounts = async (
+    authority: PublicKey,
+    game: PublicKey,
+    receiver?: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
     payer: PublicKey;
+    game: PublicKey;
+    closedGame: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game,
+      closedGame: this.utils.deriveClosedGameAddress(game)[0],
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  closeLeaderboardAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+  }> => {
+    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
+      leaderboard
+    );
+
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game: leaderboardAccount.game,
+      leaderboard,
+      topEntries: leaderboardAccount.topEntries,
+    };
+  };
+
+  closeSeasonTopEntriesAccounts = async (
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    season: BN,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    archive: PublicKey;
+  }> => {
+    const { game } = await this.program.account.leaderBoard.fetch(leaderboard);
+
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game,
+      leaderboard,
+      archive: this.utils.deriveSeasonTopEntriesAddress(leaderboard, season)[0],
+    };
+  };
+
+  closeAchievementAccounts = async (
+    authority: PublicKey,
+    achievement: PublicKey,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+  }> => {
+    const { game } = await this.program.account.achievement.fetch(achievement);
+
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game,
+      achievement,
+    };
+  };
+
+  closeRewardAccounts = async (
+    authority: PublicKey,
+    reward: PublicKey,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardVault: PublicKey | null;
+    tokenProgram: PublicKey | null;
+  }> => {
+    const rewardAccount = await this.fetchReward(reward);
+    const { game } = await this.program.account.achievement.fetch(
+      rewardAccount.achievement
+    );
+    const ft = rewardAccount.FungibleToken;
+
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game,
+      achievement: rewardAccount.achievement,
+      reward,
+      rewardVault: ft !== undefined ? ft.account : null,
+      tokenProgram:
+        ft !== undefined
+          ? this.utils.rewardTokenProgram(ft.tokenProgram)
+          : null,
+    };
+  };
+
+  closeRankRewardAccounts = async (
+    authority: PublicKey,
+    rankReward: PublicKey,
+    destinationTokenAccount?: PublicKey,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    rankReward: PublicKey;
+    rewardTokenMint: PublicKey | null;
+    rewardVault: PublicKey | null;
+    destinationTokenAccount: PublicKey | null;
+    tokenProgram: PublicKey | null;
+  }> => {
+    const rankRewardAccount = RankRewardAccount.fromIdlAccount(
+      await this.program.account.rankReward.fetch(rankReward),
+      rankReward
+    );
+    const { game } = await this.program.account.leaderBoard.fetch(
+      rankRewardAccount.leaderboard
+    );
+
+    const ft = rankRewardAccount.FungibleToken;
+    const tokenProgram =
+      ft !== undefined ? this.utils.rewardTokenProgram(ft.tokenProgram) : null;
+
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game,
+      leaderboard: rankRewardAccount.leaderboard,
+      rankReward,
+      rewardTokenMint: ft !== undefined ? ft.mint : null,
+      rewardVault: ft !== undefined ? ft.account : null,
+      destinationTokenAccount:
+        ft !== undefined
+          ? destinationTokenAccount ??
+            this.utils.deriveAssociatedTokenAddress(
+              ft.mint,
+              authority,
+              tokenProgram
+            )
+          : null,
+      tokenProgram,
+    };
+  };
+
+  closePlayerAccounts = async (
+    user: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    playerAccount: PublicKey;
+  }> => {
+    return this.updatePlayerAccounts(user);
+  };
+
+  closePlayerScoresAccounts = async (
+    user: PublicKey,
+    leaderboard: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    playerAccount: PublicKey;
+    playerScores: PublicKey;
+  }> => {
+    return {
+      user,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      playerScores: this.utils.derivePlayerScoresListAddress(
+        user,
+        leaderboard
+      )[0],
+    };
+  };
+
+  closePlayerAchievementAccounts = async (
+    user: PublicKey,
+    achievement: PublicKey
+  ): Promise<{
     user: PublicKey;
     playerAccount: PublicKey;
     achievement: PublicKey;
+    playerAchievement: PublicKey;
+  }> => {
+    return {
+      user,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      achievement,
+      playerAchievement: this.utils.derivePlayerAchievementAddress(
+        user,
+        achievement
+      )[0],
+    };
+  };
+
+  closeMergedAccounts = async (
+    user: PublicKey,
+    mergeAccount: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    mergeAccount: PublicKey;
+  }> => {
+    return { user, mergeAccount };
+  };
+
+  closeNftClaimAccounts = async (
+    authority: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    receiver?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    receiver: PublicKey;
     game: PublicKey;
+    achievement: PublicKey;
     reward: PublicKey;
-    playerAchievement: PublicKey;
     mint: PublicKey;
     claim: PublicKey;
-    metadataToVerify: PublicKey;
-    collectionMint: PublicKey;
-    collectionMetadata: PublicKey;
-    collectionEdition: PublicKey;
-    tokenMetadataProgram: PublicKey;
   }> => {
-    let rewardAddress: PublicKey;
-    let gameAddress: PublicKey;
+    const { achievement } = await this.program.account.reward.fetch(reward);
+    const { game } = await this.program.account.achievement.fetch(achievement);
 
-    if (reward === undefined || game === undefined) {
-      const account = await this.program.account.achievement.fetch(achievement);
-      const achievementAccount = AchievementAccount.fromIdlAccount(
-        account,
-        achievement
-      );
-      if (achievementAccount.reward === null) {
-        throw new Error("No reward for achievement");
-      }
+    return {
+      authority,
+      receiver: receiver != null ? receiver : this.provider.publicKey,
+      game,
+      achievement,
+      reward,
+      mint,
+      claim: this.utils.deriveNftClaimAddress(reward, mint)[0],
+    };
+  };
 
-      rewardAddress = achievementAccount.reward;
-      gameAddress = achievementAccount.game;
-    } else {
-      rewardAddress = reward;
-      gameAddress = game;
-    }
+  closeRewardClaimAccounts = async (
+    user: PublicKey,
+    reward: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    playerAccount: PublicKey;
+    reward: PublicKey;
+    claim: PublicKey;
+  }> => {
+    return {
+      user,
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      reward,
+      claim: this.utils.deriveRewardClaimAddress(user, reward)[0],
+    };
+  };
 
-    const playerAccount = this.utils.derivePlayerAddress(user)[0];
-    const playerAchievement = this.utils.derivePlayerAchievementAddress(
+  closeRankRewardClaimAccounts = async (
+    user: PublicKey,
+    rankReward: PublicKey
+  ): Promise<{
+    user: PublicKey;
+    playerAccount: PublicKey;
+    rankReward: PublicKey;
+    claim: PublicKey;
+  }> => {
+    return {
       user,
-      achievement
-    )[0];
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      rankReward,
+      claim: this.utils.deriveRankRewardClaimAddress(user, rankReward)[0],
+    };
+  };
 
-    const claim = this.utils.deriveNftClaimAddress(rewardAddress, mint)[0];
-    const metadata = this.utils.deriveMetadataAddress(mint)[0];
+  migrateGameAccounts = async (
+    authority: PublicKey,
+    game: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    return {
+      authority,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game,
+      systemProgram: SystemProgram.programId,
+    };
+  };
 
-    const rewardAccount = RewardAccount.fromIdlAccount(
-      await this.program.account.reward.fetch(rewardAddress),
-      rewardAddress
-    );
-    if (
-      rewardAccount.NonFungibleToken === undefined ||
-      rewardAccount.NonFungibleToken?.collection === null
-    ) {
-      throw new Error("No collection to verify rewards for.");
-    }
+  /**
+   * Legacy leaderboards can't be decoded with the current layout, so their
+   * top-entries account is passed if it exists at its derived address.
+   */
+  migrateLeaderboardAccounts = async (
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
+  }> => {
+    const topEntries = this.utils.deriveLeaderTopEntriesAddress(leaderboard)[0];
+    const info = await this.provider.connection.getAccountInfo(topEntries);
 
-    const collectionMint = rewardAccount.NonFungibleToken.collection;
-    const collectionMetadata =
-      this.utils.deriveMetadataAddress(collectionMint)[0];
-    const collectionEdition =
-      this.utils.deriveEditionAddress(collectionMint)[0];
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      leaderboard,
+      topEntries: info !== null ? topEntries : null,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  migratePlayerScoresAccounts = async (
+    user: PublicKey,
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    playerScores: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    return {
+      payer: payer != null ? payer : this.provider.publicKey,
+      playerScores: this.utils.derivePlayerScoresListAddress(
+        user,
+        leaderboard
+      )[0],
+      systemProgram: SystemProgram.programId,
+    };
+  };
 
+  migrateAchievementAccounts = async (
+    achievement: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    payer: PublicKey;
+    achievement: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
     return {
       payer: payer != null ? payer : this.provider.publicKey,
-      user,
-      playerAccount,
       achievement,
-      game: gameAddress,
-      reward: rewardAddress,
-      playerAchievement,
-      mint,
-      claim,
-      metadataToVerify: metadata,
-      collectionMint,
-      collectionEdition,
-      collectionMetadata,
-      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
     };
   };
+
+  /** Legacy rewards always hold SPL Token mints. */
+  migrateFtRewardAccounts = async (
+    authority: PublicKey,
+    reward: PublicKey,
+    payer?: PublicKey
+  ): Promise<{
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey;
+    legacyTokenAccount: PublicKey;
+    rewardVault: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  }> => {
+    const { game, achievement, ft } = await this.ftReward(reward);
+
+    return {
+      authority,
+      payer: payer != null ? payer : this.provider.publicKey,
+      game,
+      achievement,
+      reward,
+      rewardTokenMint: ft.mint,
+      legacyTokenAccount: ft.account,
+      rewardVault: this.utils.deriveRewardVaultAddress(reward)[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  private readonly fetchReward = async (
+    reward: PublicKey
+  ): Promise<RewardAccount> => {
+    return RewardAccount.fromIdlAccount(
+      await this.program.account.reward.fetch(reward),
+      reward
+    );
+  };
+
+  /** A fungible-token reward, along with its achievement and game. */
+  private readonly ftReward = async (
+    reward: PublicKey,
+    game?: PublicKey
+  ): Promise<{
+    game: PublicKey;
+    achievement: PublicKey;
+    ft: FungibleTokenReward;
+  }> => {
+    const rewardAccount = await this.fetchReward(reward);
+    if (rewardAccount.FungibleToken === undefined) {
+      throw new Error("Not a fungible-token reward");
+    }
+    const achievement = rewardAccount.achievement;
+
+    return {
+      game: game ?? (await this.achievementGame(achievement)),
+      achievement,
+      ft: rewardAccount.FungibleToken,
+    };
+  };
+
+  /** The game of `achievement` and the address of its next reward. */
+  private readonly nextReward = async (
+    achievement: PublicKey,
+    nextReward?: PublicKey,
+    game?: PublicKey
+  ): Promise<[PublicKey, PublicKey]> => {
+    if (nextReward !== undefined && game !== undefined) {
+      return [game, nextReward];
+    }
+
+    const account = await this.program.account.achievement.fetch(achievement);
+    const index = account.rewardCount.addn(1);
+    return [
+      game ?? account.game,
+      nextReward ?? this.utils.deriveRewardAddress(index, achievement)[0],
+    ];
+  };
+
+  /**
+   * A rank reward, along with the accounts ranking its claims: the
+   * leaderboard's live top entries, or the archived top entries of the reward's
+   * season.
+   */
+  private readonly rankRewardRanking = async (
+    rankReward: PublicKey
+  ): Promise<
+    [
+      RankRewardAccount,
+      {
+        game: PublicKey;
+        leaderboard: PublicKey;
+        topEntries: PublicKey | null;
+        seasonTopEntries: PublicKey | null;
+      }
+    ]
+  > => {
+    const account = RankRewardAccount.fromIdlAccount(
+      await this.program.account.rankReward.fetch(rankReward),
+      rankReward
+    );
+    const leaderboard = account.leaderboard;
+    const leaderboardAccount = await this.program.account.leaderBoard.fetch(
+      leaderboard
+    );
+
+    return [
+      account,
+      {
+        game: leaderboardAccount.game,
+        leaderboard,
+        topEntries:
+          account.season === null ? leaderboardAccount.topEntries : null,
+        seasonTopEntries:
+          account.season !== null
+            ? this.utils.deriveSeasonTopEntriesAddress(
+                leaderboard,
+                account.season
+              )[0]
+            : null,
+      },
+    ];
+  };
+
+  private readonly achievementGame = async (
+    achievement: PublicKey
+  ): Promise<PublicKey> => {
+    return (await this.program.account.achievement.fetch(achievement)).game;
+  };
+
+  private readonly leaderboardTopEntries = async (
+    leaderboard: PublicKey
+  ): Promise<PublicKey> => {
+    const { topEntries } = await this.program.account.leaderBoard.fetch(
+      leaderboard
+    );
+    if (topEntries === null) {
+      throw new Error("Leaderboard has no top entries");
+    }
+    return topEntries;
+  };
+
+  /** The token program owning `mint`, either SPL Token or Token-2022. */
+  private readonly mintTokenProgram = async (
+    mint: PublicKey
+  ): Promise<PublicKey> => {
+    const info = await this.provider.connection.getAccountInfo(mint);
+    if (info === null) {
+      throw new Error("Mint account not found");
+    }
+    return info.owner;
+  };
 }
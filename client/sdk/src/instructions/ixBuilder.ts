@@ -2,47 +2,116 @@ import { IDL, type Soar } from "../idl/soar";
 import { type AnchorProvider, Program } from "@coral-xyz/anchor";
 import {
   Transaction,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  type AccountMeta,
   type PublicKey,
   type TransactionInstruction,
   type Signer,
   type ConfirmOptions,
 } from "@solana/web3.js";
 import {
-  type InitializeGameArgs,
   type AddAchievementArgs,
-  type AddNftRewardArgs,
+  type AddFtRankRewardArgs,
   type AddFtRewardArgs,
-  type InitializePlayerArgs,
+  type AddLeaderBoardArgs,
+  type AddNftRankRewardArgs,
+  type AddNftRewardArgs,
+  type ClaimRankRewardArgs,
   type InitMergeArgs,
+  type InitializeGameArgs,
+  type InitializePlayerArgs,
+  type ProposeAuthorityArgs,
+  type RebuildTopEntriesArgs,
+  type RedeemClaimVoucherArgs,
+  type RemovePlayerScoreArgs,
+  type ResizeTopEntriesArgs,
+  type SetPausedArgs,
+  type SubmitAttestedScoreArgs,
   type SubmitScoreArgs,
+  type SubmitScoreWithTiebreakArgs,
+  type TopUpFtRewardArgs,
+  type TransferGameOwnershipArgs,
   type UpdateAchievementArgs,
   type UpdateGameArgs,
   type UpdateLeaderboardArgs,
   type UpdatePlayerArgs,
-  type AddLeaderBoardArgs,
+  type UpdateRewardArgs,
 } from "../types";
 import {
-  addLeaderBoardInstruction,
+  acceptAuthorityInstruction,
+  acceptGameOwnershipInstruction,
   addAchievementInstruction,
+  addFtRankRewardInstruction,
+  addFtRewardInstruction,
+  addLeaderBoardInstruction,
+  addNftRankRewardInstruction,
+  addNftRewardInstruction,
+  addTopEntriesInstruction,
+  archiveSeasonInstruction,
+  banPlayerInstruction,
+  cancelAuthorityProposalInstruction,
+  cancelRewardInstruction,
+  claimFtRankRewardInstruction,
+  claimFtRewardInstruction,
+  claimFtRewardsInstruction,
+  claimNftRankRewardInstruction,
+  claimNftRewardInstruction,
+  closeAchievementInstruction,
+  closeGameInstruction,
+  closeLeaderboardInstruction,
+  closeMergedInstruction,
+  closeNftClaimInstruction,
+  closePlayerAchievementInstruction,
+  closePlayerInstruction,
+  closePlayerScoresInstruction,
+  closeRankRewardClaimInstruction,
+  closeRankRewardInstruction,
+  closeRewardClaimInstruction,
+  closeRewardInstruction,
+  closeSeasonTopEntriesInstruction,
+  evictPlayerInstruction,
+  growTopEntriesInstruction,
   initPlayerInstruction,
   initializeGameInstruction,
+  initiateMergeInstruction,
+  mergePlayerAchievementInstruction,
+  mergePlayerScoresInstruction,
+  migrateAchievementInstruction,
+  migrateFtRewardInstruction,
+  migrateGameInstruction,
+  migrateLeaderboardInstruction,
+  migratePlayerScoresInstruction,
+  proposeAuthorityInstruction,
+  rebuildTopEntriesInstruction,
+  redeemClaimVoucherInstruction,
+  redeemNftClaimVoucherInstruction,
+  registerMergeApprovalInstruction,
   registerPlayerEntryInstruction,
+  removePlayerScoreInstruction,
+  resizeTopEntriesInstruction,
+  setGamePausedInstruction,
+  setLeaderboardPausedInstruction,
+  setRewardPausedInstruction,
+  submitAttestedScoreInstruction,
+  submitScoreInstruction,
+  submitScoreWithTiebreakInstruction,
+  topUpFtRewardInstruction,
+  transferGameOwnershipInstruction,
+  unbanPlayerInstruction,
+  unlockAndClaimFtRewardInstruction,
+  unlockAndClaimNftRewardInstruction,
+  unlockPlayerAchievementInstruction,
   updateAchievementInstruction,
   updateGameInstruction,
-  updatePlayerInstruction,
   updateLeaderBoardInstruction,
-  submitScoreInstruction,
-  unlockPlayerAchievementInstruction,
-  initiateMergeInstruction,
-  registerMergeApprovalInstruction,
-  addFtRewardInstruction,
-  addNftRewardInstruction,
-  claimFtRewardInstruction,
-  claimNftRewardInstruction,
+  updatePlayerInstruction,
+  updateRewardInstruction,
   verifyNftRewardInstruction,
+  withdrawFtRewardInstruction,
 } from "./rawInstructions";
 import { Utils } from "../utils";
 import { AccountsBuilder } from "./accountsBuilder";
+import type BN from "bn.js";
 
 /** A class for constructing more-tailored and specific instructions. */
 export class InstructionBuilder {
@@ -91,20 +160,23 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async initPlayerStep(
-    args: InitializePlayerArgs,
-    user: PublicKey,
+  public async updateGameStep(
+    args: UpdateGameArgs,
+    game: PublicKey,
+    authority: PublicKey,
     payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
-      Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.initializePlayerAccounts>
-      >
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.updateGameAccounts>>
     ]
   > {
-    const accounts = await this.accounts.initializePlayerAccounts(user, payer);
-    const instruction = await initPlayerInstruction(
+    const accounts = await this.accounts.updateGameAccounts(
+      game,
+      authority,
+      payer
+    );
+    const instruction = await updateGameInstruction(
       this.program,
       args,
       accounts
@@ -113,23 +185,26 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async updateGameStep(
-    args: UpdateGameArgs,
+  public async proposeAuthorityStep(
+    args: ProposeAuthorityArgs,
     game: PublicKey,
     authority: PublicKey,
     payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
-      Awaited<ReturnType<typeof AccountsBuilder.prototype.updateGameAccounts>>
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.proposeAuthorityAccounts>
+      >
     ]
   > {
-    const accounts = await this.accounts.updateGameAccounts(
+    const accounts = await this.accounts.proposeAuthorityAccounts(
       game,
       authority,
+      args.authority.key,
       payer
     );
-    const instruction = await updateGameInstruction(
+    const instruction = await proposeAuthorityInstruction(
       this.program,
       args,
       accounts
@@ -138,135 +213,120 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async updatePlayerStep(
-    args: UpdatePlayerArgs,
-    user: PublicKey
+  public async acceptAuthorityStep(
+    game: PublicKey,
+    newAuthority: PublicKey
   ): Promise<
     [
       InstructionBuilder,
-      Awaited<ReturnType<typeof AccountsBuilder.prototype.updatePlayerAccounts>>
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.acceptAuthorityAccounts>
+      >
     ]
   > {
-    const accounts = await this.accounts.updatePlayerAccounts(user);
-    const instruction = await updatePlayerInstruction(
+    const accounts = await this.accounts.acceptAuthorityAccounts(
+      game,
+      newAuthority
+    );
+    const instruction = await acceptAuthorityInstruction(
       this.program,
-      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async initMergeStep(
-    args: InitMergeArgs,
-    user: PublicKey,
-    newMergeAccount: PublicKey,
-    payer?: PublicKey
+  public async cancelAuthorityProposalStep(
+    game: PublicKey,
+    authority: PublicKey,
+    proposed: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.initiateMergeAccounts>
+        ReturnType<
+          typeof AccountsBuilder.prototype.cancelAuthorityProposalAccounts
+        >
       >
     ]
   > {
-    const accounts = await this.accounts.initiateMergeAccounts(
-      user,
-      newMergeAccount,
-      payer
+    const accounts = await this.accounts.cancelAuthorityProposalAccounts(
+      game,
+      authority,
+      proposed
     );
-    const instruction = await initiateMergeInstruction(
+    const instruction = await cancelAuthorityProposalInstruction(
       this.program,
-      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async registerMergeApprovalStep(
-    user: PublicKey,
-    mergeAccount: PublicKey
+  public async transferGameOwnershipStep(
+    args: TransferGameOwnershipArgs,
+    game: PublicKey,
+    owner: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
         ReturnType<
-          typeof AccountsBuilder.prototype.registerMergeApprovalAccounts
+          typeof AccountsBuilder.prototype.transferGameOwnershipAccounts
         >
       >
     ]
   > {
-    const accounts = await this.accounts.registerMergeApprovalAccounts(
-      user,
-      mergeAccount
+    const accounts = await this.accounts.transferGameOwnershipAccounts(
+      game,
+      owner
     );
-    const instruction = await registerMergeApprovalInstruction(
+    const instruction = await transferGameOwnershipInstruction(
       this.program,
+      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async addAchievementStep(
-    args: AddAchievementArgs,
+  public async acceptGameOwnershipStep(
     game: PublicKey,
-    authority: PublicKey,
-    nextAchievement?: PublicKey,
-    payer?: PublicKey
+    newOwner: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.addAchievementAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.acceptGameOwnershipAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.addAchievementAccounts(
+    const accounts = await this.accounts.acceptGameOwnershipAccounts(
       game,
-      authority,
-      nextAchievement,
-      payer
+      newOwner
     );
-    const instruction = await addAchievementInstruction(
+    const instruction = await acceptGameOwnershipInstruction(
       this.program,
-      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async addLeaderBoardStep(
-    args: AddLeaderBoardArgs,
-    gameAddress: PublicKey,
-    authority: PublicKey,
-    nextLeaderboard?: PublicKey,
-    payer?: PublicKey
+  public async setGamePausedStep(
+    args: SetPausedArgs,
+    game: PublicKey,
+    authority: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.addLeaderboardAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.setGamePausedAccounts>
       >
     ]
   > {
-    let nullTopEntries;
-    if (args.scoresToRetain !== null && args.scoresToRetain > 0) {
-      nullTopEntries = false;
-    } else {
-      nullTopEntries = true;
-    }
-
-    const accounts = await this.accounts.addLeaderboardAccounts(
-      gameAddress,
-      authority,
-      nextLeaderboard,
-      nullTopEntries,
-      payer
-    );
-    const instruction = await addLeaderBoardInstruction(
+    const accounts = await this.accounts.setGamePausedAccounts(game, authority);
+    const instruction = await setGamePausedInstruction(
       this.program,
       args,
       accounts
@@ -275,27 +335,39 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async updateLeaderboardStep(
-    args: UpdateLeaderboardArgs,
-    authority: PublicKey,
-    leaderboard: PublicKey,
-    game?: PublicKey,
-    topEntries?: PublicKey
+  public async initPlayerStep(
+    args: InitializePlayerArgs,
+    user: PublicKey,
+    payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.updateLeaderboardAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.initializePlayerAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.updateLeaderboardAccounts(
-      authority,
-      leaderboard,
-      game,
-      topEntries
+    const accounts = await this.accounts.initializePlayerAccounts(user, payer);
+    const instruction = await initPlayerInstruction(
+      this.program,
+      args,
+      accounts
     );
-    const instruction = await updateLeaderBoardInstruction(
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async updatePlayerStep(
+    args: UpdatePlayerArgs,
+    user: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.updatePlayerAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.updatePlayerAccounts(user);
+    const instruction = await updatePlayerInstruction(
       this.program,
       args,
       accounts
@@ -304,149 +376,146 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async registerPlayerEntryStep(
+  public async initMergeStep(
+    args: InitMergeArgs,
     user: PublicKey,
-    leaderboard: PublicKey,
-    game?: PublicKey,
     payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.registerPlayerEntryAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.initiateMergeAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.registerPlayerEntryAccounts(
+    const mergeAccount = this.utils.deriveMergedAddress(user, args.nonce)[0];
+    const accounts = await this.accounts.initiateMergeAccounts(
       user,
-      leaderboard,
-      game,
+      mergeAccount,
       payer
     );
-    const instruction = await registerPlayerEntryInstruction(
+    const instruction = await initiateMergeInstruction(
       this.program,
+      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async submitScoreStep(
-    args: SubmitScoreArgs,
+  public async registerMergeApprovalStep(
     user: PublicKey,
-    authority: PublicKey,
-    leaderboard: PublicKey,
-    game?: PublicKey,
-    payer?: PublicKey
+    mergeAccount: PublicKey
   ): Promise<
     [
       InstructionBuilder,
-      Awaited<ReturnType<typeof AccountsBuilder.prototype.submitScoreAccounts>>
+      Awaited<
+        ReturnType<
+          typeof AccountsBuilder.prototype.registerMergeApprovalAccounts
+        >
+      >
     ]
   > {
-    const accounts = await this.accounts.submitScoreAccounts(
+    const accounts = await this.accounts.registerMergeApprovalAccounts(
       user,
-      authority,
-      leaderboard,
-      game,
-      payer
+      mergeAccount
     );
-    const instruction = await submitScoreInstruction(
+    const instruction = await registerMergeApprovalInstruction(
       this.program,
-      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async updateAchievementStep(
-    args: UpdateAchievementArgs,
-    authority: PublicKey,
-    achievement: PublicKey,
-    game?: PublicKey
+  public async mergePlayerScoresStep(
+    user: PublicKey,
+    mergeAccount: PublicKey,
+    mergedPlayerAccount: PublicKey,
+    leaderboard: PublicKey,
+    payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.updateAchievementAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.mergePlayerScoresAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.updateAchievementAccounts(
-      authority,
-      achievement,
-      game
+    const accounts = await this.accounts.mergePlayerScoresAccounts(
+      user,
+      mergeAccount,
+      mergedPlayerAccount,
+      leaderboard,
+      payer
     );
-    const instruction = await updateAchievementInstruction(
+    const instruction = await mergePlayerScoresInstruction(
       this.program,
-      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async unlockPlayerAchievementStep(
+  public async mergePlayerAchievementStep(
     user: PublicKey,
-    authority: PublicKey,
+    mergeAccount: PublicKey,
+    mergedPlayerAccount: PublicKey,
     achievement: PublicKey,
-    leaderboard: PublicKey,
-    game?: PublicKey,
     payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
         ReturnType<
-          typeof AccountsBuilder.prototype.unlockPlayerAchievementAccounts
+          typeof AccountsBuilder.prototype.mergePlayerAchievementAccounts
         >
       >
     ]
   > {
-    const accounts = await this.accounts.unlockPlayerAchievementAccounts(
+    const accounts = await this.accounts.mergePlayerAchievementAccounts(
       user,
-      authority,
+      mergeAccount,
+      mergedPlayerAccount,
       achievement,
-      leaderboard,
-      game,
       payer
     );
-    const instruction = await unlockPlayerAchievementInstruction(
+    const remainingAccounts =
+      await this.accounts.mergePlayerAchievementRemainingAccounts(
+        user,
+        mergedPlayerAccount,
+        achievement
+      );
+    const instruction = await mergePlayerAchievementInstruction(
       this.program,
-      accounts
+      accounts,
+      remainingAccounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async addFungibleRewardStep(
-    args: AddFtRewardArgs,
+  public async addAchievementStep(
+    args: AddAchievementArgs,
+    game: PublicKey,
     authority: PublicKey,
-    newReward: PublicKey,
-    achievement: PublicKey,
-    sourceTokenAccount: PublicKey,
-    tokenAccountOwner: PublicKey,
-    mint: PublicKey,
-    game?: PublicKey,
+    nextAchievement?: PublicKey,
     payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
-      Awaited<ReturnType<typeof AccountsBuilder.prototype.addFtRewardAccounts>>
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.addAchievementAccounts>
+      >
     ]
   > {
-    const accounts = await this.accounts.addFtRewardAccounts(
-      authority,
-      newReward,
-      achievement,
-      sourceTokenAccount,
-      tokenAccountOwner,
-      mint,
+    const accounts = await this.accounts.addAchievementAccounts(
       game,
+      authority,
+      nextAchievement,
       payer
     );
-    const instruction = await addFtRewardInstruction(
+    const instruction = await addAchievementInstruction(
       this.program,
       args,
       accounts
@@ -455,31 +524,57 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async addNonFungibleRewardStep(
-    args: AddNftRewardArgs,
+  public async updateAchievementStep(
+    args: UpdateAchievementArgs,
     authority: PublicKey,
-    newReward: PublicKey,
     achievement: PublicKey,
-    collectionMint?: PublicKey,
-    collectionUpdateAuthority?: PublicKey,
-    game?: PublicKey,
-    payer?: PublicKey
+    game?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
-      Awaited<ReturnType<typeof AccountsBuilder.prototype.addNftRewardAccounts>>
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.updateAchievementAccounts>
+      >
     ]
   > {
-    const accounts = await this.accounts.addNftRewardAccounts(
+    const accounts = await this.accounts.updateAchievementAccounts(
       authority,
-      newReward,
       achievement,
-      collectionMint,
-      collectionUpdateAuthority,
-      game,
+      game
+    );
+    const instruction = await updateAchievementInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async addLeaderBoardStep(
+    args: AddLeaderBoardArgs,
+    gameAddress: PublicKey,
+    authority: PublicKey,
+    nextLeaderboard?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.addLeaderboardAccounts>
+      >
+    ]
+  > {
+    const nullTopEntries = args.scoresToRetain === 0;
+
+    const accounts = await this.accounts.addLeaderboardAccounts(
+      gameAddress,
+      authority,
+      nextLeaderboard,
+      nullTopEntries,
       payer
     );
-    const instruction = await addNftRewardInstruction(
+    const instruction = await addLeaderBoardInstruction(
       this.program,
       args,
       accounts
@@ -488,99 +583,1430 @@ export class InstructionBuilder {
     return [this.append([instruction]), accounts];
   }
 
-  public async claimNftRewardStep(
+  public async updateLeaderboardStep(
+    args: UpdateLeaderboardArgs,
     authority: PublicKey,
-    achievement: PublicKey,
-    mint: PublicKey,
-    user: PublicKey,
-    reward?: PublicKey,
+    leaderboard: PublicKey,
     game?: PublicKey,
-    payer?: PublicKey
+    topEntries?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.claimNftRewardAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.updateLeaderboardAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.claimNftRewardAccounts(
+    const accounts = await this.accounts.updateLeaderboardAccounts(
       authority,
-      achievement,
-      mint,
-      user,
-      reward,
+      leaderboard,
       game,
+      topEntries
+    );
+    const instruction = await updateLeaderBoardInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async setLeaderboardPausedStep(
+    args: SetPausedArgs,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<
+          typeof AccountsBuilder.prototype.setLeaderboardPausedAccounts
+        >
+      >
+    ]
+  > {
+    const accounts = await this.accounts.setLeaderboardPausedAccounts(
+      authority,
+      leaderboard,
+      game
+    );
+    const instruction = await setLeaderboardPausedInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async archiveSeasonStep(
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.archiveSeasonAccounts>
+      >
+    ]
+  > {
+    const { season, ...accounts } = await this.accounts.archiveSeasonAccounts(
+      leaderboard,
+      payer
+    );
+    const instruction = await archiveSeasonInstruction(this.program, accounts);
+
+    return [this.append([instruction]), { ...accounts, season }];
+  }
+
+  public async growTopEntriesStep(
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.growTopEntriesAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.growTopEntriesAccounts(
+      leaderboard,
       payer
     );
-    const instruction = await claimNftRewardInstruction(this.program, accounts);
+    const instruction = await growTopEntriesInstruction(this.program, accounts);
 
     return [this.append([instruction]), accounts];
   }
 
-  public async claimFtRewardStep(
+  public async rebuildTopEntriesStep(
+    args: RebuildTopEntriesArgs,
     authority: PublicKey,
-    achievement: PublicKey,
-    user: PublicKey,
-    reward?: PublicKey,
+    leaderboard: PublicKey,
+    playerAccounts: PublicKey[],
     game?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.claimFtRewardAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.rebuildTopEntriesAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.claimFtRewardAccounts(
+    const accounts = await this.accounts.rebuildTopEntriesAccounts(
       authority,
-      achievement,
-      user,
-      reward,
+      leaderboard,
       game
     );
-    const omitted: Omit<
+    const instruction = await rebuildTopEntriesInstruction(
+      this.program,
+      args,
+      accounts,
+      this.accounts.topEntriesRemainingAccounts(leaderboard, playerAccounts)
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async addTopEntriesStep(
+    args: ResizeTopEntriesArgs,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    playerAccounts: PublicKey[],
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.claimFtRewardAccounts>
-      >,
-      "mint"
-    > = { ...accounts };
-    const instruction = await claimFtRewardInstruction(this.program, omitted);
+        ReturnType<typeof AccountsBuilder.prototype.addTopEntriesAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.addTopEntriesAccounts(
+      authority,
+      leaderboard,
+      game,
+      payer
+    );
+    const instruction = await addTopEntriesInstruction(
+      this.program,
+      args,
+      accounts,
+      this.accounts.topEntriesRemainingAccounts(leaderboard, playerAccounts)
+    );
 
     return [this.append([instruction]), accounts];
   }
 
-  public async verifyPlayerNftRewardStep(
+  public async resizeTopEntriesStep(
+    args: ResizeTopEntriesArgs,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    playerAccounts: PublicKey[],
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.resizeTopEntriesAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.resizeTopEntriesAccounts(
+      authority,
+      leaderboard,
+      game,
+      payer
+    );
+    const instruction = await resizeTopEntriesInstruction(
+      this.program,
+      args,
+      accounts,
+      this.accounts.topEntriesRemainingAccounts(leaderboard, playerAccounts)
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async registerPlayerEntryStep(
     user: PublicKey,
-    achievement: PublicKey,
-    mint: PublicKey,
-    reward?: PublicKey,
+    leaderboard: PublicKey,
     game?: PublicKey,
     payer?: PublicKey
   ): Promise<
     [
       InstructionBuilder,
       Awaited<
-        ReturnType<typeof AccountsBuilder.prototype.verifyNftRewardAccounts>
+        ReturnType<typeof AccountsBuilder.prototype.registerPlayerEntryAccounts>
       >
     ]
   > {
-    const accounts = await this.accounts.verifyNftRewardAccounts(
+    const accounts = await this.accounts.registerPlayerEntryAccounts(
       user,
-      achievement,
-      mint,
-      reward,
+      leaderboard,
       game,
       payer
     );
-    const instruction = await verifyNftRewardInstruction(
+    const instruction = await registerPlayerEntryInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async submitScoreStep(
+    args: SubmitScoreArgs,
+    user: PublicKey,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.submitScoreAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.submitScoreAccounts(
+      user,
+      authority,
+      leaderboard,
+      game,
+      payer
+    );
+    const instruction = await submitScoreInstruction(
       this.program,
+      args,
       accounts
     );
 
     return [this.append([instruction]), accounts];
   }
 
+  public async submitScoreWithTiebreakStep(
+    args: SubmitScoreWithTiebreakArgs,
+    user: PublicKey,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.submitScoreAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.submitScoreAccounts(
+      user,
+      authority,
+      leaderboard,
+      game,
+      payer
+    );
+    const instruction = await submitScoreWithTiebreakInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async submitAttestedScoreStep(
+    args: SubmitAttestedScoreArgs,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    verifyInstruction: TransactionInstruction,
+    game?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.submitAttestedScoreAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.submitAttestedScoreAccounts(
+      user,
+      leaderboard,
+      game
+    );
+    const instruction = await submitAttestedScoreInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([verifyInstruction, instruction]), accounts];
+  }
+
+  public async removePlayerScoreStep(
+    args: RemovePlayerScoreArgs,
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.removePlayerScoreAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.removePlayerScoreAccounts(
+      authority,
+      user,
+      leaderboard,
+      game
+    );
+    const instruction = await removePlayerScoreInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async evictPlayerStep(
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.evictPlayerAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.evictPlayerAccounts(
+      authority,
+      user,
+      leaderboard,
+      game
+    );
+    const instruction = await evictPlayerInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async banPlayerStep(
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.banPlayerAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.banPlayerAccounts(
+      authority,
+      user,
+      leaderboard,
+      game,
+      payer
+    );
+    const instruction = await banPlayerInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async unbanPlayerStep(
+    authority: PublicKey,
+    user: PublicKey,
+    leaderboard: PublicKey,
+    game?: PublicKey,
+    receiver?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.unbanPlayerAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.unbanPlayerAccounts(
+      authority,
+      user,
+      leaderboard,
+      game,
+      receiver
+    );
+    const instruction = await unbanPlayerInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async unlockPlayerAchievementStep(
+    user: PublicKey,
+    authority: PublicKey,
+    achievement: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<
+          typeof AccountsBuilder.prototype.unlockPlayerAchievementAccounts
+        >
+      >
+    ]
+  > {
+    const accounts = await this.accounts.unlockPlayerAchievementAccounts(
+      user,
+      authority,
+      achievement,
+      game,
+      payer
+    );
+    const instruction = await unlockPlayerAchievementInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async addFungibleRewardStep(
+    args: AddFtRewardArgs,
+    authority: PublicKey,
+    achievement: PublicKey,
+    sourceTokenAccount: PublicKey,
+    tokenAccountOwner: PublicKey,
+    mint: PublicKey,
+    nextReward?: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.addFtRewardAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.addFtRewardAccounts(
+      authority,
+      achievement,
+      sourceTokenAccount,
+      tokenAccountOwner,
+      mint,
+      nextReward,
+      game,
+      payer
+    );
+    const instruction = await addFtRewardInstruction(
+      this.program,
+      args,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async addNonFungibleRewardStep(
+    args: AddNftRewardArgs,
+    authority: PublicKey,
+    achievement: PublicKey,
+    collectionMint?: PublicKey,
+    collectionUpdateAuthority?: PublicKey,
+    nextReward?: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.addNftRewardAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.addNftRewardAccounts(
+      authority,
+      achievement,
+      collectionMint,
+      collectionUpdateAuthority,
+      nextReward,
+      game,
+      payer
+    );
+    const instruction = await addNftRewardInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async topUpFtRewardStep(
+    args: TopUpFtRewardArgs,
+    authority: PublicKey,
+    reward: PublicKey,
+    sourceTokenAccount: PublicKey,
+    tokenAccountOwner: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.topUpFtRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.topUpFtRewardAccounts(
+      authority,
+      reward,
+      sourceTokenAccount,
+      tokenAccountOwner
+    );
+    const instruction = await topUpFtRewardInstruction(
+      this.program,
+      args,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async withdrawFtRewardStep(
+    authority: PublicKey,
+    reward: PublicKey,
+    destinationTokenAccount: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.withdrawFtRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.withdrawFtRewardAccounts(
+      authority,
+      reward,
+      destinationTokenAccount
+    );
+    const instruction = await withdrawFtRewardInstruction(
+      this.program,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async updateRewardStep(
+    args: UpdateRewardArgs,
+    authority: PublicKey,
+    reward: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.updateRewardAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.updateRewardAccounts(
+      authority,
+      reward
+    );
+    const instruction = await updateRewardInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async setRewardPausedStep(
+    args: SetPausedArgs,
+    authority: PublicKey,
+    reward: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.setRewardPausedAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.setRewardPausedAccounts(
+      authority,
+      reward
+    );
+    const instruction = await setRewardPausedInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async cancelRewardStep(
+    authority: PublicKey,
+    reward: PublicKey,
+    destinationTokenAccount?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.cancelRewardAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.cancelRewardAccounts(
+      authority,
+      reward,
+      destinationTokenAccount
+    );
+    const instruction = await cancelRewardInstruction(
+      this.program,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async redeemClaimVoucherStep(
+    args: RedeemClaimVoucherArgs,
+    user: PublicKey,
+    reward: PublicKey,
+    verifyInstruction: TransactionInstruction,
+    game?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimFtRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimFtRewardAccounts(
+      user,
+      reward,
+      game,
+      user
+    );
+    const instruction = await redeemClaimVoucherInstruction(
+      this.program,
+      args,
+      { claim: accounts, user, instructions: SYSVAR_INSTRUCTIONS_PUBKEY },
+      remainingAccounts
+    );
+
+    return [this.append([verifyInstruction, instruction]), accounts];
+  }
+
+  public async redeemNftClaimVoucherStep(
+    args: RedeemClaimVoucherArgs,
+    user: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    verifyInstruction: TransactionInstruction,
+    game?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimNftRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimNftRewardAccounts(
+      user,
+      reward,
+      mint,
+      game,
+      user
+    );
+    const instruction = await redeemNftClaimVoucherInstruction(
+      this.program,
+      args,
+      { claim: accounts, user, instructions: SYSVAR_INSTRUCTIONS_PUBKEY }
+    );
+
+    return [this.append([verifyInstruction, instruction]), accounts];
+  }
+
+  public async claimFtRewardStep(
+    authority: PublicKey,
+    user: PublicKey,
+    reward: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimFtRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimFtRewardAccounts(
+      user,
+      reward,
+      game,
+      payer
+    );
+    const instruction = await claimFtRewardInstruction(
+      this.program,
+      { claim: accounts, authority },
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async unlockAndClaimFtRewardStep(
+    authority: PublicKey,
+    user: PublicKey,
+    reward: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimFtRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimFtRewardAccounts(
+      user,
+      reward,
+      game,
+      payer
+    );
+    const instruction = await unlockAndClaimFtRewardInstruction(
+      this.program,
+      { claim: accounts, authority },
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async claimNftRewardStep(
+    authority: PublicKey,
+    user: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimNftRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimNftRewardAccounts(
+      user,
+      reward,
+      mint,
+      game,
+      payer
+    );
+    const instruction = await claimNftRewardInstruction(
+      this.program,
+      { claim: accounts, authority }
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async unlockAndClaimNftRewardStep(
+    authority: PublicKey,
+    user: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimNftRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimNftRewardAccounts(
+      user,
+      reward,
+      mint,
+      game,
+      payer
+    );
+    const instruction = await unlockAndClaimNftRewardInstruction(
+      this.program,
+      { claim: accounts, authority }
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async claimFtRewardsStep(
+    authority: PublicKey,
+    user: PublicKey,
+    achievement: PublicKey,
+    rewards: PublicKey[],
+    tokenProgram?: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimFtRewardsAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimFtRewardsAccounts(
+      authority,
+      user,
+      achievement,
+      tokenProgram,
+      game,
+      payer
+    );
+    const remainingAccounts =
+      await this.accounts.claimFtRewardsRemainingAccounts(user, rewards);
+    const instruction = await claimFtRewardsInstruction(
+      this.program,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async verifyPlayerNftRewardStep(
+    user: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.verifyNftRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.verifyNftRewardAccounts(
+      user,
+      reward,
+      mint,
+      game,
+      payer
+    );
+    const instruction = await verifyNftRewardInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async addFtRankRewardStep(
+    args: AddFtRankRewardArgs,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    newReward: PublicKey,
+    sourceTokenAccount: PublicKey,
+    tokenAccountOwner: PublicKey,
+    mint: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.addFtRankRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.addFtRankRewardAccounts(
+      authority,
+      leaderboard,
+      newReward,
+      sourceTokenAccount,
+      tokenAccountOwner,
+      mint,
+      game,
+      payer
+    );
+    const instruction = await addFtRankRewardInstruction(
+      this.program,
+      args,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async addNftRankRewardStep(
+    args: AddNftRankRewardArgs,
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    newReward: PublicKey,
+    game?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.addNftRankRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.addNftRankRewardAccounts(
+      authority,
+      leaderboard,
+      newReward,
+      game,
+      payer
+    );
+    const instruction = await addNftRankRewardInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async claimFtRankRewardStep(
+    args: ClaimRankRewardArgs,
+    user: PublicKey,
+    rankReward: PublicKey,
+    payer?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimFtRankRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimFtRankRewardAccounts(
+      user,
+      rankReward,
+      payer
+    );
+    const instruction = await claimFtRankRewardInstruction(
+      this.program,
+      args,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async claimNftRankRewardStep(
+    args: ClaimRankRewardArgs,
+    user: PublicKey,
+    rankReward: PublicKey,
+    mint: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.claimNftRankRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.claimNftRankRewardAccounts(
+      user,
+      rankReward,
+      mint,
+      payer
+    );
+    const instruction = await claimNftRankRewardInstruction(
+      this.program,
+      args,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeGameStep(
+    authority: PublicKey,
+    game: PublicKey,
+    receiver?: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.closeGameAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.closeGameAccounts(
+      authority,
+      game,
+      receiver,
+      payer
+    );
+    const instruction = await closeGameInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeLeaderboardStep(
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    receiver?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.closeLeaderboardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeLeaderboardAccounts(
+      authority,
+      leaderboard,
+      receiver
+    );
+    const instruction = await closeLeaderboardInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeSeasonTopEntriesStep(
+    authority: PublicKey,
+    leaderboard: PublicKey,
+    season: BN,
+    receiver?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<
+          typeof AccountsBuilder.prototype.closeSeasonTopEntriesAccounts
+        >
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeSeasonTopEntriesAccounts(
+      authority,
+      leaderboard,
+      season,
+      receiver
+    );
+    const instruction = await closeSeasonTopEntriesInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeAchievementStep(
+    authority: PublicKey,
+    achievement: PublicKey,
+    receiver?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.closeAchievementAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeAchievementAccounts(
+      authority,
+      achievement,
+      receiver
+    );
+    const instruction = await closeAchievementInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeRewardStep(
+    authority: PublicKey,
+    reward: PublicKey,
+    receiver?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.closeRewardAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.closeRewardAccounts(
+      authority,
+      reward,
+      receiver
+    );
+    const instruction = await closeRewardInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeRankRewardStep(
+    authority: PublicKey,
+    rankReward: PublicKey,
+    destinationTokenAccount?: PublicKey,
+    receiver?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.closeRankRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeRankRewardAccounts(
+      authority,
+      rankReward,
+      destinationTokenAccount,
+      receiver
+    );
+    const instruction = await closeRankRewardInstruction(
+      this.program,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closePlayerStep(
+    user: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.closePlayerAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.closePlayerAccounts(user);
+    const instruction = await closePlayerInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closePlayerScoresStep(
+    user: PublicKey,
+    leaderboard: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.closePlayerScoresAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closePlayerScoresAccounts(
+      user,
+      leaderboard
+    );
+    const instruction = await closePlayerScoresInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closePlayerAchievementStep(
+    user: PublicKey,
+    achievement: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<
+          typeof AccountsBuilder.prototype.closePlayerAchievementAccounts
+        >
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closePlayerAchievementAccounts(
+      user,
+      achievement
+    );
+    const instruction = await closePlayerAchievementInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeMergedStep(
+    user: PublicKey,
+    mergeAccount: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.closeMergedAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.closeMergedAccounts(
+      user,
+      mergeAccount
+    );
+    const instruction = await closeMergedInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeNftClaimStep(
+    authority: PublicKey,
+    reward: PublicKey,
+    mint: PublicKey,
+    receiver?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.closeNftClaimAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeNftClaimAccounts(
+      authority,
+      reward,
+      mint,
+      receiver
+    );
+    const instruction = await closeNftClaimInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeRewardClaimStep(
+    user: PublicKey,
+    reward: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.closeRewardClaimAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeRewardClaimAccounts(user, reward);
+    const instruction = await closeRewardClaimInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async closeRankRewardClaimStep(
+    user: PublicKey,
+    rankReward: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<
+          typeof AccountsBuilder.prototype.closeRankRewardClaimAccounts
+        >
+      >
+    ]
+  > {
+    const accounts = await this.accounts.closeRankRewardClaimAccounts(
+      user,
+      rankReward
+    );
+    const instruction = await closeRankRewardClaimInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async migrateGameStep(
+    authority: PublicKey,
+    game: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<ReturnType<typeof AccountsBuilder.prototype.migrateGameAccounts>>
+    ]
+  > {
+    const accounts = await this.accounts.migrateGameAccounts(
+      authority,
+      game,
+      payer
+    );
+    const instruction = await migrateGameInstruction(this.program, accounts);
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async migrateLeaderboardStep(
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.migrateLeaderboardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.migrateLeaderboardAccounts(
+      leaderboard,
+      payer
+    );
+    const instruction = await migrateLeaderboardInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async migratePlayerScoresStep(
+    user: PublicKey,
+    leaderboard: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.migratePlayerScoresAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.migratePlayerScoresAccounts(
+      user,
+      leaderboard,
+      payer
+    );
+    const instruction = await migratePlayerScoresInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async migrateAchievementStep(
+    achievement: PublicKey,
+    payer?: PublicKey
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.migrateAchievementAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.migrateAchievementAccounts(
+      achievement,
+      payer
+    );
+    const instruction = await migrateAchievementInstruction(
+      this.program,
+      accounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
+  public async migrateFtRewardStep(
+    authority: PublicKey,
+    reward: PublicKey,
+    payer?: PublicKey,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<
+    [
+      InstructionBuilder,
+      Awaited<
+        ReturnType<typeof AccountsBuilder.prototype.migrateFtRewardAccounts>
+      >
+    ]
+  > {
+    const accounts = await this.accounts.migrateFtRewardAccounts(
+      authority,
+      reward,
+      payer
+    );
+    const instruction = await migrateFtRewardInstruction(
+      this.program,
+      accounts,
+      remainingAccounts
+    );
+
+    return [this.append([instruction]), accounts];
+  }
+
   sign(signers: Signer[]): void {
     this.signers = this.signers.concat(signers);
   }
@@ -1,20 +1,54 @@
 import { type Soar } from "../idl/soar";
 import {
   type AddAchievementArgs,
-  type AddLeaderBoardArgs,
+  type AddFtRankRewardArgs,
   type AddFtRewardArgs,
+  type AddLeaderBoardArgs,
+  type AddNftRankRewardArgs,
   type AddNftRewardArgs,
+  type ClaimFtRewardAccounts,
+  type ClaimNftRewardAccounts,
+  type ClaimRankRewardArgs,
+  type InitMergeArgs,
   type InitializeGameArgs,
   type InitializePlayerArgs,
+  type ProposeAuthorityArgs,
+  type RebuildTopEntriesArgs,
+  type RedeemClaimVoucherArgs,
+  type RemovePlayerScoreArgs,
+  type ResizeTopEntriesArgs,
+  type SetPausedArgs,
+  type SubmitAttestedScoreArgs,
   type SubmitScoreArgs,
+  type SubmitScoreWithTiebreakArgs,
+  type TopUpFtRewardArgs,
+  type TransferGameOwnershipArgs,
   type UpdateAchievementArgs,
-  type UpdatePlayerArgs,
-  type UpdateLeaderboardArgs,
   type UpdateGameArgs,
-  type InitMergeArgs,
+  type UpdateLeaderboardArgs,
+  type UpdatePlayerArgs,
+  type UpdateRewardArgs,
 } from "../types";
-import { type PublicKey, type TransactionInstruction } from "@solana/web3.js";
+import {
+  type AccountMeta,
+  type PublicKey,
+  type TransactionInstruction,
+} from "@solana/web3.js";
 import { type Program } from "@coral-xyz/anchor";
+import { Utils } from "../utils";
+
+/** Add the accounts the program emits its events through. */
+const withEvents = <T>(
+  program: Program<Soar>,
+  accounts: T
+): T & { eventAuthority: PublicKey; program: PublicKey } => {
+  const utils = new Utils(program.programId);
+  return {
+    ...accounts,
+    eventAuthority: utils.deriveEventAuthorityAddress()[0],
+    program: program.programId,
+  };
+};
 
 export const initializeGameInstruction = async (
   program: Program<Soar>,
@@ -22,322 +56,333 @@ export const initializeGameInstruction = async (
   accounts: {
     creator: PublicKey;
     game: PublicKey;
+    closedGame: PublicKey;
     systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .initializeGame(args.gameMeta, args.authorities)
-    .accounts(accounts)
+    .initializeGame(args.gameMeta, args.authorities, args.nonce)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const initPlayerInstruction = async (
+export const updateGameInstruction = async (
   program: Program<Soar>,
-  args: InitializePlayerArgs,
+  args: UpdateGameArgs,
   accounts: {
-    playerAccount: PublicKey;
-    user: PublicKey;
+    authority: PublicKey;
     payer: PublicKey;
+    game: PublicKey;
     systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .initializePlayer(args.username, args.nftMeta)
-    .accounts(accounts)
+    .updateGame(args.newMeta, args.newAuths)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const initiateMergeInstruction = async (
+export const proposeAuthorityInstruction = async (
   program: Program<Soar>,
-  args: InitMergeArgs,
+  args: ProposeAuthorityArgs,
   accounts: {
-    user: PublicKey;
+    authority: PublicKey;
     payer: PublicKey;
-    playerAccount: PublicKey;
-    mergeAccount: PublicKey;
+    game: PublicKey;
+    proposal: PublicKey;
     systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .initiateMerge(args.keys)
-    .accounts(accounts)
+    .proposeAuthority(args.authority, args.replaces, args.timelock)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const addAchievementInstruction = async (
+export const acceptAuthorityInstruction = async (
   program: Program<Soar>,
-  args: AddAchievementArgs,
   accounts: {
-    newAchievement: PublicKey;
+    newAuthority: PublicKey;
     game: PublicKey;
-    payer: PublicKey;
-    authority: PublicKey;
+    proposal: PublicKey;
+    proposer: PublicKey;
     systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .addAchievement(args.title, args.description, args.nftMeta)
-    .accounts(accounts)
+    .acceptAuthority()
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const addLeaderBoardInstruction = async (
+export const cancelAuthorityProposalInstruction = async (
   program: Program<Soar>,
-  args: AddLeaderBoardArgs,
   accounts: {
     authority: PublicKey;
     game: PublicKey;
-    payer: PublicKey;
-    leaderboard: PublicKey;
-    topEntries: PublicKey | null;
-    systemProgram: PublicKey;
+    proposal: PublicKey;
+    proposer: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .addLeaderboard(args)
-    .accounts(accounts)
+    .cancelAuthorityProposal()
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const addFtRewardInstruction = async (
+export const transferGameOwnershipInstruction = async (
   program: Program<Soar>,
-  args: AddFtRewardArgs,
+  args: TransferGameOwnershipArgs,
   accounts: {
-    authority: PublicKey;
-    payer: PublicKey;
+    owner: PublicKey;
     game: PublicKey;
-    achievement: PublicKey;
-    newReward: PublicKey;
-    rewardTokenMint: PublicKey;
-    delegateFromTokenAccount: PublicKey;
-    tokenAccountOwner: PublicKey;
-    tokenProgram: PublicKey;
-    systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .addFtReward({
-      availableSpots: args.availableRewards,
-      kind: {
-        ft: args.kind,
-      },
-    })
-    .accounts(accounts)
+    .transferGameOwnership(args.newOwner)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const addNftRewardInstruction = async (
+export const acceptGameOwnershipInstruction = async (
   program: Program<Soar>,
-  args: AddNftRewardArgs,
   accounts: {
-    authority: PublicKey;
-    payer: PublicKey;
+    newOwner: PublicKey;
     game: PublicKey;
-    achievement: PublicKey;
-    newReward: PublicKey;
-    systemProgram: PublicKey;
-    rewardCollectionMint: PublicKey | null;
-    collectionUpdateAuth: PublicKey | null;
-    collectionMetadata: PublicKey | null;
-    tokenMetadataProgram: PublicKey | null;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .addNftReward({
-      availableSpots: args.availableRewards,
-      kind: {
-        nft: args.kind,
-      },
-    })
-    .accounts(accounts)
+    .acceptGameOwnership()
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const registerMergeApprovalInstruction = async (
+export const setGamePausedInstruction = async (
   program: Program<Soar>,
+  args: SetPausedArgs,
   accounts: {
-    user: PublicKey;
-    playerAccount: PublicKey;
-    mergeAccount: PublicKey;
+    authority: PublicKey;
+    game: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .approveMerge()
-    .accounts(accounts)
+    .setGamePaused(args.paused)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const claimFtRewardInstruction = async (
+export const addAchievementInstruction = async (
   program: Program<Soar>,
+  args: AddAchievementArgs,
   accounts: {
-    user: PublicKey;
     authority: PublicKey;
-    playerAccount: PublicKey;
+    payer: PublicKey;
     game: PublicKey;
-    achievement: PublicKey;
-    reward: PublicKey;
-    playerAchievement: PublicKey;
-    sourceTokenAccount: PublicKey;
-    userTokenAccount: PublicKey;
-    tokenProgram: PublicKey;
-  }
+    newAchievement: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
-  program.methods.claimFtReward().accounts(accounts).instruction();
+  program.methods
+    .addAchievement(args.title, args.description, args.nftMeta)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
 
-export const claimNftRewardInstruction = async (
+export const updateAchievementInstruction = async (
   program: Program<Soar>,
+  args: UpdateAchievementArgs,
   accounts: {
-    user: PublicKey;
     authority: PublicKey;
-    playerAccount: PublicKey;
     game: PublicKey;
     achievement: PublicKey;
-    reward: PublicKey;
-    playerAchievement: PublicKey;
-    payer: PublicKey;
-    claim: PublicKey;
-    newMint: PublicKey;
-    newMetadata: PublicKey;
-    newMasterEdition: PublicKey;
-    mintTo: PublicKey;
-    tokenMetadataProgram: PublicKey;
-    associatedTokenProgram: PublicKey;
-    systemProgram: PublicKey;
-    tokenProgram: PublicKey;
-    rent: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .claimNftReward()
-    .accounts(accounts)
+    .updateAchievement(args.newTitle, args.newDescription, args.newNftMeta)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const registerPlayerEntryInstruction = async (
+export const addLeaderBoardInstruction = async (
   program: Program<Soar>,
+  args: AddLeaderBoardArgs,
   accounts: {
-    user: PublicKey;
+    authority: PublicKey;
     payer: PublicKey;
-    playerAccount: PublicKey;
-    newList: PublicKey;
     game: PublicKey;
     leaderboard: PublicKey;
+    topEntries: PublicKey | null;
     systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .registerPlayer()
-    .accounts(accounts)
+    .addLeaderboard(args)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const submitScoreInstruction = async (
+export const updateLeaderBoardInstruction = async (
   program: Program<Soar>,
-  args: SubmitScoreArgs,
+  args: UpdateLeaderboardArgs,
   accounts: {
-    payer: PublicKey;
-    playerAccount: PublicKey;
     authority: PublicKey;
     game: PublicKey;
     leaderboard: PublicKey;
-    playerScores: PublicKey;
     topEntries: PublicKey | null;
-    systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .submitScore(args.score)
-    .accounts(accounts)
+    .updateLeaderboard(
+      args.newDescription,
+      args.newNftMeta,
+      args.newMinScore,
+      args.newMaxScore,
+      args.newIsAscending,
+      args.newAllowMultipleScores,
+      args.newRetention,
+    )
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const updateAchievementInstruction = async (
+export const setLeaderboardPausedInstruction = async (
   program: Program<Soar>,
-  args: UpdateAchievementArgs,
+  args: SetPausedArgs,
   accounts: {
     authority: PublicKey;
     game: PublicKey;
-    achievement: PublicKey;
+    leaderboard: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .updateAchievement(args.newTitle, args.newDescription, args.newNftMeta)
-    .accounts(accounts)
+    .setLeaderboardPaused(args.paused)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const unlockPlayerAchievementInstruction = async (
+export const archiveSeasonInstruction = async (
   program: Program<Soar>,
   accounts: {
     payer: PublicKey;
-    playerAccount: PublicKey;
-    playerScores: PublicKey;
-    game: PublicKey;
-    achievement: PublicKey;
-    authority: PublicKey;
-    playerAchievement: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    archive: PublicKey;
+    systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .unlockPlayerAchievement()
-    .accounts(accounts)
+    .archiveSeason()
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const updateGameInstruction = async (
+export const growTopEntriesInstruction = async (
   program: Program<Soar>,
-  args: UpdateGameArgs,
   accounts: {
     payer: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .growTopEntries()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const rebuildTopEntriesInstruction = async (
+  program: Program<Soar>,
+  args: RebuildTopEntriesArgs,
+  accounts: {
+    authority: PublicKey;
     game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+  },
+  remainingAccounts: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .rebuildTopEntries(args.reset)
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts)
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const addTopEntriesInstruction = async (
+  program: Program<Soar>,
+  args: ResizeTopEntriesArgs,
+  accounts: {
     authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
     systemProgram: PublicKey;
   },
+  remainingAccounts: AccountMeta[],
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .updateGame(args.newMeta, args.newAuths)
-    .accounts(accounts)
+    .addTopEntries(args.scoresToRetain)
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts)
     .preInstructions(pre ?? [])
     .instruction();
 
-export const updateLeaderBoardInstruction = async (
+export const resizeTopEntriesInstruction = async (
   program: Program<Soar>,
-  args: UpdateLeaderboardArgs,
+  args: ResizeTopEntriesArgs,
   accounts: {
     authority: PublicKey;
+    payer: PublicKey;
     game: PublicKey;
     leaderboard: PublicKey;
-    topEntries: PublicKey | null;
+    topEntries: PublicKey;
+    systemProgram: PublicKey;
   },
+  remainingAccounts: AccountMeta[],
   pre?: TransactionInstruction[]
-): Promise<TransactionInstruction> => {
-  return program.methods
-    .updateLeaderboard(
-      args.newDescription,
-      args.newNftMeta,
-      args.newMinScore,
-      args.newMaxScore,
-      args.newIsAscending,
-      args.newAllowMultipleScores
-    )
-    .accounts(accounts)
+): Promise<TransactionInstruction> =>
+  program.methods
+    .resizeTopEntries(args.scoresToRetain)
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts)
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const initPlayerInstruction = async (
+  program: Program<Soar>,
+  args: InitializePlayerArgs,
+  accounts: {
+    payer: PublicKey;
+    user: PublicKey;
+    playerAccount: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .initializePlayer(args.username, args.nftMeta)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
-};
 
 export const updatePlayerInstruction = async (
   program: Program<Soar>,
@@ -350,32 +395,1006 @@ export const updatePlayerInstruction = async (
 ): Promise<TransactionInstruction> =>
   program.methods
     .updatePlayer(args.newUsername, args.newNftMeta)
-    .accounts(accounts)
+    .accounts(withEvents(program, accounts))
     .preInstructions(pre ?? [])
     .instruction();
 
-export const verifyNftRewardInstruction = async (
+export const registerPlayerEntryInstruction = async (
   program: Program<Soar>,
   accounts: {
     payer: PublicKey;
     user: PublicKey;
     playerAccount: PublicKey;
-    achievement: PublicKey;
     game: PublicKey;
-    reward: PublicKey;
-    playerAchievement: PublicKey;
-    mint: PublicKey;
-    claim: PublicKey;
-    metadataToVerify: PublicKey;
-    collectionMint: PublicKey;
-    collectionMetadata: PublicKey;
-    collectionEdition: PublicKey;
-    tokenMetadataProgram: PublicKey;
+    leaderboard: PublicKey;
+    newList: PublicKey;
+    playerBan: PublicKey;
+    systemProgram: PublicKey;
   },
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .verifyNftReward()
-    .accounts(accounts)
+    .registerPlayer()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const submitScoreInstruction = async (
+  program: Program<Soar>,
+  args: SubmitScoreArgs,
+  accounts: {
+    payer: PublicKey;
+    authority: PublicKey;
+    playerAccount: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    playerBan: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .submitScore(args.score)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const submitScoreWithTiebreakInstruction = async (
+  program: Program<Soar>,
+  args: SubmitScoreWithTiebreakArgs,
+  accounts: {
+    payer: PublicKey;
+    authority: PublicKey;
+    playerAccount: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    playerBan: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .submitScoreWithTiebreak(args.score, args.tiebreak)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const submitAttestedScoreInstruction = async (
+  program: Program<Soar>,
+  args: SubmitAttestedScoreArgs,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    playerBan: PublicKey;
+    topEntries: PublicKey | null;
+    instructions: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .submitAttestedScore(
+      args.score,
+      args.tiebreak,
+      args.nonce,
+      args.issuedAt,
+      args.expiry
+    )
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const removePlayerScoreInstruction = async (
+  program: Program<Soar>,
+  args: RemovePlayerScoreArgs,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    topEntries: PublicKey | null;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .removePlayerScore(args.index)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const evictPlayerInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    playerAccount: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .evictPlayer()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const banPlayerInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    playerAccount: PublicKey;
+    ban: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .banPlayer()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const unbanPlayerInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    ban: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .unbanPlayer()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const initiateMergeInstruction = async (
+  program: Program<Soar>,
+  args: InitMergeArgs,
+  accounts: {
+    payer: PublicKey;
+    user: PublicKey;
+    playerAccount: PublicKey;
+    mergeAccount: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .initiateMerge(args.keys, args.nonce)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const registerMergeApprovalInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+    mergeAccount: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .approveMerge()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const mergePlayerScoresInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    payer: PublicKey;
+    user: PublicKey;
+    playerAccount: PublicKey;
+    mergeAccount: PublicKey;
+    mergedPlayerAccount: PublicKey;
+    leaderboard: PublicKey;
+    playerScores: PublicKey;
+    mergedPlayerScores: PublicKey;
+    mergedUser: PublicKey;
+    mergedPlayerBan: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .mergePlayerScores()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const mergePlayerAchievementInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    payer: PublicKey;
+    user: PublicKey;
+    playerAccount: PublicKey;
+    mergeAccount: PublicKey;
+    mergedPlayerAccount: PublicKey;
+    achievement: PublicKey;
+    mergedPlayerAchievement: PublicKey;
+    playerAchievement: PublicKey;
+    systemProgram: PublicKey;
+  },
+  remainingAccounts: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .mergePlayerAchievement()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts)
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const unlockPlayerAchievementInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    playerAccount: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    playerAchievement: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .unlockPlayerAchievement()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const addFtRewardInstruction = async (
+  program: Program<Soar>,
+  args: AddFtRewardArgs,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    newReward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    sourceTokenAccount: PublicKey;
+    tokenAccountOwner: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .addFtReward({
+      availableSpots: args.availableRewards,
+      kind: {
+        ft: args.kind,
+      },
+    })
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const topUpFtRewardInstruction = async (
+  program: Program<Soar>,
+  args: TopUpFtRewardArgs,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    sourceTokenAccount: PublicKey;
+    tokenAccountOwner: PublicKey;
+    tokenProgram: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .topUpFtReward(args.amount)
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const withdrawFtRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    destinationTokenAccount: PublicKey;
+    tokenProgram: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .withdrawFtReward()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const addNftRewardInstruction = async (
+  program: Program<Soar>,
+  args: AddNftRewardArgs,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    newReward: PublicKey;
+    systemProgram: PublicKey;
+    rewardCollectionMint: PublicKey | null;
+    collectionUpdateAuth: PublicKey | null;
+    collectionMetadata: PublicKey | null;
+    tokenMetadataProgram: PublicKey | null;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .addNftReward({
+      availableSpots: args.availableRewards,
+      kind: {
+        nft: args.kind,
+      },
+    })
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const updateRewardInstruction = async (
+  program: Program<Soar>,
+  args: UpdateRewardArgs,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardVault: PublicKey | null;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .updateReward(
+      args.newAvailableSpots,
+      args.newAmount,
+      args.newUri,
+      args.newName,
+      args.newSymbol,
+    )
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const setRewardPausedInstruction = async (
+  program: Program<Soar>,
+  args: SetPausedArgs,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .setRewardPaused(args.paused)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const cancelRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey | null;
+    rewardVault: PublicKey | null;
+    destinationTokenAccount: PublicKey | null;
+    tokenProgram: PublicKey | null;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .cancelReward()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const redeemClaimVoucherInstruction = async (
+  program: Program<Soar>,
+  args: RedeemClaimVoucherArgs,
+  accounts: {
+    claim: ClaimFtRewardAccounts;
+    user: PublicKey;
+    instructions: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .redeemClaimVoucher(args.expiry)
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const redeemNftClaimVoucherInstruction = async (
+  program: Program<Soar>,
+  args: RedeemClaimVoucherArgs,
+  accounts: {
+    claim: ClaimNftRewardAccounts;
+    user: PublicKey;
+    instructions: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .redeemNftClaimVoucher(args.expiry)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const claimFtRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    claim: ClaimFtRewardAccounts;
+    authority: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .claimFtReward()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const claimFtRewardsInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    playerAccount: PublicKey;
+    playerAchievement: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  },
+  remainingAccounts: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .claimFtRewards()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts)
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const claimNftRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    claim: ClaimNftRewardAccounts;
+    authority: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .claimNftReward()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const unlockAndClaimFtRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    claim: ClaimFtRewardAccounts;
+    authority: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .unlockAndClaimFtReward()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const unlockAndClaimNftRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    claim: ClaimNftRewardAccounts;
+    authority: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .unlockAndClaimNftReward()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const verifyNftRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    user: PublicKey;
+    playerAccount: PublicKey;
+    claim: PublicKey;
+    playerAchievement: PublicKey;
+    mint: PublicKey;
+    metadataToVerify: PublicKey;
+    collectionMint: PublicKey;
+    collectionMetadata: PublicKey;
+    collectionEdition: PublicKey;
+    tokenMetadataProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .verifyNftReward()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const addFtRankRewardInstruction = async (
+  program: Program<Soar>,
+  args: AddFtRankRewardArgs,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    newReward: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    sourceTokenAccount: PublicKey;
+    tokenAccountOwner: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .addFtRankReward({
+      minRank: args.minRank,
+      maxRank: args.maxRank,
+      season: args.season,
+      kind: {
+        ft: args.kind,
+      },
+    })
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const addNftRankRewardInstruction = async (
+  program: Program<Soar>,
+  args: AddNftRankRewardArgs,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey;
+    newReward: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .addNftRankReward({
+      minRank: args.minRank,
+      maxRank: args.maxRank,
+      season: args.season,
+      kind: {
+        nft: args.kind,
+      },
+    })
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const claimFtRankRewardInstruction = async (
+  program: Program<Soar>,
+  args: ClaimRankRewardArgs,
+  accounts: {
+    user: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+    seasonTopEntries: PublicKey | null;
+    rankReward: PublicKey;
+    playerAccount: PublicKey;
+    claim: PublicKey;
+    rewardTokenMint: PublicKey;
+    rewardVault: PublicKey;
+    userTokenAccount: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .claimFtRankReward(args.rank)
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const claimNftRankRewardInstruction = async (
+  program: Program<Soar>,
+  args: ClaimRankRewardArgs,
+  accounts: {
+    user: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+    seasonTopEntries: PublicKey | null;
+    rankReward: PublicKey;
+    playerAccount: PublicKey;
+    claim: PublicKey;
+    newMint: PublicKey;
+    newMetadata: PublicKey;
+    newMasterEdition: PublicKey;
+    mintTo: PublicKey;
+    tokenMetadataProgram: PublicKey;
+    associatedTokenProgram: PublicKey;
+    systemProgram: PublicKey;
+    tokenProgram: PublicKey;
+    rent: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .claimNftRankReward(args.rank)
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeGameInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    closedGame: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeGame()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeLeaderboardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeLeaderboard()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeSeasonTopEntriesInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    archive: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeSeasonTopEntries()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeAchievementInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeAchievement()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardVault: PublicKey | null;
+    tokenProgram: PublicKey | null;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeReward()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeRankRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    leaderboard: PublicKey;
+    rankReward: PublicKey;
+    rewardTokenMint: PublicKey | null;
+    rewardVault: PublicKey | null;
+    destinationTokenAccount: PublicKey | null;
+    tokenProgram: PublicKey | null;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeRankReward()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closePlayerInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closePlayer()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closePlayerScoresInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+    playerScores: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closePlayerScores()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closePlayerAchievementInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+    achievement: PublicKey;
+    playerAchievement: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closePlayerAchievement()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeMergedInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    mergeAccount: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeMerged()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeNftClaimInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    receiver: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    mint: PublicKey;
+    claim: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeNftClaim()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeRewardClaimInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+    reward: PublicKey;
+    claim: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeRewardClaim()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const closeRankRewardClaimInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    user: PublicKey;
+    playerAccount: PublicKey;
+    rankReward: PublicKey;
+    claim: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .closeRankRewardClaim()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const migrateGameInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .migrateGame()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const migrateLeaderboardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    payer: PublicKey;
+    leaderboard: PublicKey;
+    topEntries: PublicKey | null;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .migrateLeaderboard()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const migratePlayerScoresInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    payer: PublicKey;
+    playerScores: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .migratePlayerScores()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const migrateAchievementInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    payer: PublicKey;
+    achievement: PublicKey;
+    systemProgram: PublicKey;
+  },
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .migrateAchievement()
+    .accounts(withEvents(program, accounts))
+    .preInstructions(pre ?? [])
+    .instruction();
+
+export const migrateFtRewardInstruction = async (
+  program: Program<Soar>,
+  accounts: {
+    authority: PublicKey;
+    payer: PublicKey;
+    game: PublicKey;
+    achievement: PublicKey;
+    reward: PublicKey;
+    rewardTokenMint: PublicKey;
+    legacyTokenAccount: PublicKey;
+    rewardVault: PublicKey;
+    tokenProgram: PublicKey;
+    systemProgram: PublicKey;
+  },
+  remainingAccounts?: AccountMeta[],
+  pre?: TransactionInstruction[]
+): Promise<TransactionInstruction> =>
+  program.methods
+    .migrateFtReward()
+    .accounts(withEvents(program, accounts))
+    .remainingAccounts(remainingAccounts ?? [])
     .preInstructions(pre ?? [])
     .instruction();
@@ -1,9 +1,14 @@
-import { Keypair, type PublicKey } from "@solana/web3.js";
+import { type PublicKey } from "@solana/web3.js";
 import type BN from "bn.js";
 import { type SoarProgram } from "./soar.program";
-import { type InstructionResult } from "./types";
 import {
-  GameAccount,
+  type InstructionResult,
+  type ScoreRetention,
+  type TieBreak,
+} from "./types";
+import {
+  type Authority,
+  type GameAccount,
   type Genre,
   type GameType,
   type AchievementAccount,
@@ -25,26 +30,27 @@ export class GameClient {
 
   public static async register(
     program: SoarProgram,
+    creator: PublicKey,
     title: string,
     description: string,
     genre: Genre,
     gameType: GameType,
     nftMeta: PublicKey,
-    auths: PublicKey[]
+    auths: Authority[],
+    nonce: BN
   ): Promise<GameClient> {
-    const game = Keypair.generate();
-
     const { newGame, transaction } = await program.initializeNewGame(
-      game.publicKey,
+      creator,
       title,
       description,
       genre,
       gameType,
       nftMeta,
-      auths
+      auths,
+      nonce
     );
 
-    await program.sendAndConfirmTransaction(transaction, [game]);
+    await program.sendAndConfirmTransaction(transaction);
     const client = new GameClient(program, newGame);
 
     await client.init();
@@ -52,8 +58,7 @@ export class GameClient {
   }
 
   public async init(): Promise<void> {
-    const account = await this.program.fetchGameAccount(this.address);
-    this.account = GameAccount.fromIdlAccount(account, this.address);
+    this.account = await this.program.fetchGameAccount(this.address);
   }
 
   public async refresh(): Promise<void> {
@@ -99,7 +104,7 @@ export class GameClient {
   public async update(
     authority: PublicKey,
     newMeta?: GameAttributes,
-    newAuths?: PublicKey[]
+    newAuths?: Authority[]
   ): Promise<InstructionResult.UpdateGame> {
     return this.program.updateGameAccount(
       this.address,
@@ -118,7 +123,12 @@ export class GameClient {
     decimals?: number,
     minScore?: BN,
     maxScore?: BN,
-    allowMultipleScores?: boolean
+    allowMultipleScores?: boolean,
+    startTime?: BN,
+    endTime?: BN,
+    seasonLength?: BN,
+    retention?: ScoreRetention,
+    tieBreak?: TieBreak
   ): Promise<InstructionResult.AddLeaderBoard> {
     return this.program.addNewGameLeaderBoard(
       this.address,
@@ -130,7 +140,12 @@ export class GameClient {
       decimals,
       minScore,
       maxScore,
-      allowMultipleScores
+      allowMultipleScores,
+      startTime,
+      endTime,
+      seasonLength,
+      retention,
+      tieBreak
     );
   }
 
@@ -161,14 +176,16 @@ export class GameClient {
     user: PublicKey,
     authority: PublicKey,
     score: BN,
-    leaderBoard?: PublicKey
+    leaderBoard?: PublicKey,
+    tiebreak?: BN
   ): Promise<InstructionResult.SubmitScore> {
     const leaderboard = leaderBoard ?? this.recentLeaderBoardAddress();
     return this.program.submitScoreToLeaderBoard(
       user,
       authority,
       leaderboard,
-      score
+      score,
+      tiebreak
     );
   }
 
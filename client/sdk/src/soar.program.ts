@@ -1,6 +1,8 @@
 import { type AnchorProvider, Program, type Provider } from "@coral-xyz/anchor";
 import {
+  Ed25519Program,
   PublicKey,
+  type AccountMeta,
   type ConfirmOptions,
   type Connection,
   type GetProgramAccountsFilter,
@@ -13,10 +15,17 @@ import { PROGRAM_ID } from "./constants";
 import BN from "bn.js";
 import { InstructionBuilder } from "./instructions";
 import { Utils } from "./utils";
-import { type InstructionResult } from "./types";
+import {
+  ScoreRetention,
+  TieBreak,
+  type InstructionResult,
+  type SubmitAttestedScoreArgs,
+} from "./types";
 import {
   AchievementAccount,
+  AuthorityProposalAccount,
   GameAccount,
+  type Authority,
   type GameAttributes,
   type GameType,
   type Genre,
@@ -24,14 +33,24 @@ import {
   MergedAccount,
   PlayerAccount,
   PlayerAchievementAccount,
+  PlayerBanAccount,
   PlayerScoresListAccount,
+  RankRewardAccount,
+  RankRewardClaimAccount,
   RewardAccount,
+  SeasonTopEntriesAccount,
   TopEntriesAccount,
 } from "./state";
 import bs58 from "bs58";
 import { createAssociatedTokenAccountIdempotentInstruction } from "@solana/spl-token";
 import { GameClient } from "./soar.game";
 
+/** Domain separating signed score attestations from other signed messages. */
+const SCORE_ATTESTATION_DOMAIN = "soar:score-attestation:v1";
+
+/** Domain separating signed claim vouchers from other signed messages. */
+const CLAIM_VOUCHER_DOMAIN = "soar:claim-voucher:v1";
+
 export class SimpleProvider implements Provider {
   readonly connection: Connection;
   readonly publicKey?: PublicKey;
@@ -73,20 +92,23 @@ export class SoarProgram {
   }
 
   /**
-   * Initialize a new Game account with the set parameters.
+   * Initialize a new Game account with the set parameters. The game's address
+   * is derived from its creator and a nonce unique to the creator's games.
    */
   public async initializeNewGame(
-    newGame: PublicKey | string,
+    creator: PublicKey | string,
     title: string,
     description: string,
     genre: Genre,
     gameType: GameType,
     nftMeta: PublicKey | string,
-    authorities: PublicKey[]
+    authorities: Authority[],
+    nonce: BN
   ): Promise<InstructionResult.InitializeGame> {
     this.builder.clean();
-    newGame = this.getPublicKey(newGame);
+    creator = this.getPublicKey(creator);
     nftMeta = this.getPublicKey(nftMeta);
+    const newGame = this.utils.deriveGameAddress(creator, nonce)[0];
     const step = await this.builder.initGameStep(
       {
         gameMeta: {
@@ -97,8 +119,10 @@ export class SoarProgram {
           nftMeta,
         },
         authorities,
+        nonce,
       },
-      newGame
+      newGame,
+      creator
     );
 
     return {
@@ -133,13 +157,13 @@ export class SoarProgram {
   }
 
   /**
-   * Update a game's attributes.
+   * Update a game's attributes or replace its authorities.
    */
   public async updateGameAccount(
     game: PublicKey | string,
     authority: PublicKey | string,
     newMeta?: GameAttributes,
-    newAuths?: PublicKey[]
+    newAuths?: Authority[]
   ): Promise<InstructionResult.UpdateGame> {
     game = this.getPublicKey(game);
     authority = this.getPublicKey(authority);
@@ -162,6 +186,128 @@ export class SoarProgram {
     return { transaction: step[0].build() };
   }
 
+  /**
+   * Propose adding an authority to a game, optionally in place of an existing
+   * one. The proposal can be accepted by the proposed key once `timelock`
+   * seconds have passed.
+   */
+  public async proposeAuthority(
+    game: PublicKey | string,
+    authority: PublicKey | string,
+    proposed: Authority,
+    replaces?: PublicKey | string,
+    timelock?: BN
+  ): Promise<InstructionResult.ProposeAuthority> {
+    this.builder.clean();
+    game = this.getPublicKey(game);
+    authority = this.getPublicKey(authority);
+    replaces = this.getNullablePublicKey(replaces);
+
+    const step = await this.builder.proposeAuthorityStep(
+      {
+        authority: proposed,
+        replaces: replaces ?? null,
+        timelock: timelock ?? new BN(0),
+      },
+      game,
+      authority
+    );
+
+    return { proposal: step[1].proposal, transaction: step[0].build() };
+  }
+
+  /** Accept a proposal to become one of a game's authorities. */
+  public async acceptAuthority(
+    game: PublicKey | string,
+    newAuthority: PublicKey | string
+  ): Promise<InstructionResult.AcceptAuthority> {
+    this.builder.clean();
+    game = this.getPublicKey(game);
+    newAuthority = this.getPublicKey(newAuthority);
+
+    const step = await this.builder.acceptAuthorityStep(game, newAuthority);
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Cancel a pending authority proposal, either as an admin of the game or as
+   * the proposed key.
+   */
+  public async cancelAuthorityProposal(
+    game: PublicKey | string,
+    authority: PublicKey | string,
+    proposed: PublicKey | string
+  ): Promise<InstructionResult.CancelAuthorityProposal> {
+    this.builder.clean();
+    game = this.getPublicKey(game);
+    authority = this.getPublicKey(authority);
+    proposed = this.getPublicKey(proposed);
+
+    const step = await this.builder.cancelAuthorityProposalStep(
+      game,
+      authority,
+      proposed
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Propose transferring a game's ownership to `newOwner`, or cancel a pending
+   * transfer if it's `null`.
+   */
+  public async transferGameOwnership(
+    game: PublicKey | string,
+    owner: PublicKey | string,
+    newOwner: PublicKey | string | null
+  ): Promise<InstructionResult.TransferGameOwnership> {
+    this.builder.clean();
+    game = this.getPublicKey(game);
+    owner = this.getPublicKey(owner);
+
+    const step = await this.builder.transferGameOwnershipStep(
+      { newOwner: newOwner !== null ? this.getPublicKey(newOwner) : null },
+      game,
+      owner
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Accept a pending transfer of a game's ownership. */
+  public async acceptGameOwnership(
+    game: PublicKey | string,
+    newOwner: PublicKey | string
+  ): Promise<InstructionResult.AcceptGameOwnership> {
+    this.builder.clean();
+    game = this.getPublicKey(game);
+    newOwner = this.getPublicKey(newOwner);
+
+    const step = await this.builder.acceptGameOwnershipStep(game, newOwner);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Pause or resume a game. */
+  public async setGamePaused(
+    game: PublicKey | string,
+    authority: PublicKey | string,
+    paused: boolean
+  ): Promise<InstructionResult.SetPaused> {
+    this.builder.clean();
+    game = this.getPublicKey(game);
+    authority = this.getPublicKey(authority);
+
+    const step = await this.builder.setGamePausedStep(
+      { paused },
+      game,
+      authority
+    );
+
+    return { transaction: step[0].build() };
+  }
+
   /**
    * Update a player account's attributes.
    */
@@ -192,27 +338,27 @@ export class SoarProgram {
 
   /**
    * Initiate an action to merge multiple player-accounts as belonging to a
-   * single user.
+   * single user. The merge account's address is derived from the user and a
+   * nonce unique to the user's merges.
    */
   public async initiateMerge(
     user: PublicKey | string,
-    newMergeAccount: PublicKey | string,
-    playerAccountKeys: PublicKey[]
+    playerAccountKeys: PublicKey[],
+    nonce: BN
   ): Promise<InstructionResult.InitiateMerge> {
     this.builder.clean();
     user = this.getPublicKey(user);
-    newMergeAccount = this.getPublicKey(newMergeAccount);
 
     const step = await this.builder.initMergeStep(
       {
         keys: playerAccountKeys,
+        nonce,
       },
-      user,
-      newMergeAccount
+      user
     );
 
     return {
-      newMerge: newMergeAccount,
+      newMerge: step[1].mergeAccount,
       transaction: step[0].build(),
     };
   }
@@ -234,6 +380,58 @@ export class SoarProgram {
     return { transaction: step[0].build() };
   }
 
+  /**
+   * Move a merged player's scores on a leaderboard into `user`'s scores list
+   * once the merge is approved by every player involved.
+   */
+  public async mergePlayerScores(
+    user: PublicKey | string,
+    mergeAccount: PublicKey | string,
+    mergedPlayerAccount: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.MergePlayer> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    mergeAccount = this.getPublicKey(mergeAccount);
+    mergedPlayerAccount = this.getPublicKey(mergedPlayerAccount);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.mergePlayerScoresStep(
+      user,
+      mergeAccount,
+      mergedPlayerAccount,
+      leaderboard
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Carry a merged player's unlocked achievement, and any rewards they claimed
+   * for it, over to `user` once the merge is approved by every player involved.
+   */
+  public async mergePlayerAchievement(
+    user: PublicKey | string,
+    mergeAccount: PublicKey | string,
+    mergedPlayerAccount: PublicKey | string,
+    achievement: PublicKey | string
+  ): Promise<InstructionResult.MergePlayer> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    mergeAccount = this.getPublicKey(mergeAccount);
+    mergedPlayerAccount = this.getPublicKey(mergedPlayerAccount);
+    achievement = this.getPublicKey(achievement);
+
+    const step = await this.builder.mergePlayerAchievementStep(
+      user,
+      mergeAccount,
+      mergedPlayerAccount,
+      achievement
+    );
+
+    return { transaction: step[0].build() };
+  }
+
   /** Add a new achievement for a Game. */
   public async addNewGameAchievement(
     gameAddress: PublicKey | string,
@@ -263,7 +461,13 @@ export class SoarProgram {
     };
   }
 
-  /** Add a new leaderboard for a Game. */
+  /**
+   * Add a new leaderboard for a Game.
+   *
+   * Players keep every score they submit and equal scores are ranked earliest
+   * first, unless `retention` and `tieBreak` say otherwise. A leaderboard with
+   * a `seasonLength` ranks its scores per season from `startTime`.
+   */
   public async addNewGameLeaderBoard(
     gameAddress: PublicKey | string,
     authority: PublicKey | string,
@@ -274,7 +478,12 @@ export class SoarProgram {
     decimals?: number,
     minScore?: BN,
     maxScore?: BN,
-    allowMultipleScores?: boolean
+    allowMultipleScores?: boolean,
+    startTime?: BN,
+    endTime?: BN,
+    seasonLength?: BN,
+    retention?: ScoreRetention,
+    tieBreak?: TieBreak
   ): Promise<InstructionResult.AddLeaderBoard> {
     this.builder.clean();
     gameAddress = this.getPublicKey(gameAddress);
@@ -291,6 +500,11 @@ export class SoarProgram {
         minScore: minScore ?? null,
         maxScore: maxScore ?? null,
         allowMultipleScores: allowMultipleScores ?? false,
+        startTime: startTime ?? null,
+        endTime: endTime ?? null,
+        seasonLength: seasonLength ?? null,
+        retention: retention ?? ScoreRetention.unbounded(),
+        tieBreak: tieBreak ?? TieBreak.earliestFirst(),
       },
       gameAddress,
       authority
@@ -313,17 +527,24 @@ export class SoarProgram {
     newMaxScore?: BN,
     newIsAscending?: boolean,
     newAllowMultipleScores?: boolean,
-    topEntries?: PublicKey | string
+    topEntries?: PublicKey | string,
+    newRetention?: ScoreRetention
   ): Promise<InstructionResult.UpdateLeaderboard> {
     this.builder.clean();
     authority = this.getPublicKey(authority);
     leaderboard = this.getPublicKey(leaderboard);
     newNftMeta = this.getNullablePublicKey(newNftMeta);
     topEntries = this.getNullablePublicKey(topEntries);
-    if (newDescription === undefined && newNftMeta === undefined) {
-      throw new Error(
-        "One of newDescription or newNftMeta is expected to be defined"
-      );
+    if (
+      newDescription === undefined &&
+      newNftMeta === undefined &&
+      newMinScore === undefined &&
+      newMaxScore === undefined &&
+      newIsAscending === undefined &&
+      newAllowMultipleScores === undefined &&
+      newRetention === undefined
+    ) {
+      throw new Error("At least one updated value expected to be specified");
     }
 
     const step = await this.builder.updateLeaderboardStep(
@@ -334,6 +555,7 @@ export class SoarProgram {
         newMaxScore: newMaxScore ?? null,
         newIsAscending: newIsAscending ?? null,
         newAllowMultipleScores: newAllowMultipleScores ?? null,
+        newRetention: newRetention ?? null,
       },
       authority,
       leaderboard,
@@ -344,40 +566,18 @@ export class SoarProgram {
     return { transaction: step[0].build() };
   }
 
-  /** Register a player to a particular leaderboard. */
-  public async registerPlayerEntryForLeaderBoard(
-    user: PublicKey | string,
-    leaderboard: PublicKey | string
-  ): Promise<InstructionResult.RegisterPlayerEntry> {
-    this.builder.clean();
-    user = this.getPublicKey(user);
-    leaderboard = this.getPublicKey(leaderboard);
-
-    const step = await this.builder.registerPlayerEntryStep(user, leaderboard);
-
-    return { newList: step[1].newList, transaction: step[0].build() };
-  }
-
-  /** Submit a player's score to a leaderboard they're registered to. */
-  public async submitScoreToLeaderBoard(
-    user: PublicKey | string,
+  /** Pause or resume score submissions to a leaderboard. */
+  public async setLeaderboardPaused(
     authority: PublicKey | string,
     leaderboard: PublicKey | string,
-    score: BN | number
-  ): Promise<InstructionResult.SubmitScore> {
+    paused: boolean
+  ): Promise<InstructionResult.SetPaused> {
     this.builder.clean();
-    if (typeof score === "number") {
-      score = new BN(score);
-    }
-    user = this.getPublicKey(user);
     authority = this.getPublicKey(authority);
     leaderboard = this.getPublicKey(leaderboard);
 
-    const step = await this.builder.submitScoreStep(
-      {
-        score,
-      },
-      user,
+    const step = await this.builder.setLeaderboardPausedStep(
+      { paused },
       authority,
       leaderboard
     );
@@ -385,118 +585,420 @@ export class SoarProgram {
     return { transaction: step[0].build() };
   }
 
-  /** Update an achievement's details. */
-  public async updateGameAchievement(
+  /**
+   * Archive the top entries of a leaderboard's ended season and start ranking
+   * the current one.
+   */
+  public async archiveSeason(
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.ArchiveSeason> {
+    this.builder.clean();
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.archiveSeasonStep(leaderboard);
+
+    return {
+      archive: step[1].archive,
+      season: step[1].season,
+      transaction: step[0].build(),
+    };
+  }
+
+  /**
+   * Grow a leaderboard's top-entries account towards the size it needs to
+   * rank its scores.
+   */
+  public async growTopEntries(
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.ManageTopEntries> {
+    this.builder.clean();
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.growTopEntriesStep(leaderboard);
+
+    return { topEntries: step[1].topEntries, transaction: step[0].build() };
+  }
+
+  /**
+   * Rank the scores of `playerAccounts` into a leaderboard's top entries,
+   * clearing the entries first if `reset` is set.
+   */
+  public async rebuildTopEntries(
     authority: PublicKey | string,
-    achievement: PublicKey | string,
-    newTitle?: string,
-    newDescription?: string,
-    newNftMeta?: PublicKey | string
-  ): Promise<InstructionResult.UpdateAchievement> {
+    leaderboard: PublicKey | string,
+    playerAccounts: PublicKey[],
+    reset: boolean
+  ): Promise<InstructionResult.ManageTopEntries> {
     this.builder.clean();
     authority = this.getPublicKey(authority);
-    achievement = this.getPublicKey(achievement);
-    newNftMeta = this.getNullablePublicKey(newNftMeta);
-
-    if (
-      newTitle === undefined &&
-      newDescription === undefined &&
-      newNftMeta === undefined
-    ) {
-      throw new Error("At least one updated value expected to be specified");
-    }
+    leaderboard = this.getPublicKey(leaderboard);
 
-    const step = await this.builder.updateAchievementStep(
-      {
-        newTitle: newTitle ?? null,
-        newDescription: newDescription ?? null,
-        newNftMeta: newNftMeta ?? null,
-      },
+    const step = await this.builder.rebuildTopEntriesStep(
+      { reset },
       authority,
-      achievement
+      leaderboard,
+      playerAccounts
     );
 
-    return { transaction: step[0].build() };
+    return { topEntries: step[1].topEntries, transaction: step[0].build() };
   }
 
-  /** Unlock an achievement for a player. */
-  public async unlockPlayerAchievement(
-    user: PublicKey | string,
+  /**
+   * Add top entries to a leaderboard created without them, ranking the scores
+   * of `playerAccounts` into them.
+   */
+  public async addTopEntries(
     authority: PublicKey | string,
-    achievement: PublicKey | string,
     leaderboard: PublicKey | string,
-    game?: PublicKey | string
-  ): Promise<InstructionResult.UnlockPlayerAchievement> {
+    scoresToRetain: number,
+    playerAccounts: PublicKey[]
+  ): Promise<InstructionResult.ManageTopEntries> {
     this.builder.clean();
-    user = this.getPublicKey(user);
     authority = this.getPublicKey(authority);
-    achievement = this.getPublicKey(achievement);
     leaderboard = this.getPublicKey(leaderboard);
-    game = this.getNullablePublicKey(game);
 
-    const step = await this.builder.unlockPlayerAchievementStep(
-      user,
+    const step = await this.builder.addTopEntriesStep(
+      { scoresToRetain },
       authority,
-      achievement,
-      leaderboard
+      leaderboard,
+      playerAccounts
     );
 
-    return {
-      newPlayerAchievement: step[1].playerAchievement,
-      transaction: step[0].build(),
-    };
+    return { topEntries: step[1].topEntries, transaction: step[0].build() };
   }
 
-  /** Add a fungible token reward for unlocking an achievement. */
-  public async addFungibleReward(
+  /**
+   * Change the number of scores a leaderboard's top entries retain, ranking
+   * the scores of `playerAccounts` into them.
+   */
+  public async resizeTopEntries(
     authority: PublicKey | string,
-    newReward: PublicKey | string,
-    achievement: PublicKey | string,
-    amountPerUser: BN,
-    availableRewards: BN,
-    initialDelegation: BN,
-    mint: PublicKey,
-    sourceTokenAccount: PublicKey | string,
-    tokenAccountOwner: PublicKey | string
-  ): Promise<InstructionResult.AddReward> {
+    leaderboard: PublicKey | string,
+    scoresToRetain: number,
+    playerAccounts: PublicKey[]
+  ): Promise<InstructionResult.ManageTopEntries> {
     this.builder.clean();
     authority = this.getPublicKey(authority);
-    newReward = this.getPublicKey(newReward);
-    achievement = this.getPublicKey(achievement);
-    sourceTokenAccount = this.getPublicKey(sourceTokenAccount);
-    tokenAccountOwner = this.getPublicKey(tokenAccountOwner);
+    leaderboard = this.getPublicKey(leaderboard);
 
-    const step = await this.builder.addFungibleRewardStep(
-      {
-        availableRewards,
-        kind: {
-          deposit: initialDelegation,
-          amount: amountPerUser,
-        },
-      },
+    const step = await this.builder.resizeTopEntriesStep(
+      { scoresToRetain },
       authority,
-      newReward,
-      achievement,
-      sourceTokenAccount,
-      tokenAccountOwner,
-      mint
+      leaderboard,
+      playerAccounts
     );
 
-    const prev = await this.fetchAchievementAccount(achievement).then(
-      (res) => res.reward
-    );
-    return {
-      oldReward: prev,
-      newReward,
-      transaction: step[0].build(),
-    };
+    return { topEntries: step[1].topEntries, transaction: step[0].build() };
   }
 
-  /** Add a non-fungible token reward for unlocking an achievement. */
-  public async addNonFungibleReward(
-    authority: PublicKey | string,
-    newReward: PublicKey | string,
-    achievement: PublicKey | string,
+  /** Register a player to a particular leaderboard. */
+  public async registerPlayerEntryForLeaderBoard(
+    user: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.RegisterPlayerEntry> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.registerPlayerEntryStep(user, leaderboard);
+
+    return { newList: step[1].newList, transaction: step[0].build() };
+  }
+
+  /**
+   * Submit a player's score to a leaderboard they're registered to, along with
+   * the value ranking it among equal scores if the leaderboard uses one.
+   */
+  public async submitScoreToLeaderBoard(
+    user: PublicKey | string,
+    authority: PublicKey | string,
+    leaderboard: PublicKey | string,
+    score: BN | number,
+    tiebreak?: BN | number
+  ): Promise<InstructionResult.SubmitScore> {
+    this.builder.clean();
+    if (typeof score === "number") {
+      score = new BN(score);
+    }
+    user = this.getPublicKey(user);
+    authority = this.getPublicKey(authority);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step =
+      tiebreak === undefined
+        ? await this.builder.submitScoreStep(
+            { score },
+            user,
+            authority,
+            leaderboard
+          )
+        : await this.builder.submitScoreWithTiebreakStep(
+            { score, tiebreak: new BN(tiebreak) },
+            user,
+            authority,
+            leaderboard
+          );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * The nonce the next score attestation for a player on a leaderboard must
+   * carry: the number of scores submitted to their scores list so far. The
+   * attestation must also be issued after the list's `createdAt`.
+   */
+  public async nextAttestationNonce(
+    user: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<BN> {
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+    const address = this.utils.derivePlayerScoresListAddress(
+      user,
+      leaderboard
+    )[0];
+    const list = await this.fetchPlayerScoresListAccount(address);
+    return list.submissionCount;
+  }
+
+  /** The message a score-submitting authority signs to attest a score. */
+  public scoreAttestationMessage(
+    user: PublicKey | string,
+    leaderboard: PublicKey | string,
+    args: SubmitAttestedScoreArgs
+  ): Buffer {
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+    return this.signedMessage(SCORE_ATTESTATION_DOMAIN, "ScoreAttestation", {
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      leaderboard: this.getPublicKey(leaderboard),
+      ...args,
+    });
+  }
+
+  /**
+   * Submit a score attested by one of the game's score-submitting authorities,
+   * signed by the player instead of the authority. `signature` is `signer`'s
+   * signature of the [scoreAttestationMessage] for `args`.
+   */
+  public async submitAttestedScore(
+    user: PublicKey | string,
+    leaderboard: PublicKey | string,
+    args: SubmitAttestedScoreArgs,
+    signer: PublicKey | string,
+    signature: Uint8Array
+  ): Promise<InstructionResult.SubmitScore> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+    signer = this.getPublicKey(signer);
+
+    const verifyInstruction = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: signer.toBytes(),
+      message: this.scoreAttestationMessage(user, leaderboard, args),
+      signature,
+    });
+    const step = await this.builder.submitAttestedScoreStep(
+      args,
+      user,
+      leaderboard,
+      verifyInstruction
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Remove one of a player's scores from a leaderboard. */
+  public async removePlayerScore(
+    authority: PublicKey | string,
+    user: PublicKey | string,
+    leaderboard: PublicKey | string,
+    index: number
+  ): Promise<InstructionResult.RemovePlayerScore> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.removePlayerScoreStep(
+      { index },
+      authority,
+      user,
+      leaderboard
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Remove a player's entries from a leaderboard's top entries. */
+  public async evictPlayer(
+    authority: PublicKey | string,
+    user: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.EvictPlayer> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.evictPlayerStep(
+      authority,
+      user,
+      leaderboard
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Ban a player from submitting scores to a leaderboard. */
+  public async banPlayer(
+    authority: PublicKey | string,
+    user: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.BanPlayer> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.banPlayerStep(authority, user, leaderboard);
+
+    return { ban: step[1].ban, transaction: step[0].build() };
+  }
+
+  /** Lift a player's ban from a leaderboard. */
+  public async unbanPlayer(
+    authority: PublicKey | string,
+    user: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.UnbanPlayer> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.unbanPlayerStep(
+      authority,
+      user,
+      leaderboard
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Update an achievement's details. */
+  public async updateGameAchievement(
+    authority: PublicKey | string,
+    achievement: PublicKey | string,
+    newTitle?: string,
+    newDescription?: string,
+    newNftMeta?: PublicKey | string
+  ): Promise<InstructionResult.UpdateAchievement> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    achievement = this.getPublicKey(achievement);
+    newNftMeta = this.getNullablePublicKey(newNftMeta);
+
+    if (
+      newTitle === undefined &&
+      newDescription === undefined &&
+      newNftMeta === undefined
+    ) {
+      throw new Error("At least one updated value expected to be specified");
+    }
+
+    const step = await this.builder.updateAchievementStep(
+      {
+        newTitle: newTitle ?? null,
+        newDescription: newDescription ?? null,
+        newNftMeta: newNftMeta ?? null,
+      },
+      authority,
+      achievement
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Unlock an achievement for a player. */
+  public async unlockPlayerAchievement(
+    user: PublicKey | string,
+    authority: PublicKey | string,
+    achievement: PublicKey | string,
+    game?: PublicKey | string
+  ): Promise<InstructionResult.UnlockPlayerAchievement> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    authority = this.getPublicKey(authority);
+    achievement = this.getPublicKey(achievement);
+    game = this.getNullablePublicKey(game);
+
+    const step = await this.builder.unlockPlayerAchievementStep(
+      user,
+      authority,
+      achievement,
+      game
+    );
+
+    return {
+      newPlayerAchievement: step[1].playerAchievement,
+      transaction: step[0].build(),
+    };
+  }
+
+  /**
+   * Add a fungible token reward for unlocking an achievement, depositing
+   * `initialDelegation` tokens into its vault.
+   *
+   * `remainingAccounts` are the accounts the mint's transfer hook requires,
+   * if it has one.
+   */
+  public async addFungibleReward(
+    authority: PublicKey | string,
+    achievement: PublicKey | string,
+    amountPerUser: BN,
+    availableRewards: BN,
+    initialDelegation: BN,
+    mint: PublicKey,
+    sourceTokenAccount: PublicKey | string,
+    tokenAccountOwner: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.AddReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    achievement = this.getPublicKey(achievement);
+    sourceTokenAccount = this.getPublicKey(sourceTokenAccount);
+    tokenAccountOwner = this.getPublicKey(tokenAccountOwner);
+
+    const step = await this.builder.addFungibleRewardStep(
+      {
+        availableRewards,
+        kind: {
+          deposit: initialDelegation,
+          amount: amountPerUser,
+        },
+      },
+      authority,
+      achievement,
+      sourceTokenAccount,
+      tokenAccountOwner,
+      mint,
+      undefined,
+      undefined,
+      undefined,
+      remainingAccounts
+    );
+
+    return {
+      newReward: step[1].newReward,
+      transaction: step[0].build(),
+    };
+  }
+
+  /** Add a non-fungible token reward for unlocking an achievement. */
+  public async addNonFungibleReward(
+    authority: PublicKey | string,
+    achievement: PublicKey | string,
     availableRewards: BN,
     uri: string,
     name: string,
@@ -505,7 +1007,6 @@ export class SoarProgram {
     collectionUpdateAuthority?: PublicKey | string
   ): Promise<InstructionResult.AddReward> {
     authority = this.getPublicKey(authority);
-    newReward = this.getPublicKey(newReward);
     achievement = this.getPublicKey(achievement);
     collectionMint = this.getNullablePublicKey(collectionMint);
     collectionUpdateAuthority = this.getNullablePublicKey(
@@ -513,117 +1014,848 @@ export class SoarProgram {
     );
     this.builder.clean();
 
-    if (collectionMint !== undefined) {
-      if (collectionUpdateAuthority === undefined) {
-        throw new Error("Collection update authority should be defined");
-      }
-    }
+    if (collectionMint !== undefined) {
+      if (collectionUpdateAuthority === undefined) {
+        throw new Error("Collection update authority should be defined");
+      }
+    }
+
+    const step = await this.builder.addNonFungibleRewardStep(
+      {
+        availableRewards,
+        kind: { uri, name, symbol },
+      },
+      authority,
+      achievement,
+      collectionMint,
+      collectionUpdateAuthority
+    );
+
+    return {
+      newReward: step[1].newReward,
+      transaction: step[0].build(),
+    };
+  }
+
+  /** Deposit more tokens into a fungible-token reward's vault. */
+  public async topUpFtReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    amount: BN,
+    sourceTokenAccount: PublicKey | string,
+    tokenAccountOwner: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ManageReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    sourceTokenAccount = this.getPublicKey(sourceTokenAccount);
+    tokenAccountOwner = this.getPublicKey(tokenAccountOwner);
+
+    const step = await this.builder.topUpFtRewardStep(
+      { amount },
+      authority,
+      reward,
+      sourceTokenAccount,
+      tokenAccountOwner,
+      remainingAccounts
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Withdraw the tokens of a fungible-token reward's vault that aren't owed to
+   * its remaining spots.
+   */
+  public async withdrawFtReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    destinationTokenAccount: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ManageReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    destinationTokenAccount = this.getPublicKey(destinationTokenAccount);
+
+    const step = await this.builder.withdrawFtRewardStep(
+      authority,
+      reward,
+      destinationTokenAccount,
+      remainingAccounts
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Update a reward's remaining spots, amount or metadata. */
+  public async updateReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    newAvailableSpots?: BN,
+    newAmount?: BN,
+    newUri?: string,
+    newName?: string,
+    newSymbol?: string
+  ): Promise<InstructionResult.ManageReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+
+    const step = await this.builder.updateRewardStep(
+      {
+        newAvailableSpots: newAvailableSpots ?? null,
+        newAmount: newAmount ?? null,
+        newUri: newUri ?? null,
+        newName: newName ?? null,
+        newSymbol: newSymbol ?? null,
+      },
+      authority,
+      reward
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Pause or resume claims of a reward. */
+  public async setRewardPaused(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    paused: boolean
+  ): Promise<InstructionResult.SetPaused> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+
+    const step = await this.builder.setRewardPausedStep(
+      { paused },
+      authority,
+      reward
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Cancel a reward's remaining spots, returning a fungible-token reward's
+   * unclaimed tokens to `destinationTokenAccount`, or to the authority's
+   * associated token account by default.
+   */
+  public async cancelReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    destinationTokenAccount?: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ManageReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    destinationTokenAccount = this.getNullablePublicKey(
+      destinationTokenAccount
+    );
+
+    const step = await this.builder.cancelRewardStep(
+      authority,
+      reward,
+      destinationTokenAccount,
+      remainingAccounts
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Claim an non-fungible token reward as a result of unlocking an achievement. */
+  public async claimNftReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    mint: PublicKey | string,
+    user: PublicKey | string
+  ): Promise<InstructionResult.ClaimNftReward> {
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    mint = this.getPublicKey(mint);
+    user = this.getPublicKey(user);
+    this.builder.clean();
+
+    const step = await this.builder.claimNftRewardStep(
+      authority,
+      user,
+      reward,
+      mint
+    );
+
+    return {
+      newMint: mint,
+      transaction: step[0].build(),
+    };
+  }
+
+  /**
+   * Claim a fungible-token reward as a result for unlocking an achievement.
+   * The tokens are paid into the user's associated token account, which is
+   * created if it doesn't exist.
+   */
+  public async claimFtReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    user: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ClaimFtReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    user = this.getPublicKey(user);
+
+    const step = await this.builder.claimFtRewardStep(
+      authority,
+      user,
+      reward,
+      undefined,
+      undefined,
+      remainingAccounts
+    );
+
+    return {
+      transaction: step[0].build(),
+    };
+  }
+
+  /**
+   * Unlock an achievement for a player and claim one of its fungible-token
+   * rewards in a single instruction.
+   */
+  public async unlockAndClaimFtReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    user: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ClaimFtReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    user = this.getPublicKey(user);
+
+    const step = await this.builder.unlockAndClaimFtRewardStep(
+      authority,
+      user,
+      reward,
+      undefined,
+      undefined,
+      remainingAccounts
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Unlock an achievement for a player and claim one of its non-fungible-token
+   * rewards in a single instruction.
+   */
+  public async unlockAndClaimNftReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    mint: PublicKey | string,
+    user: PublicKey | string
+  ): Promise<InstructionResult.ClaimNftReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    mint = this.getPublicKey(mint);
+    user = this.getPublicKey(user);
+
+    const step = await this.builder.unlockAndClaimNftRewardStep(
+      authority,
+      user,
+      reward,
+      mint
+    );
+
+    return { newMint: mint, transaction: step[0].build() };
+  }
+
+  /**
+   * Claim several fungible-token rewards of an unlocked achievement at once.
+   * Every reward must use the same token program. Missing associated token
+   * accounts of the user are created first.
+   */
+  public async claimFtRewards(
+    authority: PublicKey | string,
+    user: PublicKey | string,
+    achievement: PublicKey | string,
+    rewards: PublicKey[]
+  ): Promise<InstructionResult.ClaimFtReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    user = this.getPublicKey(user);
+    achievement = this.getPublicKey(achievement);
+
+    let tokenProgram: PublicKey | undefined;
+    const preInstructions: TransactionInstruction[] = [];
+    for (const reward of rewards) {
+      const ft = (await this.fetchRewardAccount(reward)).FungibleToken;
+      if (ft === undefined) {
+        throw new Error("Not a fungible-token reward");
+      }
+      tokenProgram = this.utils.rewardTokenProgram(ft.tokenProgram);
+      const instruction = await this.createMissingATA(
+        ft.mint,
+        user,
+        tokenProgram
+      );
+      if (instruction !== null) {
+        preInstructions.push(instruction);
+      }
+    }
+
+    const step = await this.builder
+      .append(preInstructions)
+      .claimFtRewardsStep(authority, user, achievement, rewards, tokenProgram);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** The message a game authority signs to issue a claim voucher. */
+  public async claimVoucherMessage(
+    user: PublicKey | string,
+    reward: PublicKey | string,
+    expiry: BN
+  ): Promise<Buffer> {
+    user = this.getPublicKey(user);
+    reward = this.getPublicKey(reward);
+    const { achievement } = await this.fetchRewardAccount(reward);
+
+    return this.signedMessage(CLAIM_VOUCHER_DOMAIN, "ClaimVoucher", {
+      playerAccount: this.utils.derivePlayerAddress(user)[0],
+      achievement,
+      reward,
+      expiry,
+    });
+  }
+
+  /**
+   * Redeem a voucher issued by one of the game's authorities, unlocking the
+   * reward's achievement and claiming its fungible tokens. `signature` is
+   * `signer`'s signature of the [claimVoucherMessage] for the reward.
+   */
+  public async redeemClaimVoucher(
+    user: PublicKey | string,
+    reward: PublicKey | string,
+    expiry: BN,
+    signer: PublicKey | string,
+    signature: Uint8Array,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ClaimFtReward> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    reward = this.getPublicKey(reward);
+    signer = this.getPublicKey(signer);
+
+    const verifyInstruction = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: signer.toBytes(),
+      message: await this.claimVoucherMessage(user, reward, expiry),
+      signature,
+    });
+    const step = await this.builder.redeemClaimVoucherStep(
+      { expiry },
+      user,
+      reward,
+      verifyInstruction,
+      undefined,
+      remainingAccounts
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Redeem a voucher issued by one of the game's authorities, unlocking the
+   * reward's achievement and minting its NFT to the user as `mint`.
+   */
+  public async redeemNftClaimVoucher(
+    user: PublicKey | string,
+    reward: PublicKey | string,
+    mint: PublicKey | string,
+    expiry: BN,
+    signer: PublicKey | string,
+    signature: Uint8Array
+  ): Promise<InstructionResult.ClaimNftReward> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    reward = this.getPublicKey(reward);
+    mint = this.getPublicKey(mint);
+    signer = this.getPublicKey(signer);
+
+    const verifyInstruction = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: signer.toBytes(),
+      message: await this.claimVoucherMessage(user, reward, expiry),
+      signature,
+    });
+    const step = await this.builder.redeemNftClaimVoucherStep(
+      { expiry },
+      user,
+      reward,
+      mint,
+      verifyInstruction
+    );
+
+    return { newMint: mint, transaction: step[0].build() };
+  }
+
+  /** Verify a minted NFT reward as part of the set collection. */
+  public async verifyPlayerNftReward(
+    user: PublicKey | string,
+    reward: PublicKey | string,
+    mint: PublicKey | string
+  ): Promise<InstructionResult.VerifyReward> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    reward = this.getPublicKey(reward);
+    mint = this.getPublicKey(mint);
+
+    const step = await this.builder.verifyPlayerNftRewardStep(
+      user,
+      reward,
+      mint
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Add a fungible-token reward for the players ranked between `minRank` and
+   * `maxRank` on a leaderboard, or on one of its archived seasons.
+   * `newReward` must sign the transaction.
+   */
+  public async addFungibleRankReward(
+    authority: PublicKey | string,
+    leaderboard: PublicKey | string,
+    newReward: PublicKey | string,
+    minRank: number,
+    maxRank: number,
+    amountPerUser: BN,
+    deposit: BN,
+    mint: PublicKey,
+    sourceTokenAccount: PublicKey | string,
+    tokenAccountOwner: PublicKey | string,
+    season?: BN,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.AddRankReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    leaderboard = this.getPublicKey(leaderboard);
+    newReward = this.getPublicKey(newReward);
+    sourceTokenAccount = this.getPublicKey(sourceTokenAccount);
+    tokenAccountOwner = this.getPublicKey(tokenAccountOwner);
+
+    const step = await this.builder.addFtRankRewardStep(
+      {
+        minRank,
+        maxRank,
+        season: season ?? null,
+        kind: { deposit, amount: amountPerUser },
+      },
+      authority,
+      leaderboard,
+      newReward,
+      sourceTokenAccount,
+      tokenAccountOwner,
+      mint,
+      undefined,
+      undefined,
+      remainingAccounts
+    );
+
+    return { newReward, transaction: step[0].build() };
+  }
+
+  /**
+   * Add a non-fungible-token reward for the players ranked between `minRank`
+   * and `maxRank` on a leaderboard, or on one of its archived seasons.
+   * `newReward` must sign the transaction.
+   */
+  public async addNonFungibleRankReward(
+    authority: PublicKey | string,
+    leaderboard: PublicKey | string,
+    newReward: PublicKey | string,
+    minRank: number,
+    maxRank: number,
+    uri: string,
+    name: string,
+    symbol: string,
+    season?: BN
+  ): Promise<InstructionResult.AddRankReward> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    leaderboard = this.getPublicKey(leaderboard);
+    newReward = this.getPublicKey(newReward);
+
+    const step = await this.builder.addNftRankRewardStep(
+      {
+        minRank,
+        maxRank,
+        season: season ?? null,
+        kind: { uri, name, symbol },
+      },
+      authority,
+      leaderboard,
+      newReward
+    );
+
+    return { newReward, transaction: step[0].build() };
+  }
+
+  /**
+   * Claim a rank reward's fungible tokens for the player holding `rank`.
+   * The user's associated token account is created first if it doesn't exist.
+   */
+  public async claimFtRankReward(
+    user: PublicKey | string,
+    rankReward: PublicKey | string,
+    rank: number,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.ClaimRankReward> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    rankReward = this.getPublicKey(rankReward);
+
+    const accounts = await this.builder.accounts.claimFtRankRewardAccounts(
+      user,
+      rankReward
+    );
+    const preInstructions: TransactionInstruction[] = [];
+    const createATA = await this.createMissingATA(
+      accounts.rewardTokenMint,
+      user,
+      accounts.tokenProgram
+    );
+    if (createATA !== null) {
+      preInstructions.push(createATA);
+    }
+
+    const step = await this.builder
+      .append(preInstructions)
+      .claimFtRankRewardStep(
+        { rank },
+        user,
+        rankReward,
+        undefined,
+        remainingAccounts
+      );
+
+    return { claim: step[1].claim, transaction: step[0].build() };
+  }
+
+  /** Mint a rank reward's NFT to the player holding `rank`, as `mint`. */
+  public async claimNftRankReward(
+    user: PublicKey | string,
+    rankReward: PublicKey | string,
+    rank: number,
+    mint: PublicKey | string
+  ): Promise<InstructionResult.ClaimRankReward> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    rankReward = this.getPublicKey(rankReward);
+    mint = this.getPublicKey(mint);
+
+    const step = await this.builder.claimNftRankRewardStep(
+      { rank },
+      user,
+      rankReward,
+      mint
+    );
+
+    return { claim: step[1].claim, transaction: step[0].build() };
+  }
+
+  /**
+   * Close a game with no live leaderboards or achievements. Its address can't
+   * be initialized again afterwards.
+   */
+  public async closeGame(
+    authority: PublicKey | string,
+    game: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    game = this.getPublicKey(game);
+
+    const step = await this.builder.closeGameStep(authority, game);
 
-    const step = await this.builder.addNonFungibleRewardStep(
-      {
-        availableRewards,
-        kind: { uri, name, symbol },
-      },
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a leaderboard along with its top entries. */
+  public async closeLeaderboard(
+    authority: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.closeLeaderboardStep(
       authority,
-      newReward,
-      achievement,
-      collectionMint,
-      collectionUpdateAuthority
+      leaderboard
     );
 
-    const prev = await this.fetchAchievementAccount(achievement).then(
-      (res) => res.reward
-    );
-    return {
-      oldReward: prev,
-      newReward,
-      transaction: step[0].build(),
-    };
+    return { transaction: step[0].build() };
   }
 
-  /** Claim an non-fungible token reward as a result of unlocking an achievement. */
-  public async claimNftReward(
+  /** Close the archived top entries of a leaderboard's season. */
+  public async closeSeasonTopEntries(
     authority: PublicKey | string,
-    achievement: PublicKey | string,
-    mint: PublicKey | string,
-    user: PublicKey | string
-  ): Promise<InstructionResult.ClaimNftReward> {
-    authority = this.getPublicKey(authority);
-    achievement = this.getPublicKey(achievement);
-    mint = this.getPublicKey(mint);
-    user = this.getPublicKey(user);
+    leaderboard: PublicKey | string,
+    season: BN
+  ): Promise<InstructionResult.Close> {
     this.builder.clean();
+    authority = this.getPublicKey(authority);
+    leaderboard = this.getPublicKey(leaderboard);
 
-    const step = await this.builder.claimNftRewardStep(
+    const step = await this.builder.closeSeasonTopEntriesStep(
       authority,
-      achievement,
-      mint,
-      user
+      leaderboard,
+      season
     );
 
-    return {
-      newMint: mint,
-      transaction: step[0].build(),
-    };
+    return { transaction: step[0].build() };
   }
 
-  /** Claim a fungible-token reward as a result for unlocking an achievement. */
-  public async claimFtReward(
+  /** Close an achievement with no live rewards. */
+  public async closeAchievement(
     authority: PublicKey | string,
-    achievement: PublicKey | string,
-    user: PublicKey | string
-  ): Promise<InstructionResult.ClaimFtReward> {
+    achievement: PublicKey | string
+  ): Promise<InstructionResult.Close> {
     this.builder.clean();
     authority = this.getPublicKey(authority);
     achievement = this.getPublicKey(achievement);
-    user = this.getPublicKey(user);
 
-    const accounts = await this.builder.accounts.claimFtRewardAccounts(
+    const step = await this.builder.closeAchievementStep(
       authority,
-      achievement,
-      user
+      achievement
     );
 
-    const preInstructions: TransactionInstruction[] = [];
-    const userAta = this.utils.deriveAssociatedTokenAddress(
-      accounts.mint,
-      user
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a cancelled or exhausted reward, along with its empty vault. */
+  public async closeReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+
+    const step = await this.builder.closeRewardStep(authority, reward);
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Close a rank reward, returning a fungible-token reward's unclaimed tokens
+   * to `destinationTokenAccount`, or to the authority's associated token
+   * account by default.
+   */
+  public async closeRankReward(
+    authority: PublicKey | string,
+    rankReward: PublicKey | string,
+    destinationTokenAccount?: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    rankReward = this.getPublicKey(rankReward);
+    destinationTokenAccount = this.getNullablePublicKey(
+      destinationTokenAccount
     );
-    const account = await this.provider.connection.getAccountInfo(userAta);
-    if (account === null) {
-      preInstructions.push(this.createATA(accounts.mint, user, userAta));
-    }
 
-    const step = await this.builder
-      .append(preInstructions)
-      .claimFtRewardStep(
-        authority,
-        achievement,
-        user,
-        accounts.reward,
-        accounts.game
-      );
+    const step = await this.builder.closeRankRewardStep(
+      authority,
+      rankReward,
+      destinationTokenAccount,
+      undefined,
+      remainingAccounts
+    );
 
-    return {
-      transaction: step[0].build(),
-    };
+    return { transaction: step[0].build() };
   }
 
-  /** Verify a minted NFT reward as part of the set collection. */
-  public async verifyPlayerNftReward(
+  /** Close a player account. */
+  public async closePlayer(
+    user: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+
+    const step = await this.builder.closePlayerStep(user);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a player's scores list on a leaderboard. */
+  public async closePlayerScores(
     user: PublicKey | string,
-    achievement: PublicKey | string,
-    mint: PublicKey | string
-  ): Promise<InstructionResult.VerifyReward> {
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.closePlayerScoresStep(user, leaderboard);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a player's achievement account. */
+  public async closePlayerAchievement(
+    user: PublicKey | string,
+    achievement: PublicKey | string
+  ): Promise<InstructionResult.Close> {
     this.builder.clean();
     user = this.getPublicKey(user);
     achievement = this.getPublicKey(achievement);
-    mint = this.getPublicKey(mint);
 
-    const step = await this.builder.verifyPlayerNftRewardStep(
+    const step = await this.builder.closePlayerAchievementStep(
       user,
-      achievement,
-      mint
+      achievement
+    );
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a merge account initiated by `user`. */
+  public async closeMerged(
+    user: PublicKey | string,
+    mergeAccount: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    mergeAccount = this.getPublicKey(mergeAccount);
+
+    const step = await this.builder.closeMergedStep(user, mergeAccount);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Close the claim record of an NFT reward minted as `mint`. */
+  public async closeNftClaim(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    mint: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+    mint = this.getPublicKey(mint);
+
+    const step = await this.builder.closeNftClaimStep(authority, reward, mint);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a player's claim of a closed reward. */
+  public async closeRewardClaim(
+    user: PublicKey | string,
+    reward: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    reward = this.getPublicKey(reward);
+
+    const step = await this.builder.closeRewardClaimStep(user, reward);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Close a player's claim of a closed rank reward. */
+  public async closeRankRewardClaim(
+    user: PublicKey | string,
+    rankReward: PublicKey | string
+  ): Promise<InstructionResult.Close> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    rankReward = this.getPublicKey(rankReward);
+
+    const step = await this.builder.closeRankRewardClaimStep(user, rankReward);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Migrate a game created by an earlier version of the program. */
+  public async migrateGame(
+    authority: PublicKey | string,
+    game: PublicKey | string
+  ): Promise<InstructionResult.Migrate> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    game = this.getPublicKey(game);
+
+    const step = await this.builder.migrateGameStep(authority, game);
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Migrate a leaderboard, and its top entries if it has them, created by an
+   * earlier version of the program.
+   */
+  public async migrateLeaderboard(
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.Migrate> {
+    this.builder.clean();
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.migrateLeaderboardStep(leaderboard);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Migrate a scores list created by an earlier version of the program. */
+  public async migratePlayerScores(
+    user: PublicKey | string,
+    leaderboard: PublicKey | string
+  ): Promise<InstructionResult.Migrate> {
+    this.builder.clean();
+    user = this.getPublicKey(user);
+    leaderboard = this.getPublicKey(leaderboard);
+
+    const step = await this.builder.migratePlayerScoresStep(user, leaderboard);
+
+    return { transaction: step[0].build() };
+  }
+
+  /** Migrate an achievement created by an earlier version of the program. */
+  public async migrateAchievement(
+    achievement: PublicKey | string
+  ): Promise<InstructionResult.Migrate> {
+    this.builder.clean();
+    achievement = this.getPublicKey(achievement);
+
+    const step = await this.builder.migrateAchievementStep(achievement);
+
+    return { transaction: step[0].build() };
+  }
+
+  /**
+   * Migrate a fungible-token reward created by an earlier version of the
+   * program, moving its tokens from the delegated token account into a vault
+   * owned by the program.
+   */
+  public async migrateFtReward(
+    authority: PublicKey | string,
+    reward: PublicKey | string,
+    remainingAccounts?: AccountMeta[]
+  ): Promise<InstructionResult.Migrate> {
+    this.builder.clean();
+    authority = this.getPublicKey(authority);
+    reward = this.getPublicKey(reward);
+
+    const step = await this.builder.migrateFtRewardStep(
+      authority,
+      reward,
+      undefined,
+      remainingAccounts
     );
 
     return { transaction: step[0].build() };
@@ -632,7 +1864,8 @@ export class SoarProgram {
   private createATA(
     mint: PublicKey | string,
     owner: PublicKey | string,
-    ata: PublicKey | string
+    ata: PublicKey | string,
+    tokenProgram?: PublicKey
   ): TransactionInstruction {
     mint = this.getPublicKey(mint);
     owner = this.getPublicKey(owner);
@@ -641,8 +1874,40 @@ export class SoarProgram {
       this.provider.publicKey,
       ata,
       owner,
-      mint
+      mint,
+      tokenProgram
+    );
+  }
+
+  /** An instruction creating `owner`'s associated token account if missing. */
+  private async createMissingATA(
+    mint: PublicKey,
+    owner: PublicKey,
+    tokenProgram: PublicKey
+  ): Promise<TransactionInstruction | null> {
+    const ata = this.utils.deriveAssociatedTokenAddress(
+      mint,
+      owner,
+      tokenProgram
     );
+    const account = await this.provider.connection.getAccountInfo(ata);
+    if (account !== null) {
+      return null;
+    }
+    return this.createATA(mint, owner, ata, tokenProgram);
+  }
+
+  /** The domain, the program id and the borsh-serialized `value`. */
+  private signedMessage(
+    domain: string,
+    type: "ScoreAttestation" | "ClaimVoucher",
+    value: unknown
+  ): Buffer {
+    return Buffer.concat([
+      Buffer.from(domain),
+      this.program.programId.toBuffer(),
+      this.program.coder.types.encode(type, value),
+    ]);
   }
 
   public async sendAndConfirmTransaction(
@@ -684,6 +1949,13 @@ export class SoarProgram {
     return AchievementAccount.fromIdlAccount(info, address);
   }
 
+  public async fetchAuthorityProposalAccount(
+    address: PublicKey
+  ): Promise<AuthorityProposalAccount> {
+    const info = await this.program.account.authorityProposal.fetch(address);
+    return AuthorityProposalAccount.fromIdlAccount(info, address);
+  }
+
   public async fetchGameAccount(address: PublicKey): Promise<GameAccount> {
     const info = await this.program.account.game.fetch(address);
     return GameAccount.fromIdlAccount(info, address);
@@ -696,11 +1968,29 @@ export class SoarProgram {
     return LeaderBoardAccount.fromIdlAccount(info, address);
   }
 
+  /**
+   * Top entries are zero-copy and can outgrow what the account coder decodes,
+   * so their score slots are read from the raw account data.
+   */
   public async fetchLeaderBoardTopEntriesAccount(
     address: PublicKey
   ): Promise<TopEntriesAccount> {
-    const info = await this.program.account.leaderTopEntries.fetch(address);
-    return TopEntriesAccount.fromIdlAccount(info, address);
+    const info = await this.provider.connection.getAccountInfo(address);
+    if (info === null) {
+      throw new Error(`Account does not exist ${address.toBase58()}`);
+    }
+    const header = this.program.coder.accounts.decode(
+      "leaderTopEntries",
+      info.data
+    );
+    return TopEntriesAccount.fromIdlAccount(header, info.data, address);
+  }
+
+  public async fetchSeasonTopEntriesAccount(
+    address: PublicKey
+  ): Promise<SeasonTopEntriesAccount> {
+    const info = await this.program.account.seasonTopEntries.fetch(address);
+    return SeasonTopEntriesAccount.fromIdlAccount(info, address);
   }
 
   public async fetchMergedAccount(address: PublicKey): Promise<MergedAccount> {
@@ -715,6 +2005,13 @@ export class SoarProgram {
     return PlayerAchievementAccount.fromIdlAccount(info, address);
   }
 
+  public async fetchPlayerBanAccount(
+    address: PublicKey
+  ): Promise<PlayerBanAccount> {
+    const info = await this.program.account.playerBan.fetch(address);
+    return PlayerBanAccount.fromIdlAccount(info, address);
+  }
+
   public async fetchPlayerScoresListAccount(
     address: PublicKey
   ): Promise<PlayerScoresListAccount> {
@@ -732,6 +2029,20 @@ export class SoarProgram {
     return RewardAccount.fromIdlAccount(info, address);
   }
 
+  public async fetchRankRewardAccount(
+    address: PublicKey
+  ): Promise<RankRewardAccount> {
+    const info = await this.program.account.rankReward.fetch(address);
+    return RankRewardAccount.fromIdlAccount(info, address);
+  }
+
+  public async fetchRankRewardClaimAccount(
+    address: PublicKey
+  ): Promise<RankRewardClaimAccount> {
+    const info = await this.program.account.rankRewardClaim.fetch(address);
+    return RankRewardClaimAccount.fromIdlAccount(info, address);
+  }
+
   public async fetchAllGameAccounts(
     memcmp?: Buffer | GetProgramAccountsFilter[]
   ): Promise<GameAccount[]> {
@@ -12,7 +12,8 @@ export class AchievementAccount {
     public readonly title: string,
     public readonly description: string,
     public readonly nftMeta: PublicKey,
-    public readonly reward: PublicKey | null
+    public readonly rewards: PublicKey[],
+    public readonly rewardCount: BN
   ) {}
 
   /** Create a new instance from an anchor-deserialized account. */
@@ -27,7 +28,8 @@ export class AchievementAccount {
       account.title,
       account.description,
       account.nftMeta,
-      account.reward
+      account.rewards,
+      account.rewardCount
     );
   }
 
@@ -39,7 +41,8 @@ export class AchievementAccount {
     title: string;
     description: string;
     nftMeta: string;
-    reward: string | PublicKey | null;
+    rewards: string[];
+    rewardCount: string;
   } {
     return {
       address: this.address.toBase58(),
@@ -48,7 +51,8 @@ export class AchievementAccount {
       title: this.title,
       description: this.description,
       nftMeta: this.nftMeta.toBase58(),
-      reward: this.reward !== null ? this.reward.toBase58() : this.reward,
+      rewards: this.rewards.map((reward) => reward.toBase58()),
+      rewardCount: this.rewardCount.toString(),
     };
   }
 }
@@ -0,0 +1,58 @@
+import { type PublicKey } from "@solana/web3.js";
+import type BN from "bn.js";
+import { type IdlAccounts } from "@coral-xyz/anchor";
+import { type Soar } from "../idl/soar";
+import { type Authority } from "./game";
+
+/** Class representing a deserialized on-chain `AuthorityProposal` account. */
+export class AuthorityProposalAccount {
+  private constructor(
+    public readonly address: PublicKey,
+    public readonly game: PublicKey,
+    public readonly proposer: PublicKey,
+    public readonly proposedBy: PublicKey,
+    public readonly authority: Authority,
+    public readonly replaces: PublicKey | null,
+    public readonly unlockTime: BN
+  ) {}
+
+  /** Create a new instance from an anchor-deserialized account. */
+  public static fromIdlAccount(
+    account: IdlAccounts<Soar>["authorityProposal"],
+    address: PublicKey
+  ): AuthorityProposalAccount {
+    return new AuthorityProposalAccount(
+      address,
+      account.game,
+      account.proposer,
+      account.proposedBy,
+      account.authority,
+      account.replaces,
+      account.unlockTime
+    );
+  }
+
+  /** Pretty print. */
+  public pretty(): {
+    address: string;
+    game: string;
+    proposer: string;
+    proposedBy: string;
+    authority: { key: string; roles: number };
+    replaces: string | null;
+    unlockTime: string;
+  } {
+    return {
+      address: this.address.toBase58(),
+      game: this.game.toBase58(),
+      proposer: this.proposer.toBase58(),
+      proposedBy: this.proposedBy.toBase58(),
+      authority: {
+        key: this.authority.key.toBase58(),
+        roles: this.authority.roles,
+      },
+      replaces: this.replaces !== null ? this.replaces.toBase58() : null,
+      unlockTime: this.unlockTime.toString(),
+    };
+  }
+}
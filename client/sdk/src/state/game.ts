@@ -10,9 +10,14 @@ export class GameAccount {
   public readonly address: PublicKey;
   public readonly meta: GameAttributes;
 
-  public readonly achievementCount: BN;
   public readonly leaderboardCount: BN;
-  public readonly auth: PublicKey[];
+  public readonly achievementCount: BN;
+  public readonly liveLeaderboards: BN;
+  public readonly liveAchievements: BN;
+  public readonly owner: PublicKey;
+  public readonly pendingOwner: PublicKey | null;
+  public readonly paused: boolean;
+  public readonly auth: Authority[];
 
   protected constructor(
     _address: PublicKey,
@@ -20,8 +25,13 @@ export class GameAccount {
   ) {
     this.address = _address;
     this.meta = account.meta;
-    this.achievementCount = account.achievementCount;
     this.leaderboardCount = account.leaderboardCount;
+    this.achievementCount = account.achievementCount;
+    this.liveLeaderboards = account.liveLeaderboards;
+    this.liveAchievements = account.liveAchievements;
+    this.owner = account.owner;
+    this.pendingOwner = account.pendingOwner;
+    this.paused = account.paused;
     this.auth = account.auth;
   }
 
@@ -37,6 +47,15 @@ export class GameAccount {
     return new GameClient(soar, this.address, this);
   }
 
+  /**
+   * Whether `key` is an authority of this game holding every role in `roles`.
+   */
+  public hasRoles(key: PublicKey, roles: number): boolean {
+    return this.auth.some(
+      (auth) => auth.key.equals(key) && (auth.roles & roles) === roles
+    );
+  }
+
   /** Pretty print. */
   public pretty(): {
     address: string;
@@ -47,9 +66,14 @@ export class GameAccount {
       gameType: GameType;
       nftMeta: string;
     };
-    achievementCount: string;
     leaderboardCount: string;
-    auth: string[];
+    achievementCount: string;
+    liveLeaderboards: string;
+    liveAchievements: string;
+    owner: string;
+    pendingOwner: string | null;
+    paused: boolean;
+    auth: Array<{ key: string; roles: number }>;
   } {
     return {
       address: this.address.toBase58(),
@@ -57,9 +81,18 @@ export class GameAccount {
         ...this.meta,
         nftMeta: this.meta.nftMeta.toBase58(),
       },
-      achievementCount: this.achievementCount.toString(),
       leaderboardCount: this.leaderboardCount.toString(),
-      auth: this.auth.map((auth) => auth.toBase58()),
+      achievementCount: this.achievementCount.toString(),
+      liveLeaderboards: this.liveLeaderboards.toString(),
+      liveAchievements: this.liveAchievements.toString(),
+      owner: this.owner.toBase58(),
+      pendingOwner:
+        this.pendingOwner !== null ? this.pendingOwner.toBase58() : null,
+      paused: this.paused,
+      auth: this.auth.map((auth) => ({
+        key: auth.key.toBase58(),
+        roles: auth.roles,
+      })),
     };
   }
 }
@@ -72,6 +105,28 @@ export interface GameAttributes {
   nftMeta: PublicKey;
 }
 
+/**
+ * A key allowed to act on a game, with the roles it holds as a bitmask of
+ * {@link AuthorityRole}s.
+ */
+export interface Authority {
+  key: PublicKey;
+  roles: number;
+}
+
+export const enum AuthorityRole {
+  Admin = 1 << 0,
+  ScoreSubmitter = 1 << 1,
+  AchievementManager = 1 << 2,
+  RewardManager = 1 << 3,
+  LeaderboardManager = 1 << 4,
+  All = Admin |
+    ScoreSubmitter |
+    AchievementManager |
+    RewardManager |
+    LeaderboardManager,
+}
+
 export const enum GameType {
   Mobile = 0,
   Desktop = 1,
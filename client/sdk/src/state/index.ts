@@ -1,9 +1,12 @@
 export * from "./achievement";
+export * from "./authorityProposal";
 export * from "./game";
 export * from "./leaderboard";
 export * from "./leaderboardTopEntries";
 export * from "./merged";
 export * from "./playerAchievement";
+export * from "./playerBan";
 export * from "./playerScoresList";
 export * from "./player";
 export * from "./reward";
+export * from "./seasonTopEntries";
@@ -1,7 +1,8 @@
 import { type PublicKey } from "@solana/web3.js";
-import type BN from "bn.js";
+import BN from "bn.js";
 import { type IdlAccounts } from "@coral-xyz/anchor";
 import { type Soar } from "../idl/soar";
+import { type ScoreRetention, type TieBreak } from "../types";
 
 /** Class representing a deserialized on-chain `Leaderboard` account. */
 export class LeaderBoardAccount {
@@ -14,8 +15,17 @@ export class LeaderBoardAccount {
     public readonly decimals: number,
     public readonly minScore: BN,
     public readonly maxScore: BN,
+    public readonly topEntries: PublicKey | null,
     public readonly allowMultipleScores: boolean,
-    public readonly topEntries: PublicKey | null
+    public readonly startTime: BN | null,
+    public readonly endTime: BN | null,
+    public readonly seasonLength: BN | null,
+    public readonly isAscending: boolean,
+    public readonly retention: ScoreRetention,
+    public readonly tieBreak: TieBreak,
+    public readonly paused: boolean,
+    public readonly liveRankRewards: BN,
+    public readonly liveArchives: BN
   ) {}
 
   /** Create a new instance from an anchor-deserialized account. */
@@ -32,11 +42,35 @@ export class LeaderBoardAccount {
       account.decimals,
       account.minScore,
       account.maxScore,
+      account.topEntries,
       account.allowMultipleScores,
-      account.topEntries
+      account.startTime,
+      account.endTime,
+      account.seasonLength,
+      account.isAscending,
+      account.retention,
+      account.tieBreak,
+      account.paused,
+      account.liveRankRewards,
+      account.liveArchives
     );
   }
 
+  /**
+   * The season unix `timestamp` falls in, starting from `0`. Always `0` for
+   * leaderboards without a season length.
+   */
+  public seasonAt(timestamp: BN): BN {
+    if (
+      this.startTime === null ||
+      this.seasonLength === null ||
+      timestamp.lte(this.startTime)
+    ) {
+      return new BN(0);
+    }
+    return timestamp.sub(this.startTime).div(this.seasonLength);
+  }
+
   /** Pretty print. */
   public pretty(): {
     address: string;
@@ -47,8 +81,17 @@ export class LeaderBoardAccount {
     decimals: number;
     minScore: string;
     maxScore: string;
-    allowMultipleScores: boolean;
     topEntries: string | null;
+    allowMultipleScores: boolean;
+    startTime: string | null;
+    endTime: string | null;
+    seasonLength: string | null;
+    isAscending: boolean;
+    retention: string;
+    tieBreak: string;
+    paused: boolean;
+    liveRankRewards: string;
+    liveArchives: string;
   } {
     return {
       address: this.address.toBase58(),
@@ -59,8 +102,18 @@ export class LeaderBoardAccount {
       decimals: this.decimals,
       minScore: this.minScore.toString(),
       maxScore: this.maxScore.toString(),
+      topEntries: this.topEntries !== null ? this.topEntries.toBase58() : null,
       allowMultipleScores: this.allowMultipleScores,
-      topEntries: this.topEntries ? this.topEntries.toBase58() : null,
+      startTime: this.startTime !== null ? this.startTime.toString() : null,
+      endTime: this.endTime !== null ? this.endTime.toString() : null,
+      seasonLength:
+        this.seasonLength !== null ? this.seasonLength.toString() : null,
+      isAscending: this.isAscending,
+      retention: JSON.stringify(this.retention),
+      tieBreak: Object.keys(this.tieBreak)[0],
+      paused: this.paused,
+      liveRankRewards: this.liveRankRewards.toString(),
+      liveArchives: this.liveArchives.toString(),
     };
   }
 }
@@ -1,70 +1,135 @@
-import { type PublicKey } from "@solana/web3.js";
-import type BN from "bn.js";
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
 import { type IdlAccounts } from "@coral-xyz/anchor";
 import { type Soar } from "../idl/soar";
 
-/** Class representing a deserialized on-chain `LeaderTopScores` account. */
+/** Offset of the first score slot in a `LeaderTopEntries` account's data. */
+export const TOP_ENTRIES_SCORES_OFFSET = 8 + 8 + 2 + 2 + 4;
+
+/** Size of a single `LeaderBoardScore` slot. */
+export const LEADERBOARD_SCORE_SIZE = 32 + 8 + 8 + 8;
+
+/**
+ * Class representing a deserialized on-chain `LeaderTopEntries` account.
+ *
+ * The account is zero-copy: its header is followed by `capacity` score slots,
+ * sorted from best to worst. Unused slots hold the default public key as their
+ * player.
+ */
 export class TopEntriesAccount {
-  constructor(
+  private constructor(
     public readonly address: PublicKey,
-    public readonly isAscending: boolean,
+    public readonly season: BN,
+    public readonly scoresToRetain: number,
+    public readonly capacity: number,
+    public readonly generation: number,
     public readonly topScores: LeaderboardScore[]
   ) {}
 
-  /** Create a new instance from an anchor-deserialized account. */
+  /**
+   * Create a new instance from an anchor-deserialized account header and the
+   * raw account data holding its score slots.
+   */
   public static fromIdlAccount(
     account: IdlAccounts<Soar>["leaderTopEntries"],
+    data: Buffer,
     address: PublicKey
   ): TopEntriesAccount {
+    const topScores: LeaderboardScore[] = [];
+    for (let i = 0; i < account.capacity; ++i) {
+      topScores.push(
+        decodeLeaderboardScore(
+          data,
+          TOP_ENTRIES_SCORES_OFFSET + i * LEADERBOARD_SCORE_SIZE
+        )
+      );
+    }
+
     return new TopEntriesAccount(
       address,
-      account.isAscending,
-      account.topScores
+      account.season,
+      account.scoresToRetain,
+      account.capacity,
+      account.generation,
+      topScores
+    );
+  }
+
+  /** The scores held by a player, without the unused slots. */
+  public entries(): LeaderboardScore[] {
+    return this.topScores.filter(
+      (score) => !score.player.equals(PublicKey.default)
     );
   }
 
   /** Pretty print. */
   public pretty(): {
     address: string;
-    isAscending: boolean;
+    season: string;
+    scoresToRetain: number;
+    capacity: number;
+    generation: number;
     topScores: Array<{
       player: string;
       entry: {
         score: string;
         timestamp: string;
+        tiebreak: string;
       };
     }>;
   } {
     return {
       address: this.address.toBase58(),
-      isAscending: this.isAscending,
+      season: this.season.toString(),
+      scoresToRetain: this.scoresToRetain,
+      capacity: this.capacity,
+      generation: this.generation,
       topScores: this.topScores.map((score) => printLeaderboardScore(score)),
     };
   }
 }
 
-interface LeaderboardScore {
+export interface LeaderboardScore {
   player: PublicKey;
   entry: {
     score: BN;
     timestamp: BN;
+    tiebreak: BN;
   };
 }
 
-const printLeaderboardScore = (
+const decodeLeaderboardScore = (
+  data: Buffer,
+  offset: number
+): LeaderboardScore => {
+  return {
+    player: new PublicKey(data.subarray(offset, offset + 32)),
+    entry: {
+      score: new BN(data.subarray(offset + 32, offset + 40), "le"),
+      timestamp: new BN(data.subarray(offset + 40, offset + 48), "le").fromTwos(
+        64
+      ),
+      tiebreak: new BN(data.subarray(offset + 48, offset + 56), "le"),
+    },
+  };
+};
+
+export const printLeaderboardScore = (
   raw: LeaderboardScore
 ): {
   player: string;
   entry: {
     score: string;
     timestamp: string;
+    tiebreak: string;
   };
 } => {
   return {
     player: raw.player.toBase58(),
     entry: {
       score: raw.entry.score.toString(),
-      timestamp: raw.entry.score.toString(),
+      timestamp: raw.entry.timestamp.toString(),
+      tiebreak: raw.entry.tiebreak.toString(),
     },
   };
 };
@@ -0,0 +1,37 @@
+import { type PublicKey } from "@solana/web3.js";
+import { type IdlAccounts } from "@coral-xyz/anchor";
+import { type Soar } from "../idl/soar";
+
+/** Class representing a deserialized on-chain `PlayerBan` account. */
+export class PlayerBanAccount {
+  private constructor(
+    public readonly address: PublicKey,
+    public readonly playerAccount: PublicKey,
+    public readonly leaderboard: PublicKey
+  ) {}
+
+  /** Create a new instance from an anchor-deserialized account. */
+  public static fromIdlAccount(
+    account: IdlAccounts<Soar>["playerBan"],
+    address: PublicKey
+  ): PlayerBanAccount {
+    return new PlayerBanAccount(
+      address,
+      account.playerAccount,
+      account.leaderboard
+    );
+  }
+
+  /** Pretty print. */
+  public pretty(): {
+    address: string;
+    playerAccount: string;
+    leaderboard: string;
+  } {
+    return {
+      address: this.address.toBase58(),
+      playerAccount: this.playerAccount.toBase58(),
+      leaderboard: this.leaderboard.toBase58(),
+    };
+  }
+}
@@ -10,6 +10,10 @@ export class PlayerScoresListAccount {
     public readonly playerAccount: PublicKey,
     public readonly leaderboard: PublicKey,
     public readonly allocCount: number,
+    public readonly submissionCount: BN,
+    public readonly createdAt: BN,
+    public readonly topEntriesGeneration: number,
+    public readonly topEntry: ScoreEntry,
     public readonly scores: ScoreEntry[]
   ) {}
 
@@ -23,6 +27,10 @@ export class PlayerScoresListAccount {
       account.playerAccount,
       account.leaderboard,
       account.allocCount,
+      account.submissionCount,
+      account.createdAt,
+      account.topEntriesGeneration,
+      account.topEntry,
       account.scores
     );
   }
@@ -33,9 +41,18 @@ export class PlayerScoresListAccount {
     playerInfo: string;
     leaderboard: string;
     allocCount: number;
+    submissionCount: string;
+    createdAt: string;
+    topEntriesGeneration: number;
+    topEntry: {
+      score: string;
+      timestamp: string;
+      tiebreak: string;
+    };
     scores: Array<{
       score: string;
       timestamp: string;
+      tiebreak: string;
     }>;
   } {
     return {
@@ -43,6 +60,10 @@ export class PlayerScoresListAccount {
       playerInfo: this.playerAccount.toBase58(),
       leaderboard: this.leaderboard.toBase58(),
       allocCount: this.allocCount,
+      submissionCount: this.submissionCount.toString(),
+      createdAt: this.createdAt.toString(),
+      topEntriesGeneration: this.topEntriesGeneration,
+      topEntry: printScoreEntry(this.topEntry),
       scores: this.scores.map((score) => printScoreEntry(score)),
     };
   }
@@ -51,6 +72,7 @@ export class PlayerScoresListAccount {
 interface ScoreEntry {
   score: BN;
   timestamp: BN;
+  tiebreak: BN;
 }
 
 const printScoreEntry = (
@@ -58,9 +80,11 @@ const printScoreEntry = (
 ): {
   score: string;
   timestamp: string;
+  tiebreak: string;
 } => {
   return {
     score: entry.score.toString(),
     timestamp: entry.timestamp.toString(),
+    tiebreak: entry.tiebreak.toString(),
   };
 };
@@ -9,22 +9,10 @@ export class RewardAccount {
     public readonly address: PublicKey,
     public readonly achievement: PublicKey,
     public readonly availableSpots: BN,
-    public readonly FungibleToken:
-      | {
-          mint: PublicKey;
-          account: PublicKey;
-          amount: BN;
-        }
-      | undefined,
-    public readonly NonFungibleToken:
-      | {
-          uri: string;
-          name: string;
-          symbol: string;
-          minted: BN;
-          collection: PublicKey | null;
-        }
-      | undefined
+    public readonly FungibleToken: FungibleTokenReward | undefined,
+    public readonly NonFungibleToken: NonFungibleTokenReward | undefined,
+    public readonly paused: boolean,
+    public readonly cancelled: boolean
   ) {}
 
   /** Create a new instance from an anchor-deserialized account. */
@@ -37,7 +25,9 @@ export class RewardAccount {
       account.achievement,
       account.availableSpots,
       account.reward.fungibleToken,
-      account.reward.nonFungibleToken
+      account.reward.nonFungibleToken,
+      account.paused,
+      account.cancelled
     );
   }
 
@@ -46,48 +36,163 @@ export class RewardAccount {
     address: string;
     achievement: string;
     availableSpots: string;
-    FungibleToken:
-      | {
-          mint: string;
-          account: string;
-          amount: string;
-        }
-      | undefined;
-    NonFungibleToken:
-      | {
-          uri: string;
-          name: string;
-          symbol: string;
-          minted: string;
-          collection: string | null;
-        }
-      | undefined;
+    FungibleToken: PrettyFungibleTokenReward | undefined;
+    NonFungibleToken: PrettyNonFungibleTokenReward | undefined;
+    paused: boolean;
+    cancelled: boolean;
   } {
     return {
       address: this.address.toBase58(),
       achievement: this.achievement.toBase58(),
       availableSpots: this.availableSpots.toString(),
-      FungibleToken:
-        this.FungibleToken !== undefined
-          ? {
-              mint: this.FungibleToken.mint.toBase58(),
-              account: this.FungibleToken.account.toBase58(),
-              amount: this.FungibleToken.amount.toString(),
-            }
-          : undefined,
-      NonFungibleToken:
-        this.NonFungibleToken !== undefined
-          ? {
-              uri: this.NonFungibleToken.uri,
-              name: this.NonFungibleToken.name,
-              symbol: this.NonFungibleToken.symbol,
-              minted: this.NonFungibleToken.minted.toString(),
-              collection:
-                this.NonFungibleToken.collection !== null
-                  ? this.NonFungibleToken.collection.toBase58()
-                  : null,
-            }
-          : undefined,
+      FungibleToken: printFungibleTokenReward(this.FungibleToken),
+      NonFungibleToken: printNonFungibleTokenReward(this.NonFungibleToken),
+      paused: this.paused,
+      cancelled: this.cancelled,
     };
   }
 }
+
+/** Class representing a deserialized on-chain `RankReward` account. */
+export class RankRewardAccount {
+  private constructor(
+    public readonly address: PublicKey,
+    public readonly leaderboard: PublicKey,
+    public readonly minRank: number,
+    public readonly maxRank: number,
+    public readonly season: BN | null,
+    public readonly FungibleToken: FungibleTokenReward | undefined,
+    public readonly NonFungibleToken: NonFungibleTokenReward | undefined
+  ) {}
+
+  /** Create a new instance from an anchor-deserialized account. */
+  public static fromIdlAccount(
+    account: IdlAccounts<Soar>["rankReward"],
+    address: PublicKey
+  ): RankRewardAccount {
+    return new RankRewardAccount(
+      address,
+      account.leaderboard,
+      account.minRank,
+      account.maxRank,
+      account.season,
+      account.reward.fungibleToken,
+      account.reward.nonFungibleToken
+    );
+  }
+
+  /** Pretty print. */
+  public pretty(): {
+    address: string;
+    leaderboard: string;
+    minRank: number;
+    maxRank: number;
+    season: string | null;
+    FungibleToken: PrettyFungibleTokenReward | undefined;
+    NonFungibleToken: PrettyNonFungibleTokenReward | undefined;
+  } {
+    return {
+      address: this.address.toBase58(),
+      leaderboard: this.leaderboard.toBase58(),
+      minRank: this.minRank,
+      maxRank: this.maxRank,
+      season: this.season !== null ? this.season.toString() : null,
+      FungibleToken: printFungibleTokenReward(this.FungibleToken),
+      NonFungibleToken: printNonFungibleTokenReward(this.NonFungibleToken),
+    };
+  }
+}
+
+/** Class representing a deserialized on-chain `RankRewardClaim` account. */
+export class RankRewardClaimAccount {
+  private constructor(
+    public readonly address: PublicKey,
+    public readonly playerAccount: PublicKey,
+    public readonly rank: number
+  ) {}
+
+  /** Create a new instance from an anchor-deserialized account. */
+  public static fromIdlAccount(
+    account: IdlAccounts<Soar>["rankRewardClaim"],
+    address: PublicKey
+  ): RankRewardClaimAccount {
+    return new RankRewardClaimAccount(
+      address,
+      account.playerAccount,
+      account.rank
+    );
+  }
+
+  /** Pretty print. */
+  public pretty(): {
+    address: string;
+    playerAccount: string;
+    rank: number;
+  } {
+    return {
+      address: this.address.toBase58(),
+      playerAccount: this.playerAccount.toBase58(),
+      rank: this.rank,
+    };
+  }
+}
+
+export interface FungibleTokenReward {
+  mint: PublicKey;
+  account: PublicKey;
+  amount: BN;
+  tokenProgram: PublicKey;
+}
+
+export interface NonFungibleTokenReward {
+  uri: string;
+  name: string;
+  symbol: string;
+  minted: BN;
+  collection: PublicKey | null;
+}
+
+interface PrettyFungibleTokenReward {
+  mint: string;
+  account: string;
+  amount: string;
+  tokenProgram: string;
+}
+
+interface PrettyNonFungibleTokenReward {
+  uri: string;
+  name: string;
+  symbol: string;
+  minted: string;
+  collection: string | null;
+}
+
+const printFungibleTokenReward = (
+  reward: FungibleTokenReward | undefined
+): PrettyFungibleTokenReward | undefined => {
+  if (reward === undefined) {
+    return undefined;
+  }
+  return {
+    mint: reward.mint.toBase58(),
+    account: reward.account.toBase58(),
+    amount: reward.amount.toString(),
+    tokenProgram: reward.tokenProgram.toBase58(),
+  };
+};
+
+const printNonFungibleTokenReward = (
+  reward: NonFungibleTokenReward | undefined
+): PrettyNonFungibleTokenReward | undefined => {
+  if (reward === undefined) {
+    return undefined;
+  }
+  return {
+    uri: reward.uri,
+    name: reward.name,
+    symbol: reward.symbol,
+    minted: reward.minted.toString(),
+    collection:
+      reward.collection !== null ? reward.collection.toBase58() : null,
+  };
+};
@@ -0,0 +1,57 @@
+import { type PublicKey } from "@solana/web3.js";
+import type BN from "bn.js";
+import { type IdlAccounts } from "@coral-xyz/anchor";
+import { type Soar } from "../idl/soar";
+import {
+  type LeaderboardScore,
+  printLeaderboardScore,
+} from "./leaderboardTopEntries";
+
+/** Class representing a deserialized on-chain `SeasonTopEntries` account. */
+export class SeasonTopEntriesAccount {
+  private constructor(
+    public readonly address: PublicKey,
+    public readonly leaderboard: PublicKey,
+    public readonly season: BN,
+    public readonly isAscending: boolean,
+    public readonly topScores: LeaderboardScore[]
+  ) {}
+
+  /** Create a new instance from an anchor-deserialized account. */
+  public static fromIdlAccount(
+    account: IdlAccounts<Soar>["seasonTopEntries"],
+    address: PublicKey
+  ): SeasonTopEntriesAccount {
+    return new SeasonTopEntriesAccount(
+      address,
+      account.leaderboard,
+      account.season,
+      account.isAscending,
+      account.topScores
+    );
+  }
+
+  /** Pretty print. */
+  public pretty(): {
+    address: string;
+    leaderboard: string;
+    season: string;
+    isAscending: boolean;
+    topScores: Array<{
+      player: string;
+      entry: {
+        score: string;
+        timestamp: string;
+        tiebreak: string;
+      };
+    }>;
+  } {
+    return {
+      address: this.address.toBase58(),
+      leaderboard: this.leaderboard.toBase58(),
+      season: this.season.toString(),
+      isAscending: this.isAscending,
+      topScores: this.topScores.map((score) => printLeaderboardScore(score)),
+    };
+  }
+}
@@ -1,5 +1,7 @@
 import { type PublicKey, type Transaction } from "@solana/web3.js";
-import { type GameType, type Genre } from "./state/game";
+import { type IdlTypes } from "@coral-xyz/anchor";
+import { type Authority, type GameType, type Genre } from "./state/game";
+import { type Soar } from "./idl/soar";
 import type BN from "bn.js";
 
 // eslint-disable-next-line @typescript-eslint/no-namespace
@@ -11,6 +13,25 @@ export module InstructionResult {
   export interface UpdateGame {
     transaction: Transaction;
   }
+  export interface ProposeAuthority {
+    proposal: PublicKey;
+    transaction: Transaction;
+  }
+  export interface AcceptAuthority {
+    transaction: Transaction;
+  }
+  export interface CancelAuthorityProposal {
+    transaction: Transaction;
+  }
+  export interface TransferGameOwnership {
+    transaction: Transaction;
+  }
+  export interface AcceptGameOwnership {
+    transaction: Transaction;
+  }
+  export interface SetPaused {
+    transaction: Transaction;
+  }
   export interface InitializePlayer {
     newPlayer: PublicKey;
     transaction: Transaction;
@@ -27,6 +48,15 @@ export module InstructionResult {
     topEntries: PublicKey | null;
     transaction: Transaction;
   }
+  export interface ArchiveSeason {
+    archive: PublicKey;
+    season: BN;
+    transaction: Transaction;
+  }
+  export interface ManageTopEntries {
+    topEntries: PublicKey;
+    transaction: Transaction;
+  }
   export interface RegisterPlayerEntry {
     newList: PublicKey;
     transaction: Transaction;
@@ -34,6 +64,19 @@ export module InstructionResult {
   export interface SubmitScore {
     transaction: Transaction;
   }
+  export interface RemovePlayerScore {
+    transaction: Transaction;
+  }
+  export interface EvictPlayer {
+    transaction: Transaction;
+  }
+  export interface BanPlayer {
+    ban: PublicKey;
+    transaction: Transaction;
+  }
+  export interface UnbanPlayer {
+    transaction: Transaction;
+  }
   export interface UpdateAchievement {
     transaction: Transaction;
   }
@@ -42,10 +85,12 @@ export module InstructionResult {
     transaction: Transaction;
   }
   export interface AddReward {
-    oldReward: PublicKey | null;
     newReward: PublicKey;
     transaction: Transaction;
   }
+  export interface ManageReward {
+    transaction: Transaction;
+  }
   export interface ClaimNftReward {
     newMint: PublicKey;
     transaction: Transaction;
@@ -56,6 +101,14 @@ export module InstructionResult {
   export interface VerifyReward {
     transaction: Transaction;
   }
+  export interface AddRankReward {
+    newReward: PublicKey;
+    transaction: Transaction;
+  }
+  export interface ClaimRankReward {
+    claim: PublicKey;
+    transaction: Transaction;
+  }
   export interface InitiateMerge {
     newMerge: PublicKey;
     transaction: Transaction;
@@ -63,11 +116,43 @@ export module InstructionResult {
   export interface RegisterMergeApproval {
     transaction: Transaction;
   }
+  export interface MergePlayer {
+    transaction: Transaction;
+  }
   export interface UpdateLeaderboard {
     transaction: Transaction;
   }
+  export interface Close {
+    transaction: Transaction;
+  }
+  export interface Migrate {
+    transaction: Transaction;
+  }
 }
 
+/** How many scores a player keeps on a leaderboard, and which ones. */
+export type ScoreRetention = IdlTypes<Soar>["ScoreRetention"];
+
+/** How a leaderboard ranks equal scores. */
+export type TieBreak = IdlTypes<Soar>["TieBreak"];
+
+/* eslint-disable @typescript-eslint/consistent-type-assertions */
+export const ScoreRetention = {
+  unbounded: () => ({ unbounded: {} } as ScoreRetention),
+  keepLatest: (count: number) =>
+    ({ keepLatest: { 0: count } } as ScoreRetention),
+  keepBest: (count: number) => ({ keepBest: { 0: count } } as ScoreRetention),
+  bestOnly: () => ({ bestOnly: {} } as ScoreRetention),
+};
+
+export const TieBreak = {
+  earliestFirst: () => ({ earliestFirst: {} } as TieBreak),
+  latestFirst: () => ({ latestFirst: {} } as TieBreak),
+  highestValue: () => ({ highestValue: {} } as TieBreak),
+  lowestValue: () => ({ lowestValue: {} } as TieBreak),
+};
+/* eslint-enable @typescript-eslint/consistent-type-assertions */
+
 export interface InitializeGameArgs {
   gameMeta: {
     title: string;
@@ -76,7 +161,19 @@ export interface InitializeGameArgs {
     gameType: GameType;
     nftMeta: PublicKey;
   };
-  authorities: PublicKey[];
+  authorities: Authority[];
+  nonce: BN;
+}
+export interface ProposeAuthorityArgs {
+  authority: Authority;
+  replaces: PublicKey | null;
+  timelock: BN;
+}
+export interface TransferGameOwnershipArgs {
+  newOwner: PublicKey | null;
+}
+export interface SetPausedArgs {
+  paused: boolean;
 }
 export interface AddAchievementArgs {
   title: string;
@@ -92,6 +189,17 @@ export interface AddLeaderBoardArgs {
   scoresToRetain: number;
   isAscending: boolean;
   allowMultipleScores: boolean;
+  startTime: BN | null;
+  endTime: BN | null;
+  seasonLength: BN | null;
+  retention: ScoreRetention;
+  tieBreak: TieBreak;
+}
+export interface RebuildTopEntriesArgs {
+  reset: boolean;
+}
+export interface ResizeTopEntriesArgs {
+  scoresToRetain: number;
 }
 export interface AddNftRewardArgs {
   availableRewards: BN;
@@ -108,16 +216,66 @@ export interface AddFtRewardArgs {
     amount: BN;
   };
 }
+export interface TopUpFtRewardArgs {
+  amount: BN;
+}
+export interface UpdateRewardArgs {
+  newAvailableSpots: BN | null;
+  newAmount: BN | null;
+  newUri: string | null;
+  newName: string | null;
+  newSymbol: string | null;
+}
+export interface RedeemClaimVoucherArgs {
+  expiry: BN;
+}
+export interface AddNftRankRewardArgs {
+  minRank: number;
+  maxRank: number;
+  season: BN | null;
+  kind: {
+    uri: string;
+    name: string;
+    symbol: string;
+  };
+}
+export interface AddFtRankRewardArgs {
+  minRank: number;
+  maxRank: number;
+  season: BN | null;
+  kind: {
+    deposit: BN;
+    amount: BN;
+  };
+}
+export interface ClaimRankRewardArgs {
+  rank: number;
+}
 export interface InitializePlayerArgs {
   username: string;
   nftMeta: PublicKey;
 }
 export interface InitMergeArgs {
   keys: PublicKey[];
+  nonce: BN;
 }
 export interface SubmitScoreArgs {
   score: BN;
 }
+export interface SubmitScoreWithTiebreakArgs {
+  score: BN;
+  tiebreak: BN;
+}
+export interface SubmitAttestedScoreArgs {
+  score: BN;
+  tiebreak: BN;
+  nonce: BN;
+  issuedAt: BN;
+  expiry: BN;
+}
+export interface RemovePlayerScoreArgs {
+  index: number;
+}
 export interface UpdateAchievementArgs {
   newTitle: string | null;
   newDescription: string | null;
@@ -131,7 +289,7 @@ export interface UpdateGameArgs {
     gameType: GameType;
     nftMeta: PublicKey;
   } | null;
-  newAuths: PublicKey[] | null;
+  newAuths: Authority[] | null;
 }
 export interface UpdateLeaderboardArgs {
   newDescription: string | null;
@@ -140,8 +298,55 @@ export interface UpdateLeaderboardArgs {
   newMaxScore: BN | null;
   newIsAscending: boolean | null;
   newAllowMultipleScores: boolean | null;
+  newRetention: ScoreRetention | null;
 }
 export interface UpdatePlayerArgs {
   newUsername: string | null;
   newNftMeta: PublicKey | null;
 }
+
+/**
+ * Accounts shared by the instructions that unlock or claim a fungible-token
+ * reward.
+ */
+export interface ClaimFtRewardAccounts {
+  user: PublicKey;
+  payer: PublicKey;
+  game: PublicKey;
+  achievement: PublicKey;
+  reward: PublicKey;
+  playerAccount: PublicKey;
+  playerAchievement: PublicKey;
+  rewardClaim: PublicKey;
+  rewardTokenMint: PublicKey;
+  rewardVault: PublicKey;
+  userTokenAccount: PublicKey;
+  tokenProgram: PublicKey;
+  associatedTokenProgram: PublicKey;
+  systemProgram: PublicKey;
+}
+
+/**
+ * Accounts shared by the instructions that unlock or claim a
+ * non-fungible-token reward.
+ */
+export interface ClaimNftRewardAccounts {
+  user: PublicKey;
+  payer: PublicKey;
+  game: PublicKey;
+  achievement: PublicKey;
+  reward: PublicKey;
+  playerAccount: PublicKey;
+  playerAchievement: PublicKey;
+  rewardClaim: PublicKey;
+  claim: PublicKey;
+  newMint: PublicKey;
+  newMetadata: PublicKey;
+  newMasterEdition: PublicKey;
+  mintTo: PublicKey;
+  tokenMetadataProgram: PublicKey;
+  associatedTokenProgram: PublicKey;
+  systemProgram: PublicKey;
+  tokenProgram: PublicKey;
+  rent: PublicKey;
+}
@@ -1,22 +1,52 @@
 import { PublicKey } from "@solana/web3.js";
 import { TOKEN_METADATA_PROGRAM_ID } from "./constants";
-import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import type BN from "bn.js";
 
 export const enum Seeds {
   GAME = "game",
+  CLOSED_GAME = "closed-game",
   LEADER = "leaderboard",
   ACHIEVEMENT = "achievement",
   PLAYER = "player",
   PLAYER_SCORES = "player-scores-list",
   PLAYER_ACHIEVEMENT = "player-achievement",
+  PLAYER_BAN = "player-ban",
   LEADER_TOP_ENTRIES = "top-scores",
+  SEASON_TOP_ENTRIES = "season-top-scores",
   NFT_CLAIM = "nft-claim",
+  REWARD = "reward",
+  REWARD_CLAIM = "reward-claim",
+  RANK_REWARD_CLAIM = "rank-reward-claim",
+  REWARD_VAULT = "reward-vault",
+  MERGED = "merged",
+  AUTHORITY_PROPOSAL = "authority-proposal",
+  EVENT_AUTHORITY = "__event_authority",
 }
 
 export class Utils {
   constructor(readonly programId: PublicKey) {}
 
+  public deriveGameAddress(
+    creator: PublicKey,
+    nonce: BN
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(Seeds.GAME), creator.toBuffer(), nonce.toBuffer("le", 8)],
+      this.programId
+    );
+  }
+
+  public deriveClosedGameAddress(game: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(Seeds.CLOSED_GAME), game.toBuffer()],
+      this.programId
+    );
+  }
+
   public deriveLeaderBoardAddress(
     id: BN,
     game: PublicKey
@@ -36,6 +66,20 @@ export class Utils {
     );
   }
 
+  public deriveSeasonTopEntriesAddress(
+    leaderboard: PublicKey,
+    season: BN
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(Seeds.SEASON_TOP_ENTRIES),
+        leaderboard.toBuffer(),
+        season.toBuffer("le", 8),
+      ],
+      this.programId
+    );
+  }
+
   public deriveAchievementAddress(
     id: BN,
     game: PublicKey
@@ -58,10 +102,17 @@ export class Utils {
     leaderboard: PublicKey
   ): [PublicKey, number] {
     const player = this.derivePlayerAddress(user)[0];
+    return this.derivePlayerScoresListAddressForPlayer(player, leaderboard);
+  }
+
+  public derivePlayerScoresListAddressForPlayer(
+    playerAccount: PublicKey,
+    leaderboard: PublicKey
+  ): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [
         Buffer.from(Seeds.PLAYER_SCORES),
-        player.toBuffer(),
+        playerAccount.toBuffer(),
         leaderboard.toBuffer(),
       ],
       this.programId
@@ -73,16 +124,45 @@ export class Utils {
     achievement: PublicKey
   ): [PublicKey, number] {
     const player = this.derivePlayerAddress(user)[0];
+    return this.derivePlayerAchievementAddressForPlayer(player, achievement);
+  }
+
+  public derivePlayerAchievementAddressForPlayer(
+    playerAccount: PublicKey,
+    achievement: PublicKey
+  ): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [
         Buffer.from(Seeds.PLAYER_ACHIEVEMENT),
-        player.toBuffer(),
+        playerAccount.toBuffer(),
         achievement.toBuffer(),
       ],
       this.programId
     );
   }
 
+  public derivePlayerBanAddress(
+    user: PublicKey,
+    leaderboard: PublicKey
+  ): [PublicKey, number] {
+    const player = this.derivePlayerAddress(user)[0];
+    return this.derivePlayerBanAddressForPlayer(player, leaderboard);
+  }
+
+  public derivePlayerBanAddressForPlayer(
+    playerAccount: PublicKey,
+    leaderboard: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(Seeds.PLAYER_BAN),
+        leaderboard.toBuffer(),
+        playerAccount.toBuffer(),
+      ],
+      this.programId
+    );
+  }
+
   public deriveNftClaimAddress(
     reward: PublicKey,
     mint: PublicKey
@@ -93,6 +173,95 @@ export class Utils {
     );
   }
 
+  public deriveRewardAddress(
+    index: BN,
+    achievement: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(Seeds.REWARD),
+        achievement.toBuffer(),
+        index.toBuffer("le", 8),
+      ],
+      this.programId
+    );
+  }
+
+  public deriveRewardClaimAddress(
+    user: PublicKey,
+    reward: PublicKey
+  ): [PublicKey, number] {
+    const player = this.derivePlayerAddress(user)[0];
+    return this.deriveRewardClaimAddressForPlayer(player, reward);
+  }
+
+  public deriveRewardClaimAddressForPlayer(
+    playerAccount: PublicKey,
+    reward: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(Seeds.REWARD_CLAIM),
+        reward.toBuffer(),
+        playerAccount.toBuffer(),
+      ],
+      this.programId
+    );
+  }
+
+  public deriveRankRewardClaimAddress(
+    user: PublicKey,
+    rankReward: PublicKey
+  ): [PublicKey, number] {
+    const player = this.derivePlayerAddress(user)[0];
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(Seeds.RANK_REWARD_CLAIM),
+        rankReward.toBuffer(),
+        player.toBuffer(),
+      ],
+      this.programId
+    );
+  }
+
+  public deriveRewardVaultAddress(reward: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(Seeds.REWARD_VAULT), reward.toBuffer()],
+      this.programId
+    );
+  }
+
+  public deriveMergedAddress(
+    user: PublicKey,
+    nonce: BN
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(Seeds.MERGED), user.toBuffer(), nonce.toBuffer("le", 8)],
+      this.programId
+    );
+  }
+
+  public deriveAuthorityProposalAddress(
+    game: PublicKey,
+    authority: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(Seeds.AUTHORITY_PROPOSAL),
+        game.toBuffer(),
+        authority.toBuffer(),
+      ],
+      this.programId
+    );
+  }
+
+  public deriveEventAuthorityAddress(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(Seeds.EVENT_AUTHORITY)],
+      this.programId
+    );
+  }
+
   deriveMetadataAddress = (mint: PublicKey): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
       [
@@ -118,9 +287,25 @@ export class Utils {
 
   deriveAssociatedTokenAddress = (
     mint: PublicKey,
-    user: PublicKey
+    user: PublicKey,
+    tokenProgram?: PublicKey
   ): PublicKey => {
-    return getAssociatedTokenAddressSync(mint, user);
+    return getAssociatedTokenAddressSync(
+      mint,
+      user,
+      true,
+      tokenProgram ?? TOKEN_PROGRAM_ID
+    );
+  };
+
+  /**
+   * The token program a fungible-token reward's mint belongs to. Rewards added
+   * before Token-2022 support store the default key, meaning SPL Token.
+   */
+  rewardTokenProgram = (tokenProgram: PublicKey): PublicKey => {
+    return tokenProgram.equals(PublicKey.default)
+      ? TOKEN_PROGRAM_ID
+      : tokenProgram;
   };
 
   zip = <T, U>(a: T[], b: U[], defaultB: U): Array<[T, U]> =>
@@ -2,8 +2,17 @@
 import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { PublicKey, Keypair } from "@solana/web3.js";
-import { SoarProgram, GameClient, GameType, Genre } from "../sdk/lib";
+import {
+  SoarProgram,
+  GameClient,
+  AuthorityRole,
+  GameType,
+  Genre,
+  ScoreRetention,
+  TieBreak,
+} from "../sdk/lib";
 import BN from "bn.js";
+import nacl from "tweetnacl";
 import * as utils from "./utils";
 
 describe("soar", () => {
@@ -13,17 +22,27 @@ describe("soar", () => {
   const client = SoarProgram.get(provider);
 
   let gameClient: GameClient;
-  const game = Keypair.generate();
-  let auths = [Keypair.generate(), Keypair.generate()];
+  const auths = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
   const leaderBoards: PublicKey[] = [];
   const achievements: PublicKey[] = [];
+  let nftReward: PublicKey;
+  let nftCollectionReward: PublicKey;
+  let ftReward: PublicKey;
+
+  // Only the game's owner can grant the admin role, so authorities added by an
+  // admin after the game is created hold every other role.
+  const MANAGER_ROLES =
+    AuthorityRole.ScoreSubmitter |
+    AuthorityRole.AchievementManager |
+    AuthorityRole.RewardManager |
+    AuthorityRole.LeaderboardManager;
 
   const user1 = Keypair.generate();
   const user2 = Keypair.generate();
   const user3 = Keypair.generate();
   let user3NftMint: PublicKey;
 
-  const mergeAccount = Keypair.generate();
+  let mergeAccount: PublicKey;
 
   const RewardConstants = {
     MAX_URI_LENGTH: 200,
@@ -37,6 +56,12 @@ describe("soar", () => {
   );
 
   let ftRewardMint: PublicKey;
+  let ftRewardSource: PublicKey;
+  const ftRewardSourceOwner = Keypair.generate();
+
+  // Issue and expiry times for attestations and vouchers, `seconds` from now.
+  const expiresIn = (seconds: number): BN =>
+    new BN(Math.floor(Date.now() / 1000) + seconds);
 
   it("Can register a game account with correct parameters", async () => {
     const title = "Game1";
@@ -44,18 +69,27 @@ describe("soar", () => {
     const genre = Genre.Action;
     const gameType = GameType.Web;
     const nftMeta = Keypair.generate().publicKey;
-    const _auths = auths.map((keypair) => keypair.publicKey);
+    const _auths = [
+      { key: auths[0].publicKey, roles: AuthorityRole.All },
+      { key: auths[1].publicKey, roles: MANAGER_ROLES },
+    ];
 
     const { newGame, transaction } = await client.initializeNewGame(
-      game.publicKey,
+      client.provider.publicKey,
       title,
       description,
       genre,
       gameType,
       nftMeta,
-      _auths
+      _auths,
+      new BN(0)
+    );
+    await client.sendAndConfirmTransaction(transaction);
+    expect(newGame.toBase58()).to.equal(
+      client.utils
+        .deriveGameAddress(client.provider.publicKey, new BN(0))[0]
+        .toBase58()
     );
-    await client.sendAndConfirmTransaction(transaction, [game]);
 
     const info = await client.fetchGameAccount(newGame);
 
@@ -65,56 +99,129 @@ describe("soar", () => {
     expect(info.meta.gameType).to.equal(gameType);
     expect(info.meta.nftMeta.toBase58()).to.equal(nftMeta.toBase58());
     expect(info.leaderboardCount.toNumber()).to.equal(0);
+    expect(info.owner.toBase58()).to.equal(
+      client.provider.publicKey.toBase58()
+    );
 
     expect(info.auth.length).to.equal(2);
-    expect(info.auth[0].toBase58()).to.equal(auths[0].publicKey.toBase58());
-    expect(info.auth[1].toBase58()).to.equal(auths[1].publicKey.toBase58());
+    expect(info.auth[0].key.toBase58()).to.equal(auths[0].publicKey.toBase58());
+    expect(info.auth[0].roles).to.equal(AuthorityRole.All);
+    expect(info.auth[1].key.toBase58()).to.equal(auths[1].publicKey.toBase58());
+    expect(info.auth[1].roles).to.equal(MANAGER_ROLES);
 
     gameClient = new GameClient(client, newGame);
   });
 
+  it("Can add an authority to a game through a proposal", async () => {
+    const proposed = {
+      key: auths[2].publicKey,
+      roles: AuthorityRole.ScoreSubmitter,
+    };
+    const { proposal, transaction } = await client.proposeAuthority(
+      gameClient.address,
+      auths[0].publicKey,
+      proposed
+    );
+    await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+
+    const account = await client.fetchAuthorityProposalAccount(proposal);
+    expect(account.game.toBase58()).to.equal(gameClient.address.toBase58());
+    expect(account.authority.key.toBase58()).to.equal(
+      auths[2].publicKey.toBase58()
+    );
+    expect(account.replaces).to.be.null;
+
+    // Admins can't grant the admin role; only the owner can.
+    let thrown = false;
+    try {
+      const { transaction } = await client.proposeAuthority(
+        gameClient.address,
+        auths[0].publicKey,
+        { key: Keypair.generate().publicKey, roles: AuthorityRole.Admin }
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    // The proposed key pays for the game account to grow.
+    await utils.airdropTo(client, auths[2].publicKey, 1);
+    const { transaction: accept } = await client.acceptAuthority(
+      gameClient.address,
+      auths[2].publicKey
+    );
+    await client.sendAndConfirmTransaction(accept, [auths[2]]);
+
+    await gameClient.refresh();
+    const authorities = gameClient.account.auth;
+    expect(authorities.length).to.equal(3);
+    expect(authorities[2].key.toBase58()).to.equal(
+      auths[2].publicKey.toBase58()
+    );
+    expect(authorities[2].roles).to.equal(AuthorityRole.ScoreSubmitter);
+
+    const info = await client.provider.connection.getAccountInfo(proposal);
+    expect(info).to.be.null;
+  });
+
   it("Can update a game with the correct parameters", async () => {
-    const newAuths = [
-      Keypair.generate(),
-      Keypair.generate(),
-      Keypair.generate(),
-    ];
+    const newAuths = gameClient.account.auth.map((authority) =>
+      authority.key.equals(auths[2].publicKey)
+        ? { key: authority.key, roles: MANAGER_ROLES }
+        : authority
+    );
     const { transaction } = await gameClient.update(
       auths[0].publicKey,
       null,
-      newAuths.map((auth) => auth.publicKey)
+      newAuths
     );
     await client.sendAndConfirmTransaction(transaction, [auths[0]]);
-    auths = newAuths;
 
     await gameClient.refresh();
-    const authKeys = gameClient.account.auth;
-    expect(authKeys.length).to.equal(3);
-    expect(authKeys[0].toBase58()).to.equal(newAuths[0].publicKey.toBase58());
-    expect(authKeys[1].toBase58()).to.equal(newAuths[1].publicKey.toBase58());
-    expect(authKeys[2].toBase58()).to.equal(newAuths[2].publicKey.toBase58());
+    const authorities = gameClient.account.auth;
+    expect(authorities.length).to.equal(3);
+    for (const [i, auth] of auths.entries()) {
+      expect(authorities[i].key.toBase58()).to.equal(auth.publicKey.toBase58());
+    }
+    expect(authorities[2].roles).to.equal(MANAGER_ROLES);
+
+    // Keys can only be added to a game by accepting a proposal.
+    let thrown = false;
+    try {
+      const { transaction } = await gameClient.update(
+        auths[0].publicKey,
+        null,
+        newAuths.concat([
+          { key: Keypair.generate().publicKey, roles: MANAGER_ROLES },
+        ])
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
   });
 
   it("Can't set fields with invalid lengths on a game", async () => {
     const title = "a".repeat(MAXIMUM_TITLE_LENGTH + 1);
     const description = "a".repeat(MAXIMUM_DESCRIPTION_LENGTH + 1);
-    const newGame = Keypair.generate();
 
     let thrown = false;
     try {
       await client.sendAndConfirmTransaction(
         await client
           .initializeNewGame(
-            newGame.publicKey,
+            client.provider.publicKey,
             title,
             description,
             Genre.Action,
             GameType.Desktop,
             PublicKey.default,
-            []
+            [{ key: auths[0].publicKey, roles: AuthorityRole.All }],
+            new BN(1)
           )
-          .then((res) => res.transaction),
-        [newGame]
+          .then((res) => res.transaction)
       );
     } catch (err) {
       thrown = true;
@@ -156,6 +263,37 @@ describe("soar", () => {
     expect(thrown).to.be.true;
   });
 
+  it("Can't initialize a game again after closing it", async () => {
+    const initialize = async (): Promise<PublicKey> => {
+      const { newGame, transaction } = await client.initializeNewGame(
+        client.provider.publicKey,
+        "Closed",
+        "testDescription",
+        Genre.Action,
+        GameType.Web,
+        PublicKey.default,
+        [{ key: auths[0].publicKey, roles: AuthorityRole.All }],
+        new BN(2)
+      );
+      await client.sendAndConfirmTransaction(transaction);
+      return newGame;
+    };
+    const game = await initialize();
+
+    const { transaction } = await client.closeGame(auths[0].publicKey, game);
+    await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    expect(await client.provider.connection.getAccountInfo(game)).to.be.null;
+
+    let thrown = false;
+    try {
+      await initialize();
+    } catch (_err) {
+      // GameClosed
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+  });
+
   it("Can register a player account with the correct parameters", async () => {
     const player1Username = "player1xx";
     const player1Pfp = Keypair.generate().publicKey;
@@ -222,13 +360,16 @@ describe("soar", () => {
     expect(info.minScore.toNumber()).to.equal(minScore.toNumber());
     expect(info.maxScore.toNumber()).to.equal(maxScore.toNumber());
     expect(info.allowMultipleScores).to.be.true;
+    expect(info.isAscending).to.be.false;
     expect(info.topEntries.toBase58()).to.equal(topEntries.toBase58());
+    expect(info.retention).to.deep.equal(ScoreRetention.unbounded());
+    expect(info.tieBreak).to.deep.equal(TieBreak.earliestFirst());
 
     const entries = await gameClient.program.fetchLeaderBoardTopEntriesAccount(
       topEntries
     );
 
-    expect(entries.isAscending).to.be.false;
+    expect(entries.scoresToRetain).to.equal(scoresToRetain);
     expect(entries.topScores.length).to.equal(scoresToRetain);
     for (const score of entries.topScores) {
       expect(score.entry.score.toNumber()).to.equal(0);
@@ -264,12 +405,9 @@ describe("soar", () => {
     const newMeta = Keypair.generate().publicKey;
 
     let info = await client.fetchLeaderBoardAccount(leaderboard);
-    let entries = await gameClient.program.fetchLeaderBoardTopEntriesAccount(
-      info.topEntries
-    );
 
-    let prevIsAscending = entries.isAscending;
-    let prevAllowMultipleScores = info.allowMultipleScores;
+    const prevIsAscending = info.isAscending;
+    const prevAllowMultipleScores = info.allowMultipleScores;
 
     const { transaction } = await client.updateGameLeaderboard(
       auths[0].publicKey,
@@ -286,11 +424,7 @@ describe("soar", () => {
 
     info = await client.fetchLeaderBoardAccount(leaderboard);
     expect(info.allowMultipleScores).to.be.not.equal(prevAllowMultipleScores);
-
-    entries = await gameClient.program.fetchLeaderBoardTopEntriesAccount(
-      info.topEntries
-    );
-    expect(entries.isAscending).to.be.not.equal(prevIsAscending);
+    expect(info.isAscending).to.be.not.equal(prevIsAscending);
 
     const { transaction: transactionR } = await client.updateGameLeaderboard(
       auths[0].publicKey,
@@ -307,11 +441,44 @@ describe("soar", () => {
 
     info = await client.fetchLeaderBoardAccount(leaderboard);
     expect(info.allowMultipleScores).to.be.equal(prevAllowMultipleScores);
+    expect(info.isAscending).to.be.equal(prevIsAscending);
+  });
 
-    entries = await gameClient.program.fetchLeaderBoardTopEntriesAccount(
-      info.topEntries
+  it("Can update a leaderboard's score retention", async () => {
+    const leaderboard = leaderBoards[0];
+    const { transaction } = await client.updateGameLeaderboard(
+      auths[0].publicKey,
+      leaderboard,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      ScoreRetention.keepBest(5)
     );
-    expect(entries.isAscending).to.be.equal(prevIsAscending);
+    await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+
+    let info = await client.fetchLeaderBoardAccount(leaderboard);
+    expect(info.retention).to.deep.equal(ScoreRetention.keepBest(5));
+
+    const { transaction: restore } = await client.updateGameLeaderboard(
+      auths[0].publicKey,
+      leaderboard,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      ScoreRetention.unbounded()
+    );
+    await client.sendAndConfirmTransaction(restore, [auths[0]]);
+
+    info = await client.fetchLeaderBoardAccount(leaderboard);
+    expect(info.retention).to.deep.equal(ScoreRetention.unbounded());
   });
 
   it("Can't add a leaderboard with the wrong authority", async () => {
@@ -360,16 +527,11 @@ describe("soar", () => {
     );
     await gameClient.program.sendAndConfirmTransaction(tx, [auths[2]]);
 
-    let thrown = false;
     // Should not exist since no scores are being retained.
-    try {
-      await gameClient.program.fetchLeaderBoardTopEntriesAccount(te);
-    } catch (err) {
-      thrown = true;
-    }
-    expect(thrown).to.be.true;
+    expect(te).to.be.null;
 
     const nlAccount = await gameClient.program.fetchLeaderBoardAccount(nl);
+    expect(nlAccount.topEntries).to.be.null;
     expect(nlAccount.decimals).to.equal(0);
     expect(nlAccount.minScore.toNumber()).to.equal(0);
     const maxBN = new BN(2).pow(new BN(64)).sub(new BN(1));
@@ -400,7 +562,7 @@ describe("soar", () => {
     expect(account.description).to.equal(description);
     expect(account.nftMeta.toBase58()).to.equal(nftMeta.toBase58());
     expect(account.game.toBase58()).to.equal(gameClient.address.toBase58());
-    expect(account.reward === null).to.be.true;
+    expect(account.rewards.length).to.equal(0);
 
     await gameClient.refresh();
     expect(gameClient.account.achievementCount.toNumber()).to.equal(1);
@@ -490,10 +652,8 @@ describe("soar", () => {
     let symbol = "x".repeat(RewardConstants.MAX_SYMBOL_LENGTH + 1);
     const availableRewards = new BN(500);
 
-    const newReward = Keypair.generate();
     const { transaction: failTx } = await client.addNonFungibleReward(
       auths[0].publicKey,
-      newReward.publicKey,
       achievements[0],
       availableRewards,
       uri,
@@ -504,7 +664,7 @@ describe("soar", () => {
     // Should fail because uri, name, symbol too long.
     let thrown = false;
     try {
-      await client.sendAndConfirmTransaction(failTx, [auths[0], newReward]);
+      await client.sendAndConfirmTransaction(failTx, [auths[0]]);
     } catch (err) {
       thrown = true;
     }
@@ -514,21 +674,22 @@ describe("soar", () => {
     name = name.substring(0, RewardConstants.MAX_NAME_LENGTH);
     symbol = symbol.substring(0, RewardConstants.MAX_SYMBOL_LENGTH);
 
-    const { transaction } = await client.addNonFungibleReward(
+    const { newReward, transaction } = await client.addNonFungibleReward(
       auths[0].publicKey,
-      newReward.publicKey,
       achievements[0],
       availableRewards,
       uri,
       name,
       symbol
     );
-
-    await client.sendAndConfirmTransaction(transaction, [auths[0], newReward]);
-    const account = await gameClient.program.fetchRewardAccount(
-      newReward.publicKey
+    expect(newReward.toBase58()).to.equal(
+      client.utils.deriveRewardAddress(new BN(1), achievements[0])[0].toBase58()
     );
 
+    await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    nftReward = newReward;
+    const account = await gameClient.program.fetchRewardAccount(newReward);
+
     expect(account.achievement.toBase58()).to.equal(achievements[0].toBase58());
     expect(account.availableSpots.toNumber()).to.equal(
       availableRewards.toNumber()
@@ -539,6 +700,10 @@ describe("soar", () => {
     expect(account.NonFungibleToken.symbol).to.equal(symbol);
     expect(account.NonFungibleToken.minted.toNumber()).to.equal(0);
     expect(account.NonFungibleToken.collection).to.be.null;
+
+    const achievement = await client.fetchAchievementAccount(achievements[0]);
+    const rewards = achievement.rewards.map((reward) => reward.toBase58());
+    expect(rewards).to.deep.equal([newReward.toBase58()]);
   });
 
   it("Can add a ft reward for an achievement", async () => {
@@ -548,18 +713,17 @@ describe("soar", () => {
     const { mint, authority } = await utils.initializeTestMint(client);
     ftRewardMint = mint;
 
-    const tokenAccountOwner = Keypair.generate();
+    const tokenAccountOwner = ftRewardSourceOwner;
     const tokenAccount = await utils.createTokenAccount(
       client,
       tokenAccountOwner.publicKey,
       mint
     );
+    ftRewardSource = tokenAccount;
     await utils.mintToAccount(client, mint, authority, tokenAccount, 5);
 
-    const newReward = Keypair.generate();
-    const { transaction } = await client.addFungibleReward(
+    const { newReward, transaction } = await client.addFungibleReward(
       auths[0].publicKey,
-      newReward.publicKey,
       achievements[1],
       amountPerUser,
       availableRewards,
@@ -571,9 +735,9 @@ describe("soar", () => {
     await client.sendAndConfirmTransaction(transaction, [
       auths[0],
       tokenAccountOwner,
-      newReward,
     ]);
-    const account = await client.fetchRewardAccount(newReward.publicKey);
+    ftReward = newReward;
+    const account = await client.fetchRewardAccount(newReward);
 
     expect(account.achievement.toBase58()).to.equal(achievements[1].toBase58());
     expect(account.availableSpots.toNumber()).to.equal(
@@ -583,12 +747,21 @@ describe("soar", () => {
     expect(account.FungibleToken).to.not.be.undefined;
 
     expect(account.FungibleToken.mint.toBase58()).to.equal(mint.toBase58());
+    const vault = client.utils.deriveRewardVaultAddress(newReward)[0];
     expect(account.FungibleToken.account.toBase58()).to.equal(
-      tokenAccount.toBase58()
+      vault.toBase58()
     );
     expect(account.FungibleToken.amount.toNumber()).to.equal(
       amountPerUser.toNumber()
     );
+
+    // The initial deposit is moved into the reward's vault.
+    const vaultBalance =
+      await client.provider.connection.getTokenAccountBalance(vault);
+    expect(vaultBalance.value.uiAmount).to.equal(2);
+    const sourceBalance =
+      await client.provider.connection.getTokenAccountBalance(tokenAccount);
+    expect(sourceBalance.value.uiAmount).to.equal(3);
   });
 
   it("Can register a player to a leaderboard", async () => {
@@ -605,6 +778,7 @@ describe("soar", () => {
     );
     expect(account.leaderboard.toBase58()).to.equal(leaderBoards[0].toBase58());
     expect(account.scores.length).to.equal(0);
+    expect(account.submissionCount.toNumber()).to.equal(0);
   });
 
   it("Can submit a player score", async () => {
@@ -626,6 +800,7 @@ describe("soar", () => {
     expect(account.scores.length).to.equal(1);
     expect(account.scores[0].score.toNumber()).to.equal(score.toNumber());
     expect(account.allocCount).to.equal(10);
+    expect(account.submissionCount.toNumber()).to.equal(1);
 
     const topEntries = await client.fetchLeaderBoardTopEntriesAccount(
       client.utils.deriveLeaderTopEntriesAddress(leaderBoards[0])[0]
@@ -642,7 +817,10 @@ describe("soar", () => {
       user1.publicKey,
       leaderBoards[0]
     )[0];
-    const initialLength = 8 + 32 + 32 + 2 + 4 + 10 * (8 + 8); // initial space allocated for 10 scores.
+    // Initial space allocated for 10 scores.
+    const scoreSize = 8 + 8 + 8;
+    const initialLength =
+      8 + 32 + 32 + 2 + 8 + 4 + scoreSize + 4 + 10 * scoreSize;
     let info = await client.provider.connection.getAccountInfo(entryList);
     expect(info.data.length).to.equal(initialLength);
 
@@ -677,7 +855,7 @@ describe("soar", () => {
 
     expect(list.allocCount).to.equal(20);
     expect(list.scores.length).to.equal(11);
-    expect(info.data.length).to.equal(initialLength + 10 * (8 + 8));
+    expect(info.data.length).to.equal(initialLength + 10 * scoreSize);
   });
 
   it("Can store top entries for a leaderboard", async () => {
@@ -692,6 +870,362 @@ describe("soar", () => {
     expect(JSON.stringify(scores), JSON.stringify(expectedScores));
   });
 
+  it("Ranks equal scores by their tiebreak value", async () => {
+    const { newLeaderBoard, topEntries, transaction } =
+      await gameClient.addLeaderBoard(
+        auths[1].publicKey,
+        "Tiebreak",
+        PublicKey.default,
+        3,
+        false,
+        0,
+        new BN(0),
+        new BN(100),
+        true,
+        undefined,
+        undefined,
+        undefined,
+        undefined,
+        TieBreak.highestValue()
+      );
+    await client.sendAndConfirmTransaction(transaction, [auths[1]]);
+
+    const { transaction: register } =
+      await client.registerPlayerEntryForLeaderBoard(
+        user1.publicKey,
+        newLeaderBoard
+      );
+    await client.sendAndConfirmTransaction(register, [user1]);
+
+    for (const tiebreak of [5, 9, 1]) {
+      const { transaction } = await client.submitScoreToLeaderBoard(
+        user1.publicKey,
+        auths[1].publicKey,
+        newLeaderBoard,
+        new BN(50),
+        new BN(tiebreak)
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[1]]);
+    }
+
+    const entries = await client.fetchLeaderBoardTopEntriesAccount(topEntries);
+    const tiebreaks = entries.topScores.map((score) =>
+      score.entry.tiebreak.toNumber()
+    );
+    expect(tiebreaks).to.deep.equal([9, 5, 1]);
+  });
+
+  it("Can archive a season of a leaderboard retaining the most scores", async () => {
+    // The most top scores a seasonal leaderboard can retain, bounded by the
+    // space a season archive can be created with.
+    const maxSeasonScores = 181;
+    const seasonLength = new BN(2);
+    const startTime = new BN(Math.floor(Date.now() / 1000) - 1);
+
+    // -> Fail because the season archive wouldn't fit its top scores.
+    let thrown = false;
+    try {
+      const { transaction } = await gameClient.addLeaderBoard(
+        auths[1].publicKey,
+        "Seasonal",
+        PublicKey.default,
+        maxSeasonScores + 1,
+        false,
+        0,
+        new BN(0),
+        new BN(100),
+        true,
+        startTime,
+        undefined,
+        seasonLength
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[1]]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    const { newLeaderBoard, transaction } = await gameClient.addLeaderBoard(
+      auths[1].publicKey,
+      "Seasonal",
+      PublicKey.default,
+      maxSeasonScores,
+      false,
+      0,
+      new BN(0),
+      new BN(100),
+      true,
+      startTime,
+      undefined,
+      seasonLength
+    );
+    await client.sendAndConfirmTransaction(transaction, [auths[1]]);
+
+    // Wait for the first season to end.
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+
+    const { archive, season, transaction: archiveSeason } =
+      await client.archiveSeason(newLeaderBoard);
+    await client.sendAndConfirmTransaction(archiveSeason);
+
+    const account = await client.fetchSeasonTopEntriesAccount(archive);
+    expect(account.leaderboard.toBase58()).to.equal(newLeaderBoard.toBase58());
+    expect(account.season.toNumber()).to.equal(season.toNumber());
+    expect(account.topScores.length).to.equal(maxSeasonScores);
+  });
+
+  it("Can ban and unban a player from a leaderboard", async () => {
+    const { ban, transaction } = await client.banPlayer(
+      auths[0].publicKey,
+      user1.publicKey,
+      leaderBoards[0]
+    );
+    await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+
+    const account = await client.fetchPlayerBanAccount(ban);
+    expect(account.playerAccount.toBase58()).to.equal(
+      client.utils.derivePlayerAddress(user1.publicKey)[0].toBase58()
+    );
+    expect(account.leaderboard.toBase58()).to.equal(leaderBoards[0].toBase58());
+
+    // Banned players can't submit scores.
+    let thrown = false;
+    try {
+      const { transaction } = await client.submitScoreToLeaderBoard(
+        user1.publicKey,
+        auths[0].publicKey,
+        leaderBoards[0],
+        new BN(20)
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    const { transaction: unban } = await client.unbanPlayer(
+      auths[0].publicKey,
+      user1.publicKey,
+      leaderBoards[0]
+    );
+    await client.sendAndConfirmTransaction(unban, [auths[0]]);
+
+    const info = await client.provider.connection.getAccountInfo(ban);
+    expect(info).to.be.null;
+  });
+
+  it("Can submit a score attested by a game authority", async () => {
+    // The player pays for any growth of their scores list.
+    await utils.airdropTo(client, user1.publicKey, 1);
+    const nonce = await client.nextAttestationNonce(
+      user1.publicKey,
+      leaderBoards[0]
+    );
+    const args = {
+      score: new BN(42),
+      tiebreak: new BN(0),
+      nonce,
+      issuedAt: expiresIn(0),
+      expiry: expiresIn(600),
+    };
+    const message = client.scoreAttestationMessage(
+      user1.publicKey,
+      leaderBoards[0],
+      args
+    );
+    const signature = nacl.sign.detached(message, auths[0].secretKey);
+
+    const { transaction } = await client.submitAttestedScore(
+      user1.publicKey,
+      leaderBoards[0],
+      args,
+      auths[0].publicKey,
+      signature
+    );
+    await client.sendAndConfirmTransaction(transaction, [user1]);
+
+    const list = await client.fetchPlayerScoresListAccount(
+      client.utils.derivePlayerScoresListAddress(
+        user1.publicKey,
+        leaderBoards[0]
+      )[0]
+    );
+    const scores = list.scores.map((score) => score.score.toNumber());
+    expect(scores).to.contain(42);
+    expect(list.submissionCount.toNumber()).to.equal(nonce.toNumber() + 1);
+
+    // Should fail because the attestation's nonce was already used.
+    let thrown = false;
+    try {
+      const { transaction } = await client.submitAttestedScore(
+        user1.publicKey,
+        leaderBoards[0],
+        args,
+        auths[0].publicKey,
+        signature
+      );
+      await client.sendAndConfirmTransaction(transaction, [user1]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+  });
+
+  it("Can't submit a score without a valid attestation", async () => {
+    const nonce = await client.nextAttestationNonce(
+      user1.publicKey,
+      leaderBoards[0]
+    );
+    const args = {
+      score: new BN(43),
+      tiebreak: new BN(0),
+      nonce,
+      issuedAt: expiresIn(0),
+      expiry: expiresIn(600),
+    };
+    const message = client.scoreAttestationMessage(
+      user1.publicKey,
+      leaderBoards[0],
+      args
+    );
+
+    // -> Fail because the signer isn't one of the game's authorities.
+    const random = Keypair.generate();
+    let thrown = false;
+    try {
+      const { transaction } = await client.submitAttestedScore(
+        user1.publicKey,
+        leaderBoards[0],
+        args,
+        random.publicKey,
+        nacl.sign.detached(message, random.secretKey)
+      );
+      await client.sendAndConfirmTransaction(transaction, [user1]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    // -> Fail because the submitted score isn't the attested one.
+    thrown = false;
+    try {
+      const { transaction } = await client.submitAttestedScore(
+        user1.publicKey,
+        leaderBoards[0],
+        { ...args, score: new BN(100) },
+        auths[0].publicKey,
+        nacl.sign.detached(message, auths[0].secretKey)
+      );
+      await client.sendAndConfirmTransaction(transaction, [user1]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    // -> Fail because the attestation expired.
+    thrown = false;
+    const expired = { ...args, expiry: expiresIn(-600) };
+    try {
+      const { transaction } = await client.submitAttestedScore(
+        user1.publicKey,
+        leaderBoards[0],
+        expired,
+        auths[0].publicKey,
+        nacl.sign.detached(
+          client.scoreAttestationMessage(
+            user1.publicKey,
+            leaderBoards[0],
+            expired
+          ),
+          auths[0].secretKey
+        )
+      );
+      await client.sendAndConfirmTransaction(transaction, [user1]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    const list = await client.fetchPlayerScoresListAccount(
+      client.utils.derivePlayerScoresListAddress(
+        user1.publicKey,
+        leaderBoards[0]
+      )[0]
+    );
+    expect(list.submissionCount.toNumber()).to.equal(nonce.toNumber());
+  });
+
+  it("Can't replay an attestation after registering to a leaderboard again", async () => {
+    const user = Keypair.generate();
+    await utils.airdropTo(client, user.publicKey, 1);
+    const { transaction: init } = await client.initializePlayerAccount(
+      user.publicKey,
+      "replayer",
+      PublicKey.default
+    );
+    await client.sendAndConfirmTransaction(init, [user]);
+    const register = async (): Promise<void> => {
+      const { transaction } = await client.registerPlayerEntryForLeaderBoard(
+        user.publicKey,
+        leaderBoards[0]
+      );
+      await client.sendAndConfirmTransaction(transaction, [user]);
+    };
+    await register();
+    // Issue the attestation after the list's registration.
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const args = {
+      score: new BN(44),
+      tiebreak: new BN(0),
+      nonce: await client.nextAttestationNonce(user.publicKey, leaderBoards[0]),
+      issuedAt: expiresIn(0),
+      expiry: expiresIn(600),
+    };
+    const signature = nacl.sign.detached(
+      client.scoreAttestationMessage(user.publicKey, leaderBoards[0], args),
+      auths[0].secretKey
+    );
+    const submit = async (): Promise<void> => {
+      const { transaction } = await client.submitAttestedScore(
+        user.publicKey,
+        leaderBoards[0],
+        args,
+        auths[0].publicKey,
+        signature
+      );
+      await client.sendAndConfirmTransaction(transaction, [user]);
+    };
+    await submit();
+
+    const { transaction: close } = await client.closePlayerScores(
+      user.publicKey,
+      leaderBoards[0]
+    );
+    await client.sendAndConfirmTransaction(close, [user]);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await register();
+
+    // The new list's nonce starts over, but the attestation predates it.
+    const list = await client.fetchPlayerScoresListAccount(
+      client.utils.derivePlayerScoresListAddress(
+        user.publicKey,
+        leaderBoards[0]
+      )[0]
+    );
+    expect(list.submissionCount.toNumber()).to.equal(args.nonce.toNumber());
+    expect(list.createdAt.gt(args.issuedAt)).to.be.true;
+
+    let thrown = false;
+    try {
+      await submit();
+    } catch (_err) {
+      // StaleAttestation
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+  });
+
   it("Should fail submitting a player score", async () => {
     // -> Fail because player isn't registered to leaderboard.
     let thrown = false;
@@ -756,13 +1290,11 @@ describe("soar", () => {
   });
 
   it("Can unlock an achievement for a player", async () => {
-    // Unlock achievements[0] for player. LeaderBoards[0] is the leaderboard the user is registered to.
     const { newPlayerAchievement, transaction } =
       await client.unlockPlayerAchievement(
         user1.publicKey,
         auths[0].publicKey,
         achievements[0],
-        leaderBoards[0],
         gameClient.address
       );
     await client.sendAndConfirmTransaction(transaction, [auths[0]]);
@@ -775,6 +1307,7 @@ describe("soar", () => {
     );
     expect(account.achievement.toBase58()).to.equal(achievements[0].toBase58());
     expect(account.unlocked).to.be.true;
+    expect(account.claimed).to.be.false;
   });
 
   it("Can initiate a merge", async () => {
@@ -788,10 +1321,14 @@ describe("soar", () => {
 
     const { newMerge, transaction } = await client.initiateMerge(
       user1.publicKey,
-      mergeAccount.publicKey,
-      keys
+      keys,
+      new BN(0)
     );
-    await client.sendAndConfirmTransaction(transaction, [mergeAccount, user1]);
+    await client.sendAndConfirmTransaction(transaction, [user1]);
+    expect(newMerge.toBase58()).to.equal(
+      client.utils.deriveMergedAddress(user1.publicKey, new BN(0))[0].toBase58()
+    );
+    mergeAccount = newMerge;
 
     const account = await client.fetchMergedAccount(newMerge);
     expect(account.initiator.toBase58()).to.equal(user1.publicKey.toBase58());
@@ -812,11 +1349,11 @@ describe("soar", () => {
   it("Can approve a merge", async () => {
     const { transaction: tx1 } = await client.registerMergeApproval(
       user2.publicKey,
-      mergeAccount.publicKey
+      mergeAccount
     );
     const { transaction: tx2 } = await client.registerMergeApproval(
       user3.publicKey,
-      mergeAccount.publicKey
+      mergeAccount
     );
 
     let thrown = false;
@@ -831,18 +1368,17 @@ describe("soar", () => {
     await client.sendAndConfirmTransaction(tx2, [user3]);
 
     const approvals = await client
-      .fetchMergedAccount(mergeAccount.publicKey)
+      .fetchMergedAccount(mergeAccount)
       .then((res) => res.approvals);
     expect(approvals[0].approved).to.be.true;
     expect(approvals[1].approved).to.be.true;
   });
 
-  it("Can overwrite the current reward for an achievement and add a collection", async () => {
+  it("Can add a second nft reward with a collection to an achievement", async () => {
     const uri = utils.TEST1_URI;
     const name = "test_name_1";
     const symbol = "t1";
     const availableRewards = new BN(10);
-    const newReward = Keypair.generate();
 
     const metaplex = utils.initMetaplex(client.provider.connection);
     const collectionMint = await utils.initTestCollectionNft(
@@ -851,10 +1387,8 @@ describe("soar", () => {
       "collection"
     );
 
-    // Overwrite achievement[0]'s reward.
-    const { transaction } = await client.addNonFungibleReward(
+    const { newReward, transaction } = await client.addNonFungibleReward(
       auths[0].publicKey,
-      newReward.publicKey,
       achievements[0],
       availableRewards,
       uri,
@@ -863,12 +1397,13 @@ describe("soar", () => {
       collectionMint.publicKey,
       client.provider.publicKey
     );
-
-    await client.sendAndConfirmTransaction(transaction, [auths[0], newReward]);
-    const account = await gameClient.program.fetchRewardAccount(
-      newReward.publicKey
+    await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    expect(newReward.toBase58()).to.equal(
+      client.utils.deriveRewardAddress(new BN(2), achievements[0])[0].toBase58()
     );
+    nftCollectionReward = newReward;
 
+    const account = await gameClient.program.fetchRewardAccount(newReward);
     expect(account.achievement.toBase58()).to.equal(achievements[0].toBase58());
     expect(account.availableSpots.toNumber()).to.equal(
       availableRewards.toNumber()
@@ -882,18 +1417,25 @@ describe("soar", () => {
     expect(account.NonFungibleToken.collection.toBase58()).to.equal(
       collectionMint.publicKey.toBase58()
     );
+
+    // Adding a reward keeps the achievement's earlier ones.
+    const achievement = await client.fetchAchievementAccount(achievements[0]);
+    expect(achievement.rewards.map((key) => key.toBase58())).to.deep.equal([
+      nftReward.toBase58(),
+      nftCollectionReward.toBase58(),
+    ]);
   });
 
   it("Can claim nft rewards for unlocking an achievement", async () => {
     let mint = Keypair.generate();
     const { transaction } = await client.claimNftReward(
       auths[0].publicKey,
-      achievements[0],
+      nftCollectionReward,
       mint.publicKey,
-      user1.publicKey
+      user3.publicKey
     );
     let thrown = false;
-    // Should fail because `unlock_player_achievement` has already been called for this player
+    // Should fail because user3 hasn't unlocked the achievement yet.
     try {
       await client.sendAndConfirmTransaction(transaction, [mint, auths[0]]);
     } catch (err) {
@@ -901,11 +1443,11 @@ describe("soar", () => {
     }
     expect(thrown).to.be.true;
 
-    // Claim an nft reward(and unlock a player achievement account).
+    // Unlock the player achievement and claim an nft reward.
     mint = Keypair.generate();
-    const { transaction: tx } = await client.claimNftReward(
+    const { transaction: tx } = await client.unlockAndClaimNftReward(
       auths[0].publicKey,
-      achievements[0],
+      nftCollectionReward,
       mint.publicKey,
       user3.publicKey
     );
@@ -928,10 +1470,7 @@ describe("soar", () => {
     );
     expect(metadata.collection.verified).to.equal(false);
 
-    const rewardAddress = await client
-      .fetchAchievementAccount(achievements[0])
-      .then((res) => res.reward);
-    const reward = await client.fetchRewardAccount(rewardAddress);
+    let reward = await client.fetchRewardAccount(nftCollectionReward);
     expect(metadata.collection.key.toBase58()).to.equal(
       reward.NonFungibleToken.collection.toBase58()
     );
@@ -944,14 +1483,34 @@ describe("soar", () => {
     const account = await client.fetchPlayerAchievementAccount(
       playerAchievement
     );
+    expect(account.unlocked).to.be.true;
     expect(account.claimed).to.be.true;
 
+    const rewardClaim = client.utils.deriveRewardClaimAddress(
+      user3.publicKey,
+      nftCollectionReward
+    )[0];
+    const info = await client.provider.connection.getAccountInfo(rewardClaim);
+    expect(info).to.not.be.null;
+
+    // Players who already unlocked the achievement can claim directly.
+    mint = Keypair.generate();
+    const { transaction: claim } = await client.claimNftReward(
+      auths[0].publicKey,
+      nftCollectionReward,
+      mint.publicKey,
+      user1.publicKey
+    );
+    await client.sendAndConfirmTransaction(claim, [mint, auths[0]]);
+    reward = await client.fetchRewardAccount(nftCollectionReward);
+    expect(reward.NonFungibleToken.minted.toNumber()).to.equal(2);
+
     // Should fail because reward already claimed.
     mint = Keypair.generate();
     thrown = false;
     const { transaction: shouldFail } = await client.claimNftReward(
       auths[0].publicKey,
-      achievements[0],
+      nftCollectionReward,
       mint.publicKey,
       user3.publicKey
     );
@@ -967,7 +1526,7 @@ describe("soar", () => {
     let mint = user3NftMint;
     const { transaction } = await client.verifyPlayerNftReward(
       user3.publicKey,
-      achievements[0],
+      nftCollectionReward,
       mint
     );
     try {
@@ -986,7 +1545,7 @@ describe("soar", () => {
     try {
       const { transaction } = await client.verifyPlayerNftReward(
         user3.publicKey,
-        achievements[0],
+        nftCollectionReward,
         mint
       );
       await client.sendAndConfirmTransaction(transaction, [user3]);
@@ -1011,9 +1570,17 @@ describe("soar", () => {
       expect(balance.value.uiAmount).to.equal(0);
     }
 
-    const { transaction } = await client.claimFtReward(
+    const { transaction: unlock } = await client.unlockPlayerAchievement(
+      user1.publicKey,
       auths[0].publicKey,
       achievements[1],
+      gameClient.address
+    );
+    await client.sendAndConfirmTransaction(unlock, [auths[0]]);
+
+    const { transaction } = await client.claimFtReward(
+      auths[0].publicKey,
+      ftReward,
       user1.publicKey
     );
     await client.sendAndConfirmTransaction(transaction, [auths[0]]);
@@ -1030,6 +1597,143 @@ describe("soar", () => {
       )[0]
     );
     expect(account.claimed).to.be.true;
+
+    // Should fail because reward already claimed.
+    let thrown = false;
+    try {
+      const { transaction } = await client.claimFtReward(
+        auths[0].publicKey,
+        ftReward,
+        user1.publicKey
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+  });
+
+  it("Can redeem a claim voucher issued by a game authority", async () => {
+    // The player pays for the accounts created by the claim.
+    await utils.airdropTo(client, user2.publicKey, 1);
+    const expiry = expiresIn(600);
+    const message = await client.claimVoucherMessage(
+      user2.publicKey,
+      ftReward,
+      expiry
+    );
+
+    // -> Fail because the signer isn't one of the game's authorities.
+    const random = Keypair.generate();
+    let thrown = false;
+    try {
+      const { transaction } = await client.redeemClaimVoucher(
+        user2.publicKey,
+        ftReward,
+        expiry,
+        random.publicKey,
+        nacl.sign.detached(message, random.secretKey)
+      );
+      await client.sendAndConfirmTransaction(transaction, [user2]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+
+    const signature = nacl.sign.detached(message, auths[0].secretKey);
+    const { transaction } = await client.redeemClaimVoucher(
+      user2.publicKey,
+      ftReward,
+      expiry,
+      auths[0].publicKey,
+      signature
+    );
+    await client.sendAndConfirmTransaction(transaction, [user2]);
+
+    const wallet = client.utils.deriveAssociatedTokenAddress(
+      ftRewardMint,
+      user2.publicKey
+    );
+    const balance = await client.provider.connection.getTokenAccountBalance(
+      wallet
+    );
+    expect(balance.value.uiAmount).to.equal(1);
+
+    const account = await client.fetchPlayerAchievementAccount(
+      client.utils.derivePlayerAchievementAddress(
+        user2.publicKey,
+        achievements[1]
+      )[0]
+    );
+    expect(account.unlocked).to.be.true;
+    expect(account.claimed).to.be.true;
+
+    // Should fail because the voucher was already redeemed.
+    thrown = false;
+    try {
+      const { transaction } = await client.redeemClaimVoucher(
+        user2.publicKey,
+        ftReward,
+        expiry,
+        auths[0].publicKey,
+        signature
+      );
+      await client.sendAndConfirmTransaction(transaction, [user2]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
+  });
+
+  it("Can give more spots to an exhausted reward but not a cancelled one", async () => {
+    let reward = await client.fetchRewardAccount(ftReward);
+    expect(reward.availableSpots.toNumber()).to.equal(0);
+    expect(reward.cancelled).to.be.false;
+
+    const { transaction: topUp } = await client.topUpFtReward(
+      auths[0].publicKey,
+      ftReward,
+      new BN(1),
+      ftRewardSource,
+      ftRewardSourceOwner.publicKey
+    );
+    await client.sendAndConfirmTransaction(topUp, [
+      auths[0],
+      ftRewardSourceOwner,
+    ]);
+
+    const { transaction: update } = await client.updateReward(
+      auths[0].publicKey,
+      ftReward,
+      new BN(1)
+    );
+    await client.sendAndConfirmTransaction(update, [auths[0]]);
+    reward = await client.fetchRewardAccount(ftReward);
+    expect(reward.availableSpots.toNumber()).to.equal(1);
+
+    const { transaction: cancel } = await client.cancelReward(
+      auths[0].publicKey,
+      ftReward,
+      ftRewardSource
+    );
+    await client.sendAndConfirmTransaction(cancel, [auths[0]]);
+    reward = await client.fetchRewardAccount(ftReward);
+    expect(reward.availableSpots.toNumber()).to.equal(0);
+    expect(reward.cancelled).to.be.true;
+
+    // -> Fail because the reward was cancelled.
+    let thrown = false;
+    try {
+      const { transaction } = await client.updateReward(
+        auths[0].publicKey,
+        ftReward,
+        new BN(1)
+      );
+      await client.sendAndConfirmTransaction(transaction, [auths[0]]);
+    } catch (err) {
+      thrown = true;
+    }
+    expect(thrown).to.be.true;
   });
 
   it("Check scores order allowing multiple scores per player, dec order", async () => {
@@ -1085,7 +1789,7 @@ describe("soar", () => {
     const user1P = client.utils
       .derivePlayerAddress(user1.publicKey)[0]
       .toBase58();
-    expect(entries.isAscending).to.be.false;
+    expect(info.isAscending).to.be.false;
     expect(entries.topScores.length).to.equal(scoresToRetain);
     expect(entries.topScores[0].entry.score.toNumber()).to.equal(
       score1.toNumber()
@@ -1191,7 +1895,7 @@ describe("soar", () => {
     const user1P = client.utils
       .derivePlayerAddress(user1.publicKey)[0]
       .toBase58();
-    expect(entries.isAscending).to.be.false;
+    expect(info.isAscending).to.be.false;
     expect(entries.topScores.length).to.equal(scoresToRetain);
     expect(entries.topScores[0].entry.score.toNumber()).to.equal(
       score1.toNumber()
@@ -1314,7 +2018,7 @@ describe("soar", () => {
     const user1P = client.utils
       .derivePlayerAddress(user1.publicKey)[0]
       .toBase58();
-    expect(entries.isAscending).to.be.true;
+    expect(info.isAscending).to.be.true;
     expect(entries.topScores.length).to.equal(scoresToRetain);
     expect(entries.topScores[0].entry.score.toNumber()).to.equal(
       score1.toNumber()
@@ -2,7 +2,13 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { IDL } from "../../target/types/tens";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
-import { SoarProgram, GameType, Genre, AccountsBuilder } from "../sdk/lib";
+import {
+  SoarProgram,
+  AuthorityRole,
+  GameType,
+  Genre,
+  AccountsBuilder,
+} from "../sdk/lib";
 import BN from "bn.js";
 import * as utils from "./utils";
 
@@ -17,9 +23,12 @@ describe("tens", () => {
 
   const soar = SoarProgram.get(provider);
 
-  const gameKp = Keypair.generate();
-  // The SOAR state for our tens program.
-  const soarGame = gameKp.publicKey;
+  // The SOAR state for our tens program, derived from its creator and a nonce.
+  const creator = Keypair.generate();
+  const soarGame = soar.utils.deriveGameAddress(
+    creator.publicKey,
+    new BN(0)
+  )[0];
 
   // The `offChainAuthority` is an authority of the SOAR state representing our `tens` game that
   // exists off-chain. The SOAR state can also have an on-chain PDA of the `tens` program, giving
@@ -34,7 +43,23 @@ describe("tens", () => {
   // automatically submit a score for a user), we can set a `tens` PDA as an additional authority and
   // write CPI functionality to call and sign the submitScore instruction from our on-chain program.
   const offChainAuthority = Keypair.generate();
-  let auths = [offChainAuthority.publicKey];
+
+  // The tensState PDA of our `tens` program. Since a PDA can't sign to accept an authority
+  // proposal, the game's creator grants it its roles when initializing the game.
+  const tensPDA = PublicKey.findProgramAddressSync(
+    [Buffer.from("tens")],
+    tensProgram.programId
+  )[0];
+  const auths = [
+    { key: offChainAuthority.publicKey, roles: AuthorityRole.All },
+    {
+      key: tensPDA,
+      roles:
+        AuthorityRole.ScoreSubmitter |
+        AuthorityRole.AchievementManager |
+        AuthorityRole.RewardManager,
+    },
+  ];
 
   const user = Keypair.generate();
 
@@ -46,16 +71,18 @@ describe("tens", () => {
     const nftMeta = Keypair.generate().publicKey;
 
     // Initialize the `SOAR` state representing the `tens` game.
+    await utils.airdropTo(soar, creator.publicKey, 1);
     const { transaction: init } = await soar.initializeNewGame(
-      gameKp.publicKey,
+      creator.publicKey,
       title,
       description,
       genre,
       gameType,
       nftMeta,
-      auths
+      auths,
+      new BN(0)
     );
-    await soar.sendAndConfirmTransaction(init, [gameKp], {
+    await soar.sendAndConfirmTransaction(init, [creator], {
       skipPreflight: true,
     });
 
@@ -74,11 +101,6 @@ describe("tens", () => {
       );
     await soar.sendAndConfirmTransaction(transaction, [offChainAuthority]);
 
-    // Derive the tensStatePDA of the `tens` program.
-    const tensPDA = PublicKey.findProgramAddressSync(
-      [Buffer.from("tens")],
-      tensProgram.programId
-    )[0];
     // Initialize the internal state and register its `SOAR` game and leaderboard in our tens program
     // so it can validate that the correct accounts are passed in for subsequent instructions.
     await tensProgram.methods
@@ -91,19 +113,6 @@ describe("tens", () => {
       .signers([])
       .rpc();
 
-    // Make the tensState PDA of our `tens` program an authority of the game so it can permissionlessly
-    // sign CPI requests to SOAR that require the authority's signature.
-    const newAuths = auths.concat([tensPDA]);
-    auths = newAuths;
-
-    const { transaction: update } = await soar.updateGameAccount(
-      soarGame,
-      offChainAuthority.publicKey,
-      undefined,
-      newAuths
-    );
-    await soar.sendAndConfirmTransaction(update, [offChainAuthority]);
-
     // Initialize a SOAR player account, required for interacting with the `tens` game.
     const { transaction: initPlayer } = await soar.initializePlayerAccount(
       user.publicKey,
@@ -128,7 +137,7 @@ describe("tens", () => {
       //
       // The authority for this submitScore instruction is the tensState PDA of the `tens` program.
       //
-      // Since this has been made an authority for the SOAR state, it is now authorized to sign
+      // Since it holds the score-submitter role for the SOAR state, it is authorized to sign
       // for the submitScore instruction and will do so by CPI.
       //
       const accounts = await new AccountsBuilder(
@@ -145,7 +154,9 @@ describe("tens", () => {
           soarLeaderboard: accounts.leaderboard,
           soarPlayerAccount: accounts.playerAccount,
           soarPlayerScores: accounts.playerScores,
-          soarTopEntries: accounts.topEntries, // created when player registered to leaderboard
+          soarPlayerBan: accounts.playerBan, // only exists for banned players
+          soarTopEntries: accounts.topEntries, // created with the leaderboard
+          soarEventAuthority: soar.utils.deriveEventAuthorityAddress()[0],
           soarProgram: soar.program.programId,
           systemProgram: accounts.systemProgram,
         })
@@ -181,7 +192,7 @@ describe("tens", () => {
       tokenAccountOwner.publicKey,
       mint
     );
-    await utils.mintToAccount(soar, mint, authority, tokenAccount, 5);
+    await utils.mintToAccount(soar, mint, authority, tokenAccount, 20);
 
     const userATA = await utils.createTokenAccount(soar, user.publicKey, mint);
     // ---------------------------------------------------------------------------------------------//
@@ -198,22 +209,17 @@ describe("tens", () => {
       );
     await soar.sendAndConfirmTransaction(addAchievement, [offChainAuthority]);
 
-    const reward = Keypair.generate();
     const { newReward, transaction: addReward } = await soar.addFungibleReward(
       offChainAuthority.publicKey,
-      reward.publicKey,
       newAchievement,
       new BN(4),
       new BN(5),
-      new BN(100),
+      new BN(20),
       mint,
       tokenAccount,
       tokenAccountOwner.publicKey
     );
-    await soar.sendAndConfirmTransaction(addReward, [
-      reward,
-      tokenAccountOwner,
-    ]);
+    await soar.sendAndConfirmTransaction(addReward, [tokenAccountOwner]);
 
     // Now the user can try to claim a reward by sending a transaction to the `tens` smart-contract.
     //
@@ -226,11 +232,10 @@ describe("tens", () => {
       provider,
       soar.program.programId
     ).claimFtRewardAccounts(
-      tensPDA,
-      newAchievement,
       user.publicKey,
       newReward,
-      soarGame
+      soarGame,
+      user.publicKey
     );
     await utils.airdropTo(soar, user.publicKey, 1);
 
@@ -252,10 +257,14 @@ describe("tens", () => {
         soarAchievement: accounts.achievement,
         soarReward: accounts.reward,
         soarPlayerAchievement: accounts.playerAchievement,
-        sourceTokenAccount: accounts.sourceTokenAccount,
+        soarRewardClaim: accounts.rewardClaim,
+        rewardTokenMint: accounts.rewardTokenMint,
+        rewardVault: accounts.rewardVault,
         userTokenAccount: accounts.userTokenAccount,
         tokenProgram: accounts.tokenProgram,
-        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: accounts.associatedTokenProgram,
+        systemProgram: accounts.systemProgram,
+        soarEventAuthority: soar.utils.deriveEventAuthorityAddress()[0],
         soarProgram: soar.program.programId,
       })
       .signers([user])
@@ -0,0 +1,41 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+
+/// Errors returned by the API's handlers.
+#[derive(Debug)]
+pub enum ApiError {
+    /// Returned if the requested resource isn't in the store.
+    NotFound(String),
+
+    /// Returned if the request's parameters are out of range.
+    BadRequest(String),
+
+    /// Returned if the store can't be queried.
+    Internal(String),
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            ApiError::NotFound(error) => (StatusCode::NOT_FOUND, error),
+            ApiError::BadRequest(error) => (StatusCode::BAD_REQUEST, error),
+            ApiError::Internal(error) => (StatusCode::INTERNAL_SERVER_ERROR, error),
+        };
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
+
+impl From<soar_indexer::Error> for ApiError {
+    fn from(err: soar_indexer::Error) -> Self {
+        ApiError::Internal(err.to_string())
+    }
+}
+
+pub type ApiResult<T> = Result<T, ApiError>;
@@ -0,0 +1,10 @@
+//! Read-only HTTP/JSON API over a SOAR store kept up to date by `soar-indexer`.
+//!
+//! Serves a game's leaderboards and achievements, paginated ranked entries with player
+//! usernames, per-player score histories, unlocked achievements and reward availability.
+
+pub mod error;
+pub mod routes;
+pub mod views;
+
+pub use routes::{router, AppState};
@@ -0,0 +1,37 @@
+//! Serves the SOAR API over HTTP.
+
+use clap::Parser;
+use soar_api::{router, AppState};
+use soar_indexer::Store;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+struct Cli {
+    /// Path of the SQLite store written by `soar-indexer`.
+    #[arg(long, default_value = "soar-index.db")]
+    db: PathBuf,
+
+    /// Address to listen on.
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    listen: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    if let Err(err) = Store::open_read_only(&cli.db) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+
+    let app = router(AppState::new(cli.db));
+    println!("Listening on {}", cli.listen);
+    if let Err(err) = axum::Server::bind(&cli.listen)
+        .serve(app.into_make_service())
+        .await
+    {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
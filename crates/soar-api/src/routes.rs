@@ -0,0 +1,290 @@
+//! The API's routes.
+
+use crate::error::{ApiError, ApiResult};
+use crate::views::{
+    AchievementView, EntriesPage, GameView, LeaderboardView, PlayerView, RankedEntryView,
+    RewardView, ScoreHistoryView, StandingView, UnlockedAchievementView,
+};
+use anchor_lang::prelude::Pubkey;
+use axum::extract::{Path, Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Deserializer};
+use soar_indexer::Store;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Number of ranked entries returned when a request doesn't set a limit.
+const DEFAULT_PAGE_LIMIT: usize = 20;
+
+/// Maximum number of ranked entries returned by a single request.
+const MAX_PAGE_LIMIT: usize = 100;
+
+/// The store queried by every handler.
+#[derive(Clone)]
+pub struct AppState {
+    db: Arc<PathBuf>,
+}
+
+impl AppState {
+    /// Serve the store at `db`, which the indexer may keep writing to.
+    pub fn new(db: PathBuf) -> Self {
+        AppState { db: Arc::new(db) }
+    }
+
+    /// Run a store query off the async runtime.
+    ///
+    /// Each query opens its own read-only connection, so requests run concurrently.
+    async fn query<T, F>(&self, f: F) -> ApiResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Store) -> ApiResult<T> + Send + 'static,
+    {
+        let db = self.db.clone();
+        tokio::task::spawn_blocking(move || f(&Store::open_read_only(&*db)?))
+            .await
+            .map_err(|err| ApiError::Internal(err.to_string()))?
+    }
+}
+
+/// A base58 address path segment.
+#[derive(Clone, Copy)]
+struct Key(Pubkey);
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Pubkey::from_str(&value)
+            .map(Key)
+            .map_err(|_| serde::de::Error::custom(format!("invalid address {value}")))
+    }
+}
+
+#[derive(Deserialize)]
+struct PageQuery {
+    season: Option<u64>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct SeasonQuery {
+    season: Option<u64>,
+}
+
+/// Build the API's router over `state`.
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/games/:game", get(game))
+        .route("/games/:game/leaderboards", get(game_leaderboards))
+        .route("/games/:game/achievements", get(game_achievements))
+        .route("/leaderboards/:leaderboard", get(leaderboard))
+        .route(
+            "/leaderboards/:leaderboard/entries",
+            get(leaderboard_entries),
+        )
+        .route(
+            "/leaderboards/:leaderboard/players/:player",
+            get(leaderboard_standing),
+        )
+        .route("/players/:player", get(player))
+        .route(
+            "/players/:player/leaderboards/:leaderboard/scores",
+            get(player_scores),
+        )
+        .route("/players/:player/achievements", get(player_achievements))
+        .route("/achievements/:achievement", get(achievement))
+        .route(
+            "/achievements/:achievement/rewards",
+            get(achievement_rewards),
+        )
+        .with_state(state)
+}
+
+fn not_found(kind: &str, address: &Pubkey) -> ApiError {
+    ApiError::NotFound(format!("{kind} {address} not found"))
+}
+
+async fn game(
+    State(state): State<AppState>,
+    Path(Key(game)): Path<Key>,
+) -> ApiResult<Json<GameView>> {
+    state
+        .query(move |store| {
+            let game = store.game(&game)?.ok_or_else(|| not_found("game", &game))?;
+            Ok(Json(game.into()))
+        })
+        .await
+}
+
+async fn game_leaderboards(
+    State(state): State<AppState>,
+    Path(Key(game)): Path<Key>,
+) -> ApiResult<Json<Vec<LeaderboardView>>> {
+    state
+        .query(move |store| {
+            store.game(&game)?.ok_or_else(|| not_found("game", &game))?;
+            let leaderboards = store.leaderboards(&game)?;
+            Ok(Json(leaderboards.into_iter().map(Into::into).collect()))
+        })
+        .await
+}
+
+async fn game_achievements(
+    State(state): State<AppState>,
+    Path(Key(game)): Path<Key>,
+) -> ApiResult<Json<Vec<AchievementView>>> {
+    state
+        .query(move |store| {
+            store.game(&game)?.ok_or_else(|| not_found("game", &game))?;
+            let achievements = store.achievements(&game)?;
+            Ok(Json(achievements.into_iter().map(Into::into).collect()))
+        })
+        .await
+}
+
+async fn leaderboard(
+    State(state): State<AppState>,
+    Path(Key(leaderboard)): Path<Key>,
+) -> ApiResult<Json<LeaderboardView>> {
+    state
+        .query(move |store| {
+            let leaderboard = store
+                .leaderboard(&leaderboard)?
+                .ok_or_else(|| not_found("leaderboard", &leaderboard))?;
+            Ok(Json(leaderboard.into()))
+        })
+        .await
+}
+
+async fn leaderboard_entries(
+    State(state): State<AppState>,
+    Path(Key(leaderboard)): Path<Key>,
+    Query(page): Query<PageQuery>,
+) -> ApiResult<Json<EntriesPage>> {
+    let offset = page.offset.unwrap_or(0);
+    if i64::try_from(offset).is_err() {
+        return Err(ApiError::BadRequest(format!(
+            "offset {offset} is larger than {}",
+            i64::MAX
+        )));
+    }
+    let limit = page.limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+
+    state
+        .query(move |store| {
+            store
+                .leaderboard(&leaderboard)?
+                .ok_or_else(|| not_found("leaderboard", &leaderboard))?;
+            let entries = store.page(&leaderboard, page.season, offset, limit)?;
+            Ok(Json(EntriesPage {
+                total: store.board_size(&leaderboard, page.season)?,
+                offset,
+                entries: entries.into_iter().map(RankedEntryView::from).collect(),
+            }))
+        })
+        .await
+}
+
+async fn leaderboard_standing(
+    State(state): State<AppState>,
+    Path((Key(leaderboard), Key(player))): Path<(Key, Key)>,
+    Query(query): Query<SeasonQuery>,
+) -> ApiResult<Json<StandingView>> {
+    state
+        .query(move |store| {
+            let standing = store
+                .standing(&leaderboard, query.season, &player)?
+                .ok_or_else(|| {
+                    ApiError::NotFound(format!("player {player} has no entry on {leaderboard}"))
+                })?;
+            Ok(Json(standing.into()))
+        })
+        .await
+}
+
+async fn player(
+    State(state): State<AppState>,
+    Path(Key(player)): Path<Key>,
+) -> ApiResult<Json<PlayerView>> {
+    state
+        .query(move |store| {
+            let record = store
+                .player(&player)?
+                .ok_or_else(|| not_found("player", &player))?;
+            let registrations = store.registrations(&player)?;
+            Ok(Json(PlayerView::new(record, registrations)))
+        })
+        .await
+}
+
+async fn player_scores(
+    State(state): State<AppState>,
+    Path((Key(player), Key(leaderboard))): Path<(Key, Key)>,
+) -> ApiResult<Json<ScoreHistoryView>> {
+    state
+        .query(move |store| {
+            let registration = store
+                .registrations(&player)?
+                .into_iter()
+                .find(|registration| registration.leaderboard == leaderboard)
+                .ok_or_else(|| {
+                    ApiError::NotFound(format!("player {player} isn't registered to {leaderboard}"))
+                })?;
+            let scores = store.score_history(&player, &leaderboard)?;
+            Ok(Json(ScoreHistoryView {
+                registration: registration.into(),
+                scores: scores.into_iter().map(Into::into).collect(),
+            }))
+        })
+        .await
+}
+
+async fn player_achievements(
+    State(state): State<AppState>,
+    Path(Key(player)): Path<Key>,
+) -> ApiResult<Json<Vec<UnlockedAchievementView>>> {
+    state
+        .query(move |store| {
+            store
+                .player(&player)?
+                .ok_or_else(|| not_found("player", &player))?;
+            let mut views = vec![];
+            for unlocked in store.unlocked_achievements(&player)? {
+                let achievement = store.achievement(&unlocked.achievement)?;
+                views.push(UnlockedAchievementView::new(unlocked, achievement));
+            }
+            Ok(Json(views))
+        })
+        .await
+}
+
+async fn achievement(
+    State(state): State<AppState>,
+    Path(Key(achievement)): Path<Key>,
+) -> ApiResult<Json<AchievementView>> {
+    state
+        .query(move |store| {
+            let record = store
+                .achievement(&achievement)?
+                .ok_or_else(|| not_found("achievement", &achievement))?;
+            Ok(Json(record.into()))
+        })
+        .await
+}
+
+async fn achievement_rewards(
+    State(state): State<AppState>,
+    Path(Key(achievement)): Path<Key>,
+) -> ApiResult<Json<Vec<RewardView>>> {
+    state
+        .query(move |store| {
+            store
+                .achievement(&achievement)?
+                .ok_or_else(|| not_found("achievement", &achievement))?;
+            let rewards = store.rewards(&achievement)?;
+            Ok(Json(rewards.into_iter().map(Into::into).collect()))
+        })
+        .await
+}
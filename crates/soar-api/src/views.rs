@@ -0,0 +1,303 @@
+//! JSON representations of indexed SOAR state.
+//!
+//! Addresses are rendered as base58 strings. Scores, tiebreak values and token amounts are
+//! rendered as decimal strings, since JSON numbers can't hold every `u64` exactly.
+
+use serde::{Serialize, Serializer};
+use soar::{ScoreEntry, TieBreak};
+use soar_indexer::{
+    AchievementRecord, GameRecord, LeaderboardRecord, PlayerAchievementRecord, PlayerRecord,
+    PlayerStanding, RankedEntry, RegistrationRecord, RewardPayout, RewardRecord,
+};
+
+#[derive(Serialize)]
+pub struct GameView {
+    pub address: String,
+    pub title: String,
+    pub description: String,
+    pub genre: u8,
+    pub game_type: u8,
+    pub nft_meta: String,
+    pub leaderboard_count: u64,
+    pub achievement_count: u64,
+    pub paused: bool,
+}
+
+impl From<GameRecord> for GameView {
+    fn from(game: GameRecord) -> Self {
+        GameView {
+            address: game.address.to_string(),
+            title: game.title,
+            description: game.description,
+            genre: game.genre,
+            game_type: game.game_type,
+            nft_meta: game.nft_meta.to_string(),
+            leaderboard_count: game.leaderboard_count,
+            achievement_count: game.achievement_count,
+            paused: game.paused,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LeaderboardView {
+    pub address: String,
+    pub game: String,
+    pub id: u64,
+    pub description: String,
+    pub nft_meta: String,
+    pub decimals: u8,
+    #[serde(serialize_with = "string")]
+    pub min_score: u64,
+    #[serde(serialize_with = "string")]
+    pub max_score: u64,
+    pub is_ascending: bool,
+    pub allow_multiple_scores: bool,
+    pub tie_break: &'static str,
+    pub top_entries: Option<String>,
+    pub paused: bool,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub season_length: Option<i64>,
+}
+
+impl From<LeaderboardRecord> for LeaderboardView {
+    fn from(leaderboard: LeaderboardRecord) -> Self {
+        LeaderboardView {
+            address: leaderboard.address.to_string(),
+            game: leaderboard.game.to_string(),
+            id: leaderboard.id,
+            description: leaderboard.description,
+            nft_meta: leaderboard.nft_meta.to_string(),
+            decimals: leaderboard.decimals,
+            min_score: leaderboard.min_score,
+            max_score: leaderboard.max_score,
+            is_ascending: leaderboard.is_ascending,
+            allow_multiple_scores: leaderboard.allow_multiple_scores,
+            tie_break: match leaderboard.tie_break {
+                TieBreak::EarliestFirst => "earliest_first",
+                TieBreak::LatestFirst => "latest_first",
+                TieBreak::HighestValue => "highest_value",
+                TieBreak::LowestValue => "lowest_value",
+            },
+            top_entries: leaderboard.top_entries.map(|key| key.to_string()),
+            paused: leaderboard.paused,
+            start_time: leaderboard.start_time,
+            end_time: leaderboard.end_time,
+            season_length: leaderboard.season_length,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ScoreView {
+    #[serde(serialize_with = "string")]
+    pub score: u64,
+    pub timestamp: i64,
+    #[serde(serialize_with = "string")]
+    pub tiebreak: u64,
+}
+
+impl From<ScoreEntry> for ScoreView {
+    fn from(entry: ScoreEntry) -> Self {
+        ScoreView {
+            score: entry.score,
+            timestamp: entry.timestamp,
+            tiebreak: entry.tiebreak,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RankedEntryView {
+    pub rank: u64,
+    pub player: String,
+    pub username: Option<String>,
+    #[serde(flatten)]
+    pub entry: ScoreView,
+}
+
+impl From<RankedEntry> for RankedEntryView {
+    fn from(entry: RankedEntry) -> Self {
+        RankedEntryView {
+            rank: entry.rank,
+            player: entry.player.to_string(),
+            username: entry.username,
+            entry: entry.entry.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct EntriesPage {
+    pub total: u64,
+    pub offset: usize,
+    pub entries: Vec<RankedEntryView>,
+}
+
+#[derive(Serialize)]
+pub struct StandingView {
+    pub total: u64,
+    pub percentile: f64,
+    #[serde(flatten)]
+    pub entry: RankedEntryView,
+}
+
+impl From<PlayerStanding> for StandingView {
+    fn from(standing: PlayerStanding) -> Self {
+        StandingView {
+            total: standing.total,
+            percentile: standing.percentile,
+            entry: standing.entry.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RegistrationView {
+    pub leaderboard: String,
+    pub player_scores: String,
+    pub banned: bool,
+}
+
+impl From<RegistrationRecord> for RegistrationView {
+    fn from(registration: RegistrationRecord) -> Self {
+        RegistrationView {
+            leaderboard: registration.leaderboard.to_string(),
+            player_scores: registration.address.to_string(),
+            banned: registration.banned,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PlayerView {
+    pub address: String,
+    pub user: String,
+    pub username: String,
+    pub nft_meta: String,
+    pub leaderboards: Vec<RegistrationView>,
+}
+
+impl PlayerView {
+    pub fn new(player: PlayerRecord, registrations: Vec<RegistrationRecord>) -> Self {
+        PlayerView {
+            address: player.address.to_string(),
+            user: player.user.to_string(),
+            username: player.username,
+            nft_meta: player.nft_meta.to_string(),
+            leaderboards: registrations.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ScoreHistoryView {
+    #[serde(flatten)]
+    pub registration: RegistrationView,
+    pub scores: Vec<ScoreView>,
+}
+
+#[derive(Serialize)]
+pub struct AchievementView {
+    pub address: String,
+    pub game: String,
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub nft_meta: String,
+    pub rewards: Vec<String>,
+}
+
+impl From<AchievementRecord> for AchievementView {
+    fn from(achievement: AchievementRecord) -> Self {
+        AchievementView {
+            address: achievement.address.to_string(),
+            game: achievement.game.to_string(),
+            id: achievement.id,
+            title: achievement.title,
+            description: achievement.description,
+            nft_meta: achievement.nft_meta.to_string(),
+            rewards: achievement
+                .rewards
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct UnlockedAchievementView {
+    pub address: String,
+    pub achievement: String,
+    pub title: Option<String>,
+    pub timestamp: i64,
+    pub claimed: bool,
+}
+
+impl UnlockedAchievementView {
+    pub fn new(unlocked: PlayerAchievementRecord, achievement: Option<AchievementRecord>) -> Self {
+        UnlockedAchievementView {
+            address: unlocked.address.to_string(),
+            achievement: unlocked.achievement.to_string(),
+            title: achievement.map(|achievement| achievement.title),
+            timestamp: unlocked.timestamp,
+            claimed: unlocked.claimed,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PayoutView {
+    FungibleToken {
+        mint: String,
+        #[serde(serialize_with = "string")]
+        amount: u64,
+        token_program: String,
+    },
+    NonFungibleToken {
+        uri: String,
+    },
+}
+
+#[derive(Serialize)]
+pub struct RewardView {
+    pub address: String,
+    pub achievement: String,
+    pub available_spots: u64,
+    pub paused: bool,
+    pub available: bool,
+    #[serde(flatten)]
+    pub payout: PayoutView,
+}
+
+impl From<RewardRecord> for RewardView {
+    fn from(reward: RewardRecord) -> Self {
+        RewardView {
+            address: reward.address.to_string(),
+            achievement: reward.achievement.to_string(),
+            available_spots: reward.available_spots,
+            paused: reward.paused,
+            available: reward.available_spots > 0 && !reward.paused,
+            payout: match reward.payout {
+                RewardPayout::FungibleToken {
+                    mint,
+                    amount,
+                    token_program,
+                } => PayoutView::FungibleToken {
+                    mint: mint.to_string(),
+                    amount,
+                    token_program: token_program.to_string(),
+                },
+                RewardPayout::NonFungibleToken { uri } => PayoutView::NonFungibleToken { uri },
+            },
+        }
+    }
+}
+
+/// Serialize a `u64` as a decimal string.
+fn string<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
@@ -0,0 +1,196 @@
+//! Requests against a store loaded with the indexer's board fixtures.
+
+use anchor_lang::prelude::Pubkey;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use soar_api::{router, AppState};
+use soar_indexer::{load_fixtures, Store};
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+const FIXTURES: &str = "../soar-indexer/tests/fixtures/board";
+
+fn player(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n + 1; 32])
+}
+
+fn leaderboard_a() -> Pubkey {
+    Pubkey::new_from_array([10; 32])
+}
+
+fn leaderboard_c() -> Pubkey {
+    Pubkey::new_from_array([12; 32])
+}
+
+/// A directory holding the loaded store, removed once dropped.
+fn store() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Store::open(dir.path().join("soar-index.db")).unwrap();
+    load_fixtures(&store, FIXTURES).unwrap();
+    dir
+}
+
+async fn get(dir: &TempDir, uri: &str) -> (StatusCode, Value) {
+    let app = router(AppState::new(dir.path().join("soar-index.db")));
+    let response = app
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn serves_leaderboards_with_u64_strings() {
+    let dir = store();
+
+    let (status, body) = get(&dir, &format!("/leaderboards/{}", leaderboard_c())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["max_score"], json!(u64::MAX.to_string()));
+    assert_eq!(body["min_score"], json!("0"));
+    assert_eq!(body["start_time"], json!(1000));
+    assert_eq!(body["season_length"], json!(100));
+}
+
+#[tokio::test]
+async fn pages_ranked_entries() {
+    let dir = store();
+
+    let (status, body) = get(
+        &dir,
+        &format!("/leaderboards/{}/entries?offset=1&limit=2", leaderboard_a()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total"], json!(4));
+    assert_eq!(body["offset"], json!(1));
+    assert_eq!(
+        body["entries"],
+        json!([
+            {
+                "rank": 2,
+                "player": player(2).to_string(),
+                "username": "player2",
+                "score": "300",
+                "timestamp": 15,
+                "tiebreak": "0",
+            },
+            {
+                "rank": 3,
+                "player": player(1).to_string(),
+                "username": "player1",
+                "score": "300",
+                "timestamp": 20,
+                "tiebreak": "0",
+            },
+        ])
+    );
+
+    let (_, body) = get(&dir, &format!("/leaderboards/{}/entries", leaderboard_a())).await;
+    assert_eq!(
+        body["entries"][0]["score"],
+        json!((u64::MAX - 1).to_string())
+    );
+}
+
+#[tokio::test]
+async fn ranks_a_season() {
+    let dir = store();
+
+    let (status, body) = get(
+        &dir,
+        &format!("/leaderboards/{}/entries?season=1", leaderboard_c()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total"], json!(2));
+    assert_eq!(body["entries"][0]["player"], json!(player(2).to_string()));
+
+    let (status, body) = get(
+        &dir,
+        &format!(
+            "/leaderboards/{}/players/{}?season=0",
+            leaderboard_c(),
+            player(1)
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["rank"], json!(1));
+    assert_eq!(body["total"], json!(2));
+}
+
+#[tokio::test]
+async fn serves_standings() {
+    let dir = store();
+
+    let (status, body) = get(
+        &dir,
+        &format!("/leaderboards/{}/players/{}", leaderboard_a(), player(1)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["rank"], json!(3));
+    assert_eq!(body["total"], json!(4));
+    assert_eq!(body["percentile"], json!(25.0));
+
+    // Player 5 is banned from the leaderboard.
+    let (status, body) = get(
+        &dir,
+        &format!("/leaderboards/{}/players/{}", leaderboard_a(), player(5)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body["error"].is_string());
+}
+
+#[tokio::test]
+async fn serves_score_histories() {
+    let dir = store();
+
+    let (status, body) = get(
+        &dir,
+        &format!(
+            "/players/{}/leaderboards/{}/scores",
+            player(4),
+            leaderboard_a()
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["scores"],
+        json!([
+            { "score": "50", "timestamp": 5, "tiebreak": "0" },
+            { "score": "50", "timestamp": 5, "tiebreak": "0" },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn rejects_unknown_and_invalid_addresses() {
+    let dir = store();
+
+    let (status, _) = get(&dir, &format!("/leaderboards/{}", Pubkey::default())).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = get(&dir, "/leaderboards/not-an-address").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn rejects_offsets_past_the_largest_rank() {
+    let dir = store();
+    let entries = format!("/leaderboards/{}/entries", leaderboard_a());
+
+    let (status, body) = get(&dir, &format!("{entries}?offset={}", i64::MAX)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total"], json!(4));
+    assert_eq!(body["entries"], json!([]));
+
+    let (status, body) = get(&dir, &format!("{entries}?offset={}", i64::MAX as u64 + 1)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].as_str().unwrap().contains("offset"));
+}
@@ -0,0 +1,124 @@
+//! Helpers for fetching and decoding SOAR accounts.
+
+use crate::{Error, Result};
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+
+pub use soar::{
+    Achievement, Game, LeaderBoard, LeaderBoardScore, LeaderTopEntries, Merged, NftClaim, Player,
+    PlayerAchievement, PlayerBan, PlayerScoresList, RankReward, RankRewardClaim, Reward,
+    SeasonTopEntries,
+};
+
+/// Decode raw account data into a SOAR account of type `T`.
+///
+/// Fails if the data doesn't start with `T`'s discriminator.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    Ok(T::try_deserialize(&mut &data[..])?)
+}
+
+/// Fetch and decode the SOAR account of type `T` at `address`.
+pub fn fetch<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<T> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())?
+        .value
+        .ok_or(Error::AccountNotFound(*address))?;
+    if account.owner != soar::ID {
+        return Err(Error::InvalidOwner(*address));
+    }
+
+    decode(&account.data)
+}
+
+/// Decode a zero-copy [LeaderTopEntries] account along with its allocated score slots,
+/// ordered from best to worst.
+pub fn decode_top_entries(data: &[u8]) -> Result<(LeaderTopEntries, Vec<LeaderBoardScore>)> {
+    let header = data
+        .get(8..LeaderTopEntries::SCORES_OFFSET)
+        .filter(|_| data[..8] == LeaderTopEntries::DISCRIMINATOR)
+        .ok_or(anchor_lang::error::Error::from(
+            ErrorCode::AccountDiscriminatorMismatch,
+        ))?;
+    let header: LeaderTopEntries = bytemuck::pod_read_unaligned(header);
+
+    let len = header.capacity as usize * LeaderBoardScore::SIZE;
+    let scores = data
+        .get(LeaderTopEntries::SCORES_OFFSET..)
+        .and_then(|slots| slots.get(..len))
+        .ok_or(anchor_lang::error::Error::from(
+            ErrorCode::AccountDidNotDeserialize,
+        ))?
+        .chunks_exact(LeaderBoardScore::SIZE)
+        .map(bytemuck::pod_read_unaligned)
+        .collect();
+
+    Ok((header, scores))
+}
+
+/// Fetch a [LeaderTopEntries] account along with its allocated score slots.
+pub fn fetch_top_entries(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> Result<(LeaderTopEntries, Vec<LeaderBoardScore>)> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())?
+        .value
+        .ok_or(Error::AccountNotFound(*address))?;
+    if account.owner != soar::ID {
+        return Err(Error::InvalidOwner(*address));
+    }
+
+    decode_top_entries(&account.data)
+}
+
+/// Fetch and decode multiple accounts of type `T`.
+///
+/// Returns [None] in place of accounts that don't exist.
+pub fn fetch_multiple<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    addresses: &[Pubkey],
+) -> Result<Vec<Option<T>>> {
+    rpc.get_multiple_accounts(addresses)?
+        .into_iter()
+        .zip(addresses)
+        .map(|(account, address)| match account {
+            Some(account) if account.owner != soar::ID => Err(Error::InvalidOwner(*address)),
+            Some(account) => decode(&account.data).map(Some),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+/// Fetch every account of type `T` owned by the SOAR program.
+///
+/// `filters` are applied in addition to a discriminator filter for `T`.
+pub fn fetch_all<T: AccountDeserialize + Discriminator>(
+    rpc: &RpcClient,
+    filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, T)>> {
+    let mut all_filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        0,
+        &T::DISCRIMINATOR,
+    ))];
+    all_filters.extend(filters);
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(all_filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    rpc.get_program_accounts_with_config(&soar::ID, config)?
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode(&account.data)?)))
+        .collect()
+}
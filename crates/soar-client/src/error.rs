@@ -0,0 +1,32 @@
+use anchor_lang::prelude::Pubkey;
+use solana_client::client_error::ClientError;
+
+/// Errors returned by this crate.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Returned if an RPC request fails.
+    #[error("rpc error: {0}")]
+    Rpc(#[from] ClientError),
+
+    /// Returned if an account's data can't be deserialized into the expected type.
+    #[error("failed to decode account: {0}")]
+    Decode(#[from] anchor_lang::error::Error),
+
+    /// Returned if an account doesn't exist.
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+
+    /// Returned if an account exists but isn't owned by the SOAR program.
+    #[error("account {0} is not owned by the SOAR program")]
+    InvalidOwner(Pubkey),
+
+    /// Returned if an event's data can't be deserialized.
+    #[error("failed to decode event: {0}")]
+    Event(std::io::Error),
+
+    /// Returned if a transaction isn't in an encoding events can be decoded from.
+    #[error("unsupported transaction encoding")]
+    UnsupportedEncoding,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,168 @@
+//! Decoders for the events emitted by the SOAR program.
+//!
+//! Events are emitted through a self-invocation signed by the program's event authority, so
+//! they appear as inner instructions of the SOAR program rather than in the logs.
+
+use crate::{Error, Result};
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInstruction, UiLoadedAddresses,
+    UiTransactionEncoding,
+};
+use std::str::FromStr;
+
+pub use soar::events::*;
+
+macro_rules! soar_events {
+    ($($event:ident),* $(,)?) => {
+        /// Any event emitted by the SOAR program.
+        #[derive(Debug)]
+        pub enum SoarEvent {
+            $($event($event),)*
+        }
+
+        /// Decode an event from the data of a SOAR event instruction.
+        ///
+        /// Returns [None] if `data` isn't an event instruction or holds an unknown event.
+        pub fn decode_event(data: &[u8]) -> Result<Option<SoarEvent>> {
+            let data = match data.strip_prefix(&EVENT_IX_TAG_LE[..]) {
+                Some(data) if data.len() >= 8 => data,
+                _ => return Ok(None),
+            };
+            let (discriminator, mut payload) = data.split_at(8);
+
+            $(
+                if discriminator == $event::DISCRIMINATOR {
+                    let event = $event::deserialize(&mut payload).map_err(Error::Event)?;
+                    return Ok(Some(SoarEvent::$event(event)));
+                }
+            )*
+            Ok(None)
+        }
+    };
+}
+
+soar_events!(
+    GameInitialized,
+    GameUpdated,
+    GamePauseUpdated,
+    AuthorityProposed,
+    AuthorityAccepted,
+    AuthorityProposalCancelled,
+    OwnershipTransferProposed,
+    OwnershipTransferred,
+    AchievementAdded,
+    AchievementUpdated,
+    LeaderboardAdded,
+    LeaderboardUpdated,
+    LeaderboardPauseUpdated,
+    SeasonArchived,
+    TopEntriesGrown,
+    TopEntriesRebuilt,
+    TopEntriesAdded,
+    TopEntriesResized,
+    PlayerInitialized,
+    PlayerUpdated,
+    PlayerRegistered,
+    ScoreSubmitted,
+    ScoreRemoved,
+    PlayerEvicted,
+    PlayerBanUpdated,
+    MergeInitiated,
+    MergeApproved,
+    PlayerScoresMerged,
+    PlayerAchievementMerged,
+    AchievementUnlocked,
+    RewardAdded,
+    RewardFunded,
+    RewardWithdrawn,
+    RewardUpdated,
+    RewardPauseUpdated,
+    RewardCancelled,
+    FtRewardClaimed,
+    NftRewardClaimed,
+    NftRewardVerified,
+    RankRewardAdded,
+    FtRankRewardClaimed,
+    NftRankRewardClaimed,
+    GameClosed,
+    LeaderboardClosed,
+    SeasonTopEntriesClosed,
+    AchievementClosed,
+    RewardClosed,
+    RankRewardClosed,
+    PlayerClosed,
+    PlayerScoresClosed,
+    PlayerAchievementClosed,
+    MergedClosed,
+    NftClaimClosed,
+    RewardClaimClosed,
+    RankRewardClaimClosed,
+);
+
+/// Decode every SOAR event emitted by a confirmed transaction, in emission order.
+///
+/// The transaction must be fetched with a binary encoding so its account keys can be resolved.
+pub fn decode_transaction_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<Vec<SoarEvent>> {
+    let transaction = tx
+        .transaction
+        .transaction
+        .decode()
+        .ok_or(Error::UnsupportedEncoding)?;
+    let meta = match &tx.transaction.meta {
+        Some(meta) => meta,
+        None => return Ok(vec![]),
+    };
+
+    // Instructions refer to programs by index into the static keys followed by the keys
+    // loaded from lookup tables.
+    let mut account_keys = transaction.message.static_account_keys().to_vec();
+    let loaded: Option<&UiLoadedAddresses> = meta.loaded_addresses.as_ref().into();
+    if let Some(loaded) = loaded {
+        for key in loaded.writable.iter().chain(&loaded.readonly) {
+            account_keys.push(Pubkey::from_str(key).map_err(|_| Error::UnsupportedEncoding)?);
+        }
+    }
+
+    let inner_instructions: Option<&Vec<_>> = meta.inner_instructions.as_ref().into();
+    let mut events = vec![];
+    for instruction in inner_instructions.into_iter().flatten() {
+        for instruction in &instruction.instructions {
+            let compiled = match instruction {
+                UiInstruction::Compiled(compiled) => compiled,
+                UiInstruction::Parsed(_) => return Err(Error::UnsupportedEncoding),
+            };
+            if account_keys.get(compiled.program_id_index as usize) != Some(&soar::ID) {
+                continue;
+            }
+
+            let data = bs58::decode(&compiled.data)
+                .into_vec()
+                .map_err(|_| Error::UnsupportedEncoding)?;
+            if let Some(event) = decode_event(&data)? {
+                events.push(event);
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// Fetch a confirmed transaction and decode the SOAR events it emitted.
+pub fn fetch_transaction_events(rpc: &RpcClient, signature: &Signature) -> Result<Vec<SoarEvent>> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(rpc.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    let tx = rpc.get_transaction_with_config(signature, config)?;
+
+    decode_transaction_events(&tx)
+}
@@ -0,0 +1,2021 @@
+//! Typed instruction builders for the SOAR program.
+//!
+//! Builders derive every PDA an instruction needs from the keys passed in, so callers
+//! only supply signers and the non-derivable accounts.
+
+use crate::pda;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::{AccountMeta, Instruction},
+    system_program, sysvar,
+};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use soar::{
+    accounts, instruction, AddNewRewardInput, AddRankRewardInput, Authority, GameAttributes,
+    RegisterLeaderBoardInput, ScoreRetention,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: soar::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Read-only metas for the scores lists of `player_accounts` on `leaderboard`, each followed
+/// by the player's ban address.
+fn scores_list_metas<'a>(
+    leaderboard: &'a Pubkey,
+    player_accounts: &'a [Pubkey],
+) -> impl Iterator<Item = AccountMeta> + 'a {
+    player_accounts.iter().flat_map(move |player_account| {
+        [
+            AccountMeta::new_readonly(
+                pda::find_player_scores_list_address(player_account, leaderboard).0,
+                false,
+            ),
+            AccountMeta::new_readonly(
+                pda::find_player_ban_address(leaderboard, player_account).0,
+                false,
+            ),
+        ]
+    })
+}
+
+/// Accounts paying out a fungible token reward to `user`'s associated token account.
+fn ft_claim_accounts(
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> accounts::ClaimFtRewardAccounts {
+    let player_account = pda::find_player_address(user).0;
+
+    accounts::ClaimFtRewardAccounts {
+        user: *user,
+        payer: *payer,
+        game: *game,
+        achievement: *achievement,
+        reward: *reward,
+        player_account,
+        player_achievement: pda::find_player_achievement_address(&player_account, achievement).0,
+        reward_claim: pda::find_reward_claim_address(reward, &player_account).0,
+        reward_token_mint: *mint,
+        reward_vault: pda::find_reward_vault_address(reward).0,
+        user_token_account: pda::associated_token_address_with_program(user, mint, token_program),
+        token_program: *token_program,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+/// Accounts minting an nft reward to `user` as `new_mint`.
+fn nft_claim_accounts(
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    new_mint: &Pubkey,
+) -> accounts::ClaimNftRewardAccounts {
+    let player_account = pda::find_player_address(user).0;
+
+    accounts::ClaimNftRewardAccounts {
+        user: *user,
+        payer: *payer,
+        game: *game,
+        achievement: *achievement,
+        reward: *reward,
+        player_account,
+        player_achievement: pda::find_player_achievement_address(&player_account, achievement).0,
+        reward_claim: pda::find_reward_claim_address(reward, &player_account).0,
+        claim: pda::find_nft_claim_address(reward, new_mint).0,
+        new_mint: *new_mint,
+        new_metadata: pda::find_metadata_address(new_mint).0,
+        new_master_edition: pda::find_master_edition_address(new_mint).0,
+        mint_to: pda::associated_token_address(user, new_mint),
+        token_metadata_program: mpl_token_metadata::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        system_program: system_program::ID,
+        token_program: anchor_spl::token::ID,
+        rent: sysvar::rent::ID,
+    }
+}
+
+/// Initialize a new [Game][soar::Game] at the address derived from `creator` and `nonce`.
+///
+/// `game_auth` must grant [Authority::ADMIN] to at least one key.
+pub fn initialize_game(
+    creator: &Pubkey,
+    nonce: u64,
+    game_meta: GameAttributes,
+    game_auth: Vec<Authority>,
+) -> Instruction {
+    let game = pda::find_game_address(creator, nonce).0;
+    build(
+        accounts::InitializeGame {
+            creator: *creator,
+            game,
+            closed_game: pda::find_closed_game_address(&game).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::InitializeGame {
+            game_meta,
+            game_auth,
+            nonce,
+        },
+    )
+}
+
+/// Update a game's meta-information or authority list.
+pub fn update_game(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    new_meta: Option<GameAttributes>,
+    new_auth: Option<Vec<Authority>>,
+) -> Instruction {
+    build(
+        accounts::UpdateGame {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UpdateGame { new_meta, new_auth },
+    )
+}
+
+/// Propose adding `new_authority` to a game, or swapping it in for the existing authority
+/// `replaces`. The change can be accepted `timelock` seconds from now.
+pub fn propose_authority(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    new_authority: Authority,
+    replaces: Option<Pubkey>,
+    timelock: i64,
+) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            proposal: pda::find_authority_proposal_address(game, &new_authority.key).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ProposeAuthority {
+            authority: new_authority,
+            replaces,
+            timelock,
+        },
+    )
+}
+
+/// Accept a proposal naming `new_authority`, refunding its rent to `proposer`.
+pub fn accept_authority(new_authority: &Pubkey, game: &Pubkey, proposer: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAuthority {
+            new_authority: *new_authority,
+            game: *game,
+            proposal: pda::find_authority_proposal_address(game, new_authority).0,
+            proposer: *proposer,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+/// Withdraw the proposal naming `proposed`, refunding its rent to `proposer`.
+pub fn cancel_authority_proposal(
+    authority: &Pubkey,
+    game: &Pubkey,
+    proposed: &Pubkey,
+    proposer: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CancelAuthorityProposal {
+            authority: *authority,
+            game: *game,
+            proposal: pda::find_authority_proposal_address(game, proposed).0,
+            proposer: *proposer,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CancelAuthorityProposal {},
+    )
+}
+
+/// Start transferring a game's ownership to `new_owner`, or withdraw a pending transfer.
+pub fn transfer_game_ownership(
+    owner: &Pubkey,
+    game: &Pubkey,
+    new_owner: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::TransferGameOwnership {
+            owner: *owner,
+            game: *game,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::TransferGameOwnership { new_owner },
+    )
+}
+
+/// Accept ownership of a game as its pending owner.
+pub fn accept_game_ownership(new_owner: &Pubkey, game: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptGameOwnership {
+            new_owner: *new_owner,
+            game: *game,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AcceptGameOwnership {},
+    )
+}
+
+/// Pause or resume a game.
+pub fn set_game_paused(authority: &Pubkey, game: &Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::SetGamePaused {
+            authority: *authority,
+            game: *game,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::SetGamePaused { paused },
+    )
+}
+
+/// Add an achievement to a game.
+///
+/// `id` must be the game's next achievement id, i.e `game.next_achievement()`.
+pub fn add_achievement(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    id: u64,
+    title: String,
+    description: String,
+    nft_meta: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AddAchievement {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            new_achievement: pda::find_achievement_address(game, id).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddAchievement {
+            title,
+            description,
+            nft_meta,
+        },
+    )
+}
+
+/// Update an achievement's meta information.
+pub fn update_achievement(
+    authority: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    new_title: Option<String>,
+    new_description: Option<String>,
+    nft_meta: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::UpdateAchievement {
+            authority: *authority,
+            game: *game,
+            achievement: *achievement,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UpdateAchievement {
+            new_title,
+            new_description,
+            nft_meta,
+        },
+    )
+}
+
+/// Add a leaderboard to a game.
+///
+/// `id` must be the game's next leaderboard id, i.e `game.next_leaderboard()`. A top-entries
+/// account is derived and included only if `input.scores_to_retain` is non-zero.
+pub fn add_leaderboard(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    id: u64,
+    input: RegisterLeaderBoardInput,
+) -> Instruction {
+    let leaderboard = pda::find_leaderboard_address(game, id).0;
+    let top_entries = if input.scores_to_retain > 0 {
+        Some(pda::find_leader_top_entries_address(&leaderboard).0)
+    } else {
+        None
+    };
+
+    build(
+        accounts::AddLeaderBoard {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            leaderboard,
+            top_entries,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddLeaderboard { input },
+    )
+}
+
+/// Allocate more of a leaderboard's top-entries slots, up to its `scores_to_retain`.
+///
+/// Repeat until the account is fully allocated. Lists of up to about 200 scores are fully
+/// allocated on creation.
+pub fn grow_top_entries(payer: &Pubkey, leaderboard: &Pubkey) -> Instruction {
+    build(
+        accounts::GrowTopEntries {
+            payer: *payer,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::GrowTopEntries {},
+    )
+}
+
+/// Recompute a leaderboard's top entries from the scores lists of `player_accounts`.
+///
+/// Set `reset` to clear the top entries first. Boards with more players than fit in one
+/// transaction are rebuilt in batches, resetting only in the first.
+pub fn rebuild_top_entries(
+    authority: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    player_accounts: &[Pubkey],
+    reset: bool,
+) -> Instruction {
+    let mut ix = build(
+        accounts::RebuildTopEntries {
+            authority: *authority,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::RebuildTopEntries { reset },
+    );
+    ix.accounts
+        .extend(scores_list_metas(leaderboard, player_accounts));
+    ix
+}
+
+/// Create the top entries of a leaderboard that was added without them.
+///
+/// The scores lists of `player_accounts` are ranked into the new top entries.
+pub fn add_top_entries(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    player_accounts: &[Pubkey],
+    scores_to_retain: u16,
+) -> Instruction {
+    let mut ix = build(
+        accounts::AddTopEntries {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddTopEntries { scores_to_retain },
+    );
+    ix.accounts
+        .extend(scores_list_metas(leaderboard, player_accounts));
+    ix
+}
+
+/// Change the number of scores a leaderboard's top entries retain.
+///
+/// When growing, the scores lists of `player_accounts` fill the new slots.
+pub fn resize_top_entries(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    player_accounts: &[Pubkey],
+    scores_to_retain: u16,
+) -> Instruction {
+    let mut ix = build(
+        accounts::ResizeTopEntries {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ResizeTopEntries { scores_to_retain },
+    );
+    ix.accounts
+        .extend(scores_list_metas(leaderboard, player_accounts));
+    ix
+}
+
+/// Update a leaderboard's configuration.
+///
+/// `top_entries` should be the leaderboard's top-entries account, if it has one. It's
+/// required when the update re-ranks the top entries.
+#[allow(clippy::too_many_arguments)]
+pub fn update_leaderboard(
+    authority: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+    new_description: Option<String>,
+    new_nft_meta: Option<Pubkey>,
+    new_min_score: Option<u64>,
+    new_max_score: Option<u64>,
+    new_is_ascending: Option<bool>,
+    new_allow_multiple_scores: Option<bool>,
+    new_retention: Option<ScoreRetention>,
+) -> Instruction {
+    build(
+        accounts::UpdateLeaderBoard {
+            authority: *authority,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UpdateLeaderboard {
+            new_description,
+            new_nft_meta,
+            new_min_score,
+            new_max_score,
+            new_is_ascending,
+            new_allow_multiple_scores,
+            new_retention,
+        },
+    )
+}
+
+/// Pause or resume score submissions and registrations for a leaderboard.
+pub fn set_leaderboard_paused(
+    authority: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    paused: bool,
+) -> Instruction {
+    build(
+        accounts::SetLeaderboardPaused {
+            authority: *authority,
+            game: *game,
+            leaderboard: *leaderboard,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::SetLeaderboardPaused { paused },
+    )
+}
+
+/// Archive a leaderboard's top entries for the season they were recorded in.
+///
+/// `season` must be the top-entries account's current `season`.
+pub fn archive_season(payer: &Pubkey, leaderboard: &Pubkey, season: u64) -> Instruction {
+    build(
+        accounts::ArchiveSeason {
+            payer: *payer,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            archive: pda::find_season_top_entries_address(leaderboard, season).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ArchiveSeason {},
+    )
+}
+
+/// Create the [Player][soar::Player] account for `user`.
+pub fn initialize_player(
+    payer: &Pubkey,
+    user: &Pubkey,
+    username: String,
+    nft_meta: Pubkey,
+) -> Instruction {
+    build(
+        accounts::InitializePlayer {
+            payer: *payer,
+            user: *user,
+            player_account: pda::find_player_address(user).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::InitializePlayer { username, nft_meta },
+    )
+}
+
+/// Update the username or nft_meta of `user`'s player account.
+pub fn update_player(
+    user: &Pubkey,
+    username: Option<String>,
+    nft_meta: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::UpdatePlayer {
+            user: *user,
+            player_account: pda::find_player_address(user).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UpdatePlayer { username, nft_meta },
+    )
+}
+
+/// Register `user`'s player account for a leaderboard.
+pub fn register_player(
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::RegisterPlayer {
+            payer: *payer,
+            user: *user,
+            player_account,
+            game: *game,
+            leaderboard: *leaderboard,
+            new_list: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            player_ban: pda::find_player_ban_address(leaderboard, &player_account).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::RegisterPlayer {},
+    )
+}
+
+/// Submit a score for `user` to a leaderboard.
+///
+/// `top_entries` should be the leaderboard's top-entries account, if it has one.
+pub fn submit_score(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+    score: u64,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::SubmitScore {
+            payer: *payer,
+            authority: *authority,
+            player_account,
+            game: *game,
+            leaderboard: *leaderboard,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            player_ban: pda::find_player_ban_address(leaderboard, &player_account).0,
+            top_entries,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::SubmitScore { score },
+    )
+}
+
+/// Submit a score for `user` with a secondary `tiebreak` value used to rank it against
+/// equal scores.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_score_with_tiebreak(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+    score: u64,
+    tiebreak: u64,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::SubmitScore {
+            payer: *payer,
+            authority: *authority,
+            player_account,
+            game: *game,
+            leaderboard: *leaderboard,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            player_ban: pda::find_player_ban_address(leaderboard, &player_account).0,
+            top_entries,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::SubmitScoreWithTiebreak { score, tiebreak },
+    )
+}
+
+/// Submit a score signed for and paid by `user`, attested by a game authority.
+///
+/// Must be preceded in the same transaction by an [ed25519_verify] instruction over the
+/// matching [ScoreAttestation][soar::ScoreAttestation] message.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_attested_score(
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+    score: u64,
+    tiebreak: u64,
+    nonce: u64,
+    issued_at: i64,
+    expiry: i64,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::SubmitAttestedScore {
+            user: *user,
+            player_account,
+            game: *game,
+            leaderboard: *leaderboard,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            player_ban: pda::find_player_ban_address(leaderboard, &player_account).0,
+            top_entries,
+            instructions: sysvar::instructions::ID,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::SubmitAttestedScore {
+            score,
+            tiebreak,
+            nonce,
+            issued_at,
+            expiry,
+        },
+    )
+}
+
+/// Remove the score at `index` from `user`'s scores list for a leaderboard, evicting it from
+/// `top_entries` if given.
+pub fn remove_player_score(
+    authority: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+    index: u32,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::RemovePlayerScore {
+            authority: *authority,
+            game: *game,
+            leaderboard: *leaderboard,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            top_entries,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::RemovePlayerScore { index },
+    )
+}
+
+/// Remove every top entry held by `user` from a leaderboard.
+pub fn evict_player(
+    authority: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::EvictPlayer {
+            authority: *authority,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            player_account: pda::find_player_address(user).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::EvictPlayer {},
+    )
+}
+
+/// Ban `user` from registering for or submitting scores to a leaderboard.
+pub fn ban_player(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::BanPlayer {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            player_account,
+            ban: pda::find_player_ban_address(leaderboard, &player_account).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::BanPlayer {},
+    )
+}
+
+/// Lift `user`'s ban from a leaderboard, sending the ban account's lamports to `receiver`.
+pub fn unban_player(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::UnbanPlayer {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            leaderboard: *leaderboard,
+            ban: pda::find_player_ban_address(leaderboard, &player_account).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UnbanPlayer {},
+    )
+}
+
+/// Build an ed25519 program instruction verifying `signature` by `signer` over `message`.
+///
+/// The signature, pubkey and message are all embedded in the instruction's data, which is
+/// the layout the program expects when checking attestations.
+pub fn ed25519_verify(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    const DATA_START: u16 = 16;
+    let pubkey_offset = DATA_START;
+    let signature_offset = pubkey_offset + 32;
+    let message_offset = signature_offset + 64;
+
+    let mut data = Vec::with_capacity(message_offset as usize + message.len());
+    data.extend_from_slice(&[1, 0]);
+    for value in [
+        signature_offset,
+        u16::MAX,
+        pubkey_offset,
+        u16::MAX,
+        message_offset,
+        u16::try_from(message.len()).unwrap(),
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Initiate a merge of `user`'s player account with the player accounts in `keys`.
+///
+/// The merge account is derived from `user` and a `nonce` unique among their merges.
+pub fn initiate_merge(payer: &Pubkey, user: &Pubkey, nonce: u64, keys: Vec<Pubkey>) -> Instruction {
+    build(
+        accounts::InitiateMerge {
+            payer: *payer,
+            user: *user,
+            player_account: pda::find_player_address(user).0,
+            merge_account: pda::find_merged_address(user, nonce).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::InitiateMerge { keys, nonce },
+    )
+}
+
+/// Approve a merge on behalf of `user`'s player account.
+pub fn approve_merge(user: &Pubkey, merge_account: &Pubkey) -> Instruction {
+    build(
+        accounts::ApproveMerge {
+            user: *user,
+            player_account: pda::find_player_address(user).0,
+            merge_account: *merge_account,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ApproveMerge {},
+    )
+}
+
+/// Move a merged player's scores for a leaderboard into the scores list of `user`, the
+/// merge's initiator.
+///
+/// `top_entries` should be the leaderboard's top-entries account, if it has one. The merged
+/// scores list of `merged_user` is closed, refunding its rent to them.
+pub fn merge_player_scores(
+    payer: &Pubkey,
+    user: &Pubkey,
+    merge_account: &Pubkey,
+    merged_user: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+    let merged_player_account = &pda::find_player_address(merged_user).0;
+
+    build(
+        accounts::MergePlayerScores {
+            payer: *payer,
+            user: *user,
+            player_account,
+            merge_account: *merge_account,
+            merged_player_account: *merged_player_account,
+            leaderboard: *leaderboard,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            merged_player_scores: pda::find_player_scores_list_address(
+                merged_player_account,
+                leaderboard,
+            )
+            .0,
+            merged_user: *merged_user,
+            merged_player_ban: pda::find_player_ban_address(leaderboard, merged_player_account).0,
+            top_entries,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MergePlayerScores {},
+    )
+}
+
+/// Carry over a merged player's achievement to `user`, the merge's initiator.
+///
+/// If the merged player claimed any of the achievement's rewards, `rewards` must be all of the
+/// achievement's rewards, in order, so their claims carry over too. Otherwise it may be empty.
+pub fn merge_player_achievement(
+    payer: &Pubkey,
+    user: &Pubkey,
+    merge_account: &Pubkey,
+    merged_player_account: &Pubkey,
+    achievement: &Pubkey,
+    rewards: &[Pubkey],
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    let mut ix = build(
+        accounts::MergePlayerAchievement {
+            payer: *payer,
+            user: *user,
+            player_account,
+            merge_account: *merge_account,
+            merged_player_account: *merged_player_account,
+            achievement: *achievement,
+            merged_player_achievement: pda::find_player_achievement_address(
+                merged_player_account,
+                achievement,
+            )
+            .0,
+            player_achievement: pda::find_player_achievement_address(&player_account, achievement)
+                .0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MergePlayerAchievement {},
+    );
+    ix.accounts.extend(rewards.iter().flat_map(|reward| {
+        [
+            AccountMeta::new_readonly(
+                pda::find_reward_claim_address(reward, merged_player_account).0,
+                false,
+            ),
+            AccountMeta::new(
+                pda::find_reward_claim_address(reward, &player_account).0,
+                false,
+            ),
+        ]
+    }));
+    ix
+}
+
+/// Unlock an achievement for `user` without claiming a reward.
+pub fn unlock_player_achievement(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::UnlockPlayerAchievement {
+            authority: *authority,
+            payer: *payer,
+            player_account,
+            game: *game,
+            achievement: *achievement,
+            player_achievement: pda::find_player_achievement_address(&player_account, achievement)
+                .0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UnlockPlayerAchievement {},
+    )
+}
+
+/// Add a fungible token reward to an achievement.
+///
+/// `index` must be the achievement's next reward index, i.e `achievement.next_reward()`.
+/// `token_program` is the program `reward_token_mint` belongs to, either SPL Token or
+/// Token-2022. The input's `deposit` is moved from `source_token_account` into the
+/// reward's vault.
+#[allow(clippy::too_many_arguments)]
+pub fn add_ft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    index: u64,
+    reward_token_mint: &Pubkey,
+    source_token_account: &Pubkey,
+    token_account_owner: &Pubkey,
+    token_program: &Pubkey,
+    input: AddNewRewardInput,
+) -> Instruction {
+    let new_reward = pda::find_reward_address(achievement, index).0;
+    build(
+        accounts::AddFtReward {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            achievement: *achievement,
+            new_reward,
+            reward_token_mint: *reward_token_mint,
+            reward_vault: pda::find_reward_vault_address(&new_reward).0,
+            source_token_account: *source_token_account,
+            token_account_owner: *token_account_owner,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddFtReward { input },
+    )
+}
+
+/// Add an nft reward to an achievement.
+///
+/// `index` must be the achievement's next reward index, i.e `achievement.next_reward()`.
+/// `collection` optionally specifies a collection mint and its update authority, used to
+/// verify minted rewards.
+pub fn add_nft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    index: u64,
+    collection: Option<(Pubkey, Pubkey)>,
+    input: AddNewRewardInput,
+) -> Instruction {
+    build(
+        accounts::AddNftReward {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            achievement: *achievement,
+            new_reward: pda::find_reward_address(achievement, index).0,
+            system_program: system_program::ID,
+            reward_collection_mint: collection.map(|(mint, _)| mint),
+            collection_update_auth: collection.map(|(_, auth)| auth),
+            collection_metadata: collection.map(|(mint, _)| pda::find_metadata_address(&mint).0),
+            token_metadata_program: collection.map(|_| mpl_token_metadata::ID),
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddNftReward { input },
+    )
+}
+
+/// Claim a fungible token reward for `user` with a voucher from a game authority, unlocking
+/// its achievement if needed. Signed for and paid by `user`.
+///
+/// Must be preceded in the same transaction by an [ed25519_verify] instruction over the
+/// matching [ClaimVoucher][soar::ClaimVoucher] message. The reward is sent to `user`'s
+/// associated token account for `mint` under `token_program`, which is created if it doesn't
+/// exist.
+#[allow(clippy::too_many_arguments)]
+pub fn redeem_claim_voucher(
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    expiry: i64,
+) -> Instruction {
+    build(
+        accounts::RedeemClaimVoucher {
+            claim: ft_claim_accounts(user, user, game, achievement, reward, mint, token_program),
+            user: *user,
+            instructions: sysvar::instructions::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::RedeemClaimVoucher { expiry },
+    )
+}
+
+/// Claim an nft reward for `user` with a voucher from a game authority, unlocking its
+/// achievement if needed. Signed for and paid by `user`.
+///
+/// Must be preceded in the same transaction by an [ed25519_verify] instruction over the
+/// matching [ClaimVoucher][soar::ClaimVoucher] message. `new_mint` is a new keypair account
+/// that must sign the transaction.
+pub fn redeem_nft_claim_voucher(
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    new_mint: &Pubkey,
+    expiry: i64,
+) -> Instruction {
+    build(
+        accounts::RedeemNftClaimVoucher {
+            claim: nft_claim_accounts(user, user, game, achievement, reward, new_mint),
+            user: *user,
+            instructions: sysvar::instructions::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::RedeemNftClaimVoucher { expiry },
+    )
+}
+
+/// Claim a fungible token reward for `user`, who must have unlocked the achievement.
+///
+/// `authority` is either a game authority or `user` claiming on their own.
+///
+/// The reward is sent to `user`'s associated token account for `mint` under `token_program`,
+/// which is created if it doesn't exist.
+/// For Token-2022 mints with a transfer hook, append the accounts the hook requires to the
+/// returned instruction's accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_ft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClaimFtReward {
+            claim: ft_claim_accounts(payer, user, game, achievement, reward, mint, token_program),
+            authority: *authority,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClaimFtReward {},
+    )
+}
+
+/// Claim several fungible token rewards of an achievement `user` has unlocked, each given
+/// as a `(reward, mint)` pair.
+///
+/// The rewards are sent to `user`'s associated token accounts under `token_program`, which
+/// must already exist.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_ft_rewards(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    rewards: &[(Pubkey, Pubkey)],
+    token_program: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    let mut ix = build(
+        accounts::ClaimFtRewards {
+            user: *user,
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            achievement: *achievement,
+            player_account,
+            player_achievement: pda::find_player_achievement_address(&player_account, achievement)
+                .0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClaimFtRewards {},
+    );
+    ix.accounts
+        .extend(rewards.iter().flat_map(|(reward, mint)| {
+            [
+                AccountMeta::new(*reward, false),
+                AccountMeta::new(
+                    pda::find_reward_claim_address(reward, &player_account).0,
+                    false,
+                ),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(pda::find_reward_vault_address(reward).0, false),
+                AccountMeta::new(
+                    pda::associated_token_address_with_program(user, mint, token_program),
+                    false,
+                ),
+            ]
+        }));
+    ix
+}
+
+/// Unlock an achievement for `user` if needed and claim one of its fungible token rewards.
+///
+/// The reward is sent to `user`'s associated token account for `mint` under `token_program`,
+/// which is created if it doesn't exist.
+/// For Token-2022 mints with a transfer hook, append the accounts the hook requires to the
+/// returned instruction's accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn unlock_and_claim_ft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::UnlockAndClaimFtReward {
+            claim: ft_claim_accounts(payer, user, game, achievement, reward, mint, token_program),
+            authority: *authority,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UnlockAndClaimFtReward {},
+    )
+}
+
+/// Deposit `amount` more tokens from `source_token_account` into an FT reward's vault.
+#[allow(clippy::too_many_arguments)]
+pub fn top_up_ft_reward(
+    authority: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    source_token_account: &Pubkey,
+    token_account_owner: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::TopUpFtReward {
+            authority: *authority,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            reward_token_mint: *mint,
+            reward_vault: pda::find_reward_vault_address(reward).0,
+            source_token_account: *source_token_account,
+            token_account_owner: *token_account_owner,
+            token_program: *token_program,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::TopUpFtReward { amount },
+    )
+}
+
+/// Withdraw the tokens in an FT reward's vault that its remaining spots aren't owed to
+/// `destination_token_account`.
+pub fn withdraw_ft_reward(
+    authority: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    destination_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawFtReward {
+            authority: *authority,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            reward_token_mint: *mint,
+            reward_vault: pda::find_reward_vault_address(reward).0,
+            destination_token_account: *destination_token_account,
+            token_program: *token_program,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::WithdrawFtReward {},
+    )
+}
+
+/// Update a reward's available spots, the amount of an FT reward, or the uri, name or
+/// symbol of an NFT reward.
+///
+/// `is_ft_reward` passes the reward's vault, which must hold any increase in what an FT
+/// reward's remaining spots are owed.
+#[allow(clippy::too_many_arguments)]
+pub fn update_reward(
+    authority: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    is_ft_reward: bool,
+    new_available_spots: Option<u64>,
+    new_amount: Option<u64>,
+    new_uri: Option<String>,
+    new_name: Option<String>,
+    new_symbol: Option<String>,
+) -> Instruction {
+    build(
+        accounts::UpdateReward {
+            authority: *authority,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            reward_vault: is_ft_reward.then(|| pda::find_reward_vault_address(reward).0),
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UpdateReward {
+            new_available_spots,
+            new_amount,
+            new_uri,
+            new_name,
+            new_symbol,
+        },
+    )
+}
+
+/// Pause or resume claims of a reward.
+pub fn set_reward_paused(
+    authority: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    paused: bool,
+) -> Instruction {
+    build(
+        accounts::SetRewardPaused {
+            authority: *authority,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::SetRewardPaused { paused },
+    )
+}
+
+/// Cancel a reward and detach it from its achievement.
+///
+/// `refund` is required for FT rewards and specifies the reward's mint, the token account
+/// the vault is emptied into and the mint's token program.
+pub fn cancel_reward(
+    authority: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    refund: Option<(Pubkey, Pubkey, Pubkey)>,
+) -> Instruction {
+    build(
+        accounts::CancelReward {
+            authority: *authority,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            reward_token_mint: refund.map(|(mint, _, _)| mint),
+            reward_vault: refund.map(|_| pda::find_reward_vault_address(reward).0),
+            destination_token_account: refund.map(|(_, destination, _)| destination),
+            token_program: refund.map(|(_, _, token_program)| token_program),
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CancelReward {},
+    )
+}
+
+/// Claim an nft reward for `user`, who must have unlocked the achievement.
+///
+/// `authority` is either a game authority or `user` claiming on their own.
+///
+/// `new_mint` is a new keypair account that must sign the transaction.
+pub fn claim_nft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    new_mint: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClaimNftReward {
+            claim: nft_claim_accounts(payer, user, game, achievement, reward, new_mint),
+            authority: *authority,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClaimNftReward {},
+    )
+}
+
+/// Unlock an achievement for `user` if needed and claim one of its nft rewards.
+///
+/// `new_mint` is a new keypair account that must sign the transaction.
+pub fn unlock_and_claim_nft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    new_mint: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::UnlockAndClaimNftReward {
+            claim: nft_claim_accounts(payer, user, game, achievement, reward, new_mint),
+            authority: *authority,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::UnlockAndClaimNftReward {},
+    )
+}
+
+/// A reward paid out by [claim_rewards].
+#[derive(Clone, Copy, Debug)]
+pub enum ClaimableReward {
+    /// A fungible token reward of `mint`, owned by `token_program`.
+    Ft {
+        reward: Pubkey,
+        mint: Pubkey,
+        token_program: Pubkey,
+    },
+    /// An NFT reward minted to the new keypair account `new_mint`, which must sign the
+    /// transaction.
+    Nft { reward: Pubkey, new_mint: Pubkey },
+}
+
+/// Claim several of an achievement's rewards for `user`, one instruction per reward.
+///
+/// `user` must have unlocked the achievement.
+///
+/// Sent in a single transaction, the player receives either every reward or none of them.
+pub fn claim_rewards(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    rewards: &[ClaimableReward],
+) -> Vec<Instruction> {
+    rewards
+        .iter()
+        .map(|claimable| match claimable {
+            ClaimableReward::Ft {
+                reward,
+                mint,
+                token_program,
+            } => claim_ft_reward(
+                authority,
+                payer,
+                user,
+                game,
+                achievement,
+                reward,
+                mint,
+                token_program,
+            ),
+            ClaimableReward::Nft { reward, new_mint } => {
+                claim_nft_reward(authority, payer, user, game, achievement, reward, new_mint)
+            }
+        })
+        .collect()
+}
+
+/// Verify an nft reward minted to `user` as part of the reward's collection.
+pub fn verify_nft_reward(
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    collection_mint: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::VerifyNftReward {
+            payer: *payer,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            user: *user,
+            player_account,
+            claim: pda::find_nft_claim_address(reward, mint).0,
+            player_achievement: pda::find_player_achievement_address(&player_account, achievement)
+                .0,
+            mint: *mint,
+            metadata_to_verify: pda::find_metadata_address(mint).0,
+            collection_mint: *collection_mint,
+            collection_metadata: pda::find_metadata_address(collection_mint).0,
+            collection_edition: pda::find_master_edition_address(collection_mint).0,
+            token_metadata_program: mpl_token_metadata::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::VerifyNftReward {},
+    )
+}
+
+/// Add a fungible token reward for a range of ranks on a leaderboard.
+///
+/// `new_reward` is a new keypair account that must sign the transaction. `token_program`
+/// is the program `reward_token_mint` belongs to, either SPL Token or Token-2022. The
+/// input's `deposit` is moved from `source_token_account` into the reward's vault.
+#[allow(clippy::too_many_arguments)]
+pub fn add_ft_rank_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    new_reward: &Pubkey,
+    reward_token_mint: &Pubkey,
+    source_token_account: &Pubkey,
+    token_account_owner: &Pubkey,
+    token_program: &Pubkey,
+    input: AddRankRewardInput,
+) -> Instruction {
+    build(
+        accounts::AddFtRankReward {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            new_reward: *new_reward,
+            reward_token_mint: *reward_token_mint,
+            reward_vault: pda::find_reward_vault_address(new_reward).0,
+            source_token_account: *source_token_account,
+            token_account_owner: *token_account_owner,
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddFtRankReward { input },
+    )
+}
+
+/// Add an nft reward for a range of ranks on a leaderboard.
+///
+/// `new_reward` is a new keypair account that must sign the transaction.
+pub fn add_nft_rank_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    new_reward: &Pubkey,
+    input: AddRankRewardInput,
+) -> Instruction {
+    build(
+        accounts::AddNftRankReward {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: pda::find_leader_top_entries_address(leaderboard).0,
+            new_reward: *new_reward,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::AddNftRankReward { input },
+    )
+}
+
+/// Claim a fungible token rank reward for the `user` holding `rank` on a closed leaderboard,
+/// or in the archive of `season` for rewards of a seasonal leaderboard.
+///
+/// The reward is sent to `user`'s associated token account for `mint` under `token_program`.
+/// For Token-2022 mints with a transfer hook, append the accounts the hook requires to the
+/// returned instruction's accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_ft_rank_reward(
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    rank_reward: &Pubkey,
+    season: Option<u64>,
+    rank: u32,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+    build(
+        accounts::ClaimFtRankReward {
+            user: *user,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: season
+                .is_none()
+                .then(|| pda::find_leader_top_entries_address(leaderboard).0),
+            season_top_entries: season
+                .map(|season| pda::find_season_top_entries_address(leaderboard, season).0),
+            rank_reward: *rank_reward,
+            player_account,
+            claim: pda::find_rank_reward_claim_address(rank_reward, &player_account).0,
+            reward_token_mint: *mint,
+            reward_vault: pda::find_reward_vault_address(rank_reward).0,
+            user_token_account: pda::associated_token_address_with_program(
+                user,
+                mint,
+                token_program,
+            ),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClaimFtRankReward { rank },
+    )
+}
+
+/// Claim an nft rank reward for the `user` holding `rank` on a closed leaderboard, or in the
+/// archive of `season` for rewards of a seasonal leaderboard.
+///
+/// `new_mint` is a new keypair account that must sign the transaction.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_nft_rank_reward(
+    payer: &Pubkey,
+    user: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    rank_reward: &Pubkey,
+    season: Option<u64>,
+    rank: u32,
+    new_mint: &Pubkey,
+) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+    build(
+        accounts::ClaimNftRankReward {
+            user: *user,
+            payer: *payer,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: season
+                .is_none()
+                .then(|| pda::find_leader_top_entries_address(leaderboard).0),
+            season_top_entries: season
+                .map(|season| pda::find_season_top_entries_address(leaderboard, season).0),
+            rank_reward: *rank_reward,
+            player_account,
+            claim: pda::find_rank_reward_claim_address(rank_reward, &player_account).0,
+            new_mint: *new_mint,
+            new_metadata: pda::find_metadata_address(new_mint).0,
+            new_master_edition: pda::find_master_edition_address(new_mint).0,
+            mint_to: pda::associated_token_address(user, new_mint),
+            token_metadata_program: mpl_token_metadata::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClaimNftRankReward { rank },
+    )
+}
+
+/// Close a game once all its leaderboards and achievements are closed.
+pub fn close_game(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseGame {
+            authority: *authority,
+            receiver: *receiver,
+            payer: *payer,
+            game: *game,
+            closed_game: pda::find_closed_game_address(game).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseGame {},
+    )
+}
+
+/// Close a leaderboard, along with its top entries if `has_top_entries` is set.
+pub fn close_leaderboard(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    has_top_entries: bool,
+) -> Instruction {
+    build(
+        accounts::CloseLeaderBoard {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            leaderboard: *leaderboard,
+            top_entries: has_top_entries
+                .then(|| pda::find_leader_top_entries_address(leaderboard).0),
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseLeaderboard {},
+    )
+}
+
+/// Close the top entries archived for a leaderboard `season`.
+pub fn close_season_top_entries(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    season: u64,
+) -> Instruction {
+    build(
+        accounts::CloseSeasonTopEntries {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            leaderboard: *leaderboard,
+            archive: pda::find_season_top_entries_address(leaderboard, season).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseSeasonTopEntries {},
+    )
+}
+
+/// Close an achievement that has no reward attached.
+pub fn close_achievement(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseAchievement {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            achievement: *achievement,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseAchievement {},
+    )
+}
+
+/// Close an achievement's reward.
+///
+/// `token_program` is required for FT rewards, whose vault must be empty and is closed
+/// along with the reward.
+pub fn close_reward(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    token_program: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::CloseReward {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            reward_vault: token_program.map(|_| pda::find_reward_vault_address(reward).0),
+            token_program,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseReward {},
+    )
+}
+
+/// Close a leaderboard's rank reward.
+///
+/// `refund` is required for FT rewards and specifies the reward's mint, the token account
+/// the vault is emptied into and the mint's token program.
+pub fn close_rank_reward(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+    leaderboard: &Pubkey,
+    rank_reward: &Pubkey,
+    refund: Option<(Pubkey, Pubkey, Pubkey)>,
+) -> Instruction {
+    build(
+        accounts::CloseRankReward {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            leaderboard: *leaderboard,
+            rank_reward: *rank_reward,
+            reward_token_mint: refund.map(|(mint, _, _)| mint),
+            reward_vault: refund.map(|_| pda::find_reward_vault_address(rank_reward).0),
+            destination_token_account: refund.map(|(_, destination, _)| destination),
+            token_program: refund.map(|(_, _, token_program)| token_program),
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseRankReward {},
+    )
+}
+
+/// Close `user`'s player account.
+pub fn close_player(user: &Pubkey) -> Instruction {
+    build(
+        accounts::ClosePlayer {
+            user: *user,
+            player_account: pda::find_player_address(user).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClosePlayer {},
+    )
+}
+
+/// Close `user`'s scores list for a leaderboard.
+pub fn close_player_scores(user: &Pubkey, leaderboard: &Pubkey) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::ClosePlayerScores {
+            user: *user,
+            player_account,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClosePlayerScores {},
+    )
+}
+
+/// Close `user`'s player achievement account for `achievement`.
+pub fn close_player_achievement(user: &Pubkey, achievement: &Pubkey) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::ClosePlayerAchievement {
+            user: *user,
+            player_account,
+            achievement: *achievement,
+            player_achievement: pda::find_player_achievement_address(&player_account, achievement)
+                .0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::ClosePlayerAchievement {},
+    )
+}
+
+/// Close a merge account initiated by `user`.
+pub fn close_merged(user: &Pubkey, merge_account: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseMerged {
+            user: *user,
+            merge_account: *merge_account,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseMerged {},
+    )
+}
+
+/// Close the claim account of an nft reward minted at `mint`.
+pub fn close_nft_claim(
+    authority: &Pubkey,
+    receiver: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseNftClaim {
+            authority: *authority,
+            receiver: *receiver,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            mint: *mint,
+            claim: pda::find_nft_claim_address(reward, mint).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseNftClaim {},
+    )
+}
+
+/// Close `user`'s claim of a closed reward.
+pub fn close_reward_claim(user: &Pubkey, reward: &Pubkey) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::CloseRewardClaim {
+            user: *user,
+            player_account,
+            reward: *reward,
+            claim: pda::find_reward_claim_address(reward, &player_account).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseRewardClaim {},
+    )
+}
+
+/// Close `user`'s claim on a closed rank reward.
+pub fn close_rank_reward_claim(user: &Pubkey, rank_reward: &Pubkey) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+    build(
+        accounts::CloseRankRewardClaim {
+            user: *user,
+            player_account,
+            rank_reward: *rank_reward,
+            claim: pda::find_rank_reward_claim_address(rank_reward, &player_account).0,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::CloseRankRewardClaim {},
+    )
+}
+
+/// Convert a game created under the legacy layout, making `authority`, one of its
+/// authorities, its owner.
+pub fn migrate_game(authority: &Pubkey, payer: &Pubkey, game: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateGame {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MigrateGame {},
+    )
+}
+
+/// Convert a leaderboard created under the legacy layout, along with its top-entries
+/// account if it has one.
+pub fn migrate_leaderboard(
+    payer: &Pubkey,
+    leaderboard: &Pubkey,
+    top_entries: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::MigrateLeaderBoard {
+            payer: *payer,
+            leaderboard: *leaderboard,
+            top_entries,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MigrateLeaderboard {},
+    )
+}
+
+/// Convert `user`'s scores list for a leaderboard, created under the legacy layout.
+pub fn migrate_player_scores(payer: &Pubkey, user: &Pubkey, leaderboard: &Pubkey) -> Instruction {
+    let player_account = pda::find_player_address(user).0;
+
+    build(
+        accounts::MigratePlayerScores {
+            payer: *payer,
+            player_scores: pda::find_player_scores_list_address(&player_account, leaderboard).0,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MigratePlayerScores {},
+    )
+}
+
+/// Move a legacy FT reward, paid from `legacy_token_account`, to a vault. Legacy rewards
+/// always hold SPL Token mints.
+pub fn migrate_ft_reward(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    game: &Pubkey,
+    achievement: &Pubkey,
+    reward: &Pubkey,
+    mint: &Pubkey,
+    legacy_token_account: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::MigrateFtReward {
+            authority: *authority,
+            payer: *payer,
+            game: *game,
+            achievement: *achievement,
+            reward: *reward,
+            reward_token_mint: *mint,
+            legacy_token_account: *legacy_token_account,
+            reward_vault: pda::find_reward_vault_address(reward).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MigrateFtReward {},
+    )
+}
+
+/// Convert an achievement created under the legacy layout.
+pub fn migrate_achievement(payer: &Pubkey, achievement: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAchievement {
+            payer: *payer,
+            achievement: *achievement,
+            system_program: system_program::ID,
+            event_authority: pda::find_event_authority_address().0,
+            program: soar::ID,
+        },
+        instruction::MigrateAchievement {},
+    )
+}
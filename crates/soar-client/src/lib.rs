@@ -0,0 +1,16 @@
+//! Rust client for the [SOAR](https://github.com/magicblock-labs/SOAR) program.
+//!
+//! Provides typed [instruction] builders for every program entrypoint, [pda] helpers
+//! for deriving program addresses, [accounts] helpers for fetching and decoding
+//! on-chain state and [events] decoders for the events the program emits.
+
+#![allow(clippy::result_large_err)]
+
+pub mod accounts;
+pub mod error;
+pub mod events;
+pub mod instruction;
+pub mod pda;
+
+pub use error::{Error, Result};
+pub use soar::{self, ID};
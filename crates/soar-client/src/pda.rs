@@ -0,0 +1,209 @@
+//! Program derived address helpers.
+//!
+//! Each function returns the derived address along with its bump.
+
+use anchor_lang::prelude::Pubkey;
+use soar::seeds;
+
+/// Derive the address of the [Game][soar::Game] created by `creator` with a given `nonce`.
+pub fn find_game_address(creator: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::GAME, creator.as_ref(), &nonce.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the [ClosedGame][soar::ClosedGame] marking `game` as closed.
+pub fn find_closed_game_address(game: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::CLOSED_GAME, game.as_ref()], &soar::ID)
+}
+
+/// Derive the address of a [LeaderBoard][soar::LeaderBoard] with a given `id`.
+pub fn find_leaderboard_address(game: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::LEADER, game.as_ref(), &id.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of a leaderboard's [LeaderTopEntries][soar::LeaderTopEntries].
+pub fn find_leader_top_entries_address(leaderboard: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::LEADER_TOP_ENTRIES, leaderboard.as_ref()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of a leaderboard's [SeasonTopEntries][soar::SeasonTopEntries] archive
+/// for a given `season`.
+pub fn find_season_top_entries_address(leaderboard: &Pubkey, season: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::SEASON_TOP_ENTRIES,
+            leaderboard.as_ref(),
+            &season.to_le_bytes(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the [AuthorityProposal][soar::AuthorityProposal] adding or
+/// swapping in `authority` for a game.
+pub fn find_authority_proposal_address(game: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::AUTHORITY_PROPOSAL, game.as_ref(), authority.as_ref()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of an [Achievement][soar::Achievement] with a given `id`.
+pub fn find_achievement_address(game: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::ACHIEVEMENT, game.as_ref(), &id.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of an achievement's [Reward][soar::Reward] with a given `index`.
+pub fn find_reward_address(achievement: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::REWARD, achievement.as_ref(), &index.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the [Player][soar::Player] account owned by `user`.
+pub fn find_player_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::PLAYER, user.as_ref()], &soar::ID)
+}
+
+/// Derive the address of a player's [PlayerScoresList][soar::PlayerScoresList]
+/// for a particular leaderboard.
+pub fn find_player_scores_list_address(
+    player_account: &Pubkey,
+    leaderboard: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::PLAYER_SCORES,
+            player_account.as_ref(),
+            leaderboard.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of a [Merged][soar::Merged] account initiated by `initiator` with a
+/// given `nonce`.
+pub fn find_merged_address(initiator: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::MERGED, initiator.as_ref(), &nonce.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of a player's [PlayerAchievement][soar::PlayerAchievement]
+/// for a particular achievement.
+pub fn find_player_achievement_address(
+    player_account: &Pubkey,
+    achievement: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::PLAYER_ACHIEVEMENT,
+            player_account.as_ref(),
+            achievement.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the [PlayerBan][soar::PlayerBan] barring `player_account` from
+/// `leaderboard`.
+pub fn find_player_ban_address(leaderboard: &Pubkey, player_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::PLAYER_BAN,
+            leaderboard.as_ref(),
+            player_account.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the [NftClaim][soar::NftClaim] for a minted nft reward.
+pub fn find_nft_claim_address(reward: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::NFT_CLAIM, reward.as_ref(), mint.as_ref()],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the vault holding a fungible token [Reward][soar::Reward]'s tokens.
+pub fn find_reward_vault_address(reward: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::REWARD_VAULT, reward.as_ref()], &soar::ID)
+}
+
+/// Derive the address of the [RewardClaim][soar::RewardClaim] recording that
+/// `player_account` claimed `reward`.
+pub fn find_reward_claim_address(reward: &Pubkey, player_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::REWARD_CLAIM,
+            reward.as_ref(),
+            player_account.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the [RankRewardClaim][soar::RankRewardClaim] recording that
+/// `player_account` claimed `rank_reward`.
+pub fn find_rank_reward_claim_address(
+    rank_reward: &Pubkey,
+    player_account: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seeds::RANK_REWARD_CLAIM,
+            rank_reward.as_ref(),
+            player_account.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Derive the address of the program's event authority, which signs the self-invocations
+/// used to emit events.
+pub fn find_event_authority_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::EVENT_AUTHORITY], &soar::ID)
+}
+
+/// Derive the metaplex metadata address for `mint`.
+pub fn find_metadata_address(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::pda::find_metadata_account(mint)
+}
+
+/// Derive the metaplex master edition address for `mint`.
+pub fn find_master_edition_address(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::pda::find_master_edition_account(mint)
+}
+
+/// Derive the associated token account of `owner` for `mint`.
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address(owner, mint)
+}
+
+/// Derive the associated token account of `owner` for `mint` under `token_program`, either
+/// SPL Token or Token-2022.
+pub fn associated_token_address_with_program(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        owner,
+        mint,
+        token_program,
+    )
+}
@@ -0,0 +1,312 @@
+//! Instruction builders: program ids, encoded arguments, derived accounts and their
+//! signer and writable flags.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use soar::{instruction, ScoreAttestation};
+use soar_client::{instruction as builders, pda};
+use solana_sdk::feature_set::FeatureSet;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+/// Decode `ix`'s arguments as `T`, checking its program and discriminator.
+fn args<T: Discriminator + AnchorDeserialize>(ix: &Instruction) -> T {
+    assert_eq!(ix.program_id, soar::ID);
+    assert_eq!(ix.data[..8], T::DISCRIMINATOR);
+    T::try_from_slice(&ix.data[8..]).unwrap()
+}
+
+/// The meta for `pubkey` in `ix`, which must appear exactly once.
+fn meta(ix: &Instruction, pubkey: &Pubkey) -> AccountMeta {
+    let metas = ix
+        .accounts
+        .iter()
+        .filter(|meta| meta.pubkey == *pubkey)
+        .collect::<Vec<_>>();
+    assert_eq!(metas.len(), 1, "{pubkey} appears {} times", metas.len());
+    metas[0].clone()
+}
+
+#[test]
+fn builds_submit_score() {
+    let (payer, authority, user, game, leaderboard) = (key(1), key(2), key(3), key(4), key(5));
+    let top_entries = pda::find_leader_top_entries_address(&leaderboard).0;
+    let player = pda::find_player_address(&user).0;
+
+    let ix = builders::submit_score_with_tiebreak(
+        &payer,
+        &authority,
+        &user,
+        &game,
+        &leaderboard,
+        Some(top_entries),
+        42,
+        7,
+    );
+    let data: instruction::SubmitScoreWithTiebreak = args(&ix);
+    assert_eq!((data.score, data.tiebreak), (42, 7));
+
+    assert_eq!(meta(&ix, &payer), AccountMeta::new(payer, true));
+    assert_eq!(
+        meta(&ix, &authority),
+        AccountMeta::new_readonly(authority, true)
+    );
+    assert_eq!(meta(&ix, &player), AccountMeta::new_readonly(player, false));
+    let scores = pda::find_player_scores_list_address(&player, &leaderboard).0;
+    assert_eq!(meta(&ix, &scores), AccountMeta::new(scores, false));
+    assert_eq!(
+        meta(&ix, &top_entries),
+        AccountMeta::new(top_entries, false)
+    );
+    assert_eq!(
+        ix.accounts.last().unwrap(),
+        &AccountMeta::new_readonly(soar::ID, false)
+    );
+
+    // Without top entries, the optional account is passed as the program id.
+    let ix = builders::submit_score(&payer, &authority, &user, &game, &leaderboard, None, 42);
+    let data: instruction::SubmitScore = args(&ix);
+    assert_eq!(data.score, 42);
+    assert_eq!(
+        ix.accounts
+            .iter()
+            .filter(|meta| meta.pubkey == soar::ID)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn appends_scores_lists_and_bans() {
+    let (authority, game, leaderboard) = (key(1), key(2), key(3));
+    let players = [key(4), key(5)];
+
+    let ix = builders::rebuild_top_entries(&authority, &game, &leaderboard, &players, true);
+    let data: instruction::RebuildTopEntries = args(&ix);
+    assert!(data.reset);
+
+    let remaining = &ix.accounts[ix.accounts.len() - 4..];
+    for (pair, player) in remaining.chunks(2).zip(players) {
+        assert_eq!(
+            pair,
+            [
+                AccountMeta::new_readonly(
+                    pda::find_player_scores_list_address(&player, &leaderboard).0,
+                    false
+                ),
+                AccountMeta::new_readonly(
+                    pda::find_player_ban_address(&leaderboard, &player).0,
+                    false
+                ),
+            ]
+        );
+    }
+}
+
+#[test]
+fn builds_batched_ft_claims() {
+    let (authority, payer, user, game, achievement) = (key(1), key(2), key(3), key(4), key(5));
+    let rewards = [(key(6), key(7)), (key(8), key(9))];
+    let token_program = anchor_spl::token::ID;
+    let player = pda::find_player_address(&user).0;
+
+    let ix = builders::claim_ft_rewards(
+        &authority,
+        &payer,
+        &user,
+        &game,
+        &achievement,
+        &rewards,
+        &token_program,
+    );
+    let _: instruction::ClaimFtRewards = args(&ix);
+
+    let remaining = &ix.accounts[ix.accounts.len() - 10..];
+    for (accounts, (reward, mint)) in remaining.chunks(5).zip(rewards) {
+        assert_eq!(
+            accounts,
+            [
+                AccountMeta::new(reward, false),
+                AccountMeta::new(pda::find_reward_claim_address(&reward, &player).0, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(pda::find_reward_vault_address(&reward).0, false),
+                AccountMeta::new(
+                    pda::associated_token_address_with_program(&user, &mint, &token_program),
+                    false
+                ),
+            ]
+        );
+    }
+}
+
+#[test]
+fn builds_single_ft_claim() {
+    let (authority, payer, user, game, achievement, reward, mint) =
+        (key(1), key(2), key(3), key(4), key(5), key(6), key(7));
+    let token_program = anchor_spl::token_2022::ID;
+    let player = pda::find_player_address(&user).0;
+
+    let ix = builders::claim_ft_reward(
+        &authority,
+        &payer,
+        &user,
+        &game,
+        &achievement,
+        &reward,
+        &mint,
+        &token_program,
+    );
+    let _: instruction::ClaimFtReward = args(&ix);
+
+    let player_achievement = pda::find_player_achievement_address(&player, &achievement).0;
+    assert_eq!(
+        meta(&ix, &player_achievement),
+        AccountMeta::new(player_achievement, false)
+    );
+    let vault = pda::find_reward_vault_address(&reward).0;
+    assert_eq!(meta(&ix, &vault), AccountMeta::new(vault, false));
+    let destination = pda::associated_token_address_with_program(&user, &mint, &token_program);
+    assert_eq!(
+        meta(&ix, &destination),
+        AccountMeta::new(destination, false)
+    );
+    assert_eq!(
+        meta(&ix, &token_program),
+        AccountMeta::new_readonly(token_program, false)
+    );
+}
+
+#[test]
+fn builds_merges() {
+    let (payer, user, merged_user, leaderboard, achievement) =
+        (key(1), key(2), key(3), key(4), key(5));
+    let merge_account = pda::find_merged_address(&user, 0).0;
+    let player = pda::find_player_address(&user).0;
+    let merged_player = pda::find_player_address(&merged_user).0;
+
+    let ix = builders::merge_player_scores(
+        &payer,
+        &user,
+        &merge_account,
+        &merged_user,
+        &leaderboard,
+        None,
+    );
+    let _: instruction::MergePlayerScores = args(&ix);
+    // The merged scores list is closed to the merged player's wallet.
+    let merged_scores = pda::find_player_scores_list_address(&merged_player, &leaderboard).0;
+    assert_eq!(
+        meta(&ix, &merged_scores),
+        AccountMeta::new(merged_scores, false)
+    );
+    assert_eq!(
+        meta(&ix, &merged_user),
+        AccountMeta::new(merged_user, false)
+    );
+
+    let rewards = [key(6), key(7)];
+    let ix = builders::merge_player_achievement(
+        &payer,
+        &user,
+        &merge_account,
+        &merged_player,
+        &achievement,
+        &rewards,
+    );
+    let _: instruction::MergePlayerAchievement = args(&ix);
+    let remaining = &ix.accounts[ix.accounts.len() - 4..];
+    for (pair, reward) in remaining.chunks(2).zip(rewards) {
+        assert_eq!(
+            pair,
+            [
+                AccountMeta::new_readonly(
+                    pda::find_reward_claim_address(&reward, &merged_player).0,
+                    false
+                ),
+                AccountMeta::new(pda::find_reward_claim_address(&reward, &player).0, false),
+            ]
+        );
+    }
+}
+
+#[test]
+fn marks_closed_games() {
+    let (creator, authority, payer, receiver) = (key(1), key(2), key(3), key(4));
+    let game = pda::find_game_address(&creator, 3).0;
+    let closed_game = pda::find_closed_game_address(&game).0;
+
+    let ix = builders::initialize_game(&creator, 3, Default::default(), vec![]);
+    let data: instruction::InitializeGame = args(&ix);
+    assert_eq!(data.nonce, 3);
+    assert_eq!(meta(&ix, &game), AccountMeta::new(game, false));
+    assert_eq!(
+        meta(&ix, &closed_game),
+        AccountMeta::new_readonly(closed_game, false)
+    );
+
+    let ix = builders::close_game(&authority, &payer, &receiver, &game);
+    let _: instruction::CloseGame = args(&ix);
+    assert_eq!(meta(&ix, &payer), AccountMeta::new(payer, true));
+    assert_eq!(meta(&ix, &receiver), AccountMeta::new(receiver, false));
+    assert_eq!(
+        meta(&ix, &closed_game),
+        AccountMeta::new(closed_game, false)
+    );
+}
+
+#[test]
+fn builds_migrations() {
+    let (authority, payer, game, achievement) = (key(1), key(2), key(3), key(4));
+
+    let ix = builders::migrate_game(&authority, &payer, &game);
+    let _: instruction::MigrateGame = args(&ix);
+    assert_eq!(
+        meta(&ix, &authority),
+        AccountMeta::new_readonly(authority, true)
+    );
+    assert_eq!(meta(&ix, &game), AccountMeta::new(game, false));
+
+    let ix = builders::migrate_achievement(&payer, &achievement);
+    let _: instruction::MigrateAchievement = args(&ix);
+    assert_eq!(meta(&ix, &payer), AccountMeta::new(payer, true));
+    assert_eq!(
+        meta(&ix, &achievement),
+        AccountMeta::new(achievement, false)
+    );
+}
+
+#[test]
+fn builds_verifiable_ed25519_instructions() {
+    let signer = Keypair::new();
+    let attestation = ScoreAttestation::new(key(1), key(2), 100, 0, 1, 500, 1_000);
+    let message = attestation.message();
+    let signature = signer.sign_message(&message);
+
+    let ix = builders::ed25519_verify(
+        &signer.pubkey(),
+        signature.as_ref().try_into().unwrap(),
+        &message,
+    );
+    assert_eq!(ix.program_id, solana_sdk::ed25519_program::ID);
+    assert!(ix.accounts.is_empty());
+    solana_sdk::ed25519_instruction::verify(&ix.data, &[&ix.data], &FeatureSet::all_enabled())
+        .unwrap();
+
+    // A signature over another message doesn't verify.
+    let other = signer.sign_message(b"another message");
+    let ix = builders::ed25519_verify(
+        &signer.pubkey(),
+        other.as_ref().try_into().unwrap(),
+        &message,
+    );
+    assert!(solana_sdk::ed25519_instruction::verify(
+        &ix.data,
+        &[&ix.data],
+        &FeatureSet::all_enabled()
+    )
+    .is_err());
+}
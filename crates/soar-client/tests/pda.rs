@@ -0,0 +1,146 @@
+//! Derived addresses against the seeds the program documents.
+
+use anchor_lang::prelude::Pubkey;
+use soar_client::pda;
+
+fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+fn derive(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &soar::ID)
+}
+
+#[test]
+fn derives_game_addresses() {
+    let creator = key(1);
+    let game = key(2);
+
+    assert_eq!(
+        pda::find_game_address(&creator, 7),
+        derive(&[b"game", creator.as_ref(), &7u64.to_le_bytes()])
+    );
+    assert_ne!(
+        pda::find_game_address(&creator, 7),
+        pda::find_game_address(&creator, 8)
+    );
+    assert_eq!(
+        pda::find_closed_game_address(&game),
+        derive(&[b"closed-game", game.as_ref()])
+    );
+    assert_eq!(
+        pda::find_leaderboard_address(&game, 1),
+        derive(&[b"leaderboard", game.as_ref(), &1u64.to_le_bytes()])
+    );
+    assert_eq!(
+        pda::find_achievement_address(&game, 1),
+        derive(&[b"achievement", game.as_ref(), &1u64.to_le_bytes()])
+    );
+    assert_eq!(
+        pda::find_authority_proposal_address(&game, &creator),
+        derive(&[b"authority-proposal", game.as_ref(), creator.as_ref()])
+    );
+}
+
+#[test]
+fn derives_leaderboard_addresses() {
+    let leaderboard = key(3);
+
+    assert_eq!(
+        pda::find_leader_top_entries_address(&leaderboard),
+        derive(&[b"top-scores", leaderboard.as_ref()])
+    );
+    assert_eq!(
+        pda::find_season_top_entries_address(&leaderboard, 2),
+        derive(&[
+            b"season-top-scores",
+            leaderboard.as_ref(),
+            &2u64.to_le_bytes()
+        ])
+    );
+}
+
+#[test]
+fn derives_player_addresses() {
+    let user = key(4);
+    let leaderboard = key(3);
+    let achievement = key(5);
+    let player = pda::find_player_address(&user).0;
+
+    assert_eq!(
+        pda::find_player_address(&user),
+        derive(&[b"player", user.as_ref()])
+    );
+    assert_eq!(
+        pda::find_player_scores_list_address(&player, &leaderboard),
+        derive(&[b"player-scores-list", player.as_ref(), leaderboard.as_ref()])
+    );
+    assert_eq!(
+        pda::find_player_achievement_address(&player, &achievement),
+        derive(&[b"player-achievement", player.as_ref(), achievement.as_ref()])
+    );
+    // Bans are derived from the leaderboard first.
+    assert_eq!(
+        pda::find_player_ban_address(&leaderboard, &player),
+        derive(&[b"player-ban", leaderboard.as_ref(), player.as_ref()])
+    );
+    assert_eq!(
+        pda::find_merged_address(&user, 3),
+        derive(&[b"merged", user.as_ref(), &3u64.to_le_bytes()])
+    );
+}
+
+#[test]
+fn derives_reward_addresses() {
+    let achievement = key(5);
+    let reward = key(6);
+    let player = key(7);
+    let mint = key(8);
+
+    assert_eq!(
+        pda::find_reward_address(&achievement, 1),
+        derive(&[b"reward", achievement.as_ref(), &1u64.to_le_bytes()])
+    );
+    assert_eq!(
+        pda::find_reward_vault_address(&reward),
+        derive(&[b"reward-vault", reward.as_ref()])
+    );
+    assert_eq!(
+        pda::find_reward_claim_address(&reward, &player),
+        derive(&[b"reward-claim", reward.as_ref(), player.as_ref()])
+    );
+    assert_eq!(
+        pda::find_rank_reward_claim_address(&reward, &player),
+        derive(&[b"rank-reward-claim", reward.as_ref(), player.as_ref()])
+    );
+    assert_eq!(
+        pda::find_nft_claim_address(&reward, &mint),
+        derive(&[b"nft-claim", reward.as_ref(), mint.as_ref()])
+    );
+    assert_eq!(
+        pda::find_event_authority_address(),
+        derive(&[b"__event_authority"])
+    );
+}
+
+#[test]
+fn derives_token_addresses() {
+    let owner = key(9);
+    let mint = key(8);
+
+    assert_eq!(
+        pda::associated_token_address_with_program(&owner, &mint, &anchor_spl::token::ID),
+        pda::associated_token_address(&owner, &mint)
+    );
+    assert_ne!(
+        pda::associated_token_address_with_program(&owner, &mint, &anchor_spl::token_2022::ID),
+        pda::associated_token_address(&owner, &mint)
+    );
+    assert_eq!(
+        pda::find_metadata_address(&mint),
+        Pubkey::find_program_address(
+            &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()],
+            &mpl_token_metadata::ID
+        )
+    );
+}
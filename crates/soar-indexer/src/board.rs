@@ -0,0 +1,303 @@
+//! Ranking queries over a leaderboard's full score history.
+//!
+//! Boards are ranked by SQLite window functions over the indexed `scores` table, so a
+//! query only returns the rows it asks for.
+
+use crate::store::{decode_u64, parse_key, parse_tie_break, Store};
+use crate::{Error, Result};
+use anchor_lang::prelude::Pubkey;
+use rusqlite::{params, OptionalExtension, ToSql};
+use soar::{ScoreEntry, TieBreak};
+
+/// A ranked entry of a full leaderboard.
+#[derive(Clone, Debug)]
+pub struct RankedEntry {
+    /// 1-based position on the board.
+    pub rank: u64,
+
+    /// The [Player][soar::Player] account holding the entry.
+    pub player: Pubkey,
+
+    /// The player's username, if their account has been indexed.
+    pub username: Option<String>,
+
+    /// The ranked score.
+    pub entry: ScoreEntry,
+}
+
+/// A player's standing on a full leaderboard.
+#[derive(Clone, Debug)]
+pub struct PlayerStanding {
+    /// The player's best ranked entry.
+    pub entry: RankedEntry,
+
+    /// Number of entries on the board.
+    pub total: u64,
+
+    /// Percentage of the board's entries ranked below the player's.
+    pub percentile: f64,
+}
+
+/// The ranking rules of a leaderboard.
+struct Rules {
+    is_ascending: bool,
+    allow_multiple_scores: bool,
+    tie_break: TieBreak,
+    start_time: Option<i64>,
+    season_length: Option<i64>,
+}
+
+impl Rules {
+    /// An `ORDER BY` clause over the `scores` columns ranking entries best first, the way
+    /// [ScoreEntry::rank_cmp] does. Entries ranked equal are ordered by player address, then
+    /// by submission order.
+    fn order_by(&self) -> String {
+        let score = if self.is_ascending {
+            "score ASC"
+        } else {
+            "score DESC"
+        };
+        let tie_break = match self.tie_break {
+            TieBreak::EarliestFirst => "timestamp ASC",
+            TieBreak::LatestFirst => "timestamp DESC",
+            TieBreak::HighestValue => "tiebreak DESC, timestamp ASC",
+            TieBreak::LowestValue => "tiebreak ASC, timestamp ASC",
+        };
+        format!("{score}, {tie_break}, player ASC, id ASC")
+    }
+
+    /// The inclusive range of timestamps ranked in `season`, or every timestamp if it's
+    /// [None]. Leaderboards without seasons only have season `0`.
+    fn timestamps(&self, season: Option<u64>) -> (i64, i64) {
+        const EMPTY: (i64, i64) = (1, 0);
+        let season = match season {
+            Some(season) => season,
+            None => return (i64::MIN, i64::MAX),
+        };
+
+        match (self.start_time, self.season_length) {
+            (Some(start), Some(length)) => i64::try_from(season)
+                .ok()
+                .and_then(|season| season.checked_mul(length))
+                .and_then(|offset| start.checked_add(offset))
+                .map(|first| (first, first.saturating_add(length - 1)))
+                .unwrap_or(EMPTY),
+            _ if season == 0 => (i64::MIN, i64::MAX),
+            _ => EMPTY,
+        }
+    }
+}
+
+impl Store {
+    /// Every entry of `leaderboard` ranked from best to worst, following the leaderboard's
+    /// order and tie-break policy.
+    ///
+    /// Entries of banned players are left out. Unless the leaderboard allows multiple scores
+    /// per player, only each player's best score is ranked. If `season` is set, only scores
+    /// submitted during that season are ranked.
+    pub fn board(&self, leaderboard: &Pubkey, season: Option<u64>) -> Result<Vec<RankedEntry>> {
+        Ok(self
+            .ranked(leaderboard, season, "1", &[])?
+            .into_iter()
+            .map(|(entry, _)| entry)
+            .collect())
+    }
+
+    /// Number of entries ranked on `leaderboard` in `season`.
+    pub fn board_size(&self, leaderboard: &Pubkey, season: Option<u64>) -> Result<u64> {
+        Ok(self
+            .ranked(leaderboard, season, "r.rank = 1", &[])?
+            .first()
+            .map_or(0, |(_, total)| *total))
+    }
+
+    /// Up to `limit` ranked entries of `leaderboard` in `season`, skipping the first `offset`.
+    pub fn page(
+        &self,
+        leaderboard: &Pubkey,
+        season: Option<u64>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<RankedEntry>> {
+        // Ranks are SQLite integers, so none lie past an offset out of their range.
+        let Some(first) = i64::try_from(offset)
+            .ok()
+            .and_then(|offset| offset.checked_add(1))
+        else {
+            return Ok(vec![]);
+        };
+        let last = (first - 1).saturating_add(i64::try_from(limit).unwrap_or(i64::MAX));
+        Ok(self
+            .ranked(
+                leaderboard,
+                season,
+                "r.rank BETWEEN ?4 AND ?5",
+                &[&first, &last],
+            )?
+            .into_iter()
+            .map(|(entry, _)| entry)
+            .collect())
+    }
+
+    /// The standing of `player` on `leaderboard` in `season`, if they hold an entry.
+    pub fn standing(
+        &self,
+        leaderboard: &Pubkey,
+        season: Option<u64>,
+        player: &Pubkey,
+    ) -> Result<Option<PlayerStanding>> {
+        let player = player.to_string();
+        Ok(self
+            .ranked(leaderboard, season, "r.player = ?4", &[&player])?
+            .into_iter()
+            .next()
+            .map(|(entry, total)| PlayerStanding {
+                percentile: (total - entry.rank) as f64 * 100.0 / total as f64,
+                entry,
+                total,
+            }))
+    }
+
+    /// The entries ranked up to `radius` places above and below `player`'s best entry on
+    /// `leaderboard` in `season`, including it. Empty if the player holds no entry.
+    pub fn around(
+        &self,
+        leaderboard: &Pubkey,
+        season: Option<u64>,
+        player: &Pubkey,
+        radius: usize,
+    ) -> Result<Vec<RankedEntry>> {
+        let rank = match self.standing(leaderboard, season, player)? {
+            Some(standing) => standing.entry.rank as i64,
+            None => return Ok(vec![]),
+        };
+
+        let radius = radius as i64;
+        let first = rank.saturating_sub(radius);
+        let last = rank.saturating_add(radius);
+        Ok(self
+            .ranked(
+                leaderboard,
+                season,
+                "r.rank BETWEEN ?4 AND ?5",
+                &[&first, &last],
+            )?
+            .into_iter()
+            .map(|(entry, _)| entry)
+            .collect())
+    }
+
+    /// The ranked entries of `leaderboard` in `season` matching `condition`, best first, along
+    /// with the number of entries on the board.
+    ///
+    /// `condition` filters the ranked rows `r` by rank or player, binding `params` from `?4`.
+    fn ranked(
+        &self,
+        leaderboard: &Pubkey,
+        season: Option<u64>,
+        condition: &str,
+        params: &[&dyn ToSql],
+    ) -> Result<Vec<(RankedEntry, u64)>> {
+        let rules = match self.rules(leaderboard)? {
+            Some(rules) => rules,
+            None => return Ok(vec![]),
+        };
+
+        let order = rules.order_by();
+        let best = if rules.allow_multiple_scores {
+            "1"
+        } else {
+            "best = 1"
+        };
+        let mut statement = self.conn.prepare(&format!(
+            "WITH eligible AS (
+                 SELECT s.rowid AS id, s.player, s.score, s.timestamp, s.tiebreak
+                 FROM scores s
+                 WHERE s.leaderboard = ?1 AND s.timestamp BETWEEN ?2 AND ?3 AND NOT EXISTS (
+                     SELECT 1 FROM bans b
+                     WHERE b.player = s.player AND b.leaderboard = s.leaderboard
+                 )
+             ),
+             numbered AS (
+                 SELECT *, ROW_NUMBER() OVER (PARTITION BY player ORDER BY {order}) AS best
+                 FROM eligible
+             ),
+             ranked AS (
+                 SELECT id, player, score, timestamp, tiebreak,
+                        ROW_NUMBER() OVER (ORDER BY {order}) AS rank,
+                        COUNT(*) OVER () AS total
+                 FROM numbered WHERE {best}
+             )
+             SELECT r.rank, r.total, r.player, p.username, r.score, r.timestamp, r.tiebreak
+             FROM ranked r
+             LEFT JOIN players p ON p.address = r.player
+             WHERE {condition}
+             ORDER BY r.rank"
+        ))?;
+
+        let leaderboard = leaderboard.to_string();
+        let (first, last) = rules.timestamps(season);
+        let mut bound: Vec<&dyn ToSql> = vec![&leaderboard, &first, &last];
+        bound.extend_from_slice(params);
+        let rows = statement.query_map(&bound[..], |row| {
+            Ok((
+                row.get::<_, i64>(0)? as u64,
+                row.get::<_, i64>(1)? as u64,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                ScoreEntry::new(
+                    decode_u64(row.get(4)?),
+                    row.get(5)?,
+                    decode_u64(row.get(6)?),
+                ),
+            ))
+        })?;
+
+        let mut entries = vec![];
+        for row in rows {
+            let (rank, total, player, username, entry) = row?;
+            let entry = RankedEntry {
+                rank,
+                player: parse_key(&player)?,
+                username,
+                entry,
+            };
+            entries.push((entry, total));
+        }
+        Ok(entries)
+    }
+
+    fn rules(&self, leaderboard: &Pubkey) -> Result<Option<Rules>> {
+        let rules = self
+            .conn
+            .query_row(
+                "SELECT is_ascending, allow_multiple_scores, tie_break, start_time, season_length
+                 FROM leaderboards WHERE address = ?1",
+                params![leaderboard.to_string()],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get::<_, u8>(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        rules
+            .map(
+                |(is_ascending, allow_multiple_scores, tie_break, start_time, season_length)| {
+                    Ok::<_, Error>(Rules {
+                        is_ascending,
+                        allow_multiple_scores,
+                        tie_break: parse_tie_break(tie_break)?,
+                        start_time,
+                        season_length,
+                    })
+                },
+            )
+            .transpose()
+    }
+}
@@ -0,0 +1,31 @@
+use solana_client::client_error::ClientError;
+
+/// Errors returned by this crate.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Returned if a store query fails.
+    #[error("store error: {0}")]
+    Store(#[from] rusqlite::Error),
+
+    /// Returned if an RPC request fails.
+    #[error("rpc error: {0}")]
+    Rpc(#[from] ClientError),
+
+    /// Returned if fetching or decoding SOAR data fails.
+    #[error("{0}")]
+    Client(#[from] soar_client::Error),
+
+    /// Returned if a fixture file can't be read.
+    #[error("failed to read fixture: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Returned if a fixture file isn't a recognized account or transaction dump.
+    #[error("invalid fixture {0}: {1}")]
+    InvalidFixture(String, String),
+
+    /// Returned if a stored value can't be converted back into its on-chain type.
+    #[error("corrupt store value: {0}")]
+    Corrupt(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
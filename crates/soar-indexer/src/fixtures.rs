@@ -0,0 +1,95 @@
+//! Ingesting recorded SOAR state from fixture files.
+//!
+//! A fixture directory holds JSON files of two kinds:
+//!
+//! - account dumps in the format written by `solana account --output json`, which is also
+//!   the format `solana-test-validator --account` loads;
+//! - transactions in the format returned by the `getTransaction` RPC method with a binary
+//!   encoding.
+
+use crate::{Error, Result, Store};
+use anchor_lang::prelude::Pubkey;
+use serde::Deserialize;
+use soar_client::events;
+use solana_account_decoder::UiAccount;
+use solana_sdk::account::Account;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// An account dump.
+#[derive(Deserialize)]
+struct AccountFixture {
+    pubkey: String,
+    account: UiAccount,
+}
+
+/// Number of fixtures ingested by [load_fixtures].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FixtureSummary {
+    /// Ingested SOAR accounts.
+    pub accounts: usize,
+
+    /// Applied transactions.
+    pub transactions: usize,
+}
+
+/// Ingest every fixture in `dir` into `store`.
+///
+/// Transactions are applied first in slot order, then accounts are ingested as a snapshot
+/// of the final state.
+pub fn load_fixtures(store: &Store, dir: impl AsRef<Path>) -> Result<FixtureSummary> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+    paths.sort();
+
+    let mut accounts = vec![];
+    let mut transactions = vec![];
+    for path in paths {
+        let invalid = |reason: String| Error::InvalidFixture(path.display().to_string(), reason);
+        let value: serde_json::Value =
+            serde_json::from_slice(&fs::read(&path)?).map_err(|e| invalid(e.to_string()))?;
+
+        if value.get("pubkey").is_some() && value.get("account").is_some() {
+            let fixture: AccountFixture =
+                serde_json::from_value(value).map_err(|e| invalid(e.to_string()))?;
+            let address = Pubkey::from_str(&fixture.pubkey)
+                .map_err(|_| invalid("invalid pubkey".to_string()))?;
+            let account: Account = fixture
+                .account
+                .decode()
+                .ok_or_else(|| invalid("unsupported account encoding".to_string()))?;
+            accounts.push((address, account));
+        } else if value.get("slot").is_some() && value.get("transaction").is_some() {
+            let transaction: EncodedConfirmedTransactionWithStatusMeta =
+                serde_json::from_value(value).map_err(|e| invalid(e.to_string()))?;
+            transactions.push(transaction);
+        } else {
+            return Err(invalid("not an account or transaction".to_string()));
+        }
+    }
+
+    let mut summary = FixtureSummary::default();
+    transactions.sort_by_key(|transaction| transaction.slot);
+    for transaction in &transactions {
+        let failed = matches!(&transaction.transaction.meta, Some(meta) if meta.err.is_some());
+        if failed {
+            continue;
+        }
+        for event in events::decode_transaction_events(transaction)? {
+            store.apply_event(&event)?;
+        }
+        summary.transactions += 1;
+    }
+
+    for (address, account) in accounts {
+        if account.owner == soar::ID && store.ingest_account(&address, &account.data)? {
+            summary.accounts += 1;
+        }
+    }
+
+    Ok(summary)
+}
@@ -0,0 +1,26 @@
+//! Off-chain indexer for the [SOAR](https://github.com/magicblock-labs/SOAR) program.
+//!
+//! Reconstructs full leaderboards from chain state into a local SQLite [Store]. State is
+//! ingested from an RPC node by snapshotting program accounts and following program
+//! transactions with an [Indexer], or from a recorded fixture set with [load_fixtures].
+//! The store answers rank, percentile and "players around me" queries over every score a
+//! leaderboard has seen, rather than only its on-chain top entries.
+
+#![allow(clippy::result_large_err)]
+
+pub mod board;
+pub mod error;
+pub mod fixtures;
+pub mod records;
+pub mod store;
+pub mod sync;
+
+pub use board::{PlayerStanding, RankedEntry};
+pub use error::{Error, Result};
+pub use fixtures::{load_fixtures, FixtureSummary};
+pub use records::{
+    AchievementRecord, GameRecord, LeaderboardRecord, PlayerAchievementRecord, PlayerRecord,
+    RegistrationRecord, RewardPayout, RewardRecord,
+};
+pub use store::Store;
+pub use sync::Indexer;
@@ -0,0 +1,175 @@
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::Pubkey;
+use clap::{Parser, Subcommand};
+use soar_indexer::{load_fixtures, Indexer, RankedEntry, Result, Store};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// Index SOAR state into a local SQLite store and query full leaderboards.
+#[derive(Parser)]
+struct Cli {
+    /// Path of the SQLite store.
+    #[arg(long, default_value = "soar-index.db")]
+    db: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Snapshot every SOAR account from an RPC node.
+    Sync {
+        /// RPC node to read from.
+        #[arg(long, default_value = "http://127.0.0.1:8899")]
+        url: String,
+    },
+    /// Follow SOAR transactions from an RPC node, polling for new ones.
+    Follow {
+        /// RPC node to read from.
+        #[arg(long, default_value = "http://127.0.0.1:8899")]
+        url: String,
+
+        /// Seconds to wait between polls.
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+    },
+    /// Ingest a directory of recorded account and transaction fixtures.
+    Load {
+        /// Directory holding the fixture files.
+        dir: PathBuf,
+    },
+    /// List the ranked entries of a leaderboard.
+    Top {
+        #[arg(long)]
+        leaderboard: Pubkey,
+
+        /// Only rank scores submitted during this season.
+        #[arg(long)]
+        season: Option<u64>,
+
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Show a player's rank and percentile on a leaderboard.
+    Rank {
+        #[arg(long)]
+        leaderboard: Pubkey,
+
+        /// Only rank scores submitted during this season.
+        #[arg(long)]
+        season: Option<u64>,
+
+        #[arg(long)]
+        player: Pubkey,
+    },
+    /// List the entries ranked around a player on a leaderboard.
+    Around {
+        #[arg(long)]
+        leaderboard: Pubkey,
+
+        /// Only rank scores submitted during this season.
+        #[arg(long)]
+        season: Option<u64>,
+
+        #[arg(long)]
+        player: Pubkey,
+
+        #[arg(long, default_value_t = 5)]
+        radius: usize,
+    },
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let store = Store::open(&cli.db)?;
+
+    match cli.command {
+        Command::Sync { url } => {
+            let indexer = Indexer::new(rpc(url), store);
+            let count = indexer.sync_accounts()?;
+            println!("Ingested {count} accounts");
+        }
+        Command::Follow { url, interval } => {
+            let indexer = Indexer::new(rpc(url), store);
+            loop {
+                let count = indexer.sync_transactions()?;
+                if count > 0 {
+                    println!("Processed {count} transactions");
+                }
+                thread::sleep(Duration::from_secs(interval));
+            }
+        }
+        Command::Load { dir } => {
+            let summary = load_fixtures(&store, dir)?;
+            println!(
+                "Applied {} transactions and ingested {} accounts",
+                summary.transactions, summary.accounts
+            );
+        }
+        Command::Top {
+            leaderboard,
+            season,
+            offset,
+            limit,
+        } => {
+            for entry in store.page(&leaderboard, season, offset, limit)? {
+                print_entry(&entry);
+            }
+        }
+        Command::Rank {
+            leaderboard,
+            season,
+            player,
+        } => match store.standing(&leaderboard, season, &player)? {
+            Some(standing) => {
+                print_entry(&standing.entry);
+                println!(
+                    "Rank {} of {}, ahead of {:.2}% of the board",
+                    standing.entry.rank, standing.total, standing.percentile
+                );
+            }
+            None => println!("{player} has no entry on {leaderboard}"),
+        },
+        Command::Around {
+            leaderboard,
+            season,
+            player,
+            radius,
+        } => {
+            for entry in store.around(&leaderboard, season, &player, radius)? {
+                print_entry(&entry);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn rpc(url: String) -> RpcClient {
+    RpcClient::new_with_commitment(url, CommitmentConfig::confirmed())
+}
+
+fn print_entry(entry: &RankedEntry) {
+    println!(
+        "{:>6}  {:<44}  {:<32}  {:>20}  {}",
+        entry.rank,
+        entry.player,
+        entry.username.as_deref().unwrap_or("-"),
+        entry.entry.score,
+        entry.entry.timestamp
+    );
+}
@@ -0,0 +1,466 @@
+//! Lookups of individual indexed accounts.
+
+use crate::store::{decode_u64, parse_key, parse_tie_break, Store};
+use crate::{Error, Result};
+use anchor_lang::prelude::Pubkey;
+use rusqlite::{params, OptionalExtension};
+use soar::{ScoreEntry, TieBreak};
+
+/// An indexed [Game][soar::Game] account.
+#[derive(Clone, Debug)]
+pub struct GameRecord {
+    pub address: Pubkey,
+    pub title: String,
+    pub description: String,
+    pub genre: u8,
+    pub game_type: u8,
+    pub nft_meta: Pubkey,
+    pub leaderboard_count: u64,
+    pub achievement_count: u64,
+    pub paused: bool,
+}
+
+/// An indexed [LeaderBoard][soar::LeaderBoard] account.
+#[derive(Clone, Debug)]
+pub struct LeaderboardRecord {
+    pub address: Pubkey,
+    pub game: Pubkey,
+    pub id: u64,
+    pub description: String,
+    pub nft_meta: Pubkey,
+    pub decimals: u8,
+    pub min_score: u64,
+    pub max_score: u64,
+    pub is_ascending: bool,
+    pub allow_multiple_scores: bool,
+    pub tie_break: TieBreak,
+    pub top_entries: Option<Pubkey>,
+    pub paused: bool,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub season_length: Option<i64>,
+}
+
+/// An indexed [Player][soar::Player] account.
+#[derive(Clone, Debug)]
+pub struct PlayerRecord {
+    pub address: Pubkey,
+    pub user: Pubkey,
+    pub username: String,
+    pub nft_meta: Pubkey,
+}
+
+/// A player's registration to a leaderboard, held by a [PlayerScoresList][soar::PlayerScoresList].
+#[derive(Clone, Debug)]
+pub struct RegistrationRecord {
+    pub address: Pubkey,
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub banned: bool,
+}
+
+/// An indexed [Achievement][soar::Achievement] account.
+#[derive(Clone, Debug)]
+pub struct AchievementRecord {
+    pub address: Pubkey,
+    pub game: Pubkey,
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub nft_meta: Pubkey,
+    pub rewards: Vec<Pubkey>,
+}
+
+/// An indexed [PlayerAchievement][soar::PlayerAchievement] account.
+#[derive(Clone, Debug)]
+pub struct PlayerAchievementRecord {
+    pub address: Pubkey,
+    pub player: Pubkey,
+    pub achievement: Pubkey,
+    pub timestamp: i64,
+    pub unlocked: bool,
+    pub claimed: bool,
+}
+
+/// What an indexed reward pays out.
+#[derive(Clone, Debug)]
+pub enum RewardPayout {
+    FungibleToken {
+        mint: Pubkey,
+        amount: u64,
+        token_program: Pubkey,
+    },
+    NonFungibleToken {
+        uri: String,
+    },
+}
+
+/// An indexed [Reward][soar::Reward] account.
+#[derive(Clone, Debug)]
+pub struct RewardRecord {
+    pub address: Pubkey,
+    pub achievement: Pubkey,
+    pub available_spots: u64,
+    pub paused: bool,
+    pub payout: RewardPayout,
+}
+
+impl Store {
+    /// The indexed game at `address`.
+    pub fn game(&self, address: &Pubkey) -> Result<Option<GameRecord>> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT address, title, description, genre, game_type, nft_meta,
+                 leaderboard_count, achievement_count, paused
+                 FROM games WHERE address = ?1",
+                params![address.to_string()],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get::<_, String>(5)?,
+                        row.get::<_, i64>(6)?,
+                        row.get::<_, i64>(7)?,
+                        row.get(8)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        row.map(
+            |(
+                address,
+                title,
+                description,
+                genre,
+                game_type,
+                nft_meta,
+                lbs,
+                achievements,
+                paused,
+            )| {
+                Ok(GameRecord {
+                    address: parse_key(&address)?,
+                    title,
+                    description,
+                    genre,
+                    game_type,
+                    nft_meta: parse_key(&nft_meta)?,
+                    leaderboard_count: lbs as u64,
+                    achievement_count: achievements as u64,
+                    paused,
+                })
+            },
+        )
+        .transpose()
+    }
+
+    /// The indexed leaderboard at `address`.
+    pub fn leaderboard(&self, address: &Pubkey) -> Result<Option<LeaderboardRecord>> {
+        Ok(self
+            .leaderboards_where("address = ?1", &address.to_string())?
+            .pop())
+    }
+
+    /// Every indexed leaderboard of `game`, ordered by id.
+    pub fn leaderboards(&self, game: &Pubkey) -> Result<Vec<LeaderboardRecord>> {
+        self.leaderboards_where("game = ?1", &game.to_string())
+    }
+
+    /// The indexed player at `address`.
+    pub fn player(&self, address: &Pubkey) -> Result<Option<PlayerRecord>> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT address, user, username, nft_meta FROM players WHERE address = ?1",
+                params![address.to_string()],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get(2)?,
+                        row.get::<_, String>(3)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        row.map(|(address, user, username, nft_meta)| {
+            Ok(PlayerRecord {
+                address: parse_key(&address)?,
+                user: parse_key(&user)?,
+                username,
+                nft_meta: parse_key(&nft_meta)?,
+            })
+        })
+        .transpose()
+    }
+
+    /// Every leaderboard `player` is registered to.
+    pub fn registrations(&self, player: &Pubkey) -> Result<Vec<RegistrationRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT r.address, r.player, r.leaderboard, EXISTS (
+                 SELECT 1 FROM bans b WHERE b.player = r.player AND b.leaderboard = r.leaderboard
+             )
+             FROM registrations r
+             WHERE r.player = ?1 ORDER BY r.leaderboard",
+        )?;
+        let rows = statement.query_map(params![player.to_string()], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get(3)?,
+            ))
+        })?;
+
+        let mut registrations = vec![];
+        for row in rows {
+            let (address, player, leaderboard, banned) = row?;
+            registrations.push(RegistrationRecord {
+                address: parse_key(&address)?,
+                player: parse_key(&player)?,
+                leaderboard: parse_key(&leaderboard)?,
+                banned,
+            });
+        }
+        Ok(registrations)
+    }
+
+    /// Every score `player` has submitted to `leaderboard`, oldest first.
+    ///
+    /// Includes scores evicted on-chain by the leaderboard's retention policy.
+    pub fn score_history(&self, player: &Pubkey, leaderboard: &Pubkey) -> Result<Vec<ScoreEntry>> {
+        let mut statement = self.conn.prepare(
+            "SELECT score, timestamp, tiebreak FROM scores
+             WHERE player = ?1 AND leaderboard = ?2 ORDER BY timestamp, rowid",
+        )?;
+        let rows = statement.query_map(
+            params![player.to_string(), leaderboard.to_string()],
+            |row| {
+                Ok(ScoreEntry::new(
+                    decode_u64(row.get(0)?),
+                    row.get(1)?,
+                    decode_u64(row.get(2)?),
+                ))
+            },
+        )?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// The indexed achievement at `address`.
+    pub fn achievement(&self, address: &Pubkey) -> Result<Option<AchievementRecord>> {
+        Ok(self
+            .achievements_where("address = ?1", &address.to_string())?
+            .pop())
+    }
+
+    /// Every indexed achievement of `game`, ordered by id.
+    pub fn achievements(&self, game: &Pubkey) -> Result<Vec<AchievementRecord>> {
+        self.achievements_where("game = ?1", &game.to_string())
+    }
+
+    /// Every achievement `player` has unlocked, oldest first.
+    pub fn unlocked_achievements(&self, player: &Pubkey) -> Result<Vec<PlayerAchievementRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT address, player, achievement, timestamp, unlocked, claimed
+             FROM player_achievements WHERE player = ?1 AND unlocked = 1
+             ORDER BY timestamp, address",
+        )?;
+        let rows = statement.query_map(params![player.to_string()], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+            ))
+        })?;
+
+        let mut achievements = vec![];
+        for row in rows {
+            let (address, player, achievement, timestamp, unlocked, claimed) = row?;
+            achievements.push(PlayerAchievementRecord {
+                address: parse_key(&address)?,
+                player: parse_key(&player)?,
+                achievement: parse_key(&achievement)?,
+                timestamp,
+                unlocked,
+                claimed,
+            });
+        }
+        Ok(achievements)
+    }
+
+    /// Every indexed reward of `achievement`.
+    pub fn rewards(&self, achievement: &Pubkey) -> Result<Vec<RewardRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT address, achievement, available_spots, paused, kind, mint, amount,
+             token_program, uri
+             FROM rewards WHERE achievement = ?1 ORDER BY address",
+        )?;
+        let rows = statement.query_map(params![achievement.to_string()], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, bool>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<i64>>(6)?,
+                row.get::<_, Option<String>>(7)?,
+                row.get::<_, Option<String>>(8)?,
+            ))
+        })?;
+
+        let mut rewards = vec![];
+        for row in rows {
+            let (
+                address,
+                achievement,
+                available_spots,
+                paused,
+                kind,
+                mint,
+                amount,
+                token_program,
+                uri,
+            ) = row?;
+            let payout = match (kind.as_str(), mint, amount, token_program, uri) {
+                ("ft", Some(mint), Some(amount), Some(token_program), _) => {
+                    RewardPayout::FungibleToken {
+                        mint: parse_key(&mint)?,
+                        amount: amount as u64,
+                        token_program: parse_key(&token_program)?,
+                    }
+                }
+                ("nft", _, _, _, Some(uri)) => RewardPayout::NonFungibleToken { uri },
+                _ => {
+                    return Err(Error::Corrupt(format!(
+                        "invalid reward {address} of kind {kind}"
+                    )))
+                }
+            };
+            rewards.push(RewardRecord {
+                address: parse_key(&address)?,
+                achievement: parse_key(&achievement)?,
+                available_spots: available_spots as u64,
+                paused,
+                payout,
+            });
+        }
+        Ok(rewards)
+    }
+
+    fn leaderboards_where(&self, condition: &str, value: &str) -> Result<Vec<LeaderboardRecord>> {
+        let mut statement = self.conn.prepare(&format!(
+            "SELECT address, game, id, description, nft_meta, decimals, min_score, max_score,
+             is_ascending, allow_multiple_scores, tie_break, top_entries, paused,
+             start_time, end_time, season_length
+             FROM leaderboards WHERE {condition} ORDER BY id"
+        ))?;
+        let rows = statement.query_map(params![value], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get(3)?,
+                row.get::<_, String>(4)?,
+                row.get(5)?,
+                row.get::<_, i64>(6)?,
+                row.get::<_, i64>(7)?,
+                row.get(8)?,
+                row.get(9)?,
+                row.get::<_, u8>(10)?,
+                row.get::<_, Option<String>>(11)?,
+                row.get(12)?,
+                row.get(13)?,
+                row.get(14)?,
+                row.get(15)?,
+            ))
+        })?;
+
+        let mut leaderboards = vec![];
+        for row in rows {
+            let (
+                address,
+                game,
+                id,
+                description,
+                nft_meta,
+                decimals,
+                min_score,
+                max_score,
+                is_ascending,
+                allow_multiple_scores,
+                tie_break,
+                top_entries,
+                paused,
+                start_time,
+                end_time,
+                season_length,
+            ) = row?;
+            leaderboards.push(LeaderboardRecord {
+                address: parse_key(&address)?,
+                game: parse_key(&game)?,
+                id: id as u64,
+                description,
+                nft_meta: parse_key(&nft_meta)?,
+                decimals,
+                min_score: min_score as u64,
+                max_score: max_score as u64,
+                is_ascending,
+                allow_multiple_scores,
+                tie_break: parse_tie_break(tie_break)?,
+                top_entries: top_entries.as_deref().map(parse_key).transpose()?,
+                paused,
+                start_time,
+                end_time,
+                season_length,
+            });
+        }
+        Ok(leaderboards)
+    }
+
+    fn achievements_where(&self, condition: &str, value: &str) -> Result<Vec<AchievementRecord>> {
+        let mut statement = self.conn.prepare(&format!(
+            "SELECT address, game, id, title, description, nft_meta, rewards
+             FROM achievements WHERE {condition} ORDER BY id"
+        ))?;
+        let rows = statement.query_map(params![value], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, String>(6)?,
+            ))
+        })?;
+
+        let mut achievements = vec![];
+        for row in rows {
+            let (address, game, id, title, description, nft_meta, rewards) = row?;
+            achievements.push(AchievementRecord {
+                address: parse_key(&address)?,
+                game: parse_key(&game)?,
+                id: id as u64,
+                title,
+                description,
+                nft_meta: parse_key(&nft_meta)?,
+                rewards: rewards
+                    .split_whitespace()
+                    .map(parse_key)
+                    .collect::<Result<_>>()?,
+            });
+        }
+        Ok(achievements)
+    }
+}
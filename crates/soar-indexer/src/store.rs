@@ -0,0 +1,655 @@
+//! SQLite store of indexed SOAR state.
+
+use crate::{Error, Result};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use soar::{RewardKind, ScoreEntry, TieBreak};
+use soar_client::accounts::{
+    decode, Achievement, Game, LeaderBoard, Player, PlayerAchievement, PlayerBan, PlayerScoresList,
+    Reward,
+};
+use soar_client::events::SoarEvent;
+use soar_client::pda;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS games (
+    address TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    genre INTEGER NOT NULL,
+    game_type INTEGER NOT NULL,
+    nft_meta TEXT NOT NULL,
+    leaderboard_count INTEGER NOT NULL,
+    achievement_count INTEGER NOT NULL,
+    paused INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS leaderboards (
+    address TEXT PRIMARY KEY,
+    game TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    description TEXT NOT NULL,
+    nft_meta TEXT NOT NULL,
+    decimals INTEGER NOT NULL,
+    min_score INTEGER NOT NULL,
+    max_score INTEGER NOT NULL,
+    is_ascending INTEGER NOT NULL,
+    allow_multiple_scores INTEGER NOT NULL,
+    tie_break INTEGER NOT NULL,
+    top_entries TEXT,
+    paused INTEGER NOT NULL,
+    start_time INTEGER,
+    end_time INTEGER,
+    season_length INTEGER
+);
+CREATE INDEX IF NOT EXISTS leaderboards_game ON leaderboards (game);
+CREATE TABLE IF NOT EXISTS players (
+    address TEXT PRIMARY KEY,
+    user TEXT NOT NULL,
+    username TEXT NOT NULL,
+    nft_meta TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS registrations (
+    address TEXT PRIMARY KEY,
+    player TEXT NOT NULL,
+    leaderboard TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS registrations_leaderboard ON registrations (leaderboard);
+CREATE TABLE IF NOT EXISTS bans (
+    address TEXT PRIMARY KEY,
+    player TEXT NOT NULL,
+    leaderboard TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS bans_player ON bans (player, leaderboard);
+CREATE TABLE IF NOT EXISTS scores (
+    player TEXT NOT NULL,
+    leaderboard TEXT NOT NULL,
+    score INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    tiebreak INTEGER NOT NULL,
+    from_event INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS scores_rank ON scores (leaderboard, score, timestamp, tiebreak);
+CREATE INDEX IF NOT EXISTS scores_season ON scores (leaderboard, timestamp);
+CREATE INDEX IF NOT EXISTS scores_player ON scores (player, leaderboard);
+CREATE TABLE IF NOT EXISTS achievements (
+    address TEXT PRIMARY KEY,
+    game TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    nft_meta TEXT NOT NULL,
+    rewards TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS achievements_game ON achievements (game);
+CREATE TABLE IF NOT EXISTS player_achievements (
+    address TEXT PRIMARY KEY,
+    player TEXT NOT NULL,
+    achievement TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    unlocked INTEGER NOT NULL,
+    claimed INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS player_achievements_player ON player_achievements (player);
+CREATE TABLE IF NOT EXISTS rewards (
+    address TEXT PRIMARY KEY,
+    achievement TEXT NOT NULL,
+    available_spots INTEGER NOT NULL,
+    paused INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    mint TEXT,
+    amount INTEGER,
+    token_program TEXT,
+    uri TEXT
+);
+CREATE INDEX IF NOT EXISTS rewards_achievement ON rewards (achievement);
+CREATE TABLE IF NOT EXISTS cursors (
+    name TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+";
+
+/// A local store of SOAR games, leaderboards, players, score histories and achievements.
+///
+/// Scores are kept as an append-only history: entries evicted on-chain by a leaderboard's
+/// retention policy stay in the store, while scores removed by a game authority are deleted.
+/// A player can submit the same entry more than once, so each submission is its own row.
+pub struct Store {
+    pub(crate) conn: Connection,
+}
+
+impl Store {
+    /// Open or create a store at `path`.
+    ///
+    /// The store is kept in write-ahead log mode, so readers don't block the indexer.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))?;
+        Self::init(conn)
+    }
+
+    /// Open an existing store at `path` for queries only.
+    pub fn open_read_only(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Store {
+            conn: Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?,
+        })
+    }
+
+    /// Open a store that lives in memory only.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Store { conn })
+    }
+
+    /// Store a raw SOAR account, dispatching on its discriminator.
+    ///
+    /// Returns `false` for account types the store doesn't track.
+    pub fn ingest_account(&self, address: &Pubkey, data: &[u8]) -> Result<bool> {
+        let discriminator = match data.get(..8) {
+            Some(discriminator) => discriminator,
+            None => return Ok(false),
+        };
+
+        if discriminator == Game::DISCRIMINATOR {
+            self.put_game(address, &decode(data)?)?;
+        } else if discriminator == LeaderBoard::DISCRIMINATOR {
+            self.put_leaderboard(address, &decode(data)?)?;
+        } else if discriminator == Player::DISCRIMINATOR {
+            self.put_player(address, &decode(data)?)?;
+        } else if discriminator == PlayerScoresList::DISCRIMINATOR {
+            self.put_player_scores(address, &decode(data)?)?;
+        } else if discriminator == PlayerBan::DISCRIMINATOR {
+            let ban: PlayerBan = decode(data)?;
+            self.put_ban(&ban.player_account, &ban.leaderboard)?;
+        } else if discriminator == Achievement::DISCRIMINATOR {
+            self.put_achievement(address, &decode(data)?)?;
+        } else if discriminator == PlayerAchievement::DISCRIMINATOR {
+            self.put_player_achievement(address, &decode(data)?)?;
+        } else if discriminator == Reward::DISCRIMINATOR {
+            self.put_reward(address, &decode(data)?)?;
+        } else {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Remove every record stored for the account at `address`, e.g. once it's been closed.
+    pub fn remove_account(&self, address: &Pubkey) -> Result<()> {
+        let address = address.to_string();
+        for table in [
+            "games",
+            "leaderboards",
+            "players",
+            "bans",
+            "achievements",
+            "player_achievements",
+            "rewards",
+        ] {
+            self.conn.execute(
+                &format!("DELETE FROM {table} WHERE address = ?1"),
+                params![address],
+            )?;
+        }
+        self.remove_registration(&address)
+    }
+
+    /// Apply a decoded program event.
+    ///
+    /// Events that carry everything the store needs are applied directly. Returns the accounts
+    /// whose records the event made stale and should be re-ingested.
+    pub fn apply_event(&self, event: &SoarEvent) -> Result<Vec<Pubkey>> {
+        let stale = match event {
+            SoarEvent::GameInitialized(e) => vec![e.game],
+            SoarEvent::GameUpdated(e) => vec![e.game],
+            SoarEvent::GamePauseUpdated(e) => {
+                self.conn.execute(
+                    "UPDATE games SET paused = ?2 WHERE address = ?1",
+                    params![e.game.to_string(), e.paused],
+                )?;
+                vec![]
+            }
+            SoarEvent::AuthorityAccepted(e) => vec![e.game],
+            SoarEvent::OwnershipTransferred(e) => vec![e.game],
+            SoarEvent::AchievementAdded(e) => vec![e.game, e.achievement],
+            SoarEvent::AchievementUpdated(e) => vec![e.achievement],
+            SoarEvent::LeaderboardAdded(e) => vec![e.game, e.leaderboard],
+            SoarEvent::LeaderboardUpdated(e) => vec![e.leaderboard],
+            SoarEvent::TopEntriesAdded(e) => vec![e.leaderboard],
+            SoarEvent::LeaderboardPauseUpdated(e) => {
+                self.conn.execute(
+                    "UPDATE leaderboards SET paused = ?2 WHERE address = ?1",
+                    params![e.leaderboard.to_string(), e.paused],
+                )?;
+                vec![]
+            }
+            SoarEvent::PlayerInitialized(e) => vec![e.player],
+            SoarEvent::PlayerUpdated(e) => vec![e.player],
+            SoarEvent::PlayerRegistered(e) => {
+                self.conn.execute(
+                    "INSERT OR IGNORE INTO registrations (address, player, leaderboard)
+                     VALUES (?1, ?2, ?3)",
+                    params![
+                        e.player_scores.to_string(),
+                        e.player.to_string(),
+                        e.leaderboard.to_string()
+                    ],
+                )?;
+                vec![]
+            }
+            SoarEvent::ScoreSubmitted(e) => {
+                // A snapshot taken after the submission already holds its entry.
+                let claimed = self.conn.execute(
+                    "UPDATE scores SET from_event = 1 WHERE rowid = (
+                         SELECT rowid FROM scores WHERE player = ?1 AND leaderboard = ?2
+                         AND score = ?3 AND timestamp = ?4 AND tiebreak = ?5 AND from_event = 0
+                         LIMIT 1
+                     )",
+                    score_params(&e.player, &e.leaderboard, &e.entry),
+                )?;
+                if claimed == 0 {
+                    self.put_score(&e.player, &e.leaderboard, &e.entry, true)?;
+                }
+                vec![]
+            }
+            SoarEvent::ScoreRemoved(e) => {
+                self.conn.execute(
+                    "DELETE FROM scores WHERE rowid = (
+                         SELECT rowid FROM scores WHERE player = ?1 AND leaderboard = ?2
+                         AND score = ?3 AND timestamp = ?4 AND tiebreak = ?5
+                         LIMIT 1
+                     )",
+                    score_params(&e.player, &e.leaderboard, &e.entry),
+                )?;
+                vec![]
+            }
+            SoarEvent::PlayerBanUpdated(e) => {
+                if e.banned {
+                    self.put_ban(&e.player, &e.leaderboard)?;
+                } else {
+                    let address = pda::find_player_ban_address(&e.leaderboard, &e.player).0;
+                    self.remove_account(&address)?;
+                }
+                vec![]
+            }
+            SoarEvent::PlayerScoresMerged(e) => {
+                self.conn.execute(
+                    "UPDATE scores SET player = ?1 WHERE player = ?2 AND leaderboard = ?3",
+                    params![
+                        e.player.to_string(),
+                        e.merged_player.to_string(),
+                        e.leaderboard.to_string()
+                    ],
+                )?;
+                vec![
+                    pda::find_player_scores_list_address(&e.player, &e.leaderboard).0,
+                    pda::find_player_scores_list_address(&e.merged_player, &e.leaderboard).0,
+                ]
+            }
+            SoarEvent::PlayerAchievementMerged(e) => {
+                vec![pda::find_player_achievement_address(&e.player, &e.achievement).0]
+            }
+            SoarEvent::AchievementUnlocked(e) => {
+                let address = pda::find_player_achievement_address(&e.player, &e.achievement).0;
+                self.conn.execute(
+                    "INSERT INTO player_achievements
+                     (address, player, achievement, timestamp, unlocked, claimed)
+                     VALUES (?1, ?2, ?3, ?4, 1, 0)
+                     ON CONFLICT (address) DO UPDATE SET unlocked = 1, timestamp = ?4",
+                    params![
+                        address.to_string(),
+                        e.player.to_string(),
+                        e.achievement.to_string(),
+                        e.timestamp
+                    ],
+                )?;
+                vec![]
+            }
+            SoarEvent::RewardAdded(e) => vec![e.achievement, e.reward],
+            SoarEvent::RewardUpdated(e) => vec![e.reward],
+            SoarEvent::RewardPauseUpdated(e) => {
+                self.conn.execute(
+                    "UPDATE rewards SET paused = ?2 WHERE address = ?1",
+                    params![e.reward.to_string(), e.paused],
+                )?;
+                vec![]
+            }
+            SoarEvent::RewardCancelled(e) => vec![e.achievement, e.reward],
+            SoarEvent::FtRewardClaimed(e) => vec![
+                e.reward,
+                pda::find_player_achievement_address(&e.player, &e.achievement).0,
+            ],
+            SoarEvent::NftRewardClaimed(e) => vec![
+                e.reward,
+                pda::find_player_achievement_address(&e.player, &e.achievement).0,
+            ],
+            SoarEvent::GameClosed(e) => {
+                self.remove_account(&e.game)?;
+                vec![]
+            }
+            SoarEvent::LeaderboardClosed(e) => {
+                self.remove_account(&e.leaderboard)?;
+                vec![]
+            }
+            SoarEvent::AchievementClosed(e) => {
+                self.remove_account(&e.achievement)?;
+                vec![]
+            }
+            SoarEvent::RewardClosed(e) => {
+                self.remove_account(&e.reward)?;
+                vec![e.achievement]
+            }
+            SoarEvent::PlayerClosed(e) => {
+                self.remove_account(&e.player)?;
+                vec![]
+            }
+            SoarEvent::PlayerScoresClosed(e) => {
+                let address = pda::find_player_scores_list_address(&e.player, &e.leaderboard).0;
+                self.remove_account(&address)?;
+                vec![]
+            }
+            SoarEvent::PlayerAchievementClosed(e) => {
+                let address = pda::find_player_achievement_address(&e.player, &e.achievement).0;
+                self.remove_account(&address)?;
+                vec![]
+            }
+            _ => vec![],
+        };
+
+        Ok(stale)
+    }
+
+    /// The value of the named cursor, if set.
+    pub fn cursor(&self, name: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT value FROM cursors WHERE name = ?1",
+                params![name],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Set the named cursor to `value`.
+    pub fn set_cursor(&self, name: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO cursors (name, value) VALUES (?1, ?2)
+             ON CONFLICT (name) DO UPDATE SET value = ?2",
+            params![name, value],
+        )?;
+        Ok(())
+    }
+
+    fn put_game(&self, address: &Pubkey, game: &Game) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO games (address, title, description, genre, game_type,
+             nft_meta, leaderboard_count, achievement_count, paused)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                address.to_string(),
+                game.meta.title,
+                game.meta.description,
+                game.meta.genre,
+                game.meta.game_type,
+                game.meta.nft_meta.to_string(),
+                game.leaderboard_count as i64,
+                game.achievement_count as i64,
+                game.paused
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn put_leaderboard(&self, address: &Pubkey, leaderboard: &LeaderBoard) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO leaderboards (address, game, id, description, nft_meta,
+             decimals, min_score, max_score, is_ascending, allow_multiple_scores, tie_break,
+             top_entries, paused, start_time, end_time, season_length)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+            params![
+                address.to_string(),
+                leaderboard.game.to_string(),
+                leaderboard.id as i64,
+                leaderboard.description,
+                leaderboard.nft_meta.to_string(),
+                leaderboard.decimals,
+                leaderboard.min_score as i64,
+                leaderboard.max_score as i64,
+                leaderboard.is_ascending,
+                leaderboard.allow_multiple_scores,
+                leaderboard.tie_break as u8,
+                leaderboard.top_entries.map(|key| key.to_string()),
+                leaderboard.paused,
+                leaderboard.start_time,
+                leaderboard.end_time,
+                leaderboard.season_length
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn put_player(&self, address: &Pubkey, player: &Player) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO players (address, user, username, nft_meta)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                address.to_string(),
+                player.user.to_string(),
+                player.username,
+                player.nft_meta.to_string()
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn put_player_scores(&self, address: &Pubkey, list: &PlayerScoresList) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO registrations (address, player, leaderboard)
+             VALUES (?1, ?2, ?3)",
+            params![
+                address.to_string(),
+                list.player_account.to_string(),
+                list.leaderboard.to_string()
+            ],
+        )?;
+
+        // The list holds a window of the player's submissions. Only add the copies of each
+        // entry the store is missing, keeping history the list has since evicted.
+        let mut counts = BTreeMap::new();
+        for entry in &list.scores {
+            *counts
+                .entry((entry.score, entry.timestamp, entry.tiebreak))
+                .or_insert(0) += 1;
+        }
+        for ((score, timestamp, tiebreak), count) in counts {
+            let entry = ScoreEntry::new(score, timestamp, tiebreak);
+            let stored: i64 = self.conn.query_row(
+                "SELECT COUNT(*) FROM scores WHERE player = ?1 AND leaderboard = ?2
+                 AND score = ?3 AND timestamp = ?4 AND tiebreak = ?5",
+                score_params(&list.player_account, &list.leaderboard, &entry),
+                |row| row.get(0),
+            )?;
+            for _ in stored..count {
+                self.put_score(&list.player_account, &list.leaderboard, &entry, false)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn put_ban(&self, player: &Pubkey, leaderboard: &Pubkey) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO bans (address, player, leaderboard) VALUES (?1, ?2, ?3)",
+            params![
+                pda::find_player_ban_address(leaderboard, player)
+                    .0
+                    .to_string(),
+                player.to_string(),
+                leaderboard.to_string()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Add a submission of `entry`, seen in a program event if `from_event`, or else in a
+    /// scores list snapshot.
+    fn put_score(
+        &self,
+        player: &Pubkey,
+        leaderboard: &Pubkey,
+        entry: &ScoreEntry,
+        from_event: bool,
+    ) -> Result<()> {
+        let (player, leaderboard, score, timestamp, tiebreak) =
+            score_params(player, leaderboard, entry);
+        self.conn.execute(
+            "INSERT INTO scores (player, leaderboard, score, timestamp, tiebreak, from_event)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![player, leaderboard, score, timestamp, tiebreak, from_event],
+        )?;
+        Ok(())
+    }
+
+    fn put_achievement(&self, address: &Pubkey, achievement: &Achievement) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO achievements (address, game, id, title, description,
+             nft_meta, rewards)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                address.to_string(),
+                achievement.game.to_string(),
+                achievement.id as i64,
+                achievement.title,
+                achievement.description,
+                achievement.nft_meta.to_string(),
+                achievement
+                    .rewards
+                    .iter()
+                    .map(|key| key.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn put_player_achievement(
+        &self,
+        address: &Pubkey,
+        player_achievement: &PlayerAchievement,
+    ) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO player_achievements (address, player, achievement,
+             timestamp, unlocked, claimed)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                address.to_string(),
+                player_achievement.player_account.to_string(),
+                player_achievement.achievement.to_string(),
+                player_achievement.timestamp,
+                player_achievement.unlocked,
+                player_achievement.claimed
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn put_reward(&self, address: &Pubkey, reward: &Reward) -> Result<()> {
+        let (kind, mint, amount, token_program, uri) = match &reward.reward {
+            RewardKind::FungibleToken {
+                mint,
+                amount,
+                token_program,
+                ..
+            } => (
+                "ft",
+                Some(mint.to_string()),
+                Some(*amount as i64),
+                Some(RewardKind::token_program(token_program).to_string()),
+                None,
+            ),
+            RewardKind::NonFungibleToken { uri, .. } => {
+                ("nft", None, None, None, Some(uri.clone()))
+            }
+        };
+        self.conn.execute(
+            "INSERT OR REPLACE INTO rewards (address, achievement, available_spots, paused, kind,
+             mint, amount, token_program, uri)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                address.to_string(),
+                reward.achievement.to_string(),
+                reward.available_spots as i64,
+                reward.paused,
+                kind,
+                mint,
+                amount,
+                token_program,
+                uri
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn remove_registration(&self, address: &str) -> Result<()> {
+        self.conn.execute(
+            "DELETE FROM scores WHERE (player, leaderboard) IN
+             (SELECT player, leaderboard FROM registrations WHERE address = ?1)",
+            params![address],
+        )?;
+        self.conn.execute(
+            "DELETE FROM registrations WHERE address = ?1",
+            params![address],
+        )?;
+        Ok(())
+    }
+}
+
+/// The parameters matching a score row's `player`, `leaderboard`, `score`, `timestamp` and
+/// `tiebreak` columns, in that order.
+fn score_params(
+    player: &Pubkey,
+    leaderboard: &Pubkey,
+    entry: &ScoreEntry,
+) -> (String, String, i64, i64, i64) {
+    (
+        player.to_string(),
+        leaderboard.to_string(),
+        encode_u64(entry.score),
+        entry.timestamp,
+        encode_u64(entry.tiebreak),
+    )
+}
+
+/// Store a `u64` in a signed SQLite integer, preserving its order.
+pub(crate) fn encode_u64(value: u64) -> i64 {
+    (value ^ (1 << 63)) as i64
+}
+
+/// Convert a value stored with [encode_u64] back into a `u64`.
+pub(crate) fn decode_u64(value: i64) -> u64 {
+    value as u64 ^ (1 << 63)
+}
+
+/// Parse a stored base58 address.
+pub(crate) fn parse_key(value: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value).map_err(|_| Error::Corrupt(format!("invalid address {value}")))
+}
+
+/// Convert a stored tie-break policy back into a [TieBreak].
+pub(crate) fn parse_tie_break(value: u8) -> Result<TieBreak> {
+    match value {
+        0 => Ok(TieBreak::EarliestFirst),
+        1 => Ok(TieBreak::LatestFirst),
+        2 => Ok(TieBreak::HighestValue),
+        3 => Ok(TieBreak::LowestValue),
+        _ => Err(Error::Corrupt(format!("invalid tie-break policy {value}"))),
+    }
+}
@@ -0,0 +1,169 @@
+//! Ingesting SOAR state from an RPC node.
+
+use crate::{Error, Result, Store};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use soar_client::accounts::{
+    Achievement, Game, LeaderBoard, Player, PlayerAchievement, PlayerBan, PlayerScoresList, Reward,
+};
+use soar_client::events;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::signature::Signature;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+/// Name of the cursor holding the last processed transaction signature.
+const SIGNATURE_CURSOR: &str = "last_signature";
+
+/// Maximum number of accounts requested in a single `getMultipleAccounts` call.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Keeps a [Store] in sync with the SOAR program's state on a cluster.
+pub struct Indexer {
+    rpc: RpcClient,
+    store: Store,
+}
+
+impl Indexer {
+    /// Create an indexer writing state read through `rpc` to `store`.
+    pub fn new(rpc: RpcClient, store: Store) -> Self {
+        Indexer { rpc, store }
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// Snapshot every tracked SOAR account into the store.
+    ///
+    /// Transactions up to the snapshot are marked as processed so [Indexer::sync_transactions]
+    /// continues from it. Returns the number of ingested accounts.
+    pub fn sync_accounts(&self) -> Result<usize> {
+        let latest = self.rpc.get_signatures_for_address_with_config(
+            &soar::ID,
+            GetConfirmedSignaturesForAddress2Config {
+                limit: Some(1),
+                commitment: Some(self.rpc.commitment()),
+                ..Default::default()
+            },
+        )?;
+
+        let discriminators = [
+            Game::DISCRIMINATOR,
+            LeaderBoard::DISCRIMINATOR,
+            Player::DISCRIMINATOR,
+            PlayerScoresList::DISCRIMINATOR,
+            PlayerBan::DISCRIMINATOR,
+            Achievement::DISCRIMINATOR,
+            PlayerAchievement::DISCRIMINATOR,
+            Reward::DISCRIMINATOR,
+        ];
+        let mut count = 0;
+        for discriminator in discriminators {
+            let config = RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                    0,
+                    &discriminator,
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    commitment: Some(self.rpc.commitment()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            for (address, account) in self
+                .rpc
+                .get_program_accounts_with_config(&soar::ID, config)?
+            {
+                if self.store.ingest_account(&address, &account.data)? {
+                    count += 1;
+                }
+            }
+        }
+
+        if let Some(latest) = latest.first() {
+            self.store.set_cursor(SIGNATURE_CURSOR, &latest.signature)?;
+        }
+        Ok(count)
+    }
+
+    /// Apply the events of every successful SOAR transaction since the last processed one,
+    /// oldest first, re-ingesting the accounts they leave stale.
+    ///
+    /// Returns the number of processed transactions.
+    pub fn sync_transactions(&self) -> Result<usize> {
+        let until = self
+            .store
+            .cursor(SIGNATURE_CURSOR)?
+            .map(|signature| parse_signature(&signature))
+            .transpose()?;
+
+        // Signatures are returned newest first, a page at a time.
+        let mut statuses = vec![];
+        let mut before = None;
+        loop {
+            let page = self.rpc.get_signatures_for_address_with_config(
+                &soar::ID,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until,
+                    limit: None,
+                    commitment: Some(self.rpc.commitment()),
+                },
+            )?;
+            match page.last() {
+                Some(last) => before = Some(parse_signature(&last.signature)?),
+                None => break,
+            }
+            statuses.extend(page);
+        }
+
+        for status in statuses.iter().rev() {
+            // Apply the transaction and move the cursor past it together, so it isn't applied
+            // twice if syncing is interrupted.
+            let transaction = self.store.conn.unchecked_transaction()?;
+            if status.err.is_none() {
+                let signature = parse_signature(&status.signature)?;
+                let mut stale = BTreeSet::new();
+                for event in events::fetch_transaction_events(&self.rpc, &signature)? {
+                    stale.extend(self.store.apply_event(&event)?);
+                }
+                self.refresh(&stale.into_iter().collect::<Vec<_>>())?;
+            }
+            self.store.set_cursor(SIGNATURE_CURSOR, &status.signature)?;
+            transaction.commit()?;
+        }
+
+        Ok(statuses.len())
+    }
+
+    /// Re-ingest the accounts at `addresses`, removing the records of those that no longer
+    /// exist.
+    pub fn refresh(&self, addresses: &[Pubkey]) -> Result<()> {
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.rpc.get_multiple_accounts(chunk)?;
+            for (address, account) in chunk.iter().zip(accounts) {
+                match account {
+                    Some(account) if account.owner == soar::ID => {
+                        self.store.ingest_account(address, &account.data)?;
+                    }
+                    Some(_) => {}
+                    None => self.store.remove_account(address)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_signature(signature: &str) -> Result<Signature> {
+    Signature::from_str(signature)
+        .map_err(|_| Error::Corrupt(format!("invalid signature {signature}")))
+}
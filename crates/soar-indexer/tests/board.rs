@@ -0,0 +1,245 @@
+//! Full-board queries over the recorded fixtures in `tests/fixtures/board`.
+//!
+//! Leaderboard `a` ranks each player's best score, highest first, breaking ties by the
+//! earliest submission. Player 5 holds its best score but is banned from it. Leaderboard `b`
+//! ranks every score, lowest first, breaking ties by the highest tiebreak value. Leaderboard
+//! `c` runs seasons of 100 seconds from timestamp 1000.
+
+use anchor_lang::prelude::Pubkey;
+use soar::ScoreEntry;
+use soar_client::events::{ScoreRemoved, ScoreSubmitted, SoarEvent};
+use soar_indexer::{load_fixtures, RankedEntry, Store};
+
+const FIXTURES: &str = "tests/fixtures/board";
+
+fn player(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n + 1; 32])
+}
+
+fn leaderboard_a() -> Pubkey {
+    Pubkey::new_from_array([10; 32])
+}
+
+fn leaderboard_b() -> Pubkey {
+    Pubkey::new_from_array([11; 32])
+}
+
+fn leaderboard_c() -> Pubkey {
+    Pubkey::new_from_array([12; 32])
+}
+
+fn store() -> Store {
+    let store = Store::open_in_memory().unwrap();
+    let summary = load_fixtures(&store, FIXTURES).unwrap();
+    assert_eq!(summary.accounts, 19);
+    store
+}
+
+fn ranking(entries: &[RankedEntry]) -> Vec<(u64, Pubkey, u64)> {
+    entries
+        .iter()
+        .map(|e| (e.rank, e.player, e.entry.score))
+        .collect()
+}
+
+fn history(store: &Store, n: u8) -> Vec<(u64, i64, u64)> {
+    store
+        .score_history(&player(n), &leaderboard_a())
+        .unwrap()
+        .iter()
+        .map(|e| (e.score, e.timestamp, e.tiebreak))
+        .collect()
+}
+
+fn submitted(n: u8, leaderboard: Pubkey, entry: ScoreEntry) -> SoarEvent {
+    SoarEvent::ScoreSubmitted(ScoreSubmitted {
+        player: player(n),
+        leaderboard,
+        entry,
+        old_rank: None,
+        new_rank: None,
+    })
+}
+
+#[test]
+fn ranks_best_score_per_player() {
+    let store = store();
+    let board = store.board(&leaderboard_a(), None).unwrap();
+
+    assert_eq!(
+        ranking(&board),
+        vec![
+            (1, player(3), u64::MAX - 1),
+            (2, player(2), 300),
+            (3, player(1), 300),
+            (4, player(4), 50),
+        ]
+    );
+    assert_eq!(board[0].username.as_deref(), Some("player3"));
+}
+
+#[test]
+fn ranks_every_score_with_tiebreak_values() {
+    let store = store();
+    let board = store.board(&leaderboard_b(), None).unwrap();
+
+    let entries = board
+        .iter()
+        .map(|e| (e.player, e.entry.score, e.entry.tiebreak, e.entry.timestamp))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            (player(2), 10, 5, 0),
+            (player(1), 10, 5, 2),
+            (player(1), 10, 1, 1),
+            (player(2), 20, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn pages_through_the_board() {
+    let store = store();
+
+    assert_eq!(
+        ranking(&store.page(&leaderboard_a(), None, 1, 2).unwrap()),
+        vec![(2, player(2), 300), (3, player(1), 300)]
+    );
+    assert_eq!(
+        ranking(&store.page(&leaderboard_a(), None, 3, 10).unwrap()),
+        vec![(4, player(4), 50)]
+    );
+    assert!(store
+        .page(&leaderboard_a(), None, 4, 10)
+        .unwrap()
+        .is_empty());
+    assert!(store.page(&leaderboard_a(), None, 0, 0).unwrap().is_empty());
+    assert!(store
+        .page(&leaderboard_a(), None, i64::MAX as usize, 10)
+        .unwrap()
+        .is_empty());
+    assert!(store
+        .page(&leaderboard_a(), None, usize::MAX, usize::MAX)
+        .unwrap()
+        .is_empty());
+    assert_eq!(store.board_size(&leaderboard_a(), None).unwrap(), 4);
+    assert_eq!(store.board_size(&leaderboard_c(), Some(1)).unwrap(), 2);
+    assert_eq!(store.board_size(&leaderboard_c(), Some(3)).unwrap(), 0);
+}
+
+#[test]
+fn finds_standings_and_neighbours() {
+    let store = store();
+
+    let standing = store
+        .standing(&leaderboard_a(), None, &player(1))
+        .unwrap()
+        .unwrap();
+    assert_eq!(standing.entry.rank, 3);
+    assert_eq!(standing.total, 4);
+    assert_eq!(standing.percentile, 25.0);
+    assert!(store
+        .standing(&leaderboard_a(), None, &player(5))
+        .unwrap()
+        .is_none());
+
+    assert_eq!(
+        ranking(&store.around(&leaderboard_a(), None, &player(1), 1).unwrap()),
+        vec![(2, player(2), 300), (3, player(1), 300), (4, player(4), 50)]
+    );
+    assert_eq!(
+        ranking(&store.around(&leaderboard_a(), None, &player(3), 1).unwrap()),
+        vec![(1, player(3), u64::MAX - 1), (2, player(2), 300)]
+    );
+    assert!(store
+        .around(&leaderboard_a(), None, &player(5), 1)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn keeps_identical_submissions() {
+    let store = store();
+    assert_eq!(history(&store, 4).len(), 2);
+
+    // Ingesting the same snapshot again adds nothing.
+    load_fixtures(&store, FIXTURES).unwrap();
+    assert_eq!(history(&store, 4).len(), 2);
+
+    // Removing one of the submissions leaves the other.
+    store
+        .apply_event(&SoarEvent::ScoreRemoved(ScoreRemoved {
+            player: player(4),
+            leaderboard: leaderboard_a(),
+            entry: ScoreEntry::new(50, 5, 0),
+        }))
+        .unwrap();
+    assert_eq!(history(&store, 4), vec![(50, 5, 0)]);
+}
+
+#[test]
+fn matches_events_against_snapshots() {
+    let store = store();
+
+    // An event for a submission the snapshot already holds doesn't add a row.
+    store
+        .apply_event(&submitted(2, leaderboard_a(), ScoreEntry::new(300, 15, 0)))
+        .unwrap();
+    assert_eq!(history(&store, 2).len(), 1);
+
+    // New submissions do, and outlive a snapshot that doesn't hold them.
+    let entry = ScoreEntry::new(400, 40, 0);
+    store
+        .apply_event(&submitted(2, leaderboard_a(), entry))
+        .unwrap();
+    store
+        .apply_event(&submitted(2, leaderboard_a(), entry))
+        .unwrap();
+    load_fixtures(&store, FIXTURES).unwrap();
+    assert_eq!(
+        history(&store, 2),
+        vec![(300, 15, 0), (400, 40, 0), (400, 40, 0)]
+    );
+    assert_eq!(
+        ranking(&store.page(&leaderboard_a(), None, 0, 2).unwrap()),
+        vec![(1, player(3), u64::MAX - 1), (2, player(2), 400)]
+    );
+}
+
+#[test]
+fn ranks_seasons_separately() {
+    let store = store();
+
+    assert_eq!(
+        ranking(&store.board(&leaderboard_c(), None).unwrap()),
+        vec![
+            (1, player(3), 900),
+            (2, player(1), 500),
+            (3, player(2), 300)
+        ]
+    );
+    assert_eq!(
+        ranking(&store.board(&leaderboard_c(), Some(0)).unwrap()),
+        vec![(1, player(1), 500), (2, player(2), 300)]
+    );
+    assert_eq!(
+        ranking(&store.board(&leaderboard_c(), Some(1)).unwrap()),
+        vec![(1, player(2), 200), (2, player(1), 100)]
+    );
+    assert!(store.board(&leaderboard_c(), Some(3)).unwrap().is_empty());
+
+    let standing = store
+        .standing(&leaderboard_c(), Some(2), &player(3))
+        .unwrap()
+        .unwrap();
+    assert_eq!((standing.entry.rank, standing.total), (1, 1));
+    assert!(store
+        .standing(&leaderboard_c(), Some(2), &player(1))
+        .unwrap()
+        .is_none());
+
+    // Leaderboards without seasons only have season 0.
+    assert_eq!(store.board(&leaderboard_a(), Some(0)).unwrap().len(), 4);
+    assert!(store.board(&leaderboard_a(), Some(1)).unwrap().is_empty());
+}
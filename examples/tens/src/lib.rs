@@ -1,7 +1,7 @@
 #![allow(clippy::result_large_err)]
 
 use anchor_lang::prelude::*;
-use soar::cpi::accounts::{ClaimFtReward, SubmitScore};
+use soar::cpi::accounts::{ClaimFtRewardAccounts, SubmitScore, UnlockAndClaimFtReward};
 use soar::cpi::{self};
 use soar::{LeaderTopEntries, PlayerScoresList};
 
@@ -35,7 +35,7 @@ mod tens {
         let tens = &mut ctx.accounts.tens_state;
         tens.counter = tens.counter.checked_add(1).unwrap();
 
-        if tens.counter % 10 == 0 {
+        if tens.counter.checked_rem(10) == Some(0) {
             msg!(" You won this round! ");
 
             let accounts = SubmitScore {
@@ -45,12 +45,15 @@ mod tens {
                 game: ctx.accounts.soar_state.to_account_info(),
                 leaderboard: ctx.accounts.soar_leaderboard.to_account_info(),
                 player_scores: ctx.accounts.soar_player_scores.to_account_info(),
+                player_ban: ctx.accounts.soar_player_ban.to_account_info(),
                 top_entries: ctx
                     .accounts
                     .soar_top_entries
                     .as_ref()
                     .map(|a| a.to_account_info()),
                 system_program: ctx.accounts.system_program.to_account_info(),
+                event_authority: ctx.accounts.soar_event_authority.to_account_info(),
+                program: ctx.accounts.soar_program.to_account_info(),
             };
 
             let state_bump = ctx.bumps.tens_state;
@@ -69,27 +72,35 @@ mod tens {
     pub fn claim_reward(ctx: Context<Claim>) -> Result<()> {
         // We claim a reward if the user's score is present in the top-entries account.
         let player = &ctx.accounts.player_account;
-        let top_entries = &ctx.accounts.soar_top_entries;
-        if top_entries
-            .top_scores
-            .iter()
-            .any(|score| score.player == player.key())
-            .eq(&true)
-        {
+        let has_top_score = {
+            let (_, top_scores) = LeaderTopEntries::load_scores(&ctx.accounts.soar_top_entries)?;
+            top_scores.iter().any(|score| score.player == player.key())
+        };
+        if has_top_score {
             msg!("Player has a top score!..Claiming reward: ");
-            let accounts = ClaimFtReward {
-                user: ctx.accounts.user.to_account_info(),
+            let accounts = UnlockAndClaimFtReward {
+                claim: ClaimFtRewardAccounts {
+                    user: ctx.accounts.user.to_account_info(),
+                    payer: ctx.accounts.user.to_account_info(),
+                    game: ctx.accounts.soar_state.to_account_info(),
+                    achievement: ctx.accounts.soar_achievement.to_account_info(),
+                    reward: ctx.accounts.soar_reward.to_account_info(),
+                    player_account: ctx.accounts.player_account.to_account_info(),
+                    player_achievement: ctx.accounts.soar_player_achievement.to_account_info(),
+                    reward_claim: ctx.accounts.soar_reward_claim.to_account_info(),
+                    reward_token_mint: ctx.accounts.reward_token_mint.to_account_info(),
+                    reward_vault: ctx.accounts.reward_vault.to_account_info(),
+                    user_token_account: ctx.accounts.user_token_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    associated_token_program: ctx
+                        .accounts
+                        .associated_token_program
+                        .to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
                 authority: ctx.accounts.tens_state.to_account_info(),
-                payer: ctx.accounts.user.to_account_info(),
-                game: ctx.accounts.soar_state.to_account_info(),
-                achievement: ctx.accounts.soar_achievement.to_account_info(),
-                reward: ctx.accounts.soar_reward.to_account_info(),
-                player_account: ctx.accounts.player_account.to_account_info(),
-                player_achievement: ctx.accounts.soar_player_achievement.to_account_info(),
-                source_token_account: ctx.accounts.source_token_account.to_account_info(),
-                user_token_account: ctx.accounts.user_token_account.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
+                event_authority: ctx.accounts.soar_event_authority.to_account_info(),
+                program: ctx.accounts.soar_program.to_account_info(),
             };
 
             let state_bump = ctx.bumps.tens_state;
@@ -99,7 +110,7 @@ mod tens {
             let cpi_ctx = CpiContext::new(ctx.accounts.soar_program.to_account_info(), accounts)
                 .with_signer(signer);
 
-            cpi::claim_ft_reward(cpi_ctx)?;
+            cpi::unlock_and_claim_ft_reward(cpi_ctx)?;
         } else {
             msg!("This user isn't eligible for a reward!");
         }
@@ -143,9 +154,13 @@ pub struct MakeMove<'info> {
     #[account(mut)]
     /// CHECK: The SOAR player scores account for this user.
     pub soar_player_scores: UncheckedAccount<'info>,
+    /// CHECK: The SOAR ban address for this user on the leaderboard.
+    pub soar_player_ban: UncheckedAccount<'info>,
     #[account(mut)]
     /// CHECK: The SOAR top entries account for this leaderboard.
     pub soar_top_entries: Option<UncheckedAccount<'info>>,
+    /// CHECK: The SOAR event authority.
+    pub soar_event_authority: UncheckedAccount<'info>,
     /// CHECK: The SOAR program ID.
     #[account(address = soar::ID)]
     pub soar_program: UncheckedAccount<'info>,
@@ -171,7 +186,7 @@ pub struct Claim<'info> {
     )]
     pub soar_player_scores: Account<'info, PlayerScoresList>,
     #[account(constraint = tens_state.soar.top_entries == soar_top_entries.key())]
-    pub soar_top_entries: Account<'info, LeaderTopEntries>,
+    pub soar_top_entries: AccountLoader<'info, LeaderTopEntries>,
     /// CHECK: The SOAR game for this tens program.
     pub soar_state: UncheckedAccount<'info>,
     /// CHECK: The SOAR achievement.
@@ -179,19 +194,28 @@ pub struct Claim<'info> {
     /// CHECK: The SOAR reward account.
     #[account(mut)]
     pub soar_reward: UncheckedAccount<'info>,
-    /// CHECK: The player-achievement account to be initialized.
+    /// CHECK: The player-achievement account, initialized if it doesn't exist.
     #[account(mut)]
     pub soar_player_achievement: UncheckedAccount<'info>,
-    /// CHECK: The specified source account for the reward.
+    /// CHECK: The account recording the player's claim of the reward.
+    #[account(mut)]
+    pub soar_reward_claim: UncheckedAccount<'info>,
+    /// CHECK: The mint of the reward.
+    pub reward_token_mint: UncheckedAccount<'info>,
+    /// CHECK: The vault holding the reward's tokens.
     #[account(mut)]
-    pub source_token_account: UncheckedAccount<'info>,
+    pub reward_vault: UncheckedAccount<'info>,
     /// CHECK: The user's token account.
     #[account(mut)]
     pub user_token_account: UncheckedAccount<'info>,
     /// CHECK: The token program.
     pub token_program: UncheckedAccount<'info>,
+    /// CHECK: The associated token program.
+    pub associated_token_program: UncheckedAccount<'info>,
     /// CHECK: The system program.
     pub system_program: UncheckedAccount<'info>,
+    /// CHECK: The SOAR event authority.
+    pub soar_event_authority: UncheckedAccount<'info>,
     /// CHECK: The SOAR program.
     #[account(address = soar::ID)]
     pub soar_program: UncheckedAccount<'info>,
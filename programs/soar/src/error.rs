@@ -31,4 +31,147 @@ pub enum SoarError {
 
     #[msg("No more rewards are being given out for this game")]
     NoAvailableRewards,
+
+    #[msg("Not all player accounts in this merge have approved it")]
+    MergeNotComplete,
+
+    #[msg("Missing or invalid ed25519 attestation")]
+    InvalidAttestation,
+
+    #[msg("The attestation has expired")]
+    AttestationExpired,
+
+    #[msg("Score submitted outside of the leaderboard's active time window")]
+    ScoreOutsideWindow,
+
+    #[msg("Invalid leaderboard start time, end time or season length")]
+    InvalidTimeWindow,
+
+    #[msg("The leaderboard's current season isn't over yet")]
+    SeasonNotOver,
+
+    #[msg("Invalid rank range for this leaderboard")]
+    InvalidRankRange,
+
+    #[msg("The leaderboard hasn't closed yet")]
+    LeaderboardNotClosed,
+
+    #[msg("The player doesn't hold this rank or it isn't covered by the reward")]
+    RankNotEligible,
+
+    #[msg("The player is banned from this leaderboard")]
+    PlayerBanned,
+
+    #[msg("No score exists at the given index")]
+    ScoreNotFound,
+
+    #[msg("The game still has leaderboards or achievements that haven't been closed")]
+    AccountsStillLive,
+
+    #[msg("The achievement still has a reward attached")]
+    RewardStillAttached,
+
+    #[msg("The reward's token delegation hasn't been revoked")]
+    DelegationNotRevoked,
+
+    #[msg("This account still guards a reward that can be claimed")]
+    RewardStillClaimable,
+
+    #[msg("A bounded score retention policy must keep at least one score")]
+    InvalidRetention,
+
+    #[msg("The top entries account already holds all its scores")]
+    TopEntriesFullyAllocated,
+
+    #[msg("The token program doesn't match the reward's mint")]
+    InvalidTokenProgram,
+
+    #[msg("The reward vault holds no tokens beyond what its remaining spots are owed")]
+    NothingToWithdraw,
+
+    #[msg("The reward vault still holds tokens")]
+    RewardVaultNotEmpty,
+
+    #[msg("Claims for this reward are paused")]
+    RewardPaused,
+
+    #[msg("The achievement already has the maximum number of rewards")]
+    TooManyRewards,
+
+    #[msg("The player hasn't unlocked this achievement")]
+    AchievementNotUnlocked,
+
+    #[msg("A game must keep at least one authority with the admin role")]
+    MissingAdmin,
+
+    #[msg("Only the game's owner can grant or revoke the admin role")]
+    NotGameOwner,
+
+    #[msg("New authorities must accept a proposal before they are added")]
+    UnconfirmedAuthority,
+
+    #[msg("The key is already one of the game's authorities")]
+    AuthorityAlreadyExists,
+
+    #[msg("The key isn't one of the game's authorities")]
+    AuthorityNotFound,
+
+    #[msg("The proposal's timelock hasn't expired yet")]
+    TimelockActive,
+
+    #[msg("The timelock can't be negative")]
+    InvalidTimelock,
+
+    #[msg("No ownership transfer to this key is pending")]
+    NoPendingOwner,
+
+    #[msg("The game is paused")]
+    GamePaused,
+
+    #[msg("The leaderboard is paused")]
+    LeaderboardPaused,
+
+    #[msg("The account isn't a scores list for this leaderboard")]
+    InvalidPlayerScoresList,
+
+    #[msg("The leaderboard already has a top entries account")]
+    TopEntriesAlreadyExist,
+
+    #[msg("A top entries account must retain at least one score")]
+    InvalidScoresToRetain,
+
+    #[msg("The previous season's top entries must be archived first")]
+    SeasonNotArchived,
+
+    #[msg(
+        "Rank rewards must name a season on seasonal leaderboards, and need an end time otherwise"
+    )]
+    InvalidRankRewardSeason,
+
+    #[msg("The leaderboard has closed and its top entries can no longer change")]
+    TopEntriesFrozen,
+
+    #[msg("The leaderboard still has rank rewards or season archives that haven't been closed")]
+    LeaderboardAccountsStillLive,
+
+    #[msg("Seasonal leaderboards can't retain more top scores than a season archive holds")]
+    TooManySeasonScores,
+
+    #[msg("The account doesn't have a legacy layout")]
+    NotLegacyAccount,
+
+    #[msg("The reward vault doesn't hold what the reward's remaining spots are owed")]
+    RewardVaultUnderfunded,
+
+    #[msg("The player's achievement was merged into another player's")]
+    PlayerAchievementMerged,
+
+    #[msg("The reward was cancelled")]
+    CancelledReward,
+
+    #[msg("A game closed at this address can't be initialized again")]
+    GameClosed,
+
+    #[msg("The attestation was issued before the player's scores list was registered")]
+    StaleAttestation,
 }
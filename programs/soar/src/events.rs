@@ -0,0 +1,502 @@
+//! Events emitted through self-invocation by every instruction that changes program state.
+
+use crate::state::{Authority, ScoreEntry};
+use anchor_lang::prelude::*;
+
+/// A [Game][crate::Game] was initialized.
+#[event]
+#[derive(Clone, Debug)]
+pub struct GameInitialized {
+    pub game: Pubkey,
+    pub owner: Pubkey,
+    pub auth: Vec<Authority>,
+}
+
+/// A [Game][crate::Game]'s attributes or authorities were updated.
+#[event]
+#[derive(Clone, Debug)]
+pub struct GameUpdated {
+    pub game: Pubkey,
+    pub auth: Vec<Authority>,
+}
+
+/// A game was paused or unpaused.
+#[event]
+#[derive(Clone, Debug)]
+pub struct GamePauseUpdated {
+    pub game: Pubkey,
+    pub paused: bool,
+}
+
+/// A change to a game's authority list was proposed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AuthorityProposed {
+    pub game: Pubkey,
+    pub proposal: Pubkey,
+    pub authority: Authority,
+    pub replaces: Option<Pubkey>,
+    pub unlock_time: i64,
+}
+
+/// A proposed authority accepted its proposal and was added to the game's authority list.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AuthorityAccepted {
+    pub game: Pubkey,
+    pub authority: Authority,
+    pub replaced: Option<Pubkey>,
+}
+
+/// An [AuthorityProposal][crate::AuthorityProposal] was withdrawn before being accepted.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AuthorityProposalCancelled {
+    pub game: Pubkey,
+    pub proposal: Pubkey,
+}
+
+/// A game's owner started, changed or withdrew a transfer of ownership.
+#[event]
+#[derive(Clone, Debug)]
+pub struct OwnershipTransferProposed {
+    pub game: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+}
+
+/// A game's pending owner accepted ownership.
+#[event]
+#[derive(Clone, Debug)]
+pub struct OwnershipTransferred {
+    pub game: Pubkey,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// An [Achievement][crate::Achievement] was added to a game.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AchievementAdded {
+    pub game: Pubkey,
+    pub achievement: Pubkey,
+    pub id: u64,
+}
+
+/// An [Achievement][crate::Achievement] was updated.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AchievementUpdated {
+    pub achievement: Pubkey,
+}
+
+/// A [LeaderBoard][crate::LeaderBoard] was added to a game.
+#[event]
+#[derive(Clone, Debug)]
+pub struct LeaderboardAdded {
+    pub game: Pubkey,
+    pub leaderboard: Pubkey,
+    pub id: u64,
+    pub top_entries: Option<Pubkey>,
+}
+
+/// A [LeaderBoard][crate::LeaderBoard] was updated.
+#[event]
+#[derive(Clone, Debug)]
+pub struct LeaderboardUpdated {
+    pub leaderboard: Pubkey,
+}
+
+/// A [LeaderBoard][crate::LeaderBoard] was paused or unpaused.
+#[event]
+#[derive(Clone, Debug)]
+pub struct LeaderboardPauseUpdated {
+    pub leaderboard: Pubkey,
+    pub paused: bool,
+}
+
+/// A leaderboard season's top entries were archived and reset.
+#[event]
+#[derive(Clone, Debug)]
+pub struct SeasonArchived {
+    pub leaderboard: Pubkey,
+    pub season: u64,
+    pub archive: Pubkey,
+}
+
+/// A [LeaderTopEntries][crate::LeaderTopEntries] account was grown to `capacity` slots.
+#[event]
+#[derive(Clone, Debug)]
+pub struct TopEntriesGrown {
+    pub leaderboard: Pubkey,
+    pub top_entries: Pubkey,
+    pub capacity: u16,
+}
+
+/// A [LeaderTopEntries][crate::LeaderTopEntries] account was recomputed from `lists`
+/// player scores lists.
+#[event]
+#[derive(Clone, Debug)]
+pub struct TopEntriesRebuilt {
+    pub leaderboard: Pubkey,
+    pub top_entries: Pubkey,
+    pub lists: u32,
+}
+
+/// A [LeaderTopEntries][crate::LeaderTopEntries] account was created for an existing
+/// leaderboard.
+#[event]
+#[derive(Clone, Debug)]
+pub struct TopEntriesAdded {
+    pub leaderboard: Pubkey,
+    pub top_entries: Pubkey,
+    pub scores_to_retain: u16,
+}
+
+/// The number of scores a leaderboard's top entries retain was changed, reallocating
+/// `capacity` slots.
+#[event]
+#[derive(Clone, Debug)]
+pub struct TopEntriesResized {
+    pub leaderboard: Pubkey,
+    pub top_entries: Pubkey,
+    pub scores_to_retain: u16,
+    pub capacity: u16,
+}
+
+/// A [Player][crate::Player] account was initialized for `user`.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerInitialized {
+    pub player: Pubkey,
+    pub user: Pubkey,
+}
+
+/// A [Player][crate::Player] account was updated.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerUpdated {
+    pub player: Pubkey,
+}
+
+/// A player registered to a leaderboard.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerRegistered {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub player_scores: Pubkey,
+}
+
+/// A score was recorded for a player.
+///
+/// Ranks are the player's best 1-based position in the leaderboard's top entries before and
+/// after the submission, [None] when absent or if the leaderboard has no top entries.
+#[event]
+#[derive(Clone, Debug)]
+pub struct ScoreSubmitted {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub entry: ScoreEntry,
+    pub old_rank: Option<u32>,
+    pub new_rank: Option<u32>,
+}
+
+/// A score was removed from a player's scores list.
+#[event]
+#[derive(Clone, Debug)]
+pub struct ScoreRemoved {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub entry: ScoreEntry,
+}
+
+/// A player's entries were evicted from a leaderboard's top entries.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerEvicted {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub removed: u32,
+}
+
+/// A player was banned from or reinstated to a leaderboard.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerBanUpdated {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub banned: bool,
+}
+
+/// A merge of player accounts was initiated.
+#[event]
+#[derive(Clone, Debug)]
+pub struct MergeInitiated {
+    pub merged: Pubkey,
+    pub initiator: Pubkey,
+    pub keys: Vec<Pubkey>,
+}
+
+/// A player approved a merge. `merge_complete` is set once every player has approved.
+#[event]
+#[derive(Clone, Debug)]
+pub struct MergeApproved {
+    pub merged: Pubkey,
+    pub player: Pubkey,
+    pub merge_complete: bool,
+}
+
+/// A merged player's scores for a leaderboard were moved into `player`'s.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerScoresMerged {
+    pub merged: Pubkey,
+    pub leaderboard: Pubkey,
+    pub player: Pubkey,
+    pub merged_player: Pubkey,
+}
+
+/// A merged player's achievement status was moved into `player`'s.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerAchievementMerged {
+    pub merged: Pubkey,
+    pub achievement: Pubkey,
+    pub player: Pubkey,
+    pub merged_player: Pubkey,
+}
+
+/// A player unlocked an achievement.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AchievementUnlocked {
+    pub player: Pubkey,
+    pub achievement: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A [Reward][crate::Reward] was added to an achievement.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardAdded {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub available_spots: u64,
+}
+
+/// Tokens were deposited into a fungible token reward's vault.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardFunded {
+    pub reward: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+}
+
+/// Tokens no longer owed to any remaining spot were withdrawn from a reward's vault.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardWithdrawn {
+    pub reward: Pubkey,
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// A [Reward][crate::Reward]'s spots or payout were updated.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardUpdated {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub available_spots: u64,
+}
+
+/// Claims for a [Reward][crate::Reward] were paused or resumed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardPauseUpdated {
+    pub reward: Pubkey,
+    pub paused: bool,
+}
+
+/// A [Reward][crate::Reward] was detached from its achievement and its unclaimed tokens
+/// refunded.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardCancelled {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub refunded: u64,
+}
+
+/// A player claimed a fungible token achievement reward.
+#[event]
+#[derive(Clone, Debug)]
+pub struct FtRewardClaimed {
+    pub player: Pubkey,
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub user_token_account: Pubkey,
+}
+
+/// A player claimed an nft achievement reward minted to `mint`.
+#[event]
+#[derive(Clone, Debug)]
+pub struct NftRewardClaimed {
+    pub player: Pubkey,
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// A minted nft reward was verified as part of its collection.
+#[event]
+#[derive(Clone, Debug)]
+pub struct NftRewardVerified {
+    pub reward: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// A [RankReward][crate::RankReward] was added to a leaderboard.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RankRewardAdded {
+    pub leaderboard: Pubkey,
+    pub rank_reward: Pubkey,
+    pub min_rank: u32,
+    pub max_rank: u32,
+    pub season: Option<u64>,
+}
+
+/// A player claimed a fungible token reward for finishing at `rank`.
+#[event]
+#[derive(Clone, Debug)]
+pub struct FtRankRewardClaimed {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub rank_reward: Pubkey,
+    pub rank: u32,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// A player claimed an nft reward for finishing at `rank`, minted to `mint`.
+#[event]
+#[derive(Clone, Debug)]
+pub struct NftRankRewardClaimed {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+    pub rank_reward: Pubkey,
+    pub rank: u32,
+    pub mint: Pubkey,
+}
+
+/// A [Game][crate::Game] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct GameClosed {
+    pub game: Pubkey,
+}
+
+/// A [LeaderBoard][crate::LeaderBoard] account was closed along with its top entries.
+#[event]
+#[derive(Clone, Debug)]
+pub struct LeaderboardClosed {
+    pub leaderboard: Pubkey,
+}
+
+/// A [SeasonTopEntries][crate::SeasonTopEntries] archive was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct SeasonTopEntriesClosed {
+    pub leaderboard: Pubkey,
+    pub season: u64,
+}
+
+/// An [Achievement][crate::Achievement] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AchievementClosed {
+    pub achievement: Pubkey,
+}
+
+/// A [Reward][crate::Reward] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardClosed {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+}
+
+/// A [RankReward][crate::RankReward] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RankRewardClosed {
+    pub leaderboard: Pubkey,
+    pub rank_reward: Pubkey,
+}
+
+/// A [Player][crate::Player] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerClosed {
+    pub player: Pubkey,
+}
+
+/// A [PlayerScoresList][crate::PlayerScoresList] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerScoresClosed {
+    pub player: Pubkey,
+    pub leaderboard: Pubkey,
+}
+
+/// A [PlayerAchievement][crate::PlayerAchievement] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct PlayerAchievementClosed {
+    pub player: Pubkey,
+    pub achievement: Pubkey,
+}
+
+/// A [Merged][crate::Merged] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct MergedClosed {
+    pub merged: Pubkey,
+}
+
+/// An [NftClaim][crate::NftClaim] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct NftClaimClosed {
+    pub reward: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// A [RewardClaim][crate::RewardClaim] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RewardClaimClosed {
+    pub reward: Pubkey,
+    pub player: Pubkey,
+}
+
+/// A [RankRewardClaim][crate::RankRewardClaim] account was closed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct RankRewardClaimClosed {
+    pub rank_reward: Pubkey,
+    pub player: Pubkey,
+}
+
+/// An account created under a legacy layout was converted to its current one.
+#[event]
+#[derive(Clone, Debug)]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+}
@@ -0,0 +1,45 @@
+use crate::{
+    error::SoarError, events::AuthorityAccepted, instructions::propose_authority, state::Game,
+    utils, AcceptAuthority,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.unlock_time,
+        SoarError::TimelockActive
+    );
+    let authority = proposal.authority;
+    let replaces = proposal.replaces;
+
+    // The authority list and the proposer's own roles may have changed since the proposal
+    // was made.
+    let game = &mut ctx.accounts.game;
+    propose_authority::check_proposal(game, &proposal.proposed_by, authority, replaces)?;
+    match replaces {
+        Some(key) => {
+            let index = game
+                .find_authority(&key)
+                .ok_or(SoarError::AuthorityNotFound)?;
+            game.auth[index] = authority;
+        }
+        None => {
+            utils::resize_account(
+                &game.to_account_info(),
+                &ctx.accounts.new_authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                Game::size(game.auth.len().checked_add(1).unwrap()),
+            )?;
+            game.auth.push(authority);
+        }
+    }
+    utils::check_auth(&game.auth)?;
+
+    emit_cpi!(AuthorityAccepted {
+        game: game.key(),
+        authority,
+        replaced: replaces,
+    });
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crate::{events::OwnershipTransferred, AcceptGameOwnership};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<AcceptGameOwnership>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let previous_owner = game.owner;
+    game.owner = ctx.accounts.new_owner.key();
+    game.pending_owner = None;
+
+    emit_cpi!(OwnershipTransferred {
+        game: game.key(),
+        previous_owner,
+        owner: game.owner,
+    });
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::{
+    events::AchievementAdded,
     state::{Achievement, FieldsCheck},
     AddAchievement,
 };
@@ -12,6 +13,7 @@ pub fn handler(
 ) -> Result<()> {
     let game = &mut ctx.accounts.game;
     game.achievement_count = game.next_achievement();
+    game.live_achievements = game.live_achievements.checked_add(1).unwrap();
     let obj = Achievement::new(
         game.key(),
         title,
@@ -22,5 +24,11 @@ pub fn handler(
 
     obj.check()?;
     ctx.accounts.new_achievement.set_inner(obj);
+
+    emit_cpi!(AchievementAdded {
+        game: ctx.accounts.game.key(),
+        achievement: ctx.accounts.new_achievement.key(),
+        id: ctx.accounts.new_achievement.id,
+    });
     Ok(())
 }
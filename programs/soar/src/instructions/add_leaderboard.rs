@@ -1,5 +1,6 @@
 use crate::error::SoarError;
-use crate::state::{FieldsCheck, LeaderBoardScore, RegisterLeaderBoardInput};
+use crate::events::LeaderboardAdded;
+use crate::state::{FieldsCheck, LeaderBoardScore, LeaderTopEntries, RegisterLeaderBoardInput};
 use crate::AddLeaderBoard;
 use anchor_lang::prelude::*;
 
@@ -17,27 +18,36 @@ pub fn handler(ctx: Context<AddLeaderBoard>, input: RegisterLeaderBoardInput) ->
     ctx.accounts.leaderboard.id = new_count;
     ctx.accounts.leaderboard.game = game.key();
     ctx.accounts.game.leaderboard_count = new_count;
+    ctx.accounts.game.live_leaderboards =
+        ctx.accounts.game.live_leaderboards.checked_add(1).unwrap();
 
     if retain_count > 0 {
-        require!(
-            ctx.accounts.top_entries.is_some(),
-            SoarError::MissingExpectedAccount
-        );
-    }
-
-    if retain_count > 0 && ctx.accounts.top_entries.is_some() {
-        let top_entries = &mut ctx.accounts.top_entries.as_mut().unwrap();
-
-        top_entries.is_ascending = order;
-        top_entries.top_scores = vec![LeaderBoardScore::default(); retain_count as usize];
-
-        if top_entries.is_ascending {
-            top_entries.top_scores.iter_mut().for_each(|s| {
-                s.entry.score = ctx.accounts.leaderboard.max_score;
-            });
-        }
+        let top_entries = ctx
+            .accounts
+            .top_entries
+            .as_ref()
+            .ok_or(SoarError::MissingExpectedAccount)?;
+
+        let season = ctx
+            .accounts
+            .leaderboard
+            .season_at(Clock::get().unwrap().unix_timestamp);
+        let capacity = LeaderTopEntries::initial_capacity(retain_count);
+
+        let (mut header, mut scores) = LeaderTopEntries::load_init_scores(top_entries)?;
+        *header = LeaderTopEntries::new(season, retain_count, capacity);
+        scores.fill(LeaderBoardScore::placeholder(
+            order,
+            ctx.accounts.leaderboard.max_score,
+        ));
         ctx.accounts.leaderboard.top_entries = Some(top_entries.key());
     }
 
+    emit_cpi!(LeaderboardAdded {
+        game: ctx.accounts.game.key(),
+        leaderboard: ctx.accounts.leaderboard.key(),
+        id: new_count,
+        top_entries: ctx.accounts.leaderboard.top_entries,
+    });
     Ok(())
 }
@@ -0,0 +1,142 @@
+use crate::{
+    error::SoarError,
+    events::{RankRewardAdded, RewardFunded},
+    state::{AddRankRewardInput, LeaderBoard, LeaderTopEntries, RewardKind, RewardKindInput},
+    utils, FieldsCheck,
+};
+use anchor_lang::prelude::*;
+
+/// Check that the ranks of `input` fit the leaderboard's top entries, and that they will
+/// become final: as a season's archive on seasonal leaderboards, or once the leaderboard
+/// closes otherwise.
+fn check_input(
+    leaderboard: &LeaderBoard,
+    top_entries: &AccountLoader<LeaderTopEntries>,
+    input: &AddRankRewardInput,
+) -> Result<()> {
+    let top_entries_len = top_entries.load()?.scores_to_retain as usize;
+    require!(
+        input.max_rank as usize <= top_entries_len,
+        SoarError::InvalidRankRange
+    );
+
+    let valid_season = match input.season {
+        Some(_) => leaderboard.season_length.is_some(),
+        None => leaderboard.season_length.is_none() && leaderboard.end_time.is_some(),
+    };
+    require!(valid_season, SoarError::InvalidRankRewardSeason);
+
+    Ok(())
+}
+
+pub mod ft {
+    use super::*;
+    use crate::AddFtRankReward;
+
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddFtRankReward<'info>>,
+        input: AddRankRewardInput,
+    ) -> Result<()> {
+        check_input(&ctx.accounts.leaderboard, &ctx.accounts.top_entries, &input)?;
+
+        let new_reward = &mut ctx.accounts.new_reward;
+        new_reward.leaderboard = ctx.accounts.leaderboard.key();
+        new_reward.min_rank = input.min_rank;
+        new_reward.max_rank = input.max_rank;
+        new_reward.season = input.season;
+
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.live_rank_rewards = leaderboard.live_rank_rewards.checked_add(1).unwrap();
+
+        match input.kind {
+            RewardKindInput::Ft { deposit, amount } => {
+                let mint = &ctx.accounts.reward_token_mint;
+                let vault = &ctx.accounts.reward_vault;
+                let token_program = &ctx.accounts.token_program;
+
+                new_reward.reward = RewardKind::FungibleToken {
+                    mint: mint.key(),
+                    account: vault.key(),
+                    amount,
+                    token_program: token_program.key(),
+                };
+                new_reward.check()?;
+
+                utils::transfer_checked(
+                    &ctx.accounts.source_token_account.to_account_info(),
+                    &mint.to_account_info(),
+                    &vault.to_account_info(),
+                    &ctx.accounts.token_account_owner.to_account_info(),
+                    &token_program.to_account_info(),
+                    ctx.remaining_accounts,
+                    deposit,
+                    mint.decimals,
+                    &[],
+                )?;
+
+                emit_cpi!(RankRewardAdded {
+                    leaderboard: new_reward.leaderboard,
+                    rank_reward: new_reward.key(),
+                    min_rank: new_reward.min_rank,
+                    max_rank: new_reward.max_rank,
+                    season: new_reward.season,
+                });
+                emit_cpi!(RewardFunded {
+                    reward: new_reward.key(),
+                    vault: vault.key(),
+                    amount: deposit,
+                });
+                Ok(())
+            }
+            RewardKindInput::Nft {
+                uri: _,
+                name: _,
+                symbol: _,
+            } => Err(SoarError::InvalidRewardKind.into()),
+        }
+    }
+}
+
+pub mod nft {
+    use super::*;
+    use crate::AddNftRankReward;
+
+    pub fn handler(ctx: Context<AddNftRankReward>, input: AddRankRewardInput) -> Result<()> {
+        check_input(&ctx.accounts.leaderboard, &ctx.accounts.top_entries, &input)?;
+
+        let new_reward = &mut ctx.accounts.new_reward;
+        new_reward.leaderboard = ctx.accounts.leaderboard.key();
+        new_reward.min_rank = input.min_rank;
+        new_reward.max_rank = input.max_rank;
+        new_reward.season = input.season;
+
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.live_rank_rewards = leaderboard.live_rank_rewards.checked_add(1).unwrap();
+
+        match input.kind {
+            RewardKindInput::Nft { uri, name, symbol } => {
+                new_reward.reward = RewardKind::NonFungibleToken {
+                    uri,
+                    name,
+                    symbol,
+                    minted: 0,
+                    collection: None,
+                };
+                new_reward.check()?;
+
+                emit_cpi!(RankRewardAdded {
+                    leaderboard: new_reward.leaderboard,
+                    rank_reward: new_reward.key(),
+                    min_rank: new_reward.min_rank,
+                    max_rank: new_reward.max_rank,
+                    season: new_reward.season,
+                });
+                Ok(())
+            }
+            RewardKindInput::Ft {
+                deposit: _,
+                amount: _,
+            } => Err(SoarError::InvalidRewardKind.into()),
+        }
+    }
+}
@@ -1,51 +1,65 @@
 use crate::{
     error::SoarError,
+    events::{RewardAdded, RewardFunded},
     state::{AddNewRewardInput, RewardKind, RewardKindInput},
     utils, FieldsCheck,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Approve};
 
 pub mod ft {
     use super::*;
     use crate::AddFtReward;
 
-    pub fn handler(ctx: Context<AddFtReward>, input: AddNewRewardInput) -> Result<()> {
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddFtReward<'info>>,
+        input: AddNewRewardInput,
+    ) -> Result<()> {
         let new_reward = &mut ctx.accounts.new_reward;
         new_reward.achievement = ctx.accounts.achievement.key();
         new_reward.available_spots = input.available_spots;
 
-        let achievement = &ctx.accounts.achievement;
-
         match input.kind {
             RewardKindInput::Ft { deposit, amount } => {
                 let mint = &ctx.accounts.reward_token_mint;
-                let token_account = &ctx.accounts.delegate_from_token_account;
-                let token_account_owner = &ctx.accounts.token_account_owner;
+                let vault = &ctx.accounts.reward_vault;
                 let token_program = &ctx.accounts.token_program;
 
                 let reward = RewardKind::FungibleToken {
                     mint: mint.key(),
-                    account: token_account.key(),
+                    account: vault.key(),
                     amount,
+                    token_program: token_program.key(),
                 };
                 new_reward.reward = reward;
                 new_reward.check()?;
 
-                // Delegate authority to spend some amount of tokens to the `achievement` PDA.
-                let cpi_ctx = CpiContext::new(
-                    token_program.to_account_info(),
-                    Approve {
-                        to: token_account.to_account_info(),
-                        delegate: achievement.to_account_info(),
-                        authority: token_account_owner.to_account_info(),
-                    },
-                );
-                token::approve(cpi_ctx, deposit)?;
+                // Fund the reward's vault, which only the `achievement` PDA can spend from.
+                utils::transfer_checked(
+                    &ctx.accounts.source_token_account.to_account_info(),
+                    &mint.to_account_info(),
+                    &vault.to_account_info(),
+                    &ctx.accounts.token_account_owner.to_account_info(),
+                    &token_program.to_account_info(),
+                    ctx.remaining_accounts,
+                    deposit,
+                    mint.decimals,
+                    &[],
+                )?;
 
                 let achievement = &mut ctx.accounts.achievement;
-                achievement.reward = Some(new_reward.key());
-
+                achievement.rewards.push(new_reward.key());
+                achievement.reward_count = achievement.next_reward();
+
+                emit_cpi!(RewardAdded {
+                    achievement: achievement.key(),
+                    reward: new_reward.key(),
+                    available_spots: new_reward.available_spots,
+                });
+                emit_cpi!(RewardFunded {
+                    reward: new_reward.key(),
+                    vault: vault.key(),
+                    amount: deposit,
+                });
                 Ok(())
             }
             RewardKindInput::Nft {
@@ -121,8 +135,14 @@ pub mod nft {
                 new_reward.check()?;
 
                 let achievement = &mut ctx.accounts.achievement;
-                achievement.reward = Some(new_reward.key());
-
+                achievement.rewards.push(new_reward.key());
+                achievement.reward_count = achievement.next_reward();
+
+                emit_cpi!(RewardAdded {
+                    achievement: achievement.key(),
+                    reward: new_reward.key(),
+                    available_spots: new_reward.available_spots,
+                });
                 Ok(())
             }
             RewardKindInput::Ft {
@@ -0,0 +1,46 @@
+use crate::{
+    events::TopEntriesAdded,
+    instructions::rebuild_top_entries::insert_from_lists,
+    state::{check_scores_to_retain, LeaderBoardScore, LeaderTopEntries},
+    AddTopEntries,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddTopEntries<'info>>,
+    scores_to_retain: u16,
+) -> Result<()> {
+    let leaderboard = &ctx.accounts.leaderboard;
+    let top_entries = &ctx.accounts.top_entries;
+
+    check_scores_to_retain(leaderboard.season_length, scores_to_retain)?;
+
+    let season = leaderboard.season_at(Clock::get()?.unix_timestamp);
+    let capacity = LeaderTopEntries::initial_capacity(scores_to_retain);
+    {
+        let (mut header, mut scores) = LeaderTopEntries::load_init_scores(top_entries)?;
+        *header = LeaderTopEntries::new(season, scores_to_retain, capacity);
+        scores.fill(LeaderBoardScore::placeholder(
+            leaderboard.is_ascending,
+            leaderboard.max_score,
+        ));
+
+        // Scores submitted before the board had a top list.
+        insert_from_lists(
+            leaderboard,
+            &mut header,
+            &mut scores,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    let top_entries = top_entries.key();
+    ctx.accounts.leaderboard.top_entries = Some(top_entries);
+
+    emit_cpi!(TopEntriesAdded {
+        leaderboard: ctx.accounts.leaderboard.key(),
+        top_entries,
+        scores_to_retain,
+    });
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::{error::SoarError, ApproveMerge};
+use crate::{error::SoarError, events::MergeApproved, ApproveMerge};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<ApproveMerge>) -> Result<()> {
@@ -22,5 +22,10 @@ pub fn handler(ctx: Context<ApproveMerge>) -> Result<()> {
         merge_account.merge_complete = true;
     }
 
+    emit_cpi!(MergeApproved {
+        merged: merge_account.key(),
+        player: player_account.key(),
+        merge_complete: merge_account.merge_complete,
+    });
     Ok(())
 }
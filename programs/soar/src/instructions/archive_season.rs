@@ -0,0 +1,42 @@
+use crate::{
+    error::SoarError,
+    events::SeasonArchived,
+    state::{LeaderTopEntries, SeasonTopEntries},
+    ArchiveSeason,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<ArchiveSeason>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.live_archives = leaderboard.live_archives.checked_add(1).unwrap();
+    let (mut top_entries, mut scores) =
+        LeaderTopEntries::load_scores_mut(&ctx.accounts.top_entries)?;
+
+    let clock = Clock::get().unwrap();
+    let current_season = leaderboard.season_at(clock.unix_timestamp);
+    require!(
+        current_season > top_entries.season,
+        SoarError::SeasonNotOver
+    );
+
+    // Boards created before seasonal scores were bounded may hold more than fit.
+    require!(
+        scores.len() <= SeasonTopEntries::MAX_SCORES,
+        SoarError::TooManySeasonScores
+    );
+    ctx.accounts.archive.set_inner(SeasonTopEntries {
+        leaderboard: leaderboard.key(),
+        season: top_entries.season,
+        is_ascending: leaderboard.is_ascending,
+        top_scores: scores.to_vec(),
+    });
+    let season = top_entries.season;
+    top_entries.reset(&mut scores, current_season, leaderboard);
+
+    emit_cpi!(SeasonArchived {
+        leaderboard: leaderboard.key(),
+        season,
+        archive: ctx.accounts.archive.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use crate::{events::AuthorityProposalCancelled, CancelAuthorityProposal};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<CancelAuthorityProposal>) -> Result<()> {
+    emit_cpi!(AuthorityProposalCancelled {
+        game: ctx.accounts.game.key(),
+        proposal: ctx.accounts.proposal.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::{
+    error::SoarError, events::RewardCancelled, instructions::reward_vault, state::RewardKind,
+    utils, CancelReward,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CancelReward<'info>>) -> Result<()> {
+    let achievement_key = ctx.accounts.achievement.key();
+    let reward_key = ctx.accounts.reward.key();
+    let mut refunded = 0;
+
+    if let RewardKind::FungibleToken { .. } = &ctx.accounts.reward.reward {
+        let mint = ctx
+            .accounts
+            .reward_token_mint
+            .as_ref()
+            .ok_or(SoarError::MissingExpectedAccount)?;
+        let vault = ctx
+            .accounts
+            .reward_vault
+            .as_ref()
+            .ok_or(SoarError::MissingExpectedAccount)?;
+        let destination = ctx
+            .accounts
+            .destination_token_account
+            .as_ref()
+            .ok_or(SoarError::MissingExpectedAccount)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(SoarError::MissingExpectedAccount)?;
+        reward_vault::check_vault(
+            &ctx.accounts.reward.reward,
+            &vault.key(),
+            &mint.key(),
+            &token_program.key(),
+        )?;
+
+        // Nothing is owed to anyone once the reward is cancelled, so refund the whole vault.
+        refunded = vault.amount;
+        if refunded > 0 {
+            let game_key = ctx.accounts.game.key();
+            let id = ctx.accounts.achievement.id;
+            let achievement_seeds = &[
+                crate::seeds::ACHIEVEMENT,
+                game_key.as_ref(),
+                &id.to_le_bytes(),
+                &[ctx.bumps.achievement],
+            ];
+
+            utils::transfer_checked(
+                &vault.to_account_info(),
+                &mint.to_account_info(),
+                &destination.to_account_info(),
+                &ctx.accounts.achievement.to_account_info(),
+                &token_program.to_account_info(),
+                ctx.remaining_accounts,
+                refunded,
+                mint.decimals,
+                &[&achievement_seeds[..]],
+            )?;
+        }
+    }
+
+    let reward = &mut ctx.accounts.reward;
+    reward.available_spots = 0;
+    reward.cancelled = true;
+
+    let achievement = &mut ctx.accounts.achievement;
+    achievement.rewards.retain(|reward| *reward != reward_key);
+
+    emit_cpi!(RewardCancelled {
+        achievement: achievement_key,
+        reward: reward_key,
+        refunded,
+    });
+    Ok(())
+}
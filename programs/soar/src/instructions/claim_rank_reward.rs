@@ -0,0 +1,218 @@
+use crate::{
+    error::SoarError,
+    events::{FtRankRewardClaimed, NftRankRewardClaimed},
+    instructions::claim_reward,
+    state::{LeaderBoard, LeaderTopEntries, RankReward, RewardKind, SeasonTopEntries},
+    utils,
+};
+use anchor_lang::prelude::*;
+
+/// Check that `player_account` holds `rank` in the final standings the reward pays out for,
+/// and that the reward covers it.
+///
+/// Seasonal rewards are paid from the archive of their season. Other rewards are paid from
+/// the leaderboard's top entries once it has closed, as they can no longer change by then.
+fn check_rank(
+    leaderboard: &LeaderBoard,
+    top_entries: Option<&AccountLoader<LeaderTopEntries>>,
+    season_top_entries: Option<&SeasonTopEntries>,
+    rank_reward: &RankReward,
+    player_account: &Pubkey,
+    rank: u32,
+) -> Result<()> {
+    require!(rank_reward.covers(rank), SoarError::RankNotEligible);
+    let index = (rank as usize).checked_sub(1).unwrap();
+
+    let holder = match rank_reward.season {
+        Some(season) => {
+            let archive = season_top_entries.ok_or(SoarError::MissingExpectedAccount)?;
+            require!(archive.season == season, SoarError::MissingExpectedAccount);
+            archive.top_scores.get(index).map(|entry| entry.player)
+        }
+        None => {
+            let clock = Clock::get()?;
+            require!(
+                leaderboard.is_closed(clock.unix_timestamp),
+                SoarError::LeaderboardNotClosed
+            );
+            let top_entries = top_entries.ok_or(SoarError::MissingExpectedAccount)?;
+            let (_, scores) = LeaderTopEntries::load_scores(top_entries)?;
+            scores.get(index).map(|entry| entry.player)
+        }
+    };
+    require_keys_eq!(
+        holder.ok_or(SoarError::RankNotEligible)?,
+        *player_account,
+        SoarError::RankNotEligible
+    );
+
+    Ok(())
+}
+
+pub mod ft {
+    use super::*;
+    use crate::ClaimFtRankReward;
+
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimFtRankReward<'info>>,
+        rank: u32,
+    ) -> Result<()> {
+        let player_key = ctx.accounts.player_account.key();
+        check_rank(
+            &ctx.accounts.leaderboard,
+            ctx.accounts.top_entries.as_ref(),
+            ctx.accounts
+                .season_top_entries
+                .as_deref()
+                .map(|archive| &**archive),
+            &ctx.accounts.rank_reward,
+            &player_key,
+            rank,
+        )?;
+
+        let game_key = ctx.accounts.game.key();
+        let id = ctx.accounts.leaderboard.id;
+        let leaderboard_bump = ctx.bumps.leaderboard;
+        let leaderboard_seeds = &[
+            crate::seeds::LEADER,
+            game_key.as_ref(),
+            &id.to_le_bytes(),
+            &[leaderboard_bump],
+        ];
+        let signer = &[&leaderboard_seeds[..]];
+
+        match &ctx.accounts.rank_reward.reward {
+            RewardKind::FungibleToken {
+                mint,
+                account,
+                amount,
+                token_program,
+            } => {
+                let reward_vault = &ctx.accounts.reward_vault;
+                require_keys_eq!(reward_vault.key(), *account);
+                require_keys_eq!(ctx.accounts.reward_token_mint.key(), *mint);
+                require_keys_eq!(
+                    ctx.accounts.token_program.key(),
+                    RewardKind::token_program(token_program),
+                    SoarError::InvalidTokenProgram
+                );
+
+                utils::transfer_checked(
+                    &reward_vault.to_account_info(),
+                    &ctx.accounts.reward_token_mint.to_account_info(),
+                    &ctx.accounts.user_token_account.to_account_info(),
+                    &ctx.accounts.leaderboard.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    ctx.remaining_accounts,
+                    *amount,
+                    ctx.accounts.reward_token_mint.decimals,
+                    signer,
+                )?;
+
+                let claim = &mut ctx.accounts.claim;
+                claim.player_account = player_key;
+                claim.rank = rank;
+
+                emit_cpi!(FtRankRewardClaimed {
+                    player: player_key,
+                    leaderboard: ctx.accounts.leaderboard.key(),
+                    rank_reward: ctx.accounts.rank_reward.key(),
+                    rank,
+                    mint: *mint,
+                    amount: *amount,
+                });
+                Ok(())
+            }
+            RewardKind::NonFungibleToken {
+                uri: _,
+                name: _,
+                symbol: _,
+                minted: _,
+                collection: _,
+            } => Err(SoarError::InvalidRewardKind.into()),
+        }
+    }
+}
+
+pub mod nft {
+    use super::*;
+    use crate::ClaimNftRankReward;
+
+    pub fn handler(ctx: Context<ClaimNftRankReward>, rank: u32) -> Result<()> {
+        let player_key = ctx.accounts.player_account.key();
+        check_rank(
+            &ctx.accounts.leaderboard,
+            ctx.accounts.top_entries.as_ref(),
+            ctx.accounts
+                .season_top_entries
+                .as_deref()
+                .map(|archive| &**archive),
+            &ctx.accounts.rank_reward,
+            &player_key,
+            rank,
+        )?;
+
+        let game_key = ctx.accounts.game.key();
+        let id = ctx.accounts.leaderboard.id;
+        let leaderboard_bump = ctx.bumps.leaderboard;
+        let leaderboard_seeds = &[
+            crate::seeds::LEADER,
+            game_key.as_ref(),
+            &id.to_le_bytes(),
+            &[leaderboard_bump],
+        ];
+        let signer = &[&leaderboard_seeds[..]];
+
+        let leaderboard = ctx.accounts.leaderboard.to_account_info();
+        match &mut ctx.accounts.rank_reward.reward {
+            RewardKind::NonFungibleToken {
+                uri,
+                name,
+                symbol,
+                minted,
+                collection,
+            } => {
+                claim_reward::mint_nft_reward(
+                    name,
+                    symbol,
+                    uri,
+                    collection,
+                    &ctx.accounts.payer,
+                    &ctx.accounts.user,
+                    &ctx.accounts.new_mint,
+                    &ctx.accounts.new_metadata,
+                    &ctx.accounts.new_master_edition,
+                    &ctx.accounts.mint_to,
+                    &leaderboard,
+                    &ctx.accounts.token_metadata_program,
+                    &ctx.accounts.associated_token_program,
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.rent.to_account_info(),
+                    signer,
+                )?;
+
+                *minted = minted.checked_add(1).unwrap();
+
+                let claim = &mut ctx.accounts.claim;
+                claim.player_account = player_key;
+                claim.rank = rank;
+
+                emit_cpi!(NftRankRewardClaimed {
+                    player: player_key,
+                    leaderboard: leaderboard.key(),
+                    rank_reward: ctx.accounts.rank_reward.key(),
+                    rank,
+                    mint: ctx.accounts.new_mint.key(),
+                });
+                Ok(())
+            }
+            RewardKind::FungibleToken {
+                mint: _,
+                account: _,
+                amount: _,
+                token_program: _,
+            } => Err(SoarError::InvalidRewardKind.into()),
+        }
+    }
+}
@@ -1,75 +1,110 @@
 use crate::{
     error::SoarError,
-    state::{PlayerAchievement, RewardKind},
-    utils,
+    events::{FtRewardClaimed, NftRewardClaimed},
+    state::{Achievement, PlayerAchievement, Reward, RewardClaim, RewardKind},
+    utils, ClaimFtRewardAccounts, ClaimNftRewardAccounts,
 };
-use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 pub mod ft {
     use super::*;
     use crate::ClaimFtReward;
 
-    pub fn handler(ctx: Context<ClaimFtReward>) -> Result<()> {
-        let token_program = &ctx.accounts.token_program;
-        let player_achievement = &mut ctx.accounts.player_achievement;
+    pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimFtReward<'info>>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let event = claim.pay(ctx.bumps.claim.achievement, ctx.remaining_accounts)?;
+        claim.player_achievement.claimed = true;
 
-        let reward_account = &mut ctx.accounts.reward;
-        let achievement_account = &ctx.accounts.achievement;
+        emit_cpi!(event);
+        Ok(())
+    }
+}
 
-        let game_key = &ctx.accounts.game.key();
-        let achievement_bump = ctx.bumps.achievement;
-        let id = ctx.accounts.achievement.id;
-        let achievement_seeds = &[
-            crate::seeds::ACHIEVEMENT,
-            game_key.as_ref(),
-            &id.to_le_bytes(),
-            &[achievement_bump],
-        ];
-        let signer = &[&achievement_seeds[..]];
+pub mod ft_batch {
+    use super::*;
+    use crate::ClaimFtRewards;
 
-        let reward = &mut reward_account.reward;
-        match reward {
-            RewardKind::FungibleToken {
-                mint: _,
-                account,
-                amount,
-            } => {
-                let user_token_account = &ctx.accounts.user_token_account;
-                let source_token_account = &ctx.accounts.source_token_account;
-                require_keys_eq!(source_token_account.key(), *account);
-
-                let cpi_ctx = CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: source_token_account.to_account_info(),
-                        to: user_token_account.to_account_info(),
-                        authority: achievement_account.to_account_info(),
-                    },
-                    signer,
-                );
+    /// Number of remaining accounts passed for each reward.
+    pub const ACCOUNTS_PER_REWARD: usize = 5;
 
-                token::transfer(cpi_ctx, *amount)?;
+    pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFtRewards<'info>>) -> Result<()> {
+        let groups = ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_REWARD);
+        require!(
+            groups.remainder().is_empty() && !ctx.remaining_accounts.is_empty(),
+            SoarError::MissingExpectedAccount
+        );
 
-                player_achievement.set_inner(PlayerAchievement::new(
-                    ctx.accounts.player_account.key(),
-                    ctx.accounts.achievement.key(),
-                    Clock::get().unwrap().unix_timestamp,
-                ));
-                player_achievement.claimed = true;
+        let achievement = &ctx.accounts.achievement;
+        let player_account = ctx.accounts.player_account.key();
+        for group in groups {
+            let [reward, reward_claim, mint, vault, user_token_account] = group else {
+                unreachable!()
+            };
 
-                reward_account.available_spots =
-                    reward_account.available_spots.checked_sub(1).unwrap();
-                Ok(())
-            }
-            RewardKind::NonFungibleToken {
-                uri: _,
-                name: _,
-                symbol: _,
-                minted: _,
-                collection: _,
-            } => Err(SoarError::InvalidRewardKind.into()),
+            require!(reward.is_writable, ErrorCode::ConstraintMut);
+            let mut reward = Account::<Reward>::try_from(reward)?;
+            require_keys_eq!(
+                reward.achievement,
+                achievement.key(),
+                SoarError::NoRewardForAchievement
+            );
+            require!(
+                achievement.rewards.contains(&reward.key()),
+                SoarError::NoRewardForAchievement
+            );
+            require!(reward.available_spots != 0, SoarError::NoAvailableRewards);
+            require!(!reward.paused, SoarError::RewardPaused);
+
+            create_reward_claim(
+                &ctx.accounts.payer.to_account_info(),
+                reward_claim,
+                &ctx.accounts.system_program.to_account_info(),
+                &reward.key(),
+                &player_account,
+            )?;
+
+            let (vault_key, _) = Pubkey::find_program_address(
+                &[crate::seeds::REWARD_VAULT, reward.key().as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(vault.key(), vault_key, ErrorCode::ConstraintSeeds);
+            let mint = InterfaceAccount::<Mint>::try_from(mint)?;
+            let vault = InterfaceAccount::<TokenAccount>::try_from(vault)?;
+            let user_token_account =
+                InterfaceAccount::<TokenAccount>::try_from(user_token_account)?;
+            require_keys_eq!(
+                user_token_account.owner,
+                ctx.accounts.user.key(),
+                ErrorCode::ConstraintTokenOwner
+            );
+            require_keys_eq!(
+                user_token_account.mint,
+                mint.key(),
+                ErrorCode::ConstraintTokenMint
+            );
+
+            let event = pay_ft_reward(
+                &ctx.accounts.game.key(),
+                achievement,
+                ctx.bumps.achievement,
+                &mut reward,
+                &mint,
+                &vault,
+                &user_token_account,
+                &ctx.accounts.token_program,
+                &[],
+                player_account,
+            )?;
+            reward.exit(&crate::ID)?;
+
+            emit_cpi!(event);
         }
+        ctx.accounts.player_achievement.claimed = true;
+
+        Ok(())
     }
 }
 
@@ -78,26 +113,218 @@ pub mod nft {
     use crate::ClaimNftReward;
 
     pub fn handler(ctx: Context<ClaimNftReward>) -> Result<()> {
-        let user = &ctx.accounts.user;
-        let token_program = &ctx.accounts.token_program;
-        let player_achievement = &mut ctx.accounts.player_achievement;
+        let claim = &mut ctx.accounts.claim;
+        let event = claim.mint(ctx.bumps.claim.achievement)?;
+        claim.player_achievement.claimed = true;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+}
+
+pub mod unlock_and_claim_ft {
+    use super::*;
+    use crate::UnlockAndClaimFtReward;
+
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnlockAndClaimFtReward<'info>>,
+    ) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let event = claim.pay(ctx.bumps.claim.achievement, ctx.remaining_accounts)?;
+
+        let player_achievement = &mut claim.player_achievement;
+        unlock_if_needed(player_achievement, event.player, event.achievement);
+        player_achievement.claimed = true;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+}
+
+pub mod unlock_and_claim_nft {
+    use super::*;
+    use crate::UnlockAndClaimNftReward;
+
+    pub fn handler(ctx: Context<UnlockAndClaimNftReward>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let event = claim.mint(ctx.bumps.claim.achievement)?;
+
+        let player_achievement = &mut claim.player_achievement;
+        unlock_if_needed(player_achievement, event.player, event.achievement);
+        player_achievement.claimed = true;
+
+        emit_cpi!(event);
+        Ok(())
+    }
+}
+
+/// Find the address and bump of the [RewardClaim] recording that `player_account` claimed
+/// `reward`.
+pub(crate) fn find_reward_claim_address(reward: &Pubkey, player_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            crate::seeds::REWARD_CLAIM,
+            reward.as_ref(),
+            player_account.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Create the [RewardClaim] recording that `player_account` claimed `reward`, failing if it
+/// already exists.
+pub(crate) fn create_reward_claim<'info>(
+    payer: &AccountInfo<'info>,
+    reward_claim: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    reward: &Pubkey,
+    player_account: &Pubkey,
+) -> Result<()> {
+    let (expected, bump) = find_reward_claim_address(reward, player_account);
+    require_keys_eq!(reward_claim.key(), expected, ErrorCode::ConstraintSeeds);
 
-        let reward_account = &mut ctx.accounts.reward;
-        let achievement_account = &ctx.accounts.achievement;
+    utils::create_pda_account(
+        payer,
+        reward_claim,
+        system_program,
+        RewardClaim::SIZE,
+        &crate::ID,
+        &[
+            crate::seeds::REWARD_CLAIM,
+            reward.as_ref(),
+            player_account.as_ref(),
+            &[bump],
+        ],
+    )?;
+    reward_claim.try_borrow_mut_data()?[..8].copy_from_slice(&RewardClaim::DISCRIMINATOR);
+    Ok(())
+}
+
+/// Unlock the achievement for a player if it isn't unlocked yet, returning whether it was.
+///
+/// Achievements unlocked earlier keep their original unlock time.
+pub fn unlock_if_needed(
+    player_achievement: &mut PlayerAchievement,
+    player_account: Pubkey,
+    achievement: Pubkey,
+) -> bool {
+    if player_achievement.unlocked {
+        return false;
+    }
+
+    *player_achievement = PlayerAchievement::new(
+        player_account,
+        achievement,
+        Clock::get().unwrap().unix_timestamp,
+    );
+    true
+}
+
+/// Transfer a fungible token `reward`'s per-user amount from its vault to
+/// `user_token_account`, taking up one of its spots.
+#[allow(clippy::too_many_arguments)]
+fn pay_ft_reward<'info>(
+    game: &Pubkey,
+    achievement: &Account<'info, Achievement>,
+    achievement_bump: u8,
+    reward: &mut Account<'info, Reward>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    user_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    extra_accounts: &[AccountInfo<'info>],
+    player_account: Pubkey,
+) -> Result<FtRewardClaimed> {
+    let id = achievement.id;
+    let achievement_seeds = &[
+        crate::seeds::ACHIEVEMENT,
+        game.as_ref(),
+        &id.to_le_bytes(),
+        &[achievement_bump],
+    ];
+    let signer = &[&achievement_seeds[..]];
+
+    let reward_key = reward.key();
+    match &reward.reward {
+        RewardKind::FungibleToken {
+            mint: reward_mint,
+            account,
+            amount,
+            token_program: reward_token_program,
+        } => {
+            let amount = *amount;
+            require_keys_eq!(vault.key(), *account);
+            require_keys_eq!(mint.key(), *reward_mint);
+            require_keys_eq!(
+                token_program.key(),
+                RewardKind::token_program(reward_token_program),
+                SoarError::InvalidTokenProgram
+            );
+
+            utils::transfer_checked(
+                &vault.to_account_info(),
+                &mint.to_account_info(),
+                &user_token_account.to_account_info(),
+                &achievement.to_account_info(),
+                &token_program.to_account_info(),
+                extra_accounts,
+                amount,
+                mint.decimals,
+                signer,
+            )?;
+
+            reward.available_spots = reward.available_spots.checked_sub(1).unwrap();
 
-        let game_key = &ctx.accounts.game.key();
-        let achievement_bump = ctx.bumps.achievement;
-        let id = ctx.accounts.achievement.id;
+            Ok(FtRewardClaimed {
+                player: player_account,
+                achievement: achievement.key(),
+                reward: reward_key,
+                mint: mint.key(),
+                amount,
+                user_token_account: user_token_account.key(),
+            })
+        }
+        RewardKind::NonFungibleToken { .. } => Err(SoarError::InvalidRewardKind.into()),
+    }
+}
+
+impl<'info> ClaimFtRewardAccounts<'info> {
+    /// Pay out `reward` to `user_token_account`, taking up one of its spots.
+    pub(crate) fn pay(
+        &mut self,
+        achievement_bump: u8,
+        extra_accounts: &[AccountInfo<'info>],
+    ) -> Result<FtRewardClaimed> {
+        pay_ft_reward(
+            &self.game.key(),
+            &self.achievement,
+            achievement_bump,
+            &mut self.reward,
+            &self.reward_token_mint,
+            &self.reward_vault,
+            &self.user_token_account,
+            &self.token_program,
+            extra_accounts,
+            self.player_account.key(),
+        )
+    }
+}
+
+impl<'info> ClaimNftRewardAccounts<'info> {
+    /// Mint `reward` to `user`, taking up one of its spots.
+    pub(crate) fn mint(&mut self, achievement_bump: u8) -> Result<NftRewardClaimed> {
+        let game = self.game.key();
+        let id = self.achievement.id.to_le_bytes();
         let achievement_seeds = &[
             crate::seeds::ACHIEVEMENT,
-            game_key.as_ref(),
-            &id.to_le_bytes(),
+            game.as_ref(),
+            &id,
             &[achievement_bump],
         ];
         let signer = &[&achievement_seeds[..]];
 
-        let reward = &mut reward_account.reward;
-        match reward {
+        let reward_key = self.reward.key();
+        match &mut self.reward.reward {
             RewardKind::NonFungibleToken {
                 uri,
                 name,
@@ -105,98 +332,125 @@ pub mod nft {
                 minted,
                 collection,
             } => {
-                let payer = &ctx.accounts.payer;
-                let mint = &ctx.accounts.new_mint;
-                let metadata = &ctx.accounts.new_metadata;
-                let master_edition = &ctx.accounts.new_master_edition;
-                let mint_to = &ctx.accounts.mint_to;
-                let token_metadata_program = &ctx.accounts.token_metadata_program;
-                let associated_token_program = &ctx.accounts.associated_token_program;
-                let system_program = &ctx.accounts.system_program;
-                let rent = &ctx.accounts.rent;
-
-                // Mint authority ends up being transferred to the master edition account.
-                let temp_mint_authority = &payer.to_account_info();
-
-                utils::create_mint(
-                    payer,
-                    mint,
-                    temp_mint_authority,
-                    system_program,
-                    token_program,
-                    &rent.to_account_info(),
-                )?;
-
-                let user_token_account = mint_to;
-                utils::create_token_account(
-                    payer,
-                    user_token_account,
-                    user,
-                    mint,
-                    system_program,
-                    token_program,
-                    associated_token_program,
-                )?;
-
-                utils::mint_token(
-                    mint,
-                    user_token_account,
-                    temp_mint_authority,
-                    token_program,
-                    None,
-                )?;
-
-                let update_authority = achievement_account.to_account_info();
-                let creator = Some(achievement_account.key());
-
-                utils::create_metadata_account(
+                mint_nft_reward(
                     name,
                     symbol,
                     uri,
-                    metadata,
-                    mint,
-                    temp_mint_authority,
-                    payer,
-                    &update_authority,
-                    &creator,
                     collection,
-                    token_metadata_program,
-                    system_program,
-                    &rent.to_account_info(),
-                    Some(signer),
-                )?;
-
-                utils::create_master_edition_account(
-                    master_edition,
-                    mint,
-                    payer,
-                    metadata,
-                    temp_mint_authority,
-                    &update_authority,
-                    token_metadata_program,
-                    system_program,
-                    &rent.to_account_info(),
-                    Some(signer),
+                    &self.payer,
+                    &self.user,
+                    &self.new_mint,
+                    &self.new_metadata,
+                    &self.new_master_edition,
+                    &self.mint_to,
+                    &self.achievement.to_account_info(),
+                    &self.token_metadata_program,
+                    &self.associated_token_program,
+                    &self.system_program,
+                    &self.token_program,
+                    &self.rent.to_account_info(),
+                    signer,
                 )?;
 
                 *minted = minted.checked_add(1).unwrap();
+                self.reward.available_spots = self.reward.available_spots.checked_sub(1).unwrap();
 
-                player_achievement.set_inner(PlayerAchievement::new(
-                    ctx.accounts.player_account.key(),
-                    ctx.accounts.achievement.key(),
-                    Clock::get().unwrap().unix_timestamp,
-                ));
-                player_achievement.claimed = true;
-
-                reward_account.available_spots =
-                    reward_account.available_spots.checked_sub(1).unwrap();
-                Ok(())
+                Ok(NftRewardClaimed {
+                    player: self.player_account.key(),
+                    achievement: self.achievement.key(),
+                    reward: reward_key,
+                    mint: self.new_mint.key(),
+                })
             }
-            RewardKind::FungibleToken {
-                mint: _,
-                account: _,
-                amount: _,
-            } => Err(SoarError::InvalidRewardKind.into()),
+            RewardKind::FungibleToken { .. } => Err(SoarError::InvalidRewardKind.into()),
         }
     }
 }
+
+/// Mint a new master edition nft to `user` with `update_authority` as its verified creator
+/// and update authority.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_nft_reward<'info>(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    collection: &Option<Pubkey>,
+    payer: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    metadata: &AccountInfo<'info>,
+    master_edition: &AccountInfo<'info>,
+    mint_to: &AccountInfo<'info>,
+    update_authority: &AccountInfo<'info>,
+    token_metadata_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    // Mint authority ends up being transferred to the master edition account.
+    let temp_mint_authority = payer;
+
+    utils::create_mint(
+        payer,
+        mint,
+        temp_mint_authority,
+        system_program,
+        token_program,
+        rent,
+    )?;
+
+    let user_token_account = mint_to;
+    utils::create_token_account(
+        payer,
+        user_token_account,
+        user,
+        mint,
+        system_program,
+        token_program,
+        associated_token_program,
+    )?;
+
+    utils::mint_token(
+        mint,
+        user_token_account,
+        temp_mint_authority,
+        token_program,
+        None,
+    )?;
+
+    let creator = Some(update_authority.key());
+
+    utils::create_metadata_account(
+        name,
+        symbol,
+        uri,
+        metadata,
+        mint,
+        temp_mint_authority,
+        payer,
+        update_authority,
+        &creator,
+        collection,
+        token_metadata_program,
+        system_program,
+        rent,
+        Some(signer),
+    )?;
+
+    utils::create_master_edition_account(
+        master_edition,
+        mint,
+        payer,
+        metadata,
+        temp_mint_authority,
+        update_authority,
+        token_metadata_program,
+        system_program,
+        rent,
+        Some(signer),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,288 @@
+use crate::{error::SoarError, events::*, state::RewardKind};
+use anchor_lang::prelude::*;
+
+pub mod game {
+    use super::*;
+    use crate::CloseGame;
+
+    pub fn handler(ctx: Context<CloseGame>) -> Result<()> {
+        emit_cpi!(GameClosed {
+            game: ctx.accounts.game.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod leaderboard {
+    use super::*;
+    use crate::CloseLeaderBoard;
+
+    pub fn handler(ctx: Context<CloseLeaderBoard>) -> Result<()> {
+        if ctx.accounts.leaderboard.top_entries.is_some() {
+            require!(
+                ctx.accounts.top_entries.is_some(),
+                SoarError::MissingExpectedAccount
+            );
+        }
+
+        let game = &mut ctx.accounts.game;
+        game.live_leaderboards = game.live_leaderboards.checked_sub(1).unwrap();
+
+        emit_cpi!(LeaderboardClosed {
+            leaderboard: ctx.accounts.leaderboard.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod season_top_entries {
+    use super::*;
+    use crate::CloseSeasonTopEntries;
+
+    pub fn handler(ctx: Context<CloseSeasonTopEntries>) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.live_archives = leaderboard.live_archives.checked_sub(1).unwrap();
+
+        emit_cpi!(SeasonTopEntriesClosed {
+            leaderboard: ctx.accounts.archive.leaderboard,
+            season: ctx.accounts.archive.season,
+        });
+        Ok(())
+    }
+}
+
+pub mod achievement {
+    use super::*;
+    use crate::CloseAchievement;
+
+    pub fn handler(ctx: Context<CloseAchievement>) -> Result<()> {
+        let game = &mut ctx.accounts.game;
+        game.live_achievements = game.live_achievements.checked_sub(1).unwrap();
+
+        emit_cpi!(AchievementClosed {
+            achievement: ctx.accounts.achievement.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod reward {
+    use super::*;
+    use crate::CloseReward;
+    use anchor_spl::token_interface::{self, CloseAccount};
+
+    pub fn handler(ctx: Context<CloseReward>) -> Result<()> {
+        let achievement_key = ctx.accounts.achievement.key();
+        let reward_key = ctx.accounts.reward.key();
+
+        if let RewardKind::FungibleToken { account, .. } = &ctx.accounts.reward.reward {
+            let vault = ctx
+                .accounts
+                .reward_vault
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            require_keys_eq!(vault.key(), *account);
+            require!(vault.amount == 0, SoarError::RewardVaultNotEmpty);
+
+            let game_key = ctx.accounts.game.key();
+            let id = ctx.accounts.achievement.id;
+            let achievement_seeds = &[
+                crate::seeds::ACHIEVEMENT,
+                game_key.as_ref(),
+                &id.to_le_bytes(),
+                &[ctx.bumps.achievement],
+            ];
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: ctx.accounts.receiver.to_account_info(),
+                    authority: ctx.accounts.achievement.to_account_info(),
+                },
+                &[&achievement_seeds[..]],
+            ))?;
+        }
+
+        let achievement = &mut ctx.accounts.achievement;
+        achievement.rewards.retain(|reward| *reward != reward_key);
+
+        emit_cpi!(RewardClosed {
+            achievement: achievement_key,
+            reward: reward_key,
+        });
+        Ok(())
+    }
+}
+
+pub mod rank_reward {
+    use super::*;
+    use crate::{instructions::reward_vault, utils, CloseRankReward};
+    use anchor_spl::token_interface::{self, CloseAccount};
+
+    pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseRankReward<'info>>) -> Result<()> {
+        if let RewardKind::FungibleToken { .. } = &ctx.accounts.rank_reward.reward {
+            let mint = ctx
+                .accounts
+                .reward_token_mint
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            let vault = ctx
+                .accounts
+                .reward_vault
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            let destination = ctx
+                .accounts
+                .destination_token_account
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            reward_vault::check_vault(
+                &ctx.accounts.rank_reward.reward,
+                &vault.key(),
+                &mint.key(),
+                &token_program.key(),
+            )?;
+
+            let game_key = ctx.accounts.game.key();
+            let id = ctx.accounts.leaderboard.id;
+            let leaderboard_seeds = &[
+                crate::seeds::LEADER,
+                game_key.as_ref(),
+                &id.to_le_bytes(),
+                &[ctx.bumps.leaderboard],
+            ];
+            let signer = &[&leaderboard_seeds[..]];
+
+            if vault.amount > 0 {
+                utils::transfer_checked(
+                    &vault.to_account_info(),
+                    &mint.to_account_info(),
+                    &destination.to_account_info(),
+                    &ctx.accounts.leaderboard.to_account_info(),
+                    &token_program.to_account_info(),
+                    ctx.remaining_accounts,
+                    vault.amount,
+                    mint.decimals,
+                    signer,
+                )?;
+            }
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: ctx.accounts.receiver.to_account_info(),
+                    authority: ctx.accounts.leaderboard.to_account_info(),
+                },
+                signer,
+            ))?;
+        }
+
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.live_rank_rewards = leaderboard.live_rank_rewards.checked_sub(1).unwrap();
+
+        emit_cpi!(RankRewardClosed {
+            leaderboard: ctx.accounts.leaderboard.key(),
+            rank_reward: ctx.accounts.rank_reward.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod player {
+    use super::*;
+    use crate::ClosePlayer;
+
+    pub fn handler(ctx: Context<ClosePlayer>) -> Result<()> {
+        emit_cpi!(PlayerClosed {
+            player: ctx.accounts.player_account.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod player_scores {
+    use super::*;
+    use crate::ClosePlayerScores;
+
+    pub fn handler(ctx: Context<ClosePlayerScores>) -> Result<()> {
+        emit_cpi!(PlayerScoresClosed {
+            player: ctx.accounts.player_account.key(),
+            leaderboard: ctx.accounts.player_scores.leaderboard,
+        });
+        Ok(())
+    }
+}
+
+pub mod player_achievement {
+    use super::*;
+    use crate::ClosePlayerAchievement;
+
+    pub fn handler(ctx: Context<ClosePlayerAchievement>) -> Result<()> {
+        emit_cpi!(PlayerAchievementClosed {
+            player: ctx.accounts.player_account.key(),
+            achievement: ctx.accounts.achievement.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod merged {
+    use super::*;
+    use crate::CloseMerged;
+
+    pub fn handler(ctx: Context<CloseMerged>) -> Result<()> {
+        emit_cpi!(MergedClosed {
+            merged: ctx.accounts.merge_account.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod nft_claim {
+    use super::*;
+    use crate::CloseNftClaim;
+
+    pub fn handler(ctx: Context<CloseNftClaim>) -> Result<()> {
+        emit_cpi!(NftClaimClosed {
+            reward: ctx.accounts.reward.key(),
+            mint: ctx.accounts.mint.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod reward_claim {
+    use super::*;
+    use crate::CloseRewardClaim;
+
+    pub fn handler(ctx: Context<CloseRewardClaim>) -> Result<()> {
+        emit_cpi!(RewardClaimClosed {
+            reward: ctx.accounts.reward.key(),
+            player: ctx.accounts.player_account.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod rank_reward_claim {
+    use super::*;
+    use crate::CloseRankRewardClaim;
+
+    pub fn handler(ctx: Context<CloseRankRewardClaim>) -> Result<()> {
+        emit_cpi!(RankRewardClaimClosed {
+            rank_reward: ctx.accounts.rank_reward.key(),
+            player: ctx.accounts.player_account.key(),
+        });
+        Ok(())
+    }
+}
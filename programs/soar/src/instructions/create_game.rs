@@ -1,5 +1,7 @@
 use crate::{
-    state::{FieldsCheck, Game, GameAttributes},
+    events::GameInitialized,
+    state::{Authority, FieldsCheck, Game, GameAttributes},
+    utils::check_auth,
     InitializeGame,
 };
 use anchor_lang::prelude::*;
@@ -7,9 +9,11 @@ use anchor_lang::prelude::*;
 pub fn handler(
     ctx: Context<InitializeGame>,
     game_meta_input: GameAttributes,
-    game_auth_input: Vec<Pubkey>,
+    game_auth_input: Vec<Authority>,
+    _nonce: u64,
 ) -> Result<()> {
     game_meta_input.check()?;
+    check_auth(&game_auth_input)?;
 
     let game_account = &mut ctx.accounts.game;
     let mut game_object = Game::default();
@@ -17,9 +21,15 @@ pub fn handler(
     game_object.set_attributes(game_meta_input);
     game_object.leaderboard_count = 0;
     game_object.achievement_count = 0;
+    game_object.owner = ctx.accounts.creator.key();
     game_object.auth = game_auth_input;
 
     game_account.set_inner(game_object);
 
+    emit_cpi!(GameInitialized {
+        game: game_account.key(),
+        owner: game_account.owner,
+        auth: game_account.auth.clone(),
+    });
     Ok(())
 }
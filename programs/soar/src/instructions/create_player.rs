@@ -1,4 +1,5 @@
 use crate::{
+    events::PlayerInitialized,
     state::{FieldsCheck, Player},
     InitializePlayer,
 };
@@ -10,5 +11,10 @@ pub fn handler(ctx: Context<InitializePlayer>, username: String, nft_meta: Pubke
     player.check()?;
 
     ctx.accounts.player_account.set_inner(player);
+
+    emit_cpi!(PlayerInitialized {
+        player: ctx.accounts.player_account.key(),
+        user: *user,
+    });
     Ok(())
 }
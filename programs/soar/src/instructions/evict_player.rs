@@ -0,0 +1,17 @@
+use crate::{events::PlayerEvicted, state::LeaderTopEntries, EvictPlayer};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<EvictPlayer>) -> Result<()> {
+    let player_key = ctx.accounts.player_account.key();
+    let (mut top_entries, mut scores) =
+        LeaderTopEntries::load_scores_mut(&ctx.accounts.top_entries)?;
+    let removed = top_entries.evict_player(&mut scores, &player_key, &ctx.accounts.leaderboard);
+    msg!("Evicted {} top entries for {}", removed, player_key);
+
+    emit_cpi!(PlayerEvicted {
+        player: player_key,
+        leaderboard: ctx.accounts.leaderboard.key(),
+        removed: removed as u32,
+    });
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::{
+    error::SoarError,
+    events::TopEntriesGrown,
+    state::{LeaderBoardScore, LeaderTopEntries},
+    utils, GrowTopEntries,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<GrowTopEntries>) -> Result<()> {
+    let top_entries = &ctx.accounts.top_entries;
+
+    let (capacity, new_capacity) = {
+        let header = top_entries.load()?;
+        let new_capacity = header
+            .next_capacity()
+            .ok_or(SoarError::TopEntriesFullyAllocated)?;
+        (header.capacity, new_capacity)
+    };
+
+    utils::resize_account(
+        &top_entries.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        LeaderTopEntries::size(new_capacity as usize),
+    )?;
+    top_entries.load_mut()?.capacity = new_capacity;
+
+    let (_, mut scores) = LeaderTopEntries::load_scores_mut(top_entries)?;
+    let leaderboard = &ctx.accounts.leaderboard;
+    scores[capacity as usize..].fill(LeaderBoardScore::placeholder(
+        leaderboard.is_ascending,
+        leaderboard.max_score,
+    ));
+
+    msg!(
+        "Grew top entries from {} to {} slots",
+        capacity,
+        new_capacity
+    );
+    emit_cpi!(TopEntriesGrown {
+        leaderboard: ctx.accounts.leaderboard.key(),
+        top_entries: top_entries.key(),
+        capacity: new_capacity,
+    });
+    Ok(())
+}
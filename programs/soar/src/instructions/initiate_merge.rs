@@ -1,7 +1,7 @@
-use crate::{state::MergeApproval, InitiateMerge};
+use crate::{events::MergeInitiated, state::MergeApproval, InitiateMerge};
 use anchor_lang::prelude::*;
 
-pub fn handler(ctx: Context<InitiateMerge>, keys: Vec<Pubkey>) -> Result<()> {
+pub fn handler(ctx: Context<InitiateMerge>, keys: Vec<Pubkey>, _nonce: u64) -> Result<()> {
     let merge_account = &mut ctx.accounts.merge_account;
     let player_key = &ctx.accounts.player_account.key();
 
@@ -13,5 +13,10 @@ pub fn handler(ctx: Context<InitiateMerge>, keys: Vec<Pubkey>) -> Result<()> {
         .collect();
     merge_account.merge_complete = merge_account.approvals.is_empty();
 
+    emit_cpi!(MergeInitiated {
+        merged: merge_account.key(),
+        initiator: merge_account.initiator,
+        keys: merge_account.approvals.iter().map(|a| a.key).collect(),
+    });
     Ok(())
 }
@@ -0,0 +1,197 @@
+use crate::{
+    error::SoarError,
+    events::{PlayerAchievementMerged, PlayerScoresMerged},
+    instructions::claim_reward,
+    state::{LeaderTopEntries, PlayerAchievement, PlayerScoresList},
+    utils,
+};
+use anchor_lang::prelude::*;
+
+pub mod scores {
+    use super::*;
+    use crate::MergePlayerScores;
+
+    pub fn handler(ctx: Context<MergePlayerScores>) -> Result<()> {
+        let player_key = ctx.accounts.player_account.key();
+        let merged_key = ctx.accounts.merged_player_account.key();
+        let leaderboard = &ctx.accounts.leaderboard;
+
+        let merged_scores = std::mem::take(&mut ctx.accounts.merged_player_scores.scores);
+        let player_scores = &mut ctx.accounts.player_scores;
+
+        let mut count = player_scores
+            .scores
+            .len()
+            .checked_add(merged_scores.len())
+            .unwrap();
+        if let Some(limit) = leaderboard.retention.limit() {
+            count = count.min(limit);
+        }
+        if count > player_scores.alloc_count as usize {
+            // Grow by as many windows as needed to fit all the merged entries.
+            let mut alloc_count = player_scores.alloc_count as usize;
+            while alloc_count < count {
+                alloc_count = alloc_count
+                    .checked_add(PlayerScoresList::REALLOC_WINDOW)
+                    .unwrap();
+            }
+            let size = player_scores.current_size();
+            let new_size = PlayerScoresList::size(alloc_count);
+
+            utils::resize_account(
+                &player_scores.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                new_size,
+            )?;
+            player_scores.alloc_count = u16::try_from(alloc_count).unwrap();
+
+            msg!(
+                "Resized account with initial space {}. New space: {}.",
+                size,
+                new_size
+            );
+        }
+
+        player_scores.scores.extend(merged_scores);
+        player_scores.scores.sort_by_key(|s| s.timestamp);
+        player_scores.apply_retention(
+            &leaderboard.retention,
+            leaderboard.is_ascending,
+            leaderboard.tie_break,
+        );
+
+        // Once the leaderboard has closed, its top entries are final and stay as they were.
+        let clock = Clock::get()?;
+        let frozen = leaderboard.is_closed(clock.unix_timestamp);
+        if let Some(expected) = leaderboard.top_entries.filter(|_| !frozen) {
+            let top_entries = ctx
+                .accounts
+                .top_entries
+                .as_ref()
+                .ok_or(SoarError::MissingExpectedAccount)?;
+            require_keys_eq!(expected, top_entries.key());
+            let (mut top_entries, mut scores) = LeaderTopEntries::load_scores_mut(top_entries)?;
+
+            scores
+                .iter_mut()
+                .filter(|s| s.player == merged_key)
+                .for_each(|s| s.player = player_key);
+            top_entries.bump_generation();
+
+            if !leaderboard.allow_multiple_scores {
+                // Scores are sorted so the first entry for the player is its best. Drop the rest
+                // and pad the end of the list to keep its length.
+                let mut seen = false;
+                top_entries.retain(&mut scores, leaderboard, |s| {
+                    if s.player != player_key {
+                        true
+                    } else if seen {
+                        false
+                    } else {
+                        seen = true;
+                        true
+                    }
+                });
+            }
+        }
+
+        emit_cpi!(PlayerScoresMerged {
+            merged: ctx.accounts.merge_account.key(),
+            leaderboard: leaderboard.key(),
+            player: player_key,
+            merged_player: merged_key,
+        });
+        Ok(())
+    }
+}
+
+pub mod achievement {
+    use super::*;
+    use crate::MergePlayerAchievement;
+
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergePlayerAchievement<'info>>,
+    ) -> Result<()> {
+        let player_key = ctx.accounts.player_account.key();
+        let merged_key = ctx.accounts.merged_player_account.key();
+        let event = PlayerAchievementMerged {
+            merged: ctx.accounts.merge_account.key(),
+            achievement: ctx.accounts.achievement.key(),
+            player: player_key,
+            merged_player: merged_key,
+        };
+
+        let merged = &mut ctx.accounts.merged_player_achievement;
+        if merged.claimed {
+            carry_over_claims(
+                &ctx.accounts.achievement.rewards,
+                ctx.remaining_accounts,
+                &merged_key,
+                &player_key,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+
+        let player_achievement = &mut ctx.accounts.player_achievement;
+        if player_achievement.player_account == Pubkey::default() {
+            // Newly initialized: take over the merged player's status as is.
+            player_achievement.set_inner(PlayerAchievement {
+                player_account: player_key,
+                achievement: ctx.accounts.achievement.key(),
+                timestamp: merged.timestamp,
+                unlocked: merged.unlocked,
+                claimed: merged.claimed,
+            });
+        } else {
+            if merged.unlocked {
+                if !player_achievement.unlocked || merged.timestamp < player_achievement.timestamp {
+                    player_achievement.timestamp = merged.timestamp;
+                }
+                player_achievement.unlocked = true;
+            }
+            // A reward claimed by either account counts as claimed for the merged player.
+            player_achievement.claimed = player_achievement.claimed || merged.claimed;
+        }
+        merged.mark_merged();
+
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Record every reward of `rewards` the merged player claimed as claimed by the player
+    /// too, from pairs of [RewardClaim] accounts of the merged player and the player.
+    fn carry_over_claims<'info>(
+        rewards: &[Pubkey],
+        claims: &[AccountInfo<'info>],
+        merged_player: &Pubkey,
+        player: &Pubkey,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        require_eq!(
+            claims.len(),
+            rewards.len() * 2,
+            SoarError::MissingExpectedAccount
+        );
+
+        for (reward, claims) in rewards.iter().zip(claims.chunks_exact(2)) {
+            let [merged_claim, claim] = claims else {
+                unreachable!()
+            };
+            require_keys_eq!(
+                merged_claim.key(),
+                claim_reward::find_reward_claim_address(reward, merged_player).0,
+                ErrorCode::ConstraintSeeds
+            );
+
+            let merged_claimed = merged_claim.owner == &crate::ID && !merged_claim.data_is_empty();
+            let claimed = claim.owner == &crate::ID && !claim.data_is_empty();
+            if merged_claimed && !claimed {
+                claim_reward::create_reward_claim(payer, claim, system_program, reward, player)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{
+    error::SoarError,
+    events::{AccountMigrated, RewardFunded},
+    state::{
+        Achievement, Game, LeaderBoard, LeaderBoardScore, LeaderTopEntries, LegacyAchievement,
+        LegacyGame, LegacyLeaderBoard, LegacyLeaderTopEntries, LegacyPlayerScoresList,
+        PlayerScoresList, RewardKind,
+    },
+    utils,
+};
+use anchor_lang::{prelude::*, solana_program::program_option::COption, Discriminator};
+
+/// Read an account's data past its discriminator, which must be `discriminator`.
+fn legacy_data<'a>(
+    info: &'a AccountInfo,
+    discriminator: [u8; 8],
+) -> Result<std::cell::Ref<'a, [u8]>> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == discriminator,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(std::cell::Ref::map(data, |data| &data[8..]))
+}
+
+/// Resize `info` to `size` and write `account` over its data.
+fn write_account<'info, T: AccountSerialize>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size: usize,
+    account: &T,
+) -> Result<()> {
+    utils::resize_account(info, payer, system_program, size)?;
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
+
+pub mod game {
+    use super::*;
+    use crate::MigrateGame;
+
+    pub fn handler(ctx: Context<MigrateGame>) -> Result<()> {
+        let info = ctx.accounts.game.to_account_info();
+        let authority = ctx.accounts.authority.key();
+
+        let legacy = {
+            let data = legacy_data(&info, Game::DISCRIMINATOR)?;
+            // The legacy and current sizes can coincide, so the data must parse exactly as the
+            // legacy layout and not as the current one.
+            let legacy = LegacyGame::deserialize(&mut &data[..])
+                .ok()
+                .filter(|legacy| LegacyGame::size(legacy.auth.len()) == info.data_len())
+                .ok_or(SoarError::NotLegacyAccount)?;
+            let is_current = Game::deserialize(&mut &data[..])
+                .map(|game| Game::size(game.auth.len()) == info.data_len())
+                .unwrap_or(false);
+            require!(!is_current, SoarError::NotLegacyAccount);
+            legacy
+        };
+        require!(
+            legacy.auth.contains(&authority),
+            SoarError::InvalidAuthority
+        );
+
+        let game = legacy.into_game(authority);
+        write_account(
+            &info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Game::size(game.auth.len()),
+            &game,
+        )?;
+
+        emit_cpi!(AccountMigrated {
+            account: info.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod leaderboard {
+    use super::*;
+    use crate::MigrateLeaderBoard;
+
+    pub fn handler(ctx: Context<MigrateLeaderBoard>) -> Result<()> {
+        let info = ctx.accounts.leaderboard.to_account_info();
+        let payer = ctx.accounts.payer.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        require_eq!(
+            info.data_len(),
+            LegacyLeaderBoard::SIZE,
+            SoarError::NotLegacyAccount
+        );
+        let legacy = LegacyLeaderBoard::deserialize(
+            &mut &legacy_data(&info, LeaderBoard::DISCRIMINATOR)?[..],
+        )?;
+
+        // Legacy leaderboards kept their order in their top entries.
+        let top_entries = match legacy.top_entries {
+            Some(expected) => {
+                let top_entries = ctx
+                    .accounts
+                    .top_entries
+                    .as_ref()
+                    .ok_or(SoarError::MissingExpectedAccount)?;
+                require_keys_eq!(expected, top_entries.key());
+
+                let top_entries = top_entries.to_account_info();
+                let scores_to_retain =
+                    LegacyLeaderTopEntries::scores_to_retain(top_entries.data_len())
+                        .ok_or(SoarError::NotLegacyAccount)?;
+                let legacy = LegacyLeaderTopEntries::deserialize(
+                    &mut &legacy_data(&top_entries, LeaderTopEntries::DISCRIMINATOR)?[..],
+                )?;
+                Some((top_entries, scores_to_retain, legacy))
+            }
+            None => None,
+        };
+
+        let is_ascending = matches!(&top_entries, Some((_, _, legacy)) if legacy.is_ascending);
+        let leaderboard = legacy.into_leaderboard(is_ascending);
+        write_account(
+            &info,
+            &payer,
+            &system_program,
+            LeaderBoard::SIZE,
+            &leaderboard,
+        )?;
+
+        if let Some((top_entries, scores_to_retain, legacy)) = top_entries {
+            // Legacy accounts were created from within the program, so they fit in a single
+            // allocation of the new layout.
+            let capacity = scores_to_retain as u16;
+            utils::resize_account(
+                &top_entries,
+                &payer,
+                &system_program,
+                LeaderTopEntries::size(capacity as usize),
+            )?;
+
+            {
+                let mut data = top_entries.try_borrow_mut_data()?;
+                let season = leaderboard.season_at(Clock::get()?.unix_timestamp);
+                let header = LeaderTopEntries::new(season, capacity, capacity);
+                data[8..LeaderTopEntries::SCORES_OFFSET]
+                    .copy_from_slice(bytemuck::bytes_of(&header));
+            }
+
+            let (mut header, mut scores) = LeaderTopEntries::split_mut(&top_entries)?;
+            let submitted = legacy.top_scores.len();
+            for (slot, score) in scores.iter_mut().zip(legacy.top_scores) {
+                *slot = score.into();
+            }
+            scores[submitted..].fill(LeaderBoardScore::placeholder(
+                leaderboard.is_ascending,
+                leaderboard.max_score,
+            ));
+            // Legacy entries were ranked without the leaderboard's tie-break policy.
+            header.rerank(&mut scores, &leaderboard);
+        }
+
+        emit_cpi!(AccountMigrated {
+            account: info.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod player_scores {
+    use super::*;
+    use crate::MigratePlayerScores;
+
+    pub fn handler(ctx: Context<MigratePlayerScores>) -> Result<()> {
+        let info = ctx.accounts.player_scores.to_account_info();
+
+        let legacy = {
+            let data = legacy_data(&info, PlayerScoresList::DISCRIMINATOR)?;
+            let offset = LegacyPlayerScoresList::ALLOC_COUNT_OFFSET - 8;
+            let alloc_count = data
+                .get(offset..offset + 2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+                .ok_or(SoarError::NotLegacyAccount)?;
+            require_eq!(
+                info.data_len(),
+                LegacyPlayerScoresList::size(alloc_count as usize),
+                SoarError::NotLegacyAccount
+            );
+            LegacyPlayerScoresList::deserialize(&mut &data[..])?
+        };
+
+        let list = PlayerScoresList::from(legacy);
+        write_account(
+            &info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            list.current_size(),
+            &list,
+        )?;
+
+        emit_cpi!(AccountMigrated {
+            account: info.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod achievement {
+    use super::*;
+    use crate::MigrateAchievement;
+
+    pub fn handler(ctx: Context<MigrateAchievement>) -> Result<()> {
+        let info = ctx.accounts.achievement.to_account_info();
+
+        require_eq!(
+            info.data_len(),
+            LegacyAchievement::SIZE,
+            SoarError::NotLegacyAccount
+        );
+        let legacy = LegacyAchievement::deserialize(
+            &mut &legacy_data(&info, Achievement::DISCRIMINATOR)?[..],
+        )?;
+
+        write_account(
+            &info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Achievement::SIZE,
+            &Achievement::from(legacy),
+        )?;
+
+        emit_cpi!(AccountMigrated {
+            account: info.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod ft_reward {
+    use super::*;
+    use crate::MigrateFtReward;
+
+    pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, MigrateFtReward<'info>>) -> Result<()> {
+        let vault = &ctx.accounts.reward_vault;
+        let legacy_account = &ctx.accounts.legacy_token_account;
+        let mint = &ctx.accounts.reward_token_mint;
+        let token_program = &ctx.accounts.token_program;
+
+        let reward = &mut ctx.accounts.reward;
+        let amount = match &mut reward.reward {
+            RewardKind::FungibleToken {
+                mint: reward_mint,
+                account,
+                amount,
+                token_program: reward_token_program,
+            } => {
+                require_keys_eq!(legacy_account.key(), *account);
+                require_keys_eq!(mint.key(), *reward_mint);
+                require_keys_eq!(
+                    token_program.key(),
+                    RewardKind::token_program(reward_token_program),
+                    SoarError::InvalidTokenProgram
+                );
+
+                *account = vault.key();
+                *reward_token_program = token_program.key();
+                *amount
+            }
+            RewardKind::NonFungibleToken { .. } => return Err(SoarError::InvalidRewardKind.into()),
+        };
+
+        // Legacy rewards were paid from a token account the achievement was approved to spend
+        // from. Move what the remaining spots are owed into the vault, as far as the approval
+        // and balance cover.
+        let achievement = &ctx.accounts.achievement;
+        let approved = match legacy_account.delegate {
+            COption::Some(delegate) if delegate == achievement.key() => {
+                legacy_account.delegated_amount
+            }
+            _ => 0,
+        };
+        let owed = amount.saturating_mul(reward.available_spots);
+        let deposit = owed.min(approved).min(legacy_account.amount);
+
+        let game_key = ctx.accounts.game.key();
+        let id = achievement.id.to_le_bytes();
+        let achievement_seeds = &[
+            crate::seeds::ACHIEVEMENT,
+            game_key.as_ref(),
+            &id,
+            &[ctx.bumps.achievement],
+        ];
+        utils::transfer_checked(
+            &legacy_account.to_account_info(),
+            &mint.to_account_info(),
+            &vault.to_account_info(),
+            &achievement.to_account_info(),
+            &token_program.to_account_info(),
+            ctx.remaining_accounts,
+            deposit,
+            mint.decimals,
+            &[&achievement_seeds[..]],
+        )?;
+
+        emit_cpi!(RewardFunded {
+            reward: reward.key(),
+            vault: vault.key(),
+            amount: deposit,
+        });
+        emit_cpi!(AccountMigrated {
+            account: reward.key(),
+        });
+        Ok(())
+    }
+}
@@ -1,34 +1,86 @@
 #![allow(unused_imports)]
 
+pub mod accept_authority;
+pub mod accept_game_ownership;
 pub mod add_achievement;
 pub mod add_leaderboard;
+pub mod add_rank_reward;
 pub mod add_reward;
+pub mod add_top_entries;
 pub mod approve_merge;
+pub mod archive_season;
+pub mod cancel_authority_proposal;
+pub mod cancel_reward;
+pub mod claim_rank_reward;
 pub mod claim_reward;
+pub mod close_accounts;
 pub mod create_game;
 pub mod create_player;
+pub mod evict_player;
+pub mod grow_top_entries;
 pub mod initiate_merge;
+pub mod merge_player_data;
+pub mod migrate;
+pub mod player_ban;
+pub mod propose_authority;
+pub mod rebuild_top_entries;
+pub mod redeem_claim_voucher;
 pub mod register_player;
+pub mod remove_player_score;
+pub mod resize_top_entries;
+pub mod reward_vault;
+pub mod set_game_paused;
+pub mod set_leaderboard_paused;
+pub mod set_reward_paused;
+pub mod submit_attested_score;
 pub mod submit_score;
+pub mod transfer_game_ownership;
 pub mod unlock_player_achievement;
 pub mod update_achievement;
 pub mod update_game;
 pub mod update_leaderboard;
 pub mod update_player;
+pub mod update_reward;
 pub mod verify_reward;
 
+pub use accept_authority::*;
+pub use accept_game_ownership::*;
 pub use add_achievement::*;
 pub use add_leaderboard::*;
+pub use add_rank_reward::*;
 pub use add_reward::*;
+pub use add_top_entries::*;
 pub use approve_merge::*;
+pub use archive_season::*;
+pub use cancel_authority_proposal::*;
+pub use cancel_reward::*;
+pub use claim_rank_reward::*;
 pub use claim_reward::*;
+pub use close_accounts::*;
 pub use create_game::*;
 pub use create_player::*;
+pub use evict_player::*;
+pub use grow_top_entries::*;
+pub use merge_player_data::*;
+pub use migrate::*;
+pub use player_ban::*;
+pub use propose_authority::*;
+pub use rebuild_top_entries::*;
+pub use redeem_claim_voucher::*;
 pub use register_player::*;
+pub use remove_player_score::*;
+pub use resize_top_entries::*;
+pub use reward_vault::*;
+pub use set_game_paused::*;
+pub use set_leaderboard_paused::*;
+pub use set_reward_paused::*;
+pub use submit_attested_score::*;
 pub use submit_score::*;
+pub use transfer_game_ownership::*;
 pub use unlock_player_achievement::*;
 pub use update_achievement::*;
 pub use update_game::*;
 pub use update_leaderboard::*;
 pub use update_player::*;
+pub use update_reward::*;
 pub use verify_reward::*;
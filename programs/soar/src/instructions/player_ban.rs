@@ -0,0 +1,34 @@
+use crate::events::PlayerBanUpdated;
+use anchor_lang::prelude::*;
+
+pub mod ban {
+    use super::*;
+    use crate::BanPlayer;
+
+    pub fn handler(ctx: Context<BanPlayer>) -> Result<()> {
+        let ban = &mut ctx.accounts.ban;
+        ban.player_account = ctx.accounts.player_account.key();
+        ban.leaderboard = ctx.accounts.leaderboard.key();
+
+        emit_cpi!(PlayerBanUpdated {
+            player: ban.player_account,
+            leaderboard: ban.leaderboard,
+            banned: true,
+        });
+        Ok(())
+    }
+}
+
+pub mod unban {
+    use super::*;
+    use crate::UnbanPlayer;
+
+    pub fn handler(ctx: Context<UnbanPlayer>) -> Result<()> {
+        emit_cpi!(PlayerBanUpdated {
+            player: ctx.accounts.ban.player_account,
+            leaderboard: ctx.accounts.ban.leaderboard,
+            banned: false,
+        });
+        Ok(())
+    }
+}
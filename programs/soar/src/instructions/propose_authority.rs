@@ -0,0 +1,74 @@
+use crate::{
+    error::SoarError,
+    events::AuthorityProposed,
+    state::{Authority, Game},
+    ProposeAuthority,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(
+    ctx: Context<ProposeAuthority>,
+    authority: Authority,
+    replaces: Option<Pubkey>,
+    timelock: i64,
+) -> Result<()> {
+    require!(timelock >= 0, SoarError::InvalidTimelock);
+    let game = &ctx.accounts.game;
+
+    check_proposal(game, &ctx.accounts.authority.key(), authority, replaces)?;
+
+    let unlock_time = Clock::get()?
+        .unix_timestamp
+        .checked_add(timelock)
+        .ok_or(SoarError::InvalidTimelock)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.game = game.key();
+    proposal.proposer = ctx.accounts.payer.key();
+    proposal.proposed_by = ctx.accounts.authority.key();
+    proposal.authority = authority;
+    proposal.replaces = replaces;
+    proposal.unlock_time = unlock_time;
+
+    emit_cpi!(AuthorityProposed {
+        game: proposal.game,
+        proposal: proposal.key(),
+        authority,
+        replaces,
+        unlock_time,
+    });
+    Ok(())
+}
+
+/// Check that `proposed_by` may propose `authority`, replacing `replaces` if given, against
+/// the game's current authority list.
+///
+/// Any admin may propose an authority, but only the owner may grant or revoke the admin role.
+pub fn check_proposal(
+    game: &Game,
+    proposed_by: &Pubkey,
+    authority: Authority,
+    replaces: Option<Pubkey>,
+) -> Result<()> {
+    require!(game.check_admin(proposed_by), SoarError::InvalidAuthority);
+
+    let replaced_roles = match replaces {
+        Some(key) => {
+            let index = game
+                .find_authority(&key)
+                .ok_or(SoarError::AuthorityNotFound)?;
+            game.auth[index].roles
+        }
+        None => 0,
+    };
+    if replaces != Some(authority.key) {
+        require!(
+            game.find_authority(&authority.key).is_none(),
+            SoarError::AuthorityAlreadyExists
+        );
+    }
+    if (authority.roles | replaced_roles) & Authority::ADMIN != 0 {
+        require_keys_eq!(*proposed_by, game.owner, SoarError::NotGameOwner);
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::{
+    error::SoarError,
+    events::TopEntriesRebuilt,
+    state::{LeaderBoard, LeaderBoardScore, LeaderTopEntries, PlayerScoresView},
+    RebuildTopEntries,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RebuildTopEntries<'info>>,
+    reset: bool,
+) -> Result<()> {
+    let leaderboard = &ctx.accounts.leaderboard;
+    let (mut top_entries, mut scores) =
+        LeaderTopEntries::load_scores_mut(&ctx.accounts.top_entries)?;
+
+    let season = leaderboard.season_at(Clock::get()?.unix_timestamp);
+    if season > top_entries.season {
+        require!(
+            reset || scores.iter().all(LeaderBoardScore::is_placeholder),
+            SoarError::SeasonNotArchived
+        );
+    }
+    if reset || season > top_entries.season {
+        top_entries.reset(&mut scores, season, leaderboard);
+    } else {
+        // Entries kept from an earlier batch may predate a change to the leaderboard's rules.
+        top_entries.rerank(&mut scores, leaderboard);
+    }
+
+    insert_from_lists(
+        leaderboard,
+        &mut top_entries,
+        &mut scores,
+        ctx.remaining_accounts,
+    )?;
+
+    emit_cpi!(TopEntriesRebuilt {
+        leaderboard: leaderboard.key(),
+        top_entries: ctx.accounts.top_entries.key(),
+        lists: (ctx.remaining_accounts.len() / 2) as u32,
+    });
+    Ok(())
+}
+
+/// Insert the ranked entries of the [PlayerScoresList][crate::state::PlayerScoresList] accounts
+/// in `lists` into `scores`, skipping entries outside the leaderboard's bounds or the top
+/// entries' season.
+///
+/// Each list is followed by the address of its player's [PlayerBan][crate::state::PlayerBan],
+/// and the lists of banned players are skipped. Bumps the top entries' generation, as the
+/// lists' recorded top entries aren't updated.
+pub fn insert_from_lists(
+    leaderboard: &Account<LeaderBoard>,
+    top_entries: &mut LeaderTopEntries,
+    scores: &mut [LeaderBoardScore],
+    lists: &[AccountInfo],
+) -> Result<()> {
+    let pairs = lists.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        SoarError::MissingExpectedAccount
+    );
+    for pair in pairs {
+        let (info, ban) = (&pair[0], &pair[1]);
+        require_keys_eq!(*info.owner, crate::ID, SoarError::InvalidPlayerScoresList);
+        let data = info.try_borrow_data()?;
+        let list = PlayerScoresView::read(&data)?;
+        require_keys_eq!(
+            list.leaderboard,
+            leaderboard.key(),
+            SoarError::InvalidPlayerScoresList
+        );
+
+        let (ban_address, _) = Pubkey::find_program_address(
+            &[
+                crate::seeds::PLAYER_BAN,
+                leaderboard.key().as_ref(),
+                list.player_account.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(ban.key(), ban_address, SoarError::MissingExpectedAccount);
+        if !ban.data_is_empty() {
+            continue;
+        }
+
+        for entry in list.scores() {
+            if entry.score < leaderboard.min_score
+                || entry.score > leaderboard.max_score
+                || leaderboard.season_at(entry.timestamp) != top_entries.season
+            {
+                continue;
+            }
+            // Lists passed in more than one batch would otherwise add the same entry twice.
+            if leaderboard.allow_multiple_scores
+                && scores
+                    .iter()
+                    .any(|s| s.matches(&list.player_account, &entry))
+            {
+                continue;
+            }
+
+            let existing = top_entries.find_best(scores, &list.player_account, None, leaderboard);
+            top_entries.insert(
+                scores,
+                LeaderBoardScore::new(list.player_account, entry),
+                existing,
+                leaderboard,
+            );
+        }
+    }
+
+    top_entries.bump_generation();
+    Ok(())
+}
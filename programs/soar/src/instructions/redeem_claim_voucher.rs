@@ -0,0 +1,107 @@
+use crate::{
+    error::SoarError,
+    events::AchievementUnlocked,
+    instructions::claim_reward,
+    state::{Authority, ClaimVoucher, Game},
+    utils,
+};
+use anchor_lang::prelude::*;
+
+pub mod ft {
+    use super::*;
+    use crate::RedeemClaimVoucher;
+
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemClaimVoucher<'info>>,
+        expiry: i64,
+    ) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        check_voucher(
+            &ctx.accounts.instructions,
+            &claim.game,
+            claim.player_account.key(),
+            claim.achievement.key(),
+            claim.reward.key(),
+            expiry,
+        )?;
+        let event = claim.pay(ctx.bumps.claim.achievement, ctx.remaining_accounts)?;
+
+        let player_achievement = &mut claim.player_achievement;
+        let unlocked =
+            claim_reward::unlock_if_needed(player_achievement, event.player, event.achievement);
+        player_achievement.claimed = true;
+        let timestamp = player_achievement.timestamp;
+
+        if unlocked {
+            emit_cpi!(AchievementUnlocked {
+                player: event.player,
+                achievement: event.achievement,
+                timestamp,
+            });
+        }
+        emit_cpi!(event);
+        Ok(())
+    }
+}
+
+pub mod nft {
+    use super::*;
+    use crate::RedeemNftClaimVoucher;
+
+    pub fn handler(ctx: Context<RedeemNftClaimVoucher>, expiry: i64) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        check_voucher(
+            &ctx.accounts.instructions,
+            &claim.game,
+            claim.player_account.key(),
+            claim.achievement.key(),
+            claim.reward.key(),
+            expiry,
+        )?;
+        let event = claim.mint(ctx.bumps.claim.achievement)?;
+
+        let player_achievement = &mut claim.player_achievement;
+        let unlocked =
+            claim_reward::unlock_if_needed(player_achievement, event.player, event.achievement);
+        player_achievement.claimed = true;
+        let timestamp = player_achievement.timestamp;
+
+        if unlocked {
+            emit_cpi!(AchievementUnlocked {
+                player: event.player,
+                achievement: event.achievement,
+                timestamp,
+            });
+        }
+        emit_cpi!(event);
+        Ok(())
+    }
+}
+
+/// Check that the ed25519 instruction before this one verifies a [ClaimVoucher] for
+/// `reward`, signed by an authority allowed to both unlock achievements and pay out rewards.
+fn check_voucher(
+    instructions: &AccountInfo,
+    game: &Game,
+    player_account: Pubkey,
+    achievement: Pubkey,
+    reward: Pubkey,
+    expiry: i64,
+) -> Result<()> {
+    let clock = Clock::get().unwrap();
+    require!(
+        clock.unix_timestamp <= expiry,
+        SoarError::AttestationExpired
+    );
+
+    let voucher = ClaimVoucher::new(player_account, achievement, reward, expiry);
+    let signer = utils::verify_ed25519_signature(instructions, &voucher.message())?;
+    require!(
+        game.check_role(
+            &signer,
+            Authority::ACHIEVEMENT_MANAGER | Authority::REWARD_MANAGER
+        ),
+        SoarError::InvalidAuthority
+    );
+    Ok(())
+}
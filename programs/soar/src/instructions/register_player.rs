@@ -1,4 +1,4 @@
-use crate::{state::PlayerScoresList, RegisterPlayer};
+use crate::{events::PlayerRegistered, state::PlayerScoresList, RegisterPlayer};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<RegisterPlayer>) -> Result<()> {
@@ -6,8 +6,15 @@ pub fn handler(ctx: Context<RegisterPlayer>) -> Result<()> {
     let leaderboard = ctx.accounts.leaderboard.key();
 
     let new_list = &mut ctx.accounts.new_list;
-    let obj = PlayerScoresList::new(player_info, leaderboard);
+    let clock = Clock::get().unwrap();
+    let obj = PlayerScoresList::new(player_info, leaderboard, clock.unix_timestamp);
 
     new_list.set_inner(obj);
+
+    emit_cpi!(PlayerRegistered {
+        player: player_info,
+        leaderboard,
+        player_scores: new_list.key(),
+    });
     Ok(())
 }
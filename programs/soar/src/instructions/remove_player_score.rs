@@ -0,0 +1,38 @@
+use crate::{error::SoarError, events::ScoreRemoved, state::LeaderTopEntries, RemovePlayerScore};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<RemovePlayerScore>, index: u32) -> Result<()> {
+    let player_scores = &mut ctx.accounts.player_scores;
+    let index = index as usize;
+    require!(index < player_scores.scores.len(), SoarError::ScoreNotFound);
+
+    let entry = player_scores.scores.remove(index);
+    msg!(
+        "Removed score {} submitted at {}",
+        entry.score,
+        entry.timestamp
+    );
+
+    if let Some(top_entries) = ctx.accounts.top_entries.as_ref() {
+        // A closed leaderboard's top entries are the standings its rank rewards pay out on.
+        let clock = Clock::get()?;
+        require!(
+            !ctx.accounts.leaderboard.is_closed(clock.unix_timestamp),
+            SoarError::TopEntriesFrozen
+        );
+        let (mut top_entries, mut scores) = LeaderTopEntries::load_scores_mut(top_entries)?;
+        top_entries.evict_entry(
+            &mut scores,
+            &player_scores.player_account,
+            &entry,
+            &ctx.accounts.leaderboard,
+        );
+    }
+
+    emit_cpi!(ScoreRemoved {
+        player: player_scores.player_account,
+        leaderboard: player_scores.leaderboard,
+        entry,
+    });
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::{
+    error::SoarError,
+    events::TopEntriesResized,
+    instructions::rebuild_top_entries::insert_from_lists,
+    state::{check_scores_to_retain, LeaderBoardScore, LeaderTopEntries},
+    utils, ResizeTopEntries,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResizeTopEntries<'info>>,
+    scores_to_retain: u16,
+) -> Result<()> {
+    require!(scores_to_retain > 0, SoarError::InvalidScoresToRetain);
+
+    let leaderboard = &ctx.accounts.leaderboard;
+    check_scores_to_retain(leaderboard.season_length, scores_to_retain)?;
+    let top_entries = &ctx.accounts.top_entries;
+
+    let (capacity, new_capacity) = {
+        let mut header = top_entries.load_mut()?;
+        let capacity = header.capacity;
+        header.scores_to_retain = scores_to_retain;
+        let new_capacity = if scores_to_retain <= capacity {
+            scores_to_retain
+        } else {
+            header.next_capacity().unwrap_or(capacity)
+        };
+        (capacity, new_capacity)
+    };
+
+    if new_capacity < capacity {
+        // The lowest-ranked slots are dropped, so the header must stop covering them before
+        // the data shrinks.
+        top_entries.load_mut()?.capacity = new_capacity;
+    }
+    utils::resize_account(
+        &top_entries.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        LeaderTopEntries::size(new_capacity as usize),
+    )?;
+
+    if new_capacity > capacity {
+        top_entries.load_mut()?.capacity = new_capacity;
+
+        let (mut header, mut scores) = LeaderTopEntries::load_scores_mut(top_entries)?;
+        scores[capacity as usize..].fill(LeaderBoardScore::placeholder(
+            leaderboard.is_ascending,
+            leaderboard.max_score,
+        ));
+        // Entries that were pushed out of the smaller list can reclaim the new slots.
+        insert_from_lists(
+            leaderboard,
+            &mut header,
+            &mut scores,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    emit_cpi!(TopEntriesResized {
+        leaderboard: leaderboard.key(),
+        top_entries: top_entries.key(),
+        scores_to_retain,
+        capacity: new_capacity,
+    });
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::{
+    error::SoarError,
+    events::{RewardFunded, RewardWithdrawn},
+    state::RewardKind,
+    utils,
+};
+use anchor_lang::prelude::*;
+
+/// Check that `vault`, `mint` and `token_program` are the ones recorded for an FT `reward`,
+/// returning the reward's per-user amount.
+pub(crate) fn check_vault(
+    reward: &RewardKind,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<u64> {
+    match reward {
+        RewardKind::FungibleToken {
+            mint: reward_mint,
+            account,
+            amount,
+            token_program: reward_token_program,
+        } => {
+            require_keys_eq!(*vault, *account);
+            require_keys_eq!(*mint, *reward_mint);
+            require_keys_eq!(
+                *token_program,
+                RewardKind::token_program(reward_token_program),
+                SoarError::InvalidTokenProgram
+            );
+            Ok(*amount)
+        }
+        RewardKind::NonFungibleToken { .. } => Err(SoarError::InvalidRewardKind.into()),
+    }
+}
+
+pub mod top_up {
+    use super::*;
+    use crate::TopUpFtReward;
+
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, '_, 'info, TopUpFtReward<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        let mint = &ctx.accounts.reward_token_mint;
+        let vault = &ctx.accounts.reward_vault;
+        let token_program = &ctx.accounts.token_program;
+        check_vault(
+            &ctx.accounts.reward.reward,
+            &vault.key(),
+            &mint.key(),
+            &token_program.key(),
+        )?;
+
+        utils::transfer_checked(
+            &ctx.accounts.source_token_account.to_account_info(),
+            &mint.to_account_info(),
+            &vault.to_account_info(),
+            &ctx.accounts.token_account_owner.to_account_info(),
+            &token_program.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            mint.decimals,
+            &[],
+        )?;
+
+        emit_cpi!(RewardFunded {
+            reward: ctx.accounts.reward.key(),
+            vault: vault.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
+
+pub mod withdraw {
+    use super::*;
+    use crate::WithdrawFtReward;
+
+    pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawFtReward<'info>>) -> Result<()> {
+        let mint = &ctx.accounts.reward_token_mint;
+        let vault = &ctx.accounts.reward_vault;
+        let token_program = &ctx.accounts.token_program;
+        let reward = &ctx.accounts.reward;
+        let amount = check_vault(
+            &reward.reward,
+            &vault.key(),
+            &mint.key(),
+            &token_program.key(),
+        )?;
+
+        // Keep enough tokens in the vault to pay out every remaining spot.
+        let owed = amount.saturating_mul(reward.available_spots);
+        let remainder = vault.amount.saturating_sub(owed);
+        require!(remainder > 0, SoarError::NothingToWithdraw);
+
+        let game_key = ctx.accounts.game.key();
+        let id = ctx.accounts.achievement.id;
+        let achievement_seeds = &[
+            crate::seeds::ACHIEVEMENT,
+            game_key.as_ref(),
+            &id.to_le_bytes(),
+            &[ctx.bumps.achievement],
+        ];
+        let signer = &[&achievement_seeds[..]];
+
+        utils::transfer_checked(
+            &vault.to_account_info(),
+            &mint.to_account_info(),
+            &ctx.accounts.destination_token_account.to_account_info(),
+            &ctx.accounts.achievement.to_account_info(),
+            &token_program.to_account_info(),
+            ctx.remaining_accounts,
+            remainder,
+            mint.decimals,
+            signer,
+        )?;
+
+        emit_cpi!(RewardWithdrawn {
+            reward: reward.key(),
+            vault: vault.key(),
+            destination: ctx.accounts.destination_token_account.key(),
+            amount: remainder,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{events::GamePauseUpdated, SetGamePaused};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<SetGamePaused>, paused: bool) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.paused = paused;
+
+    emit_cpi!(GamePauseUpdated {
+        game: game.key(),
+        paused,
+    });
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use crate::{events::LeaderboardPauseUpdated, SetLeaderboardPaused};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<SetLeaderboardPaused>, paused: bool) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.paused = paused;
+
+    emit_cpi!(LeaderboardPauseUpdated {
+        leaderboard: leaderboard.key(),
+        paused,
+    });
+    Ok(())
+}
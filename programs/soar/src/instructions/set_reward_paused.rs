@@ -0,0 +1,13 @@
+use crate::{events::RewardPauseUpdated, SetRewardPaused};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<SetRewardPaused>, paused: bool) -> Result<()> {
+    let reward = &mut ctx.accounts.reward;
+    reward.paused = paused;
+
+    emit_cpi!(RewardPauseUpdated {
+        reward: reward.key(),
+        paused,
+    });
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::{
+    error::SoarError,
+    instructions::submit_score,
+    state::{Authority, ScoreAttestation},
+    utils, SubmitAttestedScore,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(
+    ctx: Context<SubmitAttestedScore>,
+    score: u64,
+    tiebreak: u64,
+    nonce: u64,
+    issued_at: i64,
+    expiry: i64,
+) -> Result<()> {
+    let player_key = ctx.accounts.player_account.key();
+    let leaderboard_key = ctx.accounts.leaderboard.key();
+
+    let clock = Clock::get().unwrap();
+    require!(
+        clock.unix_timestamp <= expiry,
+        SoarError::AttestationExpired
+    );
+    require!(
+        nonce == ctx.accounts.player_scores.submission_count,
+        SoarError::InvalidAttestation
+    );
+    require!(
+        issued_at > ctx.accounts.player_scores.created_at,
+        SoarError::StaleAttestation
+    );
+
+    let attestation = ScoreAttestation::new(
+        player_key,
+        leaderboard_key,
+        score,
+        tiebreak,
+        nonce,
+        issued_at,
+        expiry,
+    );
+    let signer = utils::verify_ed25519_signature(
+        &ctx.accounts.instructions.to_account_info(),
+        &attestation.message(),
+    )?;
+    require!(
+        ctx.accounts
+            .game
+            .check_role(&signer, Authority::SCORE_SUBMITTER),
+        SoarError::InvalidAuthority
+    );
+
+    let event = submit_score::record_score(
+        &mut ctx.accounts.player_scores,
+        &ctx.accounts.leaderboard,
+        ctx.accounts.top_entries.as_ref(),
+        player_key,
+        score,
+        tiebreak,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit_cpi!(event);
+    Ok(())
+}
@@ -1,44 +1,73 @@
 use crate::{
     error::SoarError,
-    state::{LeaderBoardScore, PlayerScoresList, ScoreEntry},
+    events::ScoreSubmitted,
+    state::{LeaderBoard, LeaderBoardScore, LeaderTopEntries, PlayerScoresList, ScoreEntry},
     utils, SubmitScore,
 };
 use anchor_lang::prelude::*;
 
-pub fn handler(ctx: Context<SubmitScore>, score: u64) -> Result<()> {
-    let player_scores = &mut ctx.accounts.player_scores;
-    let leaderboard = &ctx.accounts.leaderboard;
+pub fn handler(ctx: Context<SubmitScore>, score: u64, tiebreak: u64) -> Result<()> {
+    let player_key = ctx.accounts.player_account.key();
+
+    let event = record_score(
+        &mut ctx.accounts.player_scores,
+        &ctx.accounts.leaderboard,
+        ctx.accounts.top_entries.as_ref(),
+        player_key,
+        score,
+        tiebreak,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
+    emit_cpi!(event);
+    Ok(())
+}
+
+/// Timestamp and append a score to a [PlayerScoresList], resizing it if needed, and
+/// update the leaderboard's [LeaderTopEntries] if one is given.
+///
+/// Returns the [ScoreSubmitted] event to emit.
+#[allow(clippy::too_many_arguments)]
+pub fn record_score<'info>(
+    player_scores: &mut Account<'info, PlayerScoresList>,
+    leaderboard: &Account<'info, LeaderBoard>,
+    top_entries: Option<&AccountLoader<'info, LeaderTopEntries>>,
+    player_key: Pubkey,
+    score: u64,
+    tiebreak: u64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<ScoreSubmitted> {
     if score < leaderboard.min_score || score > leaderboard.max_score {
         return Err(SoarError::ScoreNotWithinBounds.into());
     }
 
     let clock = Clock::get().unwrap();
-    let entry = ScoreEntry::new(score, clock.unix_timestamp);
+    require!(
+        leaderboard.is_active(clock.unix_timestamp),
+        SoarError::ScoreOutsideWindow
+    );
+    let entry = ScoreEntry::new(score, clock.unix_timestamp, tiebreak);
 
     let count = player_scores.scores.len();
-    if count == player_scores.alloc_count as usize {
-        let window = PlayerScoresList::REALLOC_WINDOW;
+    if let Some(alloc_count) = player_scores.next_alloc_count(&leaderboard.retention) {
         msg!(
             "count: {}. Reallocating space for {} entries",
             count,
-            window
+            alloc_count
         );
 
         let size = player_scores.current_size();
-        let to_add = window.checked_mul(ScoreEntry::SIZE).unwrap();
-        let new_size = size.checked_add(to_add).unwrap();
+        let new_size = PlayerScoresList::size(alloc_count);
 
         utils::resize_account(
             &player_scores.to_account_info(),
-            &ctx.accounts.payer.to_account_info(),
-            &ctx.accounts.system_program.to_account_info(),
+            payer,
+            system_program,
             new_size,
         )?;
-        player_scores.alloc_count = player_scores
-            .alloc_count
-            .checked_add(PlayerScoresList::REALLOC_WINDOW as u16)
-            .unwrap();
+        player_scores.alloc_count = u16::try_from(alloc_count).unwrap();
 
         let new_space = player_scores.to_account_info().data_len();
         msg!(
@@ -48,46 +77,58 @@ pub fn handler(ctx: Context<SubmitScore>, score: u64) -> Result<()> {
         );
     }
 
+    // Bounded lists evict an entry instead of growing once full, which may be the new one.
     player_scores.scores.push(entry);
-    let player_key = ctx.accounts.player_account.key();
+    player_scores.apply_retention(
+        &leaderboard.retention,
+        leaderboard.is_ascending,
+        leaderboard.tie_break,
+    );
+    player_scores.submission_count = player_scores.submission_count.checked_add(1).unwrap();
+
+    let mut event = ScoreSubmitted {
+        player: player_key,
+        leaderboard: leaderboard.key(),
+        entry,
+        old_rank: None,
+        new_rank: None,
+    };
 
-    if let Some(top_entries) = &mut ctx.accounts.top_entries {
+    if let Some(top_entries) = top_entries {
         require_keys_eq!(leaderboard.top_entries.unwrap(), top_entries.key());
-        let mut score_entry = LeaderBoardScore::new(player_key, entry);
-        let is_ascending = top_entries.is_ascending;
-
-        let last_index = top_entries.top_scores.len() - 1;
-        let mut index = last_index;
-
-        let scores = &mut top_entries.top_scores;
-        if is_ascending && entry.score < scores[last_index].entry.score {
-            if !ctx.accounts.leaderboard.allow_multiple_scores {
-                if let Some(idx) = scores.iter().position(|s| s.player == player_key) {
-                    index = idx;
-                    score_entry = if score_entry.entry.score < scores[idx].entry.score {
-                        score_entry
-                    } else {
-                        scores[idx].clone()
-                    };
-                }
-            }
-            scores[index] = score_entry;
-            scores.sort_by(|a, b| a.entry.score.cmp(&b.entry.score));
-        } else if !is_ascending && entry.score > scores[last_index].entry.score {
-            if !ctx.accounts.leaderboard.allow_multiple_scores {
-                if let Some(idx) = scores.iter().position(|s| s.player == player_key) {
-                    index = idx;
-                    score_entry = if score_entry.entry.score > scores[idx].entry.score {
-                        score_entry
-                    } else {
-                        scores[idx].clone()
-                    };
-                }
-            }
-            scores[index] = score_entry;
-            scores.sort_by(|a, b| b.entry.score.cmp(&a.entry.score));
+        let (mut top_entries, mut scores) = LeaderTopEntries::load_scores_mut(top_entries)?;
+
+        // Standings of a finished season are only cleared by archiving them, so a score
+        // submitted first in a new season can't wipe them before they're recorded.
+        let season = leaderboard.season_at(clock.unix_timestamp);
+        if season > top_entries.season {
+            require!(
+                scores.iter().all(LeaderBoardScore::is_placeholder),
+                SoarError::SeasonNotArchived
+            );
+            msg!("Season {} started.", season);
+            top_entries.season = season;
         }
+
+        // The player's best entry is looked up by rank while the list's record of it is
+        // current, which only a full search can refresh otherwise.
+        let hint = player_scores.top_entry_hint(top_entries.generation);
+        let old_best = top_entries.find_best(&scores, &player_key, hint, leaderboard);
+        let new_best = top_entries.insert(
+            &mut scores,
+            LeaderBoardScore::new(player_key, entry),
+            old_best,
+            leaderboard,
+        );
+        player_scores.record_top_entry(
+            top_entries.generation,
+            new_best.map(|index| scores[index].entry),
+        );
+
+        let rank = |index: usize| index as u32 + 1;
+        event.old_rank = old_best.map(rank);
+        event.new_rank = new_best.map(rank);
     }
 
-    Ok(())
+    Ok(event)
 }
@@ -0,0 +1,14 @@
+use crate::{events::OwnershipTransferProposed, TransferGameOwnership};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<TransferGameOwnership>, new_owner: Option<Pubkey>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.pending_owner = new_owner;
+
+    emit_cpi!(OwnershipTransferProposed {
+        game: game.key(),
+        owner: game.owner,
+        pending_owner: new_owner,
+    });
+    Ok(())
+}
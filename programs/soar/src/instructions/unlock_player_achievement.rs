@@ -1,4 +1,4 @@
-use crate::{state::PlayerAchievement, UnlockPlayerAchievement};
+use crate::{events::AchievementUnlocked, state::PlayerAchievement, UnlockPlayerAchievement};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<UnlockPlayerAchievement>) -> Result<()> {
@@ -12,5 +12,11 @@ pub fn handler(ctx: Context<UnlockPlayerAchievement>) -> Result<()> {
     );
 
     pa_account.set_inner(obj);
+
+    emit_cpi!(AchievementUnlocked {
+        player: pa_account.player_account,
+        achievement: pa_account.achievement,
+        timestamp: pa_account.timestamp,
+    });
     Ok(())
 }
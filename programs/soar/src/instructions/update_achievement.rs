@@ -1,3 +1,4 @@
+use crate::events::AchievementUpdated;
 use crate::state::FieldsCheck;
 use crate::UpdateAchievement;
 use anchor_lang::prelude::*;
@@ -21,5 +22,9 @@ pub fn handler(
     }
 
     achievement.check()?;
+
+    emit_cpi!(AchievementUpdated {
+        achievement: achievement.key(),
+    });
     Ok(())
 }
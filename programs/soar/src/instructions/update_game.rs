@@ -1,5 +1,7 @@
 use crate::{
-    state::{FieldsCheck, Game, GameAttributes},
+    error::SoarError,
+    events::GameUpdated,
+    state::{Authority, FieldsCheck, Game, GameAttributes},
     utils, UpdateGame,
 };
 use anchor_lang::prelude::*;
@@ -7,7 +9,7 @@ use anchor_lang::prelude::*;
 pub fn handler(
     ctx: Context<UpdateGame>,
     new_attributes: Option<GameAttributes>,
-    new_auth: Option<Vec<Pubkey>>,
+    new_auth: Option<Vec<Authority>>,
 ) -> Result<()> {
     let game_account = &mut ctx.accounts.game;
 
@@ -17,13 +19,31 @@ pub fn handler(
     }
 
     if let Some(new_auth) = new_auth {
+        utils::check_auth(&new_auth)?;
+
+        // Keys can only be added through a proposal they accept, and only the owner can
+        // change who holds the admin role.
+        for authority in &new_auth {
+            require!(
+                game_account.find_authority(&authority.key).is_some(),
+                SoarError::UnconfirmedAuthority
+            );
+        }
+        if utils::admin_keys(&new_auth) != utils::admin_keys(&game_account.auth) {
+            require_keys_eq!(
+                ctx.accounts.authority.key(),
+                game_account.owner,
+                SoarError::NotGameOwner
+            );
+        }
+
         let initial_auth_len = game_account.auth.len();
         let prev_size = Game::size(initial_auth_len);
 
         let new_size = prev_size
-            .checked_sub(initial_auth_len * 32)
+            .checked_sub(initial_auth_len * Authority::SIZE)
             .unwrap()
-            .checked_add(new_auth.len() * 32)
+            .checked_add(new_auth.len() * Authority::SIZE)
             .unwrap();
 
         utils::resize_account(
@@ -36,5 +56,9 @@ pub fn handler(
         game_account.auth = new_auth;
     };
 
+    emit_cpi!(GameUpdated {
+        game: game_account.key(),
+        auth: game_account.auth.clone(),
+    });
     Ok(())
 }
@@ -1,7 +1,10 @@
-use crate::state::FieldsCheck;
+use crate::error::SoarError;
+use crate::events::LeaderboardUpdated;
+use crate::state::{FieldsCheck, LeaderTopEntries, ScoreRetention};
 use crate::UpdateLeaderBoard;
 use anchor_lang::prelude::*;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<UpdateLeaderBoard>,
     new_description: Option<String>,
@@ -10,8 +13,13 @@ pub fn handler(
     new_max_score: Option<u64>,
     new_is_ascending: Option<bool>,
     new_allow_multiple_scores: Option<bool>,
+    new_retention: Option<ScoreRetention>,
 ) -> Result<()> {
     let leaderboard = &mut ctx.accounts.leaderboard;
+    let rerank = new_min_score.is_some()
+        || new_max_score.is_some()
+        || new_is_ascending.is_some()
+        || new_allow_multiple_scores == Some(false);
 
     if let Some(description) = new_description {
         leaderboard.description = description;
@@ -26,15 +34,35 @@ pub fn handler(
         leaderboard.min_score = min_score;
     }
     if let Some(is_ascending) = new_is_ascending {
-        let top_entries = &mut ctx.accounts.top_entries;
-        if let Some(top_entries) = top_entries {
-            top_entries.is_ascending = is_ascending;
-        }
+        leaderboard.is_ascending = is_ascending;
     }
     if let Some(allow_multiple_scores) = new_allow_multiple_scores {
         leaderboard.allow_multiple_scores = allow_multiple_scores;
     }
+    if let Some(retention) = new_retention {
+        // Existing lists are trimmed to the new policy on their next submission.
+        leaderboard.retention = retention;
+    }
     leaderboard.check()?;
 
+    if rerank && leaderboard.top_entries.is_some() {
+        let clock = Clock::get()?;
+        require!(
+            !leaderboard.is_closed(clock.unix_timestamp),
+            SoarError::TopEntriesFrozen
+        );
+        let top_entries = ctx
+            .accounts
+            .top_entries
+            .as_ref()
+            .ok_or(SoarError::MissingExpectedAccount)?;
+        let (mut header, mut scores) = LeaderTopEntries::load_scores_mut(top_entries)?;
+        let removed = header.rerank(&mut scores, leaderboard);
+        msg!("Re-ranked top entries, removing {} entries", removed);
+    }
+
+    emit_cpi!(LeaderboardUpdated {
+        leaderboard: leaderboard.key(),
+    });
     Ok(())
 }
@@ -1,3 +1,4 @@
+use crate::events::PlayerUpdated;
 use crate::state::FieldsCheck;
 use crate::UpdatePlayer;
 use anchor_lang::prelude::*;
@@ -17,5 +18,9 @@ pub fn handler(
     }
 
     player_account.check()?;
+
+    emit_cpi!(PlayerUpdated {
+        player: player_account.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,74 @@
+use crate::error::SoarError;
+use crate::events::RewardUpdated;
+use crate::state::{FieldsCheck, RewardKind};
+use crate::UpdateReward;
+use anchor_lang::prelude::*;
+
+pub fn handler(
+    ctx: Context<UpdateReward>,
+    new_available_spots: Option<u64>,
+    new_amount: Option<u64>,
+    new_uri: Option<String>,
+    new_name: Option<String>,
+    new_symbol: Option<String>,
+) -> Result<()> {
+    let reward = &mut ctx.accounts.reward;
+    let old_spots = reward.available_spots;
+
+    if let Some(available_spots) = new_available_spots {
+        reward.available_spots = available_spots;
+    }
+    let spots = reward.available_spots;
+
+    match &mut reward.reward {
+        RewardKind::FungibleToken {
+            amount, account, ..
+        } => {
+            if new_uri.is_some() || new_name.is_some() || new_symbol.is_some() {
+                return Err(SoarError::InvalidRewardKind.into());
+            }
+
+            let old_owed = amount.saturating_mul(old_spots);
+            if let Some(new_amount) = new_amount {
+                *amount = new_amount;
+            }
+            let owed = amount
+                .checked_mul(spots)
+                .ok_or(SoarError::RewardVaultUnderfunded)?;
+            if owed > old_owed {
+                let vault = ctx
+                    .accounts
+                    .reward_vault
+                    .as_ref()
+                    .ok_or(SoarError::MissingExpectedAccount)?;
+                require_keys_eq!(vault.key(), *account);
+                require_gte!(vault.amount, owed, SoarError::RewardVaultUnderfunded);
+            }
+        }
+        RewardKind::NonFungibleToken {
+            uri, name, symbol, ..
+        } => {
+            if new_amount.is_some() {
+                return Err(SoarError::InvalidRewardKind.into());
+            }
+            if let Some(new_uri) = new_uri {
+                *uri = new_uri;
+            }
+            if let Some(new_name) = new_name {
+                *name = new_name;
+            }
+            if let Some(new_symbol) = new_symbol {
+                *symbol = new_symbol;
+            }
+        }
+    }
+
+    reward.check()?;
+
+    emit_cpi!(RewardUpdated {
+        achievement: reward.achievement,
+        reward: reward.key(),
+        available_spots: reward.available_spots,
+    });
+    Ok(())
+}
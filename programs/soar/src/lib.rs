@@ -1,8 +1,11 @@
 #![allow(clippy::result_large_err)]
+// `#[program]` expands each instruction's arguments into a generated function.
+#![allow(clippy::too_many_arguments)]
 
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_interface::{self, TokenAccount, TokenInterface};
 
 #[cfg(not(feature = "no-entrypoint"))]
 use solana_security_txt::security_txt;
@@ -10,8 +13,9 @@ use solana_security_txt::security_txt;
 declare_id!("SoarNNzwQHMwcfdkdLc6kvbkoMSxcHy89gTHrjhJYkk");
 
 mod error;
+pub mod events;
 mod instructions;
-mod seeds;
+pub mod seeds;
 mod state;
 mod utils;
 
@@ -34,23 +38,81 @@ pub mod soar {
     use super::*;
 
     /// Initialize a new [Game] and register its [LeaderBoard].
+    ///
+    /// The game is derived from the `creator` and a `nonce` unique among their games. The
+    /// `creator` becomes the game's owner. A `nonce` whose game was closed can't be reused.
     pub fn initialize_game(
         ctx: Context<InitializeGame>,
         game_meta: GameAttributes,
-        game_auth: Vec<Pubkey>,
+        game_auth: Vec<Authority>,
+        nonce: u64,
     ) -> Result<()> {
-        create_game::handler(ctx, game_meta, game_auth)
+        create_game::handler(ctx, game_meta, game_auth, nonce)
     }
 
-    /// Update a [Game]'s meta-information or authority list.
+    /// Update a [Game]'s meta-information or its authorities and their roles.
+    ///
+    /// `new_auth` can remove authorities or change their roles, but new keys are added with
+    /// `propose_authority`. Only the game's owner can change who holds the admin role.
+    /// Rent freed by shrinking the authority list is refunded to `payer`.
     pub fn update_game(
         ctx: Context<UpdateGame>,
         new_meta: Option<GameAttributes>,
-        new_auth: Option<Vec<Pubkey>>,
+        new_auth: Option<Vec<Authority>>,
     ) -> Result<()> {
         update_game::handler(ctx, new_meta, new_auth)
     }
 
+    /// Propose adding `authority` to a [Game], or replacing one of its existing authorities
+    /// with it.
+    ///
+    /// The change is applied once the proposed key accepts it, no earlier than `timelock`
+    /// seconds from now. Only the game's owner can propose changes involving the admin role.
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        authority: Authority,
+        replaces: Option<Pubkey>,
+        timelock: i64,
+    ) -> Result<()> {
+        propose_authority::handler(ctx, authority, replaces, timelock)
+    }
+
+    /// Accept an [AuthorityProposal] as its proposed key, applying it to the game's
+    /// authority list.
+    ///
+    /// Fails if the proposer is no longer an admin, or no longer the owner for changes
+    /// involving the admin role.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    /// Withdraw an [AuthorityProposal], either as a game admin or as the proposed key.
+    pub fn cancel_authority_proposal(ctx: Context<CancelAuthorityProposal>) -> Result<()> {
+        cancel_authority_proposal::handler(ctx)
+    }
+
+    /// Start transferring ownership of a [Game] to `new_owner`, or withdraw a pending
+    /// transfer if [None].
+    pub fn transfer_game_ownership(
+        ctx: Context<TransferGameOwnership>,
+        new_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        transfer_game_ownership::handler(ctx, new_owner)
+    }
+
+    /// Accept ownership of a [Game] as its pending owner.
+    pub fn accept_game_ownership(ctx: Context<AcceptGameOwnership>) -> Result<()> {
+        accept_game_ownership::handler(ctx)
+    }
+
+    /// Pause or resume a [Game].
+    ///
+    /// A paused game rejects score submissions, player registrations, achievement unlocks
+    /// and reward claims, while its authorities can still manage it.
+    pub fn set_game_paused(ctx: Context<SetGamePaused>, paused: bool) -> Result<()> {
+        set_game_paused::handler(ctx, paused)
+    }
+
     /// Add a new [Achievement] that can be attained for a particular [Game].
     pub fn add_achievement(
         ctx: Context<AddAchievement>,
@@ -79,8 +141,11 @@ pub mod soar {
         add_leaderboard::handler(ctx, input)
     }
 
-    /// Update's a leaderboard's description, nft metadata information, min/max score, or whether
-    /// or not multiple scores are allowed for a single player.
+    /// Update's a leaderboard's description, nft metadata information, min/max score, whether
+    /// or not multiple scores are allowed for a single player, or its score retention policy.
+    ///
+    /// Changing the order or score bounds, or disallowing multiple scores, re-ranks the
+    /// leaderboard's [LeaderTopEntries], which must then be passed in.
     pub fn update_leaderboard(
         ctx: Context<UpdateLeaderBoard>,
         new_description: Option<String>,
@@ -89,6 +154,7 @@ pub mod soar {
         new_max_score: Option<u64>,
         new_is_ascending: Option<bool>,
         new_allow_multiple_scores: Option<bool>,
+        new_retention: Option<ScoreRetention>,
     ) -> Result<()> {
         update_leaderboard::handler(
             ctx,
@@ -98,9 +164,77 @@ pub mod soar {
             new_max_score,
             new_is_ascending,
             new_allow_multiple_scores,
+            new_retention,
         )
     }
 
+    /// Pause or resume score submissions and player registrations for a [LeaderBoard].
+    pub fn set_leaderboard_paused(ctx: Context<SetLeaderboardPaused>, paused: bool) -> Result<()> {
+        set_leaderboard_paused::handler(ctx, paused)
+    }
+
+    /// Snapshot a seasonal [LeaderBoard]'s [LeaderTopEntries] into a [SeasonTopEntries] account
+    /// once its season is over, and reset the top entries for the current season.
+    ///
+    /// Anyone can archive a season. Until then, scores submitted in a later season are
+    /// rejected so the finished season's standings can't be lost; a season that ended
+    /// without any entries needs no archive.
+    pub fn archive_season(ctx: Context<ArchiveSeason>) -> Result<()> {
+        archive_season::handler(ctx)
+    }
+
+    /// Allocate more of a [LeaderTopEntries] account's slots, up to its `scores_to_retain`.
+    ///
+    /// Accounts can only be created or grown by about 10KB per instruction, so large top lists
+    /// start out partially allocated and rank only the slots allocated so far. Anyone can pay
+    /// to grow them.
+    pub fn grow_top_entries(ctx: Context<GrowTopEntries>) -> Result<()> {
+        grow_top_entries::handler(ctx)
+    }
+
+    /// Recompute a [LeaderBoard]'s [LeaderTopEntries] from the [PlayerScoresList] accounts
+    /// passed as remaining accounts, to repair a corrupted top list. Each list is followed by
+    /// the address of its player's [PlayerBan], and players banned from the leaderboard are
+    /// left out.
+    ///
+    /// The top entries are cleared first if `reset`, which also discards a finished season's
+    /// standings that haven't been archived. Lists are read in place, but a board with
+    /// many players still needs several transactions: reset in the first, then pass the rest
+    /// of the lists in batches.
+    pub fn rebuild_top_entries<'info>(
+        ctx: Context<'_, '_, '_, 'info, RebuildTopEntries<'info>>,
+        reset: bool,
+    ) -> Result<()> {
+        rebuild_top_entries::handler(ctx, reset)
+    }
+
+    /// Create a [LeaderTopEntries] account for a [LeaderBoard] that was added without one.
+    ///
+    /// Scores submitted before then can be ranked by passing their players' [PlayerScoresList]
+    /// accounts as remaining accounts, paired with their [PlayerBan] addresses as in
+    /// `rebuild_top_entries`, or later through `rebuild_top_entries` itself.
+    pub fn add_top_entries<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddTopEntries<'info>>,
+        scores_to_retain: u16,
+    ) -> Result<()> {
+        add_top_entries::handler(ctx, scores_to_retain)
+    }
+
+    /// Change the number of scores a [LeaderBoard]'s [LeaderTopEntries] retain, reallocating
+    /// the account.
+    ///
+    /// Shrinking drops the lowest-ranked entries and refunds the freed rent to the payer, and
+    /// makes the dropped ranks unclaimable for the leaderboard's [RankReward]s. New slots from
+    /// growing are filled from the [PlayerScoresList] accounts passed as remaining accounts,
+    /// paired with their [PlayerBan] addresses as in `rebuild_top_entries`; a list too large
+    /// to allocate at once keeps growing through `grow_top_entries`.
+    pub fn resize_top_entries<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResizeTopEntries<'info>>,
+        scores_to_retain: u16,
+    ) -> Result<()> {
+        resize_top_entries::handler(ctx, scores_to_retain)
+    }
+
     /// Create a [Player] account for a particular user.
     pub fn initialize_player(
         ctx: Context<InitializePlayer>,
@@ -130,7 +264,59 @@ pub mod soar {
     ///
     /// This instruction automatically resizes the [PlayerScoresList] account if needed.
     pub fn submit_score(ctx: Context<SubmitScore>, score: u64) -> Result<()> {
-        submit_score::handler(ctx, score)
+        submit_score::handler(ctx, score, 0)
+    }
+
+    /// Like [submit_score], with a secondary `tiebreak` value used to rank the score against
+    /// equal ones on leaderboards with a [TieBreak::HighestValue] or [TieBreak::LowestValue]
+    /// policy.
+    pub fn submit_score_with_tiebreak(
+        ctx: Context<SubmitScore>,
+        score: u64,
+        tiebreak: u64,
+    ) -> Result<()> {
+        submit_score::handler(ctx, score, tiebreak)
+    }
+
+    /// Submit a score signed for and paid by the player, carrying an attestation from one
+    /// of the [Game]'s authorities.
+    ///
+    /// The transaction must include an ed25519 program instruction right before this one that
+    /// verifies an authority's signature over the attestation's
+    /// [message][ScoreAttestation::message].
+    pub fn submit_attested_score(
+        ctx: Context<SubmitAttestedScore>,
+        score: u64,
+        tiebreak: u64,
+        nonce: u64,
+        issued_at: i64,
+        expiry: i64,
+    ) -> Result<()> {
+        submit_attested_score::handler(ctx, score, tiebreak, nonce, issued_at, expiry)
+    }
+
+    /// Remove the score at `index` from a [PlayerScoresList], also evicting it from the
+    /// [LeaderTopEntries] if given.
+    pub fn remove_player_score(ctx: Context<RemovePlayerScore>, index: u32) -> Result<()> {
+        remove_player_score::handler(ctx, index)
+    }
+
+    /// Remove every entry held by a [Player] from a [LeaderBoard]'s [LeaderTopEntries].
+    pub fn evict_player(ctx: Context<EvictPlayer>) -> Result<()> {
+        evict_player::handler(ctx)
+    }
+
+    /// Ban a [Player] from registering for or submitting scores to a [LeaderBoard].
+    ///
+    /// The ban is held by a [PlayerBan] account, so players can be banned before they
+    /// register and stay banned if they close their [PlayerScoresList].
+    pub fn ban_player(ctx: Context<BanPlayer>) -> Result<()> {
+        player_ban::ban::handler(ctx)
+    }
+
+    /// Lift a [Player]'s ban from a [LeaderBoard], closing its [PlayerBan] account.
+    pub fn unban_player(ctx: Context<UnbanPlayer>) -> Result<()> {
+        player_ban::unban::handler(ctx)
     }
 
     /// Initialize a new merge account and await approval from the verified users of all the
@@ -138,8 +324,15 @@ pub mod soar {
     ///
     /// A merge is complete when all the users of the [Player] account keys referenced in it
     /// have signed to set their approval to `true`.
-    pub fn initiate_merge(ctx: Context<InitiateMerge>, keys: Vec<Pubkey>) -> Result<()> {
-        initiate_merge::handler(ctx, keys)
+    ///
+    /// The [Merged] account is derived from the initiating user and a `nonce` unique among
+    /// their merges.
+    pub fn initiate_merge(
+        ctx: Context<InitiateMerge>,
+        keys: Vec<Pubkey>,
+        nonce: u64,
+    ) -> Result<()> {
+        initiate_merge::handler(ctx, keys, nonce)
     }
 
     /// Register merge confirmation for a particular [Player] account included in a [Merged].
@@ -147,50 +340,206 @@ pub mod soar {
         approve_merge::handler(ctx)
     }
 
-    /// Unlock a [PlayerAchievement] account without minting a reward.
+    /// Move a merged [Player]'s scores for a [LeaderBoard] into the initiator's [PlayerScoresList]
+    /// once the [Merged] account is complete.
+    ///
+    /// Rows in the leaderboard's [LeaderTopEntries] that belong to the merged player are
+    /// reassigned to the initiator's [Player] account.
+    ///
+    /// The merged player's [PlayerScoresList] is closed, refunding its rent to their wallet.
+    pub fn merge_player_scores(ctx: Context<MergePlayerScores>) -> Result<()> {
+        merge_player_data::scores::handler(ctx)
+    }
+
+    /// Carry over a merged [Player]'s [PlayerAchievement] to the initiator's [Player] account
+    /// once the [Merged] account is complete.
     ///
-    /// Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that
-    /// can serve as a gated verification-method for claims.
+    /// The merged player's status is [marked as merged][PlayerAchievement::mark_merged], so
+    /// neither it nor the merged player can claim the achievement's rewards anymore. If the
+    /// merged player claimed any, the [RewardClaim]s of both players are passed as remaining
+    /// accounts for each of the achievement's rewards, in order, so the initiator can't claim
+    /// them again.
+    pub fn merge_player_achievement<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergePlayerAchievement<'info>>,
+    ) -> Result<()> {
+        merge_player_data::achievement::handler(ctx)
+    }
+
+    /// Unlock a [PlayerAchievement] account without paying out a reward.
     ///
-    /// Since claim instructions like [claim_ft_reward] and [claim_nft_reward] for reward types
-    /// defined by this program try to initialize this account and will fail if it already exists,
-    /// calling this means opting out of using these functions.
+    /// The achievement's rewards can be claimed later with [claim_ft_reward] and
+    /// [claim_nft_reward]. The account can also serve as a gated verification-method for
+    /// custom rewards mechanisms.
     pub fn unlock_player_achievement(ctx: Context<UnlockPlayerAchievement>) -> Result<()> {
         unlock_player_achievement::handler(ctx)
     }
 
     /// Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.
     ///
-    /// Overwrites the current reward if one exists.
-    pub fn add_ft_reward(ctx: Context<AddFtReward>, input: AddNewRewardInput) -> Result<()> {
+    /// The reward's mint may belong to either SPL Token or Token-2022. `deposit` tokens are
+    /// moved into a vault owned by the achievement, from which claims are paid out.
+    ///
+    /// An achievement can hold up to [Achievement::MAX_REWARDS] rewards, each paid out
+    /// independently while it has spots left. The reward is derived from the achievement
+    /// and [Achievement::next_reward].
+    pub fn add_ft_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddFtReward<'info>>,
+        input: AddNewRewardInput,
+    ) -> Result<()> {
         add_reward::ft::handler(ctx, input)
     }
 
+    /// Deposit more tokens into the vault of a fungible token [Reward].
+    pub fn top_up_ft_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, TopUpFtReward<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        reward_vault::top_up::handler(ctx, amount)
+    }
+
+    /// Withdraw the tokens in a fungible token [Reward]'s vault that aren't owed to any of its
+    /// remaining spots.
+    pub fn withdraw_ft_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFtReward<'info>>,
+    ) -> Result<()> {
+        reward_vault::withdraw::handler(ctx)
+    }
+
     /// Add a nft [Reward] to an [Achievement] to mint to users on unlock.
     ///
-    /// Overwrites the current reward if one exists.
+    /// An achievement can hold up to [Achievement::MAX_REWARDS] rewards, each paid out
+    /// independently while it has spots left. The reward is derived from the achievement
+    /// and [Achievement::next_reward].
     pub fn add_nft_reward(ctx: Context<AddNftReward>, input: AddNewRewardInput) -> Result<()> {
         add_reward::nft::handler(ctx, input)
     }
 
-    /// Mint an NFT reward for unlocking a [PlayerAchievement] account.
+    /// Update a [Reward]'s available spots, or the amount given out by a fungible token reward,
+    /// or the uri, name or symbol of NFTs minted by an NFT reward.
+    ///
+    /// Only affects claims made after the update. Cancelled rewards can't be updated, while
+    /// rewards whose spots ran out can be given more. A fungible token reward's vault must
+    /// already hold any increase in what its remaining spots are owed.
+    pub fn update_reward(
+        ctx: Context<UpdateReward>,
+        new_available_spots: Option<u64>,
+        new_amount: Option<u64>,
+        new_uri: Option<String>,
+        new_name: Option<String>,
+        new_symbol: Option<String>,
+    ) -> Result<()> {
+        update_reward::handler(
+            ctx,
+            new_available_spots,
+            new_amount,
+            new_uri,
+            new_name,
+            new_symbol,
+        )
+    }
+
+    /// Pause or resume claims of a [Reward].
+    pub fn set_reward_paused(ctx: Context<SetRewardPaused>, paused: bool) -> Result<()> {
+        set_reward_paused::handler(ctx, paused)
+    }
+
+    /// Cancel a [Reward], detaching it from its [Achievement].
+    ///
+    /// The whole vault of a fungible token reward is refunded to `destination_token_account`.
+    /// The reward account itself is left for `close_reward`.
+    pub fn cancel_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelReward<'info>>,
+    ) -> Result<()> {
+        cancel_reward::handler(ctx)
+    }
+
+    /// Redeem a [ClaimVoucher] signed by one of the [Game]'s authorities for a fungible token
+    /// [Reward], unlocking its [Achievement] for the signing player if needed and paying the
+    /// reward out to them.
+    ///
+    /// The transaction must include an ed25519 program instruction right before this one that
+    /// verifies the signature over the voucher's [message][ClaimVoucher::message]. The signing
+    /// authority must be allowed to both unlock achievements and manage rewards.
+    ///
+    /// For Token-2022 mints with a transfer hook, the accounts the hook requires are passed
+    /// as remaining accounts.
+    pub fn redeem_claim_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemClaimVoucher<'info>>,
+        expiry: i64,
+    ) -> Result<()> {
+        redeem_claim_voucher::ft::handler(ctx, expiry)
+    }
+
+    /// Same as [redeem_claim_voucher], but for an NFT [Reward].
+    pub fn redeem_nft_claim_voucher(
+        ctx: Context<RedeemNftClaimVoucher>,
+        expiry: i64,
+    ) -> Result<()> {
+        redeem_claim_voucher::nft::handler(ctx, expiry)
+    }
+
+    /// Pay out one of an [Achievement]'s fungible token [Reward]s to a player who has already
+    /// unlocked it.
+    ///
+    /// Signed by either one of the [Game]'s authorities or the player themselves.
+    ///
+    /// Each reward can be claimed once per player, tracked by a [RewardClaim] account. To pay
+    /// out every reward of an achievement at once, use [claim_ft_rewards].
+    ///
+    /// Marks the [PlayerAchievement] as `claimed`, which only records that at least one of
+    /// the achievement's rewards was claimed.
     ///
-    /// This will attempt to create a [PlayerAchievement] account and fail if it already exists.
+    /// Relevant `ONLY` if an FT reward is specified for that achievement. The user's associated
+    /// token account is created if it doesn't exist yet.
     ///
-    /// Relevant `ONLY` if an FT reward is specified for that achievement.    
-    pub fn claim_ft_reward(ctx: Context<ClaimFtReward>) -> Result<()> {
+    /// For Token-2022 mints with a transfer hook, the accounts the hook requires are passed
+    /// as remaining accounts.
+    pub fn claim_ft_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimFtReward<'info>>,
+    ) -> Result<()> {
         claim_reward::ft::handler(ctx)
     }
 
-    /// Mint an NFT reward for unlocking a [PlayerAchievement] account.
+    /// Pay out several of an [Achievement]'s fungible token [Reward]s to a player who has
+    /// already unlocked it, in one instruction.
+    ///
+    /// Each reward is passed as a group of remaining accounts: the reward (mut), its
+    /// [RewardClaim] (mut), its mint, its vault (mut) and the user's token account for the
+    /// mint (mut), which must already exist. All rewards must use `token_program`.
+    ///
+    /// Mints with a transfer hook aren't supported here and are claimed with
+    /// [claim_ft_reward] instead.
+    pub fn claim_ft_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimFtRewards<'info>>,
+    ) -> Result<()> {
+        claim_reward::ft_batch::handler(ctx)
+    }
+
+    /// Mint one of an [Achievement]'s NFT [Reward]s to a player who has already unlocked it.
     ///
-    /// This will attempt to create a [PlayerAchievement] account and fail if it already exists.
+    /// Signed by either one of the [Game]'s authorities or the player themselves.
+    ///
+    /// Each reward can be claimed once per player, tracked by a [RewardClaim] account.
     ///
     /// Relevant `ONLY` if an NFT reward is specified for that achievement.
     pub fn claim_nft_reward(ctx: Context<ClaimNftReward>) -> Result<()> {
         claim_reward::nft::handler(ctx)
     }
 
+    /// Same as [claim_ft_reward], but first unlocks the achievement for the player if they
+    /// haven't unlocked it yet.
+    pub fn unlock_and_claim_ft_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnlockAndClaimFtReward<'info>>,
+    ) -> Result<()> {
+        claim_reward::unlock_and_claim_ft::handler(ctx)
+    }
+
+    /// Same as [claim_nft_reward], but first unlocks the achievement for the player if they
+    /// haven't unlocked it yet.
+    pub fn unlock_and_claim_nft_reward(ctx: Context<UnlockAndClaimNftReward>) -> Result<()> {
+        claim_reward::unlock_and_claim_nft::handler(ctx)
+    }
+
     /// Verify NFT reward as belonging to a particular collection.
     ///
     /// Optional: Only relevant if an NFT reward is specified and the reward's
@@ -198,40 +547,324 @@ pub mod soar {
     pub fn verify_nft_reward(ctx: Context<VerifyNftReward>) -> Result<()> {
         verify_reward::handler(ctx)
     }
+
+    /// Add a fungible token [RankReward] to a [LeaderBoard], paid out to players who finish
+    /// within a range of ranks in its [LeaderTopEntries].
+    ///
+    /// `deposit` tokens are moved into a vault owned by the leaderboard PDA, which claims are
+    /// paid from. On seasonal leaderboards the reward names the season it pays out for;
+    /// otherwise the leaderboard must have an end time.
+    pub fn add_ft_rank_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddFtRankReward<'info>>,
+        input: AddRankRewardInput,
+    ) -> Result<()> {
+        add_rank_reward::ft::handler(ctx, input)
+    }
+
+    /// Add a nft [RankReward] to a [LeaderBoard], minted to players who finish within a range
+    /// of ranks in its [LeaderTopEntries].
+    ///
+    /// As with [add_ft_rank_reward], the reward names its season on seasonal leaderboards.
+    pub fn add_nft_rank_reward(
+        ctx: Context<AddNftRankReward>,
+        input: AddRankRewardInput,
+    ) -> Result<()> {
+        add_rank_reward::nft::handler(ctx, input)
+    }
+
+    /// Claim an FT [RankReward] for the player holding `rank` in a closed [LeaderBoard], or
+    /// in the [SeasonTopEntries] archive of the reward's season.
+    ///
+    /// Ranks start from `1`. Each player can claim a reward once. For Token-2022 mints with a
+    /// transfer hook, the accounts the hook requires are passed as remaining accounts.
+    pub fn claim_ft_rank_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimFtRankReward<'info>>,
+        rank: u32,
+    ) -> Result<()> {
+        claim_rank_reward::ft::handler(ctx, rank)
+    }
+
+    /// Claim an NFT [RankReward] for the player holding `rank` in a closed [LeaderBoard], or
+    /// in the [SeasonTopEntries] archive of the reward's season.
+    ///
+    /// Ranks start from `1`. Each player can claim a reward once.
+    pub fn claim_nft_rank_reward(ctx: Context<ClaimNftRankReward>, rank: u32) -> Result<()> {
+        claim_rank_reward::nft::handler(ctx, rank)
+    }
+
+    /// Close a [Game] account, sending its lamports to `receiver`.
+    ///
+    /// Fails if any of the game's leaderboards or achievements are still open. Leaves a
+    /// [ClosedGame] account behind, so the game's address can't be initialized again.
+    pub fn close_game(ctx: Context<CloseGame>) -> Result<()> {
+        close_accounts::game::handler(ctx)
+    }
+
+    /// Close a [LeaderBoard] along with its [LeaderTopEntries] if it has one.
+    ///
+    /// Fails while any of the leaderboard's [RankReward]s or [SeasonTopEntries] are still
+    /// open, as they can't be closed without it.
+    pub fn close_leaderboard(ctx: Context<CloseLeaderBoard>) -> Result<()> {
+        close_accounts::leaderboard::handler(ctx)
+    }
+
+    /// Close an archived [SeasonTopEntries] account.
+    pub fn close_season_top_entries(ctx: Context<CloseSeasonTopEntries>) -> Result<()> {
+        close_accounts::season_top_entries::handler(ctx)
+    }
+
+    /// Close an [Achievement]. Fails if it still has a [Reward] attached.
+    pub fn close_achievement(ctx: Context<CloseAchievement>) -> Result<()> {
+        close_accounts::achievement::handler(ctx)
+    }
+
+    /// Close a [Reward], detaching it from its [Achievement].
+    ///
+    /// For FT rewards, the reward's vault must be emptied first and is closed along with it.
+    /// For mints with transfer fees, fees withheld in the vault must be harvested too.
+    /// Close the reward's [NftClaim]s first, as they can't be closed without it.
+    pub fn close_reward(ctx: Context<CloseReward>) -> Result<()> {
+        close_accounts::reward::handler(ctx)
+    }
+
+    /// Close a [RankReward]. For FT rewards, whatever is left in the reward's vault is
+    /// refunded to `destination_token_account` and the vault is closed along with it.
+    pub fn close_rank_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseRankReward<'info>>,
+    ) -> Result<()> {
+        close_accounts::rank_reward::handler(ctx)
+    }
+
+    /// Close a user's [Player] account.
+    pub fn close_player(ctx: Context<ClosePlayer>) -> Result<()> {
+        close_accounts::player::handler(ctx)
+    }
+
+    /// Close a user's [PlayerScoresList].
+    pub fn close_player_scores(ctx: Context<ClosePlayerScores>) -> Result<()> {
+        close_accounts::player_scores::handler(ctx)
+    }
+
+    /// Close a user's [PlayerAchievement].
+    ///
+    /// Claimed achievements can only be closed once the [Achievement] itself is closed, so
+    /// the record that the player claimed from it lasts as long as the achievement.
+    pub fn close_player_achievement(ctx: Context<ClosePlayerAchievement>) -> Result<()> {
+        close_accounts::player_achievement::handler(ctx)
+    }
+
+    /// Close a [Merged] account. Only the merge initiator can close it.
+    pub fn close_merged(ctx: Context<CloseMerged>) -> Result<()> {
+        close_accounts::merged::handler(ctx)
+    }
+
+    /// Close an [NftClaim], after which the minted nft can no longer be verified.
+    pub fn close_nft_claim(ctx: Context<CloseNftClaim>) -> Result<()> {
+        close_accounts::nft_claim::handler(ctx)
+    }
+
+    /// Close a user's [RewardClaim] once its [Reward] is closed.
+    pub fn close_reward_claim(ctx: Context<CloseRewardClaim>) -> Result<()> {
+        close_accounts::reward_claim::handler(ctx)
+    }
+
+    /// Close a user's [RankRewardClaim] once its [RankReward] is closed.
+    pub fn close_rank_reward_claim(ctx: Context<CloseRankRewardClaim>) -> Result<()> {
+        close_accounts::rank_reward_claim::handler(ctx)
+    }
+
+    /// Convert a [Game] created under the legacy layout, reallocating it. Its authorities keep
+    /// every role, and the signing authority, which must be one of them, becomes its owner.
+    pub fn migrate_game(ctx: Context<MigrateGame>) -> Result<()> {
+        migrate::game::handler(ctx)
+    }
+
+    /// Convert a [LeaderBoard] created under the legacy layout, along with its
+    /// [LeaderTopEntries], reallocating both. The leaderboard takes its order from its top
+    /// entries, and its newer settings are left at their defaults.
+    pub fn migrate_leaderboard(ctx: Context<MigrateLeaderBoard>) -> Result<()> {
+        migrate::leaderboard::handler(ctx)
+    }
+
+    /// Convert a [PlayerScoresList] created under the legacy layout, reallocating it for its
+    /// larger score entries.
+    pub fn migrate_player_scores(ctx: Context<MigratePlayerScores>) -> Result<()> {
+        migrate::player_scores::handler(ctx)
+    }
+
+    /// Convert an [Achievement] created under the legacy layout, reallocating it to hold
+    /// several rewards. Its reward, if any, becomes the first of them.
+    pub fn migrate_achievement(ctx: Context<MigrateAchievement>) -> Result<()> {
+        migrate::achievement::handler(ctx)
+    }
+
+    /// Move a fungible token [Reward] created under the legacy layout to a vault.
+    ///
+    /// Legacy rewards were paid from a token account the [Achievement] was approved to spend
+    /// from. What the remaining spots are owed is moved from it into a new vault, as far as
+    /// the approval and balance cover, and the reward is paid from the vault from then on.
+    pub fn migrate_ft_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateFtReward<'info>>,
+    ) -> Result<()> {
+        migrate::ft_reward::handler(ctx)
+    }
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(_attr: GameAttributes, auth: Vec<Pubkey>)]
+#[instruction(_attr: GameAttributes, auth: Vec<Authority>, nonce: u64)]
 pub struct InitializeGame<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
     #[account(
         init,
         payer = creator,
-        space = Game::size(auth.len())
+        space = Game::size(auth.len()),
+        seeds = [
+            seeds::GAME,
+            creator.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        bump
     )]
     pub game: Account<'info, Game>,
+    /// CHECK: Only exists if a game at the same address was closed.
+    #[account(
+        seeds = [seeds::CLOSED_GAME, game.key().as_ref()],
+        bump,
+        constraint = closed_game.data_is_empty()
+        @SoarError::GameClosed
+    )]
+    pub closed_game: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateGame<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_admin(&authority.key())
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(new_authority: Authority)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        constraint = game.check_admin(&authority.key())
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = AuthorityProposal::SIZE,
+        seeds = [
+            seeds::AUTHORITY_PROPOSAL,
+            game.key().as_ref(),
+            new_authority.key.as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, AuthorityProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
     #[account(mut)]
     pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game,
+        has_one = proposer,
+        close = proposer,
+        constraint = proposal.authority.key == new_authority.key()
+        @SoarError::InvalidAuthority
+    )]
+    pub proposal: Account<'info, AuthorityProposal>,
+    #[account(mut)]
+    /// CHECK: Checked with `proposal` and refunded its lamports.
+    pub proposer: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAuthorityProposal<'info> {
+    #[account(
+        constraint = game.check_admin(&authority.key())
+            || proposal.authority.key == authority.key()
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, AuthorityProposal>,
+    #[account(mut)]
+    /// CHECK: Checked with `proposal` and refunded its lamports.
+    pub proposer: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferGameOwnership<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @SoarError::NotGameOwner
+    )]
+    pub game: Account<'info, Game>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptGameOwnership<'info> {
+    pub new_owner: Signer<'info>,
+    #[account(
+        mut,
+        constraint = game.pending_owner == Some(new_owner.key())
+        @SoarError::NoPendingOwner
+    )]
+    pub game: Account<'info, Game>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGamePaused<'info> {
+    #[account(
+        constraint = game.check_admin(&authority.key())
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddAchievement<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(&authority.key(), Authority::ACHIEVEMENT_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
@@ -254,10 +887,11 @@ pub struct AddAchievement<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateAchievement<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(&authority.key(), Authority::ACHIEVEMENT_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
@@ -269,11 +903,12 @@ pub struct UpdateAchievement<'info> {
     pub achievement: Account<'info, Achievement>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(input: RegisterLeaderBoardInput)]
 pub struct AddLeaderBoard<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
@@ -295,8 +930,9 @@ pub struct AddLeaderBoard<'info> {
     #[account(
         init,
         constraint = input.scores_to_retain > 0,
-        space =
-            LeaderTopEntries::size(input.scores_to_retain as usize),
+        space = LeaderTopEntries::size(
+            LeaderTopEntries::initial_capacity(input.scores_to_retain) as usize
+        ),
         payer = payer,
         seeds = [
             seeds::LEADER_TOP_ENTRIES,
@@ -304,14 +940,15 @@ pub struct AddLeaderBoard<'info> {
         ],
         bump,
     )]
-    pub top_entries: Option<Account<'info, LeaderTopEntries>>,
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateLeaderBoard<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
@@ -321,26 +958,174 @@ pub struct UpdateLeaderBoard<'info> {
         has_one = game
     )]
     pub leaderboard: Account<'info, LeaderBoard>,
-    #[account(mut)]
-    pub top_entries: Option<Account<'info, LeaderTopEntries>>,
+    #[account(
+        mut,
+        constraint = check_top_entries(&leaderboard, top_entries)
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct InitializePlayer<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    pub user: Signer<'info>,
+pub struct SetLeaderboardPaused<'info> {
     #[account(
-        init,
-        payer = payer,
-        space = Player::SIZE,
-        seeds = [seeds::PLAYER, user.key().as_ref()],
-        bump,
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+            || game.check_admin(&authority.key())
+        @SoarError::InvalidAuthority
     )]
-    pub player_account: Account<'info, Player>,
+    pub authority: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ArchiveSeason<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    #[account(
+        init,
+        payer = payer,
+        space = SeasonTopEntries::size(top_entries.load()?.capacity as usize),
+        seeds = [
+            seeds::SEASON_TOP_ENTRIES,
+            leaderboard.key().as_ref(),
+            &top_entries.load()?.season.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub archive: Account<'info, SeasonTopEntries>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct GrowTopEntries<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RebuildTopEntries<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.is_closed(Clock::get()?.unix_timestamp)
+        @SoarError::TopEntriesFrozen
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(scores_to_retain: u16)]
+pub struct AddTopEntries<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game,
+        constraint = leaderboard.top_entries.is_none() @SoarError::TopEntriesAlreadyExist
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        init,
+        constraint = scores_to_retain > 0 @SoarError::InvalidScoresToRetain,
+        space = LeaderTopEntries::size(
+            LeaderTopEntries::initial_capacity(scores_to_retain) as usize
+        ),
+        payer = payer,
+        seeds = [
+            seeds::LEADER_TOP_ENTRIES,
+            leaderboard.key().as_ref()
+        ],
+        bump,
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResizeTopEntries<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.is_closed(Clock::get()?.unix_timestamp)
+        @SoarError::TopEntriesFrozen
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePlayer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub user: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = Player::SIZE,
+        seeds = [seeds::PLAYER, user.key().as_ref()],
+        bump,
+    )]
+    pub player_account: Account<'info, Player>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterPlayer<'info> {
     #[account(mut)]
@@ -348,8 +1133,16 @@ pub struct RegisterPlayer<'info> {
     pub user: Signer<'info>,
     #[account(has_one = user)]
     pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
     pub game: Account<'info, Game>,
-    #[account(has_one = game)]
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.paused
+        @SoarError::LeaderboardPaused
+    )]
     pub leaderboard: Account<'info, LeaderBoard>,
     #[account(
         init,
@@ -363,9 +1156,22 @@ pub struct RegisterPlayer<'info> {
         bump
     )]
     pub new_list: Account<'info, PlayerScoresList>,
+    #[account(
+        seeds = [
+            seeds::PLAYER_BAN,
+            leaderboard.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump,
+        constraint = player_ban.data_is_empty()
+        @SoarError::PlayerBanned
+    )]
+    /// CHECK: Checked to be empty, with the seeds of the player's [PlayerBan].
+    pub player_ban: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdatePlayer<'info> {
     pub user: Signer<'info>,
@@ -373,18 +1179,72 @@ pub struct UpdatePlayer<'info> {
     pub player_account: Account<'info, Player>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SubmitScore<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(&authority.key(), Authority::SCORE_SUBMITTER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
     pub game: Account<'info, Game>,
-    #[account(has_one = game)]
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.paused
+        @SoarError::LeaderboardPaused
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = leaderboard
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+    #[account(
+        seeds = [
+            seeds::PLAYER_BAN,
+            leaderboard.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump,
+        constraint = player_ban.data_is_empty()
+        @SoarError::PlayerBanned
+    )]
+    /// CHECK: Checked to be empty, with the seeds of the player's [PlayerBan].
+    pub player_ban: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SubmitAttestedScore<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.paused
+        @SoarError::LeaderboardPaused
+    )]
     pub leaderboard: Account<'info, LeaderBoard>,
     #[account(
         mut,
@@ -392,18 +1252,131 @@ pub struct SubmitScore<'info> {
         has_one = leaderboard
     )]
     pub player_scores: Account<'info, PlayerScoresList>,
+    #[account(
+        seeds = [
+            seeds::PLAYER_BAN,
+            leaderboard.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump,
+        constraint = player_ban.data_is_empty()
+        @SoarError::PlayerBanned
+    )]
+    /// CHECK: Checked to be empty, with the seeds of the player's [PlayerBan].
+    pub player_ban: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+    /// CHECK: The instructions sysvar.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemovePlayerScore<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = leaderboard
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
     #[account(
         mut,
         constraint =
             check_top_entries(&leaderboard, top_entries)
     )]
-    pub top_entries: Option<Account<'info, LeaderTopEntries>>,
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EvictPlayer<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.is_closed(Clock::get()?.unix_timestamp)
+        @SoarError::TopEntriesFrozen
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    pub player_account: Account<'info, Player>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BanPlayer<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    pub player_account: Account<'info, Player>,
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerBan::SIZE,
+        seeds = [
+            seeds::PLAYER_BAN,
+            leaderboard.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub ban: Account<'info, PlayerBan>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnbanPlayer<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = leaderboard,
+        close = receiver
+    )]
+    pub ban: Account<'info, PlayerBan>,
+}
+
 fn check_top_entries(
     leaderboard: &Account<LeaderBoard>,
-    entry: &Account<LeaderTopEntries>,
+    entry: &AccountLoader<LeaderTopEntries>,
 ) -> bool {
     if let Some(expected) = leaderboard.top_entries {
         expected == entry.key()
@@ -412,8 +1385,9 @@ fn check_top_entries(
     }
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(keys: Vec<Pubkey>)]
+#[instruction(keys: Vec<Pubkey>, nonce: u64)]
 pub struct InitiateMerge<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -427,7 +1401,13 @@ pub struct InitiateMerge<'info> {
         space =
             Merged::size(
                 dedup_input(&player_account.key(), keys).1
-            )
+            ),
+        seeds = [
+            seeds::MERGED,
+            user.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        bump
     )]
     pub merge_account: Account<'info, Merged>,
     pub system_program: Program<'info, System>,
@@ -447,6 +1427,7 @@ pub fn dedup_input(initiator_player_account: &Pubkey, input: Vec<Pubkey>) -> (Ve
     (keys, size)
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ApproveMerge<'info> {
     pub user: Signer<'info>,
@@ -456,38 +1437,140 @@ pub struct ApproveMerge<'info> {
     pub merge_account: Account<'info, Merged>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct UnlockPlayerAchievement<'info> {
-    #[account(
-        constraint = game.check_signer(&authority.key())
-        @SoarError::InvalidAuthority
-    )]
-    pub authority: Signer<'info>,
+pub struct MergePlayerScores<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
     pub player_account: Account<'info, Player>,
-    pub game: Account<'info, Game>,
-    #[account(has_one = game)]
-    pub achievement: Account<'info, Achievement>,
     #[account(
-        init,
-        payer = payer,
-        space = PlayerAchievement::SIZE,
-        seeds = [
-            seeds::PLAYER_ACHIEVEMENT,
-            player_account.key().as_ref(),
-            achievement.key().as_ref()
-        ],
-        bump
+        constraint = merge_account.initiator == user.key()
+        @SoarError::InvalidAuthority,
+        constraint = merge_account.merge_complete
+        @SoarError::MergeNotComplete,
+        constraint = merge_account.contains(&merged_player_account.key())
+        @SoarError::AccountNotPartOfMerge
     )]
-    pub player_achievement: Account<'info, PlayerAchievement>,
-    pub system_program: Program<'info, System>,
+    pub merge_account: Account<'info, Merged>,
+    pub merged_player_account: Account<'info, Player>,
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = leaderboard
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+    #[account(
+        mut,
+        has_one = leaderboard,
+        constraint = merged_player_scores.player_account == merged_player_account.key(),
+        close = merged_user
+    )]
+    pub merged_player_scores: Account<'info, PlayerScoresList>,
+    #[account(mut, address = merged_player_account.user)]
+    /// CHECK: The merged player's wallet, refunded the rent of their scores list.
+    pub merged_user: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            seeds::PLAYER_BAN,
+            leaderboard.key().as_ref(),
+            merged_player_account.key().as_ref()
+        ],
+        bump,
+        constraint = merged_player_ban.data_is_empty()
+        @SoarError::PlayerBanned
+    )]
+    /// CHECK: Checked to be empty, with the seeds of the player's [PlayerBan].
+    pub merged_player_ban: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MergePlayerAchievement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = merge_account.initiator == user.key()
+        @SoarError::InvalidAuthority,
+        constraint = merge_account.merge_complete
+        @SoarError::MergeNotComplete,
+        constraint = merge_account.contains(&merged_player_account.key())
+        @SoarError::AccountNotPartOfMerge
+    )]
+    pub merge_account: Account<'info, Merged>,
+    pub merged_player_account: Account<'info, Player>,
+    pub achievement: Account<'info, Achievement>,
+    #[account(
+        mut,
+        has_one = achievement,
+        constraint = merged_player_achievement.player_account == merged_player_account.key(),
+        constraint = !merged_player_achievement.is_merged()
+        @SoarError::PlayerAchievementMerged
+    )]
+    pub merged_player_achievement: Account<'info, PlayerAchievement>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerAchievement::SIZE,
+        seeds = [
+            seeds::PLAYER_ACHIEVEMENT,
+            player_account.key().as_ref(),
+            achievement.key().as_ref()
+        ],
+        bump,
+        constraint = !player_achievement.is_merged()
+        @SoarError::PlayerAchievementMerged
+    )]
+    pub player_achievement: Account<'info, PlayerAchievement>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockPlayerAchievement<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::ACHIEVEMENT_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub achievement: Account<'info, Achievement>,
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerAchievement::SIZE,
+        seeds = [
+            seeds::PLAYER_ACHIEVEMENT,
+            player_account.key().as_ref(),
+            achievement.key().as_ref()
+        ],
+        bump
+    )]
+    pub player_achievement: Account<'info, PlayerAchievement>,
+    pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddFtReward<'info> {
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
@@ -496,28 +1579,54 @@ pub struct AddFtReward<'info> {
     pub game: Box<Account<'info, Game>>,
     #[account(
         mut,
-        has_one = game
+        has_one = game,
+        constraint = achievement.rewards.len() < Achievement::MAX_REWARDS
+        @SoarError::TooManyRewards
     )]
     pub achievement: Box<Account<'info, Achievement>>,
     #[account(
         init,
         payer = payer,
         space = Reward::SIZE,
+        seeds = [
+            seeds::REWARD,
+            achievement.key().as_ref(),
+            &achievement.next_reward().to_le_bytes()
+        ],
+        bump
     )]
     pub new_reward: Box<Account<'info, Reward>>,
 
-    pub reward_token_mint: Box<Account<'info, Mint>>,
-    #[account(mut)]
-    pub delegate_from_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = *reward_token_mint.to_account_info().owner == token_program.key()
+        @SoarError::InvalidTokenProgram
+    )]
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [seeds::REWARD_VAULT, new_reward.key().as_ref()],
+        bump,
+        token::mint = reward_token_mint,
+        token::authority = achievement,
+        token::token_program = token_program,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = source_token_account.mint == reward_token_mint.key()
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub token_account_owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddNftReward<'info> {
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
@@ -526,13 +1635,21 @@ pub struct AddNftReward<'info> {
     pub game: Box<Account<'info, Game>>,
     #[account(
         mut,
-        has_one = game
+        has_one = game,
+        constraint = achievement.rewards.len() < Achievement::MAX_REWARDS
+        @SoarError::TooManyRewards
     )]
     pub achievement: Box<Account<'info, Achievement>>,
     #[account(
         init,
         payer = payer,
         space = Reward::SIZE,
+        seeds = [
+            seeds::REWARD,
+            achievement.key().as_ref(),
+            &achievement.next_reward().to_le_bytes()
+        ],
+        bump
     )]
     pub new_reward: Box<Account<'info, Reward>>,
     pub system_program: Program<'info, System>,
@@ -547,15 +1664,40 @@ pub struct AddNftReward<'info> {
     pub token_metadata_program: Option<UncheckedAccount<'info>>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct ClaimFtReward<'info> {
+pub struct RedeemClaimVoucher<'info> {
+    pub claim: ClaimFtRewardAccounts<'info>,
+    #[account(address = claim.user.key() @SoarError::InvalidAuthority)]
+    pub user: Signer<'info>,
+    /// CHECK: The instructions sysvar.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RedeemNftClaimVoucher<'info> {
+    pub claim: ClaimNftRewardAccounts<'info>,
+    #[account(address = claim.user.key() @SoarError::InvalidAuthority)]
+    pub user: Signer<'info>,
+    /// CHECK: The instructions sysvar.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Accounts shared by the instructions paying out a fungible token [Reward].
+#[derive(Accounts)]
+pub struct ClaimFtRewardAccounts<'info> {
     /// CHECK: Checked with `player_account`
     pub user: UncheckedAccount<'info>,
-    pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(constraint = game.check_signer(&authority.key()))]
-    pub game: Account<'info, Game>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Box<Account<'info, Game>>,
     #[account(
         seeds = [
             seeds::ACHIEVEMENT,
@@ -563,22 +1705,23 @@ pub struct ClaimFtReward<'info> {
             &achievement.id.to_le_bytes()
         ],
         bump,
-        constraint = achievement.reward.is_some()
-        @SoarError::NoRewardForAchievement,
-        constraint = matches!(achievement.reward, Some(reward))
+        constraint = achievement.rewards.contains(&reward.key())
+        @SoarError::NoRewardForAchievement
     )]
     pub achievement: Box<Account<'info, Achievement>>,
     #[account(
         mut,
         has_one = achievement,
         constraint = reward.available_spots != 0
-        @SoarError::NoAvailableRewards
+        @SoarError::NoAvailableRewards,
+        constraint = !reward.paused
+        @SoarError::RewardPaused
     )]
     pub reward: Box<Account<'info, Reward>>,
     #[account(has_one = user)]
     pub player_account: Box<Account<'info, Player>>,
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         space = PlayerAchievement::SIZE,
         seeds = [
@@ -586,98 +1729,109 @@ pub struct ClaimFtReward<'info> {
             player_account.key().as_ref(),
             achievement.key().as_ref()
         ],
-        bump
+        bump,
+        constraint = !player_achievement.is_merged()
+        @SoarError::PlayerAchievementMerged
     )]
     pub player_achievement: Box<Account<'info, PlayerAchievement>>,
-    #[account(mut)]
-    pub source_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = RewardClaim::SIZE,
+        seeds = [
+            seeds::REWARD_CLAIM,
+            reward.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
     #[account(
         mut,
-        constraint = user_token_account.owner == user.key()
+        seeds = [seeds::REWARD_VAULT, reward.key().as_ref()],
+        bump
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = reward_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct ClaimNftReward<'info> {
-    /// CHECK: Checked with `player_account`
-    pub user: UncheckedAccount<'info>,
+pub struct ClaimFtReward<'info> {
+    pub claim: ClaimFtRewardAccounts<'info>,
+    #[account(
+        constraint = authority.key() == claim.user.key()
+            || claim.game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority,
+        constraint = claim.player_achievement.unlocked
+        @SoarError::AchievementNotUnlocked
+    )]
     pub authority: Signer<'info>,
-    #[account(constraint = game.check_signer(&authority.key()))]
-    pub game: Account<'info, Game>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockAndClaimFtReward<'info> {
+    pub claim: ClaimFtRewardAccounts<'info>,
     #[account(
-        seeds = [
-            seeds::ACHIEVEMENT,
-            game.key().as_ref(),
-            &achievement.id.to_le_bytes()
-        ],
-        bump,
-        constraint = achievement.reward.is_some()
-        @SoarError::NoRewardForAchievement,
-        constraint = matches!(achievement.reward, Some(reward))
+        constraint = claim.game.check_role(
+            &authority.key(),
+            Authority::ACHIEVEMENT_MANAGER | Authority::REWARD_MANAGER
+        )
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TopUpFtReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
     )]
+    pub authority: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(has_one = game)]
     pub achievement: Box<Account<'info, Achievement>>,
     #[account(
-        mut,
         has_one = achievement,
-        constraint = reward.available_spots != 0
-        @SoarError::NoAvailableRewards
+        constraint = !reward.cancelled
+        @SoarError::CancelledReward
     )]
     pub reward: Box<Account<'info, Reward>>,
-    #[account(has_one = user)]
-    pub player_account: Box<Account<'info, Player>>,
-    #[account(
-        init,
-        payer = payer,
-        space = PlayerAchievement::SIZE,
-        seeds = [
-            seeds::PLAYER_ACHIEVEMENT,
-            player_account.key().as_ref(),
-            achievement.key().as_ref()
-        ],
-        bump
-    )]
-    pub player_achievement: Account<'info, PlayerAchievement>,
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
     #[account(
-        init,
-        payer = payer,
-        space = NftClaim::SIZE,
-        seeds = [
-            seeds::NFT_CLAIM,
-            reward.key().as_ref(),
-            new_mint.key().as_ref()
-        ],
+        mut,
+        seeds = [seeds::REWARD_VAULT, reward.key().as_ref()],
         bump
     )]
-    pub claim: Account<'info, NftClaim>,
-    #[account(mut)]
-    pub new_mint: Signer<'info>,
-    #[account(mut)]
-    /// CHECK: Checked in Metaplex CPI.
-    pub new_metadata: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: Checked in Metaplex CPI.
-    pub new_master_edition: UncheckedAccount<'info>,
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut)]
-    /// CHECK: Initialized in handler as token account owned by `user`.
-    pub mint_to: UncheckedAccount<'info>,
-    #[account(address = mpl_token_metadata::ID)]
-    /// CHECK: mpl_token_metadata ID.
-    pub token_metadata_program: UncheckedAccount<'info>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub rent: Sysvar<'info, Rent>,
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_account_owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct VerifyNftReward<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct WithdrawFtReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
     pub game: Box<Account<'info, Game>>,
     #[account(
         has_one = game,
@@ -686,49 +1840,964 @@ pub struct VerifyNftReward<'info> {
             game.key().as_ref(),
             &achievement.id.to_le_bytes()
         ],
-        bump,
-        constraint = achievement.reward.unwrap() == reward.key()
+        bump
     )]
     pub achievement: Box<Account<'info, Achievement>>,
     #[account(has_one = achievement)]
     pub reward: Box<Account<'info, Reward>>,
-    /// CHECK: Checked in has_one relationship with `player`.
-    pub user: Signer<'info>,
-    #[account(has_one = user)]
-    pub player_account: Box<Account<'info, Player>>,
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
     #[account(
-        seeds = [
-            seeds::NFT_CLAIM,
-            reward.key().as_ref(),
-            mint.key().as_ref()
-        ],
+        mut,
+        seeds = [seeds::REWARD_VAULT, reward.key().as_ref()],
         bump
     )]
-    pub claim: Account<'info, NftClaim>,
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateReward<'info> {
     #[account(
-        has_one = player_account,
-        has_one = achievement
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
     )]
-    pub player_achievement: Box<Account<'info, PlayerAchievement>>,
-    /// CHECK: Checked with constraint on `claim`.
-    pub mint: UncheckedAccount<'info>,
-    /// CHECK: Checked in handler.
-    #[account(mut)]
-    pub metadata_to_verify: UncheckedAccount<'info>,
-    /// CHECK: Checked in constraint.
+    pub authority: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(has_one = game)]
+    pub achievement: Box<Account<'info, Achievement>>,
     #[account(
-        constraint =
-            check_reward_collection(&reward, collection_mint.key())
+        mut,
+        has_one = achievement,
+        constraint = !reward.cancelled
+        @SoarError::CancelledReward
     )]
-    pub collection_mint: UncheckedAccount<'info>,
-    #[account(mut)]
-    /// CHECK: Checked in Metaplex CPI.
-    pub collection_metadata: UncheckedAccount<'info>,
-    /// CHECK: Checked in Metaplex CPI.
-    pub collection_edition: UncheckedAccount<'info>,
-    #[account(address = mpl_token_metadata::ID)]
-    /// CHECK: mpl_token_metadata address.
-    pub token_metadata_program: UncheckedAccount<'info>,
+    pub reward: Box<Account<'info, Reward>>,
+    /// The reward's vault, checked in the handler. Only needed when an FT reward's
+    /// remaining spots are owed more.
+    pub reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRewardPaused<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(has_one = game)]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(
+        mut,
+        has_one = achievement
+    )]
+    pub reward: Box<Account<'info, Reward>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        mut,
+        has_one = game,
+        seeds = [
+            seeds::ACHIEVEMENT,
+            game.key().as_ref(),
+            &achievement.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(
+        mut,
+        has_one = achievement,
+        constraint = !reward.cancelled
+        @SoarError::CancelledReward
+    )]
+    pub reward: Box<Account<'info, Reward>>,
+    pub reward_token_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
+    #[account(
+        mut,
+        seeds = [seeds::REWARD_VAULT, reward.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub destination_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimFtRewards<'info> {
+    /// CHECK: Checked with `player_account`
+    pub user: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == user.key()
+            || game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority,
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        seeds = [
+            seeds::ACHIEVEMENT,
+            game.key().as_ref(),
+            &achievement.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(has_one = user)]
+    pub player_account: Box<Account<'info, Player>>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = achievement,
+        constraint = player_achievement.unlocked
+        @SoarError::AchievementNotUnlocked
+    )]
+    pub player_achievement: Box<Account<'info, PlayerAchievement>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts shared by the instructions minting an NFT [Reward].
+#[derive(Accounts)]
+pub struct ClaimNftRewardAccounts<'info> {
+    /// CHECK: Checked with `player_account`
+    pub user: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        seeds = [
+            seeds::ACHIEVEMENT,
+            game.key().as_ref(),
+            &achievement.id.to_le_bytes()
+        ],
+        bump,
+        constraint = achievement.rewards.contains(&reward.key())
+        @SoarError::NoRewardForAchievement
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(
+        mut,
+        has_one = achievement,
+        constraint = reward.available_spots != 0
+        @SoarError::NoAvailableRewards,
+        constraint = !reward.paused
+        @SoarError::RewardPaused
+    )]
+    pub reward: Box<Account<'info, Reward>>,
+    #[account(has_one = user)]
+    pub player_account: Box<Account<'info, Player>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerAchievement::SIZE,
+        seeds = [
+            seeds::PLAYER_ACHIEVEMENT,
+            player_account.key().as_ref(),
+            achievement.key().as_ref()
+        ],
+        bump,
+        constraint = !player_achievement.is_merged()
+        @SoarError::PlayerAchievementMerged
+    )]
+    pub player_achievement: Box<Account<'info, PlayerAchievement>>,
+    #[account(
+        init,
+        payer = payer,
+        space = RewardClaim::SIZE,
+        seeds = [
+            seeds::REWARD_CLAIM,
+            reward.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    #[account(
+        init,
+        payer = payer,
+        space = NftClaim::SIZE,
+        seeds = [
+            seeds::NFT_CLAIM,
+            reward.key().as_ref(),
+            new_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, NftClaim>,
+    #[account(mut)]
+    pub new_mint: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Checked in Metaplex CPI.
+    pub new_metadata: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Checked in Metaplex CPI.
+    pub new_master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized in handler as token account owned by `user`.
+    pub mint_to: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::ID)]
+    /// CHECK: mpl_token_metadata ID.
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimNftReward<'info> {
+    pub claim: ClaimNftRewardAccounts<'info>,
+    #[account(
+        constraint = authority.key() == claim.user.key()
+            || claim.game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority,
+        constraint = claim.player_achievement.unlocked
+        @SoarError::AchievementNotUnlocked
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockAndClaimNftReward<'info> {
+    pub claim: ClaimNftRewardAccounts<'info>,
+    #[account(
+        constraint = claim.game.check_role(
+            &authority.key(),
+            Authority::ACHIEVEMENT_MANAGER | Authority::REWARD_MANAGER
+        )
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VerifyNftReward<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        has_one = game,
+        seeds = [
+            seeds::ACHIEVEMENT,
+            game.key().as_ref(),
+            &achievement.id.to_le_bytes()
+        ],
+        bump,
+        constraint = achievement.rewards.contains(&reward.key())
+        @SoarError::NoRewardForAchievement
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(has_one = achievement)]
+    pub reward: Box<Account<'info, Reward>>,
+    /// CHECK: Checked in has_one relationship with `player`.
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Box<Account<'info, Player>>,
+    #[account(
+        seeds = [
+            seeds::NFT_CLAIM,
+            reward.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, NftClaim>,
+    #[account(
+        has_one = player_account,
+        has_one = achievement
+    )]
+    pub player_achievement: Box<Account<'info, PlayerAchievement>>,
+    /// CHECK: Checked with constraint on `claim`.
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: Checked in handler.
+    #[account(mut)]
+    pub metadata_to_verify: UncheckedAccount<'info>,
+    /// CHECK: Checked in constraint.
+    #[account(
+        constraint =
+            check_reward_collection(&reward, collection_mint.key())
+    )]
+    pub collection_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Checked in Metaplex CPI.
+    pub collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: Checked in Metaplex CPI.
+    pub collection_edition: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::ID)]
+    /// CHECK: mpl_token_metadata address.
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddFtRankReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    #[account(
+        init,
+        payer = payer,
+        space = RankReward::SIZE,
+    )]
+    pub new_reward: Box<Account<'info, RankReward>>,
+
+    #[account(
+        constraint = *reward_token_mint.to_account_info().owner == token_program.key()
+        @SoarError::InvalidTokenProgram
+    )]
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [seeds::REWARD_VAULT, new_reward.key().as_ref()],
+        bump,
+        token::mint = reward_token_mint,
+        token::authority = leaderboard,
+        token::token_program = token_program,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = source_token_account.mint == reward_token_mint.key()
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_account_owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddNftRankReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntries>,
+    #[account(
+        init,
+        payer = payer,
+        space = RankReward::SIZE,
+    )]
+    pub new_reward: Box<Account<'info, RankReward>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(rank: u32)]
+pub struct ClaimFtRankReward<'info> {
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        has_one = game,
+        seeds = [
+            seeds::LEADER,
+            game.key().as_ref(),
+            &leaderboard.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+    #[account(has_one = leaderboard)]
+    pub season_top_entries: Option<Box<Account<'info, SeasonTopEntries>>>,
+    #[account(has_one = leaderboard)]
+    pub rank_reward: Box<Account<'info, RankReward>>,
+    #[account(has_one = user)]
+    pub player_account: Box<Account<'info, Player>>,
+    #[account(
+        init,
+        payer = payer,
+        space = RankRewardClaim::SIZE,
+        seeds = [
+            seeds::RANK_REWARD_CLAIM,
+            rank_reward.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Box<Account<'info, RankRewardClaim>>,
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        seeds = [seeds::REWARD_VAULT, rank_reward.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(rank: u32)]
+pub struct ClaimNftRankReward<'info> {
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = !game.paused
+        @SoarError::GamePaused
+    )]
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        has_one = game,
+        seeds = [
+            seeds::LEADER,
+            game.key().as_ref(),
+            &leaderboard.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+    #[account(has_one = leaderboard)]
+    pub season_top_entries: Option<Box<Account<'info, SeasonTopEntries>>>,
+    #[account(
+        mut,
+        has_one = leaderboard
+    )]
+    pub rank_reward: Box<Account<'info, RankReward>>,
+    #[account(has_one = user)]
+    pub player_account: Box<Account<'info, Player>>,
+    #[account(
+        init,
+        payer = payer,
+        space = RankRewardClaim::SIZE,
+        seeds = [
+            seeds::RANK_REWARD_CLAIM,
+            rank_reward.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Box<Account<'info, RankRewardClaim>>,
+    #[account(mut)]
+    pub new_mint: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Checked in Metaplex CPI.
+    pub new_metadata: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Checked in Metaplex CPI.
+    pub new_master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized in handler as token account owned by `user`.
+    pub mint_to: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::ID)]
+    /// CHECK: mpl_token_metadata ID.
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseGame<'info> {
+    #[account(
+        constraint = game.check_admin(&authority.key())
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        constraint = !game.has_live_accounts()
+        @SoarError::AccountsStillLive
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = ClosedGame::SIZE,
+        seeds = [seeds::CLOSED_GAME, game.key().as_ref()],
+        bump
+    )]
+    pub closed_game: Account<'info, ClosedGame>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseLeaderBoard<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed accounts' lamports.
+    pub receiver: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game,
+        close = receiver,
+        constraint = !leaderboard.has_live_accounts()
+        @SoarError::LeaderboardAccountsStillLive
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        close = receiver,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntries>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSeasonTopEntries<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::LEADERBOARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = leaderboard,
+        close = receiver
+    )]
+    pub archive: Account<'info, SeasonTopEntries>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAchievement<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::ACHIEVEMENT_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game,
+        close = receiver,
+        constraint = achievement.rewards.is_empty()
+        @SoarError::RewardStillAttached
+    )]
+    pub achievement: Account<'info, Achievement>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        mut,
+        has_one = game,
+        seeds = [
+            seeds::ACHIEVEMENT,
+            game.key().as_ref(),
+            &achievement.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(
+        mut,
+        has_one = achievement,
+        close = receiver
+    )]
+    pub reward: Box<Account<'info, Reward>>,
+    #[account(
+        mut,
+        seeds = [seeds::REWARD_VAULT, reward.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseRankReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        mut,
+        has_one = game,
+        seeds = [
+            seeds::LEADER,
+            game.key().as_ref(),
+            &leaderboard.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        mut,
+        has_one = leaderboard,
+        close = receiver
+    )]
+    pub rank_reward: Box<Account<'info, RankReward>>,
+    pub reward_token_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
+    #[account(
+        mut,
+        seeds = [seeds::REWARD_VAULT, rank_reward.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub destination_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePlayer<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = user,
+        close = user
+    )]
+    pub player_account: Account<'info, Player>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePlayerScores<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        mut,
+        has_one = player_account,
+        close = user
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePlayerAchievement<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    /// CHECK: Checked in has_one relationship with `player_achievement`.
+    pub achievement: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = achievement,
+        close = user,
+        constraint = !player_achievement.claimed || achievement.data_is_empty()
+        @SoarError::RewardStillClaimable
+    )]
+    pub player_achievement: Account<'info, PlayerAchievement>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMerged<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        close = user,
+        constraint = merge_account.initiator == user.key()
+        @SoarError::InvalidAuthority
+    )]
+    pub merge_account: Account<'info, Merged>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseNftClaim<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Receives the closed account's lamports.
+    pub receiver: UncheckedAccount<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(has_one = game)]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(has_one = achievement)]
+    pub reward: Box<Account<'info, Reward>>,
+    /// CHECK: Checked with the seeds of `claim`.
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [
+            seeds::NFT_CLAIM,
+            reward.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, NftClaim>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseRewardClaim<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = reward.data_is_empty()
+        @SoarError::RewardStillClaimable
+    )]
+    /// CHECK: Checked to be closed, and with the seeds of `claim`.
+    pub reward: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            seeds::REWARD_CLAIM,
+            reward.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, RewardClaim>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseRankRewardClaim<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = rank_reward.data_is_empty()
+        @SoarError::RewardStillClaimable
+    )]
+    /// CHECK: Checked to be closed, and with the seeds of `claim`.
+    pub rank_reward: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = player_account,
+        close = user,
+        seeds = [
+            seeds::RANK_REWARD_CLAIM,
+            rank_reward.key().as_ref(),
+            player_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, RankRewardClaim>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateLeaderBoard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Checked to be a legacy [LeaderBoard] in the handler.
+    pub leaderboard: UncheckedAccount<'info>,
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Checked to be the leaderboard's legacy [LeaderTopEntries] in the handler.
+    pub top_entries: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigratePlayerScores<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Checked to be a legacy [PlayerScoresList] in the handler.
+    pub player_scores: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateGame<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Checked to be a legacy [Game] in the handler.
+    pub game: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAchievement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Checked to be a legacy [Achievement] in the handler.
+    pub achievement: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateFtReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), Authority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        has_one = game,
+        seeds = [
+            seeds::ACHIEVEMENT,
+            game.key().as_ref(),
+            &achievement.id.to_le_bytes()
+        ],
+        bump,
+        constraint = achievement.rewards.contains(&reward.key())
+        @SoarError::NoRewardForAchievement
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(
+        mut,
+        has_one = achievement,
+        realloc = Reward::SIZE,
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub reward: Box<Account<'info, Reward>>,
+    #[account(
+        constraint = *reward_token_mint.to_account_info().owner == token_program.key()
+        @SoarError::InvalidTokenProgram
+    )]
+    pub reward_token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub legacy_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [seeds::REWARD_VAULT, reward.key().as_ref()],
+        bump,
+        token::mint = reward_token_mint,
+        token::authority = achievement,
+        token::token_program = token_program,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 fn check_reward_collection(reward: &Reward, maybe_collection_mint: Pubkey) -> bool {
@@ -1,7 +1,18 @@
+pub const GAME: &[u8] = b"game";
+pub const CLOSED_GAME: &[u8] = b"closed-game";
 pub const LEADER: &[u8] = b"leaderboard";
 pub const ACHIEVEMENT: &[u8] = b"achievement";
 pub const PLAYER: &[u8] = b"player";
 pub const PLAYER_SCORES: &[u8] = b"player-scores-list";
 pub const PLAYER_ACHIEVEMENT: &[u8] = b"player-achievement";
+pub const PLAYER_BAN: &[u8] = b"player-ban";
 pub const LEADER_TOP_ENTRIES: &[u8] = b"top-scores";
+pub const SEASON_TOP_ENTRIES: &[u8] = b"season-top-scores";
 pub const NFT_CLAIM: &[u8] = b"nft-claim";
+pub const REWARD: &[u8] = b"reward";
+pub const REWARD_CLAIM: &[u8] = b"reward-claim";
+pub const RANK_REWARD_CLAIM: &[u8] = b"rank-reward-claim";
+pub const REWARD_VAULT: &[u8] = b"reward-vault";
+pub const MERGED: &[u8] = b"merged";
+pub const AUTHORITY_PROPOSAL: &[u8] = b"authority-proposal";
+pub const EVENT_AUTHORITY: &[u8] = b"__event_authority";
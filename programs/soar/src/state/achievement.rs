@@ -26,11 +26,18 @@ pub struct Achievement {
     /// Public key of a nft metadata account describing this achievement.
     pub nft_meta: Pubkey,
 
-    /// Optional: Specify a reward to players for unlocking this achievement.
-    pub reward: Option<Pubkey>,
+    /// Rewards given out to players for unlocking this achievement, each with its own spots.
+    pub rewards: Vec<Pubkey>,
+
+    /// Number of rewards ever added to this achievement. Also used to determine the u64 seed
+    /// for the next reward.
+    pub reward_count: u64,
 }
 
 impl Achievement {
+    /// Maximum number of rewards an achievement can give out at once.
+    pub const MAX_REWARDS: usize = 5;
+
     /// Size of a borsh-serialized achievement account.
     pub const SIZE: usize = 8 + // discriminator
         32 + // game
@@ -38,7 +45,8 @@ impl Achievement {
         4 + MAX_TITLE_LEN + // title
         4 + MAX_DESCRIPTION_LEN + // description
         32 + // nft_meta
-        1 + 32; // reward
+        4 + 32 * Self::MAX_REWARDS + // rewards
+        8; // reward_count
 
     /// Create a new [Achievement] instance.
     pub fn new(
@@ -54,7 +62,13 @@ impl Achievement {
             title,
             description,
             nft_meta,
-            reward: None,
+            rewards: vec![],
+            reward_count: 0,
         }
     }
+
+    /// Get next reward index.
+    pub fn next_reward(&self) -> u64 {
+        self.reward_count.checked_add(1).unwrap()
+    }
 }
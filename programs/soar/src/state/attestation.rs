@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+/// The message a [Game][super::Game] authority signs to let a player submit a score
+/// on their own.
+///
+/// Signed as its [message][ScoreAttestation::message] and verified through an ed25519
+/// program instruction placed right before the submission in the same transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ScoreAttestation {
+    /// The [Player][super::Player] account the score is for.
+    pub player_account: Pubkey,
+
+    /// The [LeaderBoard][super::LeaderBoard] the score is submitted to.
+    pub leaderboard: Pubkey,
+
+    /// The attested score.
+    pub score: u64,
+
+    /// The attested secondary value used to rank equal scores.
+    pub tiebreak: u64,
+
+    /// Must equal the `submission_count` of the player's
+    /// [PlayerScoresList][super::PlayerScoresList] when submitted, so the attestation
+    /// can't be replayed.
+    pub nonce: u64,
+
+    /// Unix timestamp the attestation was issued at. Must be later than the list's
+    /// `created_at`, so the attestation can't be replayed once the list is closed and
+    /// registered again with its `submission_count` starting over.
+    pub issued_at: i64,
+
+    /// Unix timestamp after which the attestation is no longer valid.
+    pub expiry: i64,
+}
+
+impl ScoreAttestation {
+    /// Prefix of every signed score attestation, keeping it from being valid as any other
+    /// signed message.
+    pub const DOMAIN: &'static [u8] = b"soar:score-attestation:v1";
+
+    /// Create a new instance of Self.
+    pub fn new(
+        player_account: Pubkey,
+        leaderboard: Pubkey,
+        score: u64,
+        tiebreak: u64,
+        nonce: u64,
+        issued_at: i64,
+        expiry: i64,
+    ) -> Self {
+        ScoreAttestation {
+            player_account,
+            leaderboard,
+            score,
+            tiebreak,
+            nonce,
+            issued_at,
+            expiry,
+        }
+    }
+
+    /// The bytes an authority signs: [Self::DOMAIN], the program id and the borsh-serialized
+    /// attestation.
+    pub fn message(&self) -> Vec<u8> {
+        signed_message(Self::DOMAIN, self)
+    }
+}
+
+/// The message a [Game][super::Game] authority signs to let a player unlock an
+/// [Achievement][super::Achievement] and claim one of its [Reward][super::Reward]s on their
+/// own.
+///
+/// Signed as its [message][ClaimVoucher::message] and verified through an ed25519 program
+/// instruction placed right before the redemption in the same transaction. Redeeming it
+/// claims the reward, so the reward's [RewardClaim][super::RewardClaim] keeps it from being
+/// redeemed twice.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimVoucher {
+    /// The [Player][super::Player] account the voucher is for.
+    pub player_account: Pubkey,
+
+    /// The achievement the player is allowed to unlock.
+    pub achievement: Pubkey,
+
+    /// The reward of `achievement` the player is allowed to claim.
+    pub reward: Pubkey,
+
+    /// Unix timestamp after which the voucher is no longer valid.
+    pub expiry: i64,
+}
+
+impl ClaimVoucher {
+    /// Prefix of every signed claim voucher, keeping it from being valid as any other signed
+    /// message.
+    pub const DOMAIN: &'static [u8] = b"soar:claim-voucher:v1";
+
+    /// Create a new instance of Self.
+    pub fn new(player_account: Pubkey, achievement: Pubkey, reward: Pubkey, expiry: i64) -> Self {
+        ClaimVoucher {
+            player_account,
+            achievement,
+            reward,
+            expiry,
+        }
+    }
+
+    /// The bytes an authority signs: [Self::DOMAIN], the program id and the borsh-serialized
+    /// voucher.
+    pub fn message(&self) -> Vec<u8> {
+        signed_message(Self::DOMAIN, self)
+    }
+}
+
+/// Prefix `message` with `domain` and the program id, so a signature over it is only valid
+/// for this kind of message and this deployment of the program.
+fn signed_message<T: AnchorSerialize>(domain: &[u8], message: &T) -> Vec<u8> {
+    let mut bytes = [domain, crate::ID.as_ref()].concat();
+    message.serialize(&mut bytes).unwrap();
+    bytes
+}
@@ -0,0 +1,41 @@
+use super::Authority;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug)]
+/// A pending change to a [Game][super::Game]'s authority list, applied once the
+/// proposed key accepts it.
+///
+/// Seeds = `[b"authority-proposal", game.key().as_ref(), authority.key.as_ref()]`.
+pub struct AuthorityProposal {
+    /// The game whose authority list is being changed.
+    pub game: Pubkey,
+
+    /// The account that paid for this proposal, refunded when it's closed.
+    pub proposer: Pubkey,
+
+    /// The admin or owner that made the proposal, who must still be allowed to make it when
+    /// it's accepted.
+    pub proposed_by: Pubkey,
+
+    /// The proposed authority and the roles it will hold.
+    pub authority: Authority,
+
+    /// An existing authority to be replaced by the proposed one. The proposed authority
+    /// is added alongside the existing ones if [None].
+    pub replaces: Option<Pubkey>,
+
+    /// Unix timestamp from which the proposal can be accepted.
+    pub unlock_time: i64,
+}
+
+impl AuthorityProposal {
+    /// Size of a serialized [AuthorityProposal] account.
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        32 + // proposer
+        32 + // proposed_by
+        Authority::SIZE + // authority
+        33 + // replaces
+        8; // unlock_time
+}
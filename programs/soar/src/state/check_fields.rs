@@ -46,7 +46,9 @@ impl FieldsCheck for RegisterLeaderBoardInput {
             return Err(SoarError::InvalidFieldLength.into());
         }
 
-        Ok(())
+        check_time_window(self.start_time, self.end_time, self.season_length)?;
+        check_scores_to_retain(self.season_length, self.scores_to_retain)?;
+        check_retention(&self.retention)
     }
 }
 
@@ -56,8 +58,44 @@ impl FieldsCheck for LeaderBoard {
             return Err(SoarError::InvalidFieldLength.into());
         }
 
-        Ok(())
+        check_time_window(self.start_time, self.end_time, self.season_length)?;
+        check_retention(&self.retention)
+    }
+}
+
+fn check_retention(retention: &ScoreRetention) -> Result<()> {
+    require!(retention.limit() != Some(0), SoarError::InvalidRetention);
+    Ok(())
+}
+
+/// Check that a leaderboard's top entries can be archived at the end of each season, if it
+/// has seasons.
+pub fn check_scores_to_retain(season_length: Option<i64>, scores_to_retain: u16) -> Result<()> {
+    if season_length.is_some() {
+        require!(
+            scores_to_retain as usize <= SeasonTopEntries::MAX_SCORES,
+            SoarError::TooManySeasonScores
+        );
+    }
+    Ok(())
+}
+
+fn check_time_window(
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    season_length: Option<i64>,
+) -> Result<()> {
+    if let (Some(start), Some(end)) = (start_time, end_time) {
+        require!(start < end, SoarError::InvalidTimeWindow);
+    }
+    if let Some(length) = season_length {
+        require!(
+            length > 0 && start_time.is_some(),
+            SoarError::InvalidTimeWindow
+        );
     }
+
+    Ok(())
 }
 
 impl FieldsCheck for Player {
@@ -72,7 +110,23 @@ impl FieldsCheck for Player {
 
 impl FieldsCheck for Reward {
     fn check(&self) -> Result<()> {
-        match &self.reward {
+        self.reward.check()
+    }
+}
+
+impl FieldsCheck for RankReward {
+    fn check(&self) -> Result<()> {
+        if self.min_rank == 0 || self.min_rank > self.max_rank {
+            return Err(SoarError::InvalidRankRange.into());
+        }
+
+        self.reward.check()
+    }
+}
+
+impl FieldsCheck for RewardKind {
+    fn check(&self) -> Result<()> {
+        match self {
             RewardKind::NonFungibleToken {
                 uri,
                 name,
@@ -4,6 +4,10 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(Debug, Default)]
 /// An account representing a single game.
+///
+/// PDA with seeds = `[b"game", creator.key().as_ref(), &nonce.to_le_bytes()]`, where `nonce`
+/// tells apart games by the same creator. Games created before seeded addresses live at
+/// keypair addresses, which every other instruction still accepts.
 pub struct Game {
     /// Game meta-information.
     pub meta: GameAttributes,
@@ -17,9 +21,23 @@ pub struct Game {
     /// used to determine the u64 seed for the next achievement.
     pub achievement_count: u64,
 
-    /// A collection of pubkeys which each represent a valid
-    /// authority for this game.
-    pub auth: Vec<Pubkey>,
+    /// Number of this game's leaderboards that haven't been closed.
+    pub live_leaderboards: u64,
+
+    /// Number of this game's achievements that haven't been closed.
+    pub live_achievements: u64,
+
+    /// The key that alone can grant or revoke the admin role, and transfer ownership.
+    pub owner: Pubkey,
+
+    /// The key ownership is being transferred to, once it accepts.
+    pub pending_owner: Option<Pubkey>,
+
+    /// Whether score submissions, registrations, unlocks and reward claims are suspended.
+    pub paused: bool,
+
+    /// The game's authorities, each limited to the roles it was granted.
+    pub auth: Vec<Authority>,
 }
 
 impl Game {
@@ -27,18 +45,37 @@ impl Game {
     pub const SIZE_NO_AUTHS: usize = 8 + // discriminator
         GameAttributes::SIZE + // GameMeta fixed size
         8 + // leaderboard_count
-        8; // achievement_count
+        8 + // achievement_count
+        8 + // live_leaderboards
+        8 + // live_achievements
+        32 + // owner
+        33 + // pending_owner
+        1; // paused
 
     /// The size of a game account, considering a auth vec of
     /// size `auths_len`.
     pub fn size(auth_len: usize) -> usize {
         Self::SIZE_NO_AUTHS + // Base size
-        4 + (auth_len * 32) // Auths vector.
+        4 + (auth_len * Authority::SIZE) // Auths vector.
+    }
+
+    /// Check that a given pubkey is one of the Game's authorities and holds every role
+    /// in `roles`.
+    pub fn check_role(&self, key: &Pubkey, roles: u8) -> bool {
+        self.auth
+            .iter()
+            .any(|authority| authority.key == *key && authority.has_roles(roles))
     }
 
-    /// Check that a given pubkey is one of the Game's authorities.
-    pub fn check_signer(&self, key: &Pubkey) -> bool {
-        self.auth.contains(key)
+    /// Check that a given pubkey is either the game's owner or an authority with the
+    /// admin role.
+    pub fn check_admin(&self, key: &Pubkey) -> bool {
+        self.owner == *key || self.check_role(key, Authority::ADMIN)
+    }
+
+    /// Find the position of `key` in the game's authority list.
+    pub fn find_authority(&self, key: &Pubkey) -> Option<usize> {
+        self.auth.iter().position(|authority| authority.key == *key)
     }
 
     /// Set a game's attributes.
@@ -51,12 +88,77 @@ impl Game {
         self.achievement_count.checked_add(1).unwrap()
     }
 
+    /// Whether the game still has leaderboards or achievements that haven't been closed.
+    pub fn has_live_accounts(&self) -> bool {
+        self.live_leaderboards != 0 || self.live_achievements != 0
+    }
+
     /// Get next leaderboard id.
     pub fn next_leaderboard(&self) -> u64 {
         self.leaderboard_count.checked_add(1).unwrap()
     }
 }
 
+// Existence serves as proof that a game was closed, so its address can't be initialized
+// again and inherit the accounts left over from the closed game's leaderboards and
+// achievements.
+//
+// Seeds: `[b"closed-game", game.key().as_ref()]`
+#[account]
+pub struct ClosedGame {}
+
+impl ClosedGame {
+    pub const SIZE: usize = 8;
+}
+
+/// A [Game] authority and the roles it was granted, as a bitmask of [Authority] role flags.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Authority {
+    /// The authority's pubkey.
+    pub key: Pubkey,
+
+    /// The roles granted to `key`.
+    pub roles: u8,
+}
+
+impl Authority {
+    /// Update the game's attributes and authorities, and close the game.
+    pub const ADMIN: u8 = 1 << 0;
+
+    /// Submit scores, and attest scores submitted by players.
+    pub const SCORE_SUBMITTER: u8 = 1 << 1;
+
+    /// Add, update and close achievements, unlock them for players and sign claim vouchers.
+    pub const ACHIEVEMENT_MANAGER: u8 = 1 << 2;
+
+    /// Add, fund, update and close rewards, and pay them out to players.
+    pub const REWARD_MANAGER: u8 = 1 << 3;
+
+    /// Add, update and close leaderboards, and moderate the players registered to them.
+    pub const LEADERBOARD_MANAGER: u8 = 1 << 4;
+
+    /// Every role.
+    pub const ALL: u8 = Self::ADMIN
+        | Self::SCORE_SUBMITTER
+        | Self::ACHIEVEMENT_MANAGER
+        | Self::REWARD_MANAGER
+        | Self::LEADERBOARD_MANAGER;
+
+    /// Size of a borsh-serialized [Authority].
+    pub const SIZE: usize = 32 + // key
+        1; // roles
+
+    /// Create a new [Authority] instance.
+    pub fn new(key: Pubkey, roles: u8) -> Self {
+        Authority { key, roles }
+    }
+
+    /// Whether this authority holds every role in `roles`.
+    pub fn has_roles(&self, roles: u8) -> bool {
+        self.roles & roles == roles
+    }
+}
+
 /// A type that represents game-specific information.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct GameAttributes {
@@ -1,14 +1,20 @@
 use super::{RegisterLeaderBoardInput, MAX_DESCRIPTION_LEN};
 use anchor_lang::prelude::*;
+use std::cmp::Ordering;
 
 /// A single score entry for a player.
-#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
 pub struct ScoreEntry {
     /// The player's score.
     pub score: u64,
 
     /// When this entry was made.
     pub timestamp: i64,
+
+    /// Secondary value used to rank equal scores under [TieBreak::HighestValue] and
+    /// [TieBreak::LowestValue].
+    pub tiebreak: u64,
 }
 
 #[account]
@@ -43,6 +49,88 @@ pub struct LeaderBoard {
 
     /// Whether or not multiple scores are allowed for a single player.
     pub allow_multiple_scores: bool,
+
+    /// Unix timestamp before which scores are rejected.
+    pub start_time: Option<i64>,
+
+    /// Unix timestamp from which scores are rejected.
+    pub end_time: Option<i64>,
+
+    /// Length of a recurring season in seconds, counted from `start_time`.
+    pub season_length: Option<i64>,
+
+    /// Order by which scores are ranked. `true` for ascending, `false` for descending.
+    pub is_ascending: bool,
+
+    /// Which of a player's scores are kept in their [PlayerScoresList][super::PlayerScoresList].
+    pub retention: ScoreRetention,
+
+    /// How equal scores are ranked against each other.
+    pub tie_break: TieBreak,
+
+    /// Whether score submissions and registrations are suspended.
+    pub paused: bool,
+
+    /// Number of this leaderboard's [RankReward][super::RankReward]s that haven't been closed.
+    pub live_rank_rewards: u64,
+
+    /// Number of this leaderboard's [SeasonTopEntries][super::SeasonTopEntries] archives that
+    /// haven't been closed.
+    pub live_archives: u64,
+}
+
+/// Policy for the scores a [PlayerScoresList][super::PlayerScoresList] keeps once it
+/// holds a set number of entries.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ScoreRetention {
+    /// Keep every score, growing the account as needed.
+    #[default]
+    Unbounded,
+
+    /// Keep the most recent `n` scores, evicting the oldest.
+    KeepLatest(u16),
+
+    /// Keep the best `n` scores, evicting the worst.
+    KeepBest(u16),
+
+    /// Keep only the player's best score.
+    BestOnly,
+}
+
+impl ScoreRetention {
+    /// Size of a borsh-serialized [ScoreRetention].
+    pub const SIZE: usize = 1 + 2;
+
+    /// Maximum number of scores kept, if bounded.
+    pub fn limit(&self) -> Option<usize> {
+        match self {
+            ScoreRetention::Unbounded => None,
+            ScoreRetention::KeepLatest(n) | ScoreRetention::KeepBest(n) => Some(*n as usize),
+            ScoreRetention::BestOnly => Some(1),
+        }
+    }
+}
+
+/// Policy for ranking equal scores.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The earliest score ranks higher.
+    #[default]
+    EarliestFirst,
+
+    /// The latest score ranks higher.
+    LatestFirst,
+
+    /// The score with the highest tiebreak value ranks higher, then the earliest.
+    HighestValue,
+
+    /// The score with the lowest tiebreak value ranks higher, then the earliest.
+    LowestValue,
+}
+
+impl TieBreak {
+    /// Size of a borsh-serialized [TieBreak].
+    pub const SIZE: usize = 1;
 }
 
 impl LeaderBoard {
@@ -56,7 +144,16 @@ impl LeaderBoard {
         8 +  // min_score
         8 +  // max_score
         1 + // allow_multiple_scores
-        1 + 32; // top_entries
+        1 + 32 + // top_entries
+        1 + 8 + // start_time
+        1 + 8 + // end_time
+        1 + 8 + // season_length
+        1 + // is_ascending
+        ScoreRetention::SIZE + // retention
+        TieBreak::SIZE + // tie_break
+        1 + // paused
+        8 + // live_rank_rewards
+        8; // live_archives
 
     /// Create a new [LeaderBoard] instance.
     pub fn new(
@@ -78,6 +175,44 @@ impl LeaderBoard {
             max_score: max_score.unwrap_or(u64::MAX),
             allow_multiple_scores: false,
             top_entries: None,
+            start_time: None,
+            end_time: None,
+            season_length: None,
+            is_ascending: false,
+            retention: ScoreRetention::Unbounded,
+            tie_break: TieBreak::EarliestFirst,
+            paused: false,
+            live_rank_rewards: 0,
+            live_archives: 0,
+        }
+    }
+
+    /// Whether the leaderboard still has rank rewards or season archives that haven't been
+    /// closed.
+    pub fn has_live_accounts(&self) -> bool {
+        self.live_rank_rewards != 0 || self.live_archives != 0
+    }
+
+    /// Whether scores can be submitted at `timestamp`.
+    pub fn is_active(&self, timestamp: i64) -> bool {
+        let started = !matches!(self.start_time, Some(start) if timestamp < start);
+        let ended = matches!(self.end_time, Some(end) if timestamp >= end);
+        started && !ended
+    }
+
+    /// Whether the leaderboard has an end time and it has passed at `timestamp`.
+    pub fn is_closed(&self, timestamp: i64) -> bool {
+        matches!(self.end_time, Some(end) if timestamp >= end)
+    }
+
+    /// The season `timestamp` falls in, starting from `0`. Always `0` for leaderboards
+    /// without a season length.
+    pub fn season_at(&self, timestamp: i64) -> u64 {
+        match (self.start_time, self.season_length) {
+            (Some(start), Some(length)) if timestamp > start => {
+                (timestamp.checked_sub(start).unwrap() / length) as u64
+            }
+            _ => 0,
         }
     }
 }
@@ -91,6 +226,12 @@ impl From<RegisterLeaderBoardInput> for LeaderBoard {
             min_score: input.min_score.unwrap_or(u64::MIN),
             max_score: input.max_score.unwrap_or(u64::MAX),
             allow_multiple_scores: input.allow_multiple_scores,
+            start_time: input.start_time,
+            end_time: input.end_time,
+            season_length: input.season_length,
+            is_ascending: input.is_ascending,
+            retention: input.retention,
+            tie_break: input.tie_break,
             ..Default::default()
         }
     }
@@ -98,10 +239,37 @@ impl From<RegisterLeaderBoardInput> for LeaderBoard {
 
 impl ScoreEntry {
     /// Size of a [ScoreEntry].
-    pub const SIZE: usize = 8 + 8;
+    pub const SIZE: usize = 8 + 8 + 8;
 
     /// Create a new instance of self.
-    pub fn new(score: u64, timestamp: i64) -> Self {
-        ScoreEntry { score, timestamp }
+    pub fn new(score: u64, timestamp: i64, tiebreak: u64) -> Self {
+        ScoreEntry {
+            score,
+            timestamp,
+            tiebreak,
+        }
+    }
+
+    /// Compare the rank of this entry to `other`'s, with [Ordering::Less] meaning this
+    /// entry ranks higher.
+    pub fn rank_cmp(
+        &self,
+        other: &ScoreEntry,
+        is_ascending: bool,
+        tie_break: TieBreak,
+    ) -> Ordering {
+        let by_score = if is_ascending {
+            self.score.cmp(&other.score)
+        } else {
+            other.score.cmp(&self.score)
+        };
+        let earliest = self.timestamp.cmp(&other.timestamp);
+
+        by_score.then(match tie_break {
+            TieBreak::EarliestFirst => earliest,
+            TieBreak::LatestFirst => earliest.reverse(),
+            TieBreak::HighestValue => other.tiebreak.cmp(&self.tiebreak).then(earliest),
+            TieBreak::LowestValue => self.tiebreak.cmp(&other.tiebreak).then(earliest),
+        })
     }
 }
@@ -0,0 +1,234 @@
+//! Account layouts from before accounts gained their current fields, read when migrating
+//! accounts created under them.
+
+use super::{
+    Achievement, Authority, Game, GameAttributes, LeaderBoard, LeaderBoardScore, PlayerScoresList,
+    ScoreEntry, MAX_DESCRIPTION_LEN, MAX_TITLE_LEN,
+};
+use anchor_lang::prelude::*;
+
+/// A [ScoreEntry] without a tiebreak value.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+pub struct LegacyScoreEntry {
+    pub score: u64,
+    pub timestamp: i64,
+}
+
+impl LegacyScoreEntry {
+    /// Size of a borsh-serialized [LegacyScoreEntry].
+    pub const SIZE: usize = 8 + 8;
+}
+
+impl From<LegacyScoreEntry> for ScoreEntry {
+    fn from(entry: LegacyScoreEntry) -> Self {
+        ScoreEntry::new(entry.score, entry.timestamp, 0)
+    }
+}
+
+/// A [LeaderBoard] without seasons, ranking rules, retention or live account counts. Its
+/// order was held by its [LegacyLeaderTopEntries].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct LegacyLeaderBoard {
+    pub id: u64,
+    pub game: Pubkey,
+    pub description: String,
+    pub nft_meta: Pubkey,
+    pub decimals: u8,
+    pub min_score: u64,
+    pub max_score: u64,
+    pub top_entries: Option<Pubkey>,
+    pub allow_multiple_scores: bool,
+}
+
+impl LegacyLeaderBoard {
+    /// Size of a legacy leaderboard account.
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // id
+        32 + // game
+        4 + MAX_DESCRIPTION_LEN + // description
+        32 + // nft_meta
+        1 +  // decimals
+        8 +  // min_score
+        8 +  // max_score
+        1 + // allow_multiple_scores
+        1 + 32; // top_entries
+
+    /// Convert into a [LeaderBoard] ranking scores in the given order, with every newer
+    /// setting left at its default.
+    pub fn into_leaderboard(self, is_ascending: bool) -> LeaderBoard {
+        LeaderBoard {
+            id: self.id,
+            game: self.game,
+            description: self.description,
+            nft_meta: self.nft_meta,
+            decimals: self.decimals,
+            min_score: self.min_score,
+            max_score: self.max_score,
+            top_entries: self.top_entries,
+            allow_multiple_scores: self.allow_multiple_scores,
+            is_ascending,
+            ..Default::default()
+        }
+    }
+}
+
+/// A [LeaderBoardScore] holding a [LegacyScoreEntry].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+pub struct LegacyLeaderBoardScore {
+    pub player: Pubkey,
+    pub entry: LegacyScoreEntry,
+}
+
+impl LegacyLeaderBoardScore {
+    /// Size of a borsh-serialized [LegacyLeaderBoardScore].
+    pub const SIZE: usize = 32 + LegacyScoreEntry::SIZE;
+}
+
+impl From<LegacyLeaderBoardScore> for LeaderBoardScore {
+    fn from(score: LegacyLeaderBoardScore) -> Self {
+        LeaderBoardScore::new(score.player, score.entry.into())
+    }
+}
+
+/// A borsh-serialized [LeaderTopEntries][super::LeaderTopEntries] holding only the scores
+/// submitted so far, and the leaderboard's order.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct LegacyLeaderTopEntries {
+    pub is_ascending: bool,
+    pub top_scores: Vec<LegacyLeaderBoardScore>,
+}
+
+impl LegacyLeaderTopEntries {
+    /// Size of a legacy top entries account retaining `scores_to_retain` scores.
+    pub fn size(scores_to_retain: usize) -> usize {
+        8 + // discriminator
+        1 + // is_ascending
+        4 + (scores_to_retain * LegacyLeaderBoardScore::SIZE) // top_scores vec
+    }
+
+    /// Number of scores a legacy top entries account of `data_len` bytes retains, if it has
+    /// the legacy layout.
+    pub fn scores_to_retain(data_len: usize) -> Option<usize> {
+        let slots = data_len.checked_sub(Self::size(0))?;
+        (slots % LegacyLeaderBoardScore::SIZE == 0).then_some(slots / LegacyLeaderBoardScore::SIZE)
+    }
+}
+
+/// A [PlayerScoresList] of [LegacyScoreEntry]s, without a submission count or recorded top
+/// entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct LegacyPlayerScoresList {
+    pub player_account: Pubkey,
+    pub leaderboard: Pubkey,
+    pub alloc_count: u16,
+    pub scores: Vec<LegacyScoreEntry>,
+}
+
+impl LegacyPlayerScoresList {
+    /// Offset of `alloc_count` in the account data, which decides the account's size.
+    pub const ALLOC_COUNT_OFFSET: usize = 8 + 32 + 32;
+
+    /// Size of a legacy list with space allocated for `alloc_count` scores.
+    pub fn size(alloc_count: usize) -> usize {
+        Self::ALLOC_COUNT_OFFSET + 2 + // base size
+        4 + (alloc_count * LegacyScoreEntry::SIZE) // scores vec
+    }
+}
+
+impl From<LegacyPlayerScoresList> for PlayerScoresList {
+    fn from(list: LegacyPlayerScoresList) -> Self {
+        PlayerScoresList {
+            player_account: list.player_account,
+            leaderboard: list.leaderboard,
+            alloc_count: list.alloc_count,
+            // Removals weren't possible, so every submitted score is still in the list.
+            submission_count: list.scores.len() as u64,
+            // Not recorded. A list registered again after this one is closed records its own.
+            created_at: 0,
+            top_entries_generation: 0,
+            top_entry: ScoreEntry::default(),
+            scores: list.scores.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// An [Achievement] with at most one reward, created from a keypair rather than derived
+/// from the achievement.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct LegacyAchievement {
+    pub game: Pubkey,
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub nft_meta: Pubkey,
+    pub reward: Option<Pubkey>,
+}
+
+impl LegacyAchievement {
+    /// Size of a legacy achievement account.
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        8 +  // id
+        4 + MAX_TITLE_LEN + // title
+        4 + MAX_DESCRIPTION_LEN + // description
+        32 + // nft_meta
+        1 + 32; // reward
+}
+
+impl From<LegacyAchievement> for Achievement {
+    fn from(achievement: LegacyAchievement) -> Self {
+        let rewards: Vec<Pubkey> = achievement.reward.into_iter().collect();
+        Achievement {
+            game: achievement.game,
+            id: achievement.id,
+            title: achievement.title,
+            description: achievement.description,
+            nft_meta: achievement.nft_meta,
+            // The legacy reward wasn't derived from an index, so none are taken yet, but it
+            // still counts as added.
+            reward_count: rewards.len() as u64,
+            rewards,
+        }
+    }
+}
+
+/// A [Game] whose authorities all held every role, without an owner, pause flag or live
+/// account counts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct LegacyGame {
+    pub meta: GameAttributes,
+    pub leaderboard_count: u64,
+    pub achievement_count: u64,
+    pub auth: Vec<Pubkey>,
+}
+
+impl LegacyGame {
+    /// Size of a legacy game account with `auth_len` authorities.
+    pub fn size(auth_len: usize) -> usize {
+        8 + // discriminator
+        GameAttributes::SIZE + // meta
+        8 + // leaderboard_count
+        8 + // achievement_count
+        4 + (auth_len * 32) // auth vec
+    }
+
+    /// Convert into a [Game] owned by `owner`, with every authority keeping all roles.
+    pub fn into_game(self, owner: Pubkey) -> Game {
+        Game {
+            meta: self.meta,
+            leaderboard_count: self.leaderboard_count,
+            achievement_count: self.achievement_count,
+            // Leaderboards and achievements couldn't be closed, so all of them are still live.
+            live_leaderboards: self.leaderboard_count,
+            live_achievements: self.achievement_count,
+            owner,
+            pending_owner: None,
+            paused: false,
+            auth: self
+                .auth
+                .into_iter()
+                .map(|key| Authority::new(key, Authority::ALL))
+                .collect(),
+        }
+    }
+}
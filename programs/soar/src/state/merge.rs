@@ -2,6 +2,10 @@ use anchor_lang::prelude::*;
 
 /// An account that represents a single user's ownership of
 /// multiple [Player][super::Player] accounts.
+///
+/// PDA with seeds = `[b"merged", initiator.as_ref(), &nonce.to_le_bytes()]`, where `nonce`
+/// tells apart merges by the same user. Only `initiate_merge` derives the address, so
+/// merges already open at keypair addresses can still be approved and carried out.
 #[account]
 #[derive(Debug)]
 pub struct Merged {
@@ -35,6 +39,11 @@ impl Merged {
         4 + (count * MergeApproval::SIZE) // approvals vec
         + 1 // merge_complete
     }
+
+    /// Check that a [Player][super::Player] account is one of those being merged.
+    pub fn contains(&self, player_account: &Pubkey) -> bool {
+        self.approvals.iter().any(|a| a.key == *player_account)
+    }
 }
 
 impl MergeApproval {
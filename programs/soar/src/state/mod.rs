@@ -1,23 +1,31 @@
 mod achievement;
+mod attestation;
+mod authority_proposal;
 mod check_fields;
 mod game;
 mod game_types;
 mod leaderboard;
+mod legacy;
 mod merge;
 mod player;
 mod player_achievement;
+mod player_ban;
 mod player_scores_list;
 mod reward;
 mod top_entries;
 
 pub use achievement::*;
+pub use attestation::*;
+pub use authority_proposal::*;
 pub use check_fields::*;
 pub use game::*;
 pub use game_types::*;
 pub use leaderboard::*;
+pub use legacy::*;
 pub use merge::*;
 pub use player::*;
 pub use player_achievement::*;
+pub use player_ban::*;
 pub use player_scores_list::*;
 pub use reward::*;
 pub use top_entries::*;
@@ -43,13 +51,32 @@ pub struct RegisterLeaderBoardInput {
     pub max_score: Option<u64>,
 
     /// Number of top scores to store on-chain.
-    pub scores_to_retain: u8,
+    ///
+    /// Lists too large to allocate on creation are filled in with `grow_top_entries`.
+    pub scores_to_retain: u16,
 
     /// Order by which scores are stored. `true` for ascending, `false` for descending.
     pub is_ascending: bool,
 
     /// Whether or not multiple scores are kept in the leaderboard for a single player.
     pub allow_multiple_scores: bool,
+
+    /// Unix timestamp before which scores are rejected. Open from creation if [None].
+    pub start_time: Option<i64>,
+
+    /// Unix timestamp from which scores are rejected. Never closes if [None].
+    pub end_time: Option<i64>,
+
+    /// Length of a recurring season in seconds, counted from `start_time`. The top entries
+    /// are reset at the start of every season. Requires `start_time` to be set.
+    pub season_length: Option<i64>,
+
+    /// Which of a player's scores are kept. Defaults to keeping every score.
+    pub retention: ScoreRetention,
+
+    /// How equal scores are ranked against each other. Defaults to the earliest score
+    /// ranking higher.
+    pub tie_break: TieBreak,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -61,6 +88,20 @@ pub struct AddNewRewardInput {
     pub kind: RewardKindInput,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+/// Input to add a new reward for a range of ranks on a leaderboard.
+pub struct AddRankRewardInput {
+    /// Highest rank eligible for the reward, starting from `1`.
+    pub min_rank: u32,
+    /// Lowest rank eligible for the reward, inclusive.
+    pub max_rank: u32,
+    /// The season whose archived top entries decide the ranks. Required on seasonal
+    /// leaderboards, and must be [None] otherwise.
+    pub season: Option<u64>,
+    /// Specific reward kind.
+    pub kind: RewardKindInput,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 /// Specific variant of [AddNewRewardInput].
 pub enum RewardKindInput {
@@ -16,9 +16,17 @@ pub struct PlayerAchievement {
     pub timestamp: i64,
 
     /// A player's unlock status for this achievement.
+    ///
+    /// Cleared, with `claimed` set, once merged into another player's account. See
+    /// [PlayerAchievement::is_merged].
     pub unlocked: bool,
 
-    /// Whether or not this player has claimed their reward.
+    /// Whether this player has claimed any of the achievement's rewards.
+    ///
+    /// Set by the first claim, so with several rewards it doesn't mean all of them were
+    /// claimed. Which rewards were claimed is recorded by their
+    /// [RewardClaim](super::RewardClaim) accounts, which also keep each from being claimed
+    /// twice.
     pub claimed: bool,
 }
 
@@ -40,4 +48,17 @@ impl PlayerAchievement {
             claimed: false,
         }
     }
+
+    /// Mark this status as merged into another player's, so it can't be unlocked or claimed
+    /// from again.
+    pub fn mark_merged(&mut self) {
+        self.unlocked = false;
+        self.claimed = true;
+    }
+
+    /// Whether this status was merged into another player's. Rewards are only claimed from
+    /// unlocked achievements, so no other status is claimed without being unlocked.
+    pub fn is_merged(&self) -> bool {
+        self.claimed && !self.unlocked
+    }
 }
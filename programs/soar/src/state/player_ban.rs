@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug, Default)]
+/// Bars a [Player][super::Player] from registering for or submitting scores to a
+/// [LeaderBoard][super::LeaderBoard] for as long as it exists.
+///
+/// Seeds: `[b"player-ban", leaderboard.key().as_ref(), player_account.key().as_ref()]`
+pub struct PlayerBan {
+    /// The banned player.
+    pub player_account: Pubkey,
+
+    /// The leaderboard the player is banned from.
+    pub leaderboard: Pubkey,
+}
+
+impl PlayerBan {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // player_account
+        32; // leaderboard
+}
@@ -1,5 +1,6 @@
-use super::ScoreEntry;
+use super::{ScoreEntry, ScoreRetention, TieBreak};
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 #[account]
 #[derive(Debug, Default)]
@@ -17,6 +18,21 @@ pub struct PlayerScoresList {
     /// Max number of [scores][ScoreEntry] the current space allocation supports.
     pub alloc_count: u16,
 
+    /// Number of scores ever submitted to this list, including removed ones.
+    pub submission_count: u64,
+
+    /// Unix timestamp the list was registered at. Score attestations issued no later than
+    /// this can't be submitted to it.
+    pub created_at: i64,
+
+    /// The [LeaderTopEntries][super::LeaderTopEntries] generation `top_entry` was recorded
+    /// in, or `0` if it never was.
+    pub top_entries_generation: u32,
+
+    /// The player's best entry in the leaderboard's top entries as of
+    /// `top_entries_generation`. The default entry if the player had none.
+    pub top_entry: ScoreEntry,
+
     /// Collection of [scores][ScoreEntry].
     pub scores: Vec<ScoreEntry>,
 }
@@ -26,7 +42,11 @@ impl PlayerScoresList {
     pub const SIZE_WITHOUT_VEC: usize = 8 + // discriminator
         32 + // player_account
         32 + // leaderboard
-        2; // alloc_count
+        2 + // alloc_count
+        8 + // submission_count
+        8 + // created_at
+        4 + // top_entries_generation
+        ScoreEntry::SIZE; // top_entry
 
     /// Initial number of scores[ScoreEntry] space is allocated for.
     pub const INITIAL_SCORES_LENGTH: usize = 10;
@@ -42,17 +62,138 @@ impl PlayerScoresList {
 
     /// Gets the current size of a [PlayerScoresList] account.
     pub fn current_size(&self) -> usize {
+        Self::size(self.alloc_count as usize)
+    }
+
+    /// Calculate the size of an account with space allocated for `alloc_count` scores.
+    pub fn size(alloc_count: usize) -> usize {
         Self::SIZE_WITHOUT_VEC + // base size.
-        4 + (self.alloc_count as usize * ScoreEntry::SIZE) // size of scores vec.
+        4 + (alloc_count * ScoreEntry::SIZE) // size of scores vec.
+    }
+
+    /// Number of scores to allocate space for in the next resize, given a `retention` policy.
+    ///
+    /// Returns [None] if the list has room for the next score or can't grow any further.
+    pub fn next_alloc_count(&self, retention: &ScoreRetention) -> Option<usize> {
+        let alloc_count = self.alloc_count as usize;
+        if self.scores.len() < alloc_count {
+            return None;
+        }
+
+        let grown = alloc_count.checked_add(Self::REALLOC_WINDOW).unwrap();
+        match retention.limit() {
+            Some(limit) if alloc_count >= limit => None,
+            Some(limit) => Some(grown.min(limit)),
+            None => Some(grown),
+        }
+    }
+
+    /// Evict entries according to a `retention` policy until the list is within its limit.
+    ///
+    /// `is_ascending` decides which scores are considered the best, with equal scores ranked
+    /// according to `tie_break`.
+    pub fn apply_retention(
+        &mut self,
+        retention: &ScoreRetention,
+        is_ascending: bool,
+        tie_break: TieBreak,
+    ) {
+        let limit = match retention.limit() {
+            Some(limit) if self.scores.len() > limit => limit,
+            _ => return,
+        };
+
+        match retention {
+            ScoreRetention::KeepLatest(_) => {
+                let excess = self.scores.len() - limit;
+                self.scores.drain(..excess);
+            }
+            _ => {
+                // Rank a copy of the list, keep its best `limit` entries and restore their
+                // submission order. The sort is stable, so the later of equal entries goes.
+                let mut ranked: Vec<(usize, ScoreEntry)> =
+                    self.scores.drain(..).enumerate().collect();
+                ranked.sort_by(|(_, a), (_, b)| a.rank_cmp(b, is_ascending, tie_break));
+                ranked.truncate(limit);
+                ranked.sort_unstable_by_key(|(index, _)| *index);
+                self.scores
+                    .extend(ranked.into_iter().map(|(_, entry)| entry));
+            }
+        }
+    }
+
+    /// The player's best top entry, if it was recorded in the top entries' current
+    /// `generation`.
+    pub fn top_entry_hint(&self, generation: u32) -> Option<&ScoreEntry> {
+        (self.top_entries_generation == generation).then_some(&self.top_entry)
+    }
+
+    /// Record the player's best top entry in the top entries' current `generation`.
+    pub fn record_top_entry(&mut self, generation: u32, top_entry: Option<ScoreEntry>) {
+        self.top_entries_generation = generation;
+        self.top_entry = top_entry.unwrap_or_default();
     }
 
     /// Create a new instance of Self.
-    pub fn new(player_account: Pubkey, leaderboard: Pubkey) -> Self {
+    pub fn new(player_account: Pubkey, leaderboard: Pubkey, created_at: i64) -> Self {
         PlayerScoresList {
             player_account,
             leaderboard,
             alloc_count: Self::INITIAL_SCORES_LENGTH as u16,
+            submission_count: 0,
+            created_at,
+            top_entries_generation: 0,
+            top_entry: ScoreEntry::default(),
             scores: Vec::with_capacity(Self::INITIAL_SCORES_LENGTH),
         }
     }
 }
+
+/// The fields of a serialized [PlayerScoresList] read in place from its account data,
+/// without copying its scores onto the heap.
+pub struct PlayerScoresView<'a> {
+    /// The player[super::Player] account the list belongs to.
+    pub player_account: Pubkey,
+
+    /// The leaderboard the list's scores were submitted to.
+    pub leaderboard: Pubkey,
+
+    scores: &'a [u8],
+}
+
+impl<'a> PlayerScoresView<'a> {
+    /// Read a view of the [PlayerScoresList] serialized in an account's `data`.
+    pub fn read(data: &'a [u8]) -> Result<Self> {
+        let scores_start = PlayerScoresList::SIZE_WITHOUT_VEC + 4;
+        let header = data
+            .get(..scores_start)
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        require!(
+            header[..8] == PlayerScoresList::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        // Offsets follow the field order of [PlayerScoresList].
+        let player_account = Pubkey::try_from(&header[8..40]).unwrap();
+        let leaderboard = Pubkey::try_from(&header[40..72]).unwrap();
+        let len = u32::from_le_bytes(header[scores_start - 4..].try_into().unwrap()) as usize;
+
+        let scores = len
+            .checked_mul(ScoreEntry::SIZE)
+            .and_then(|scores_len| data.get(scores_start..scores_start + scores_len))
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+
+        Ok(PlayerScoresView {
+            player_account,
+            leaderboard,
+            scores,
+        })
+    }
+
+    /// The list's scores, deserialized one at a time.
+    pub fn scores(&self) -> impl Iterator<Item = ScoreEntry> + 'a {
+        self.scores
+            .chunks_exact(ScoreEntry::SIZE)
+            .map(|mut entry| ScoreEntry::deserialize(&mut entry).unwrap())
+    }
+}
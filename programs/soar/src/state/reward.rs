@@ -11,6 +11,12 @@ impl NftClaim {
 #[account]
 #[derive(Debug)]
 /// An account representing a reward for a given achievement.
+///
+/// PDA with seeds = `[b"reward", achievement.key().as_ref(), &index.to_le_bytes()]`, where
+/// `index` is the 1-based position of the reward in the order rewards were added to the
+/// achievement, i.e. its [reward_count](super::Achievement::reward_count) after adding it.
+/// Older rewards at keypair addresses keep working, as only `add_*_reward` checks the seeds.
+/// A migrated achievement counts its legacy reward, so its first derived reward has index 2.
 pub struct Reward {
     /// The achievement this reward is given for.
     pub achievement: Pubkey,
@@ -20,6 +26,58 @@ pub struct Reward {
 
     /// The reward kind. Current supports Nft and Ft rewards only.
     pub reward: RewardKind,
+
+    /// Whether claims are currently suspended.
+    pub paused: bool,
+
+    /// Whether the reward was cancelled. Unlike a reward whose spots ran out, a cancelled
+    /// reward can't be updated or topped up again.
+    pub cancelled: bool,
+}
+
+#[account]
+#[derive(Debug)]
+/// An account representing a reward given out to players that finish within a range of
+/// ranks on a leaderboard.
+///
+/// Fungible token rank rewards are paid out of a vault owned by the leaderboard, at
+/// seeds = `[b"reward-vault", rank_reward.key().as_ref()]`.
+pub struct RankReward {
+    /// The leaderboard this reward is given for.
+    pub leaderboard: Pubkey,
+
+    /// Highest rank eligible for this reward, starting from `1`.
+    pub min_rank: u32,
+
+    /// Lowest rank eligible for this reward, inclusive.
+    pub max_rank: u32,
+
+    /// The season whose [SeasonTopEntries][super::SeasonTopEntries] archive decides the
+    /// ranks, on seasonal leaderboards. Otherwise ranks are read from the leaderboard's
+    /// [LeaderTopEntries][super::LeaderTopEntries] once it closes.
+    pub season: Option<u64>,
+
+    /// The reward kind. Current supports Nft and Ft rewards only.
+    pub reward: RewardKind,
+}
+
+// Existence serves as proof that a player has claimed a reward.
+//
+// Seeds: `[b"reward-claim", reward.key().as_ref(), player_account.key().as_ref()]`
+#[account]
+pub struct RewardClaim {}
+
+// Existence serves as proof that a player has claimed a rank reward. A player holding
+// several ranks in range can claim only once.
+//
+// Seeds: `[b"rank-reward-claim", rank_reward.key().as_ref(), player_account.key().as_ref()]`
+#[account]
+pub struct RankRewardClaim {
+    /// The player account that claimed the reward.
+    pub player_account: Pubkey,
+
+    /// The rank the reward was claimed for.
+    pub rank: u32,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -35,6 +93,9 @@ pub enum RewardKind {
 
         /// Reward amount per user.
         amount: u64,
+
+        /// The token program `mint` belongs to, either SPL Token or Token-2022.
+        token_program: Pubkey,
     },
     /// NFT rewards.
     NonFungibleToken {
@@ -56,6 +117,20 @@ pub enum RewardKind {
     },
 }
 
+impl RewardKind {
+    /// The token program a fungible token reward's mint belongs to.
+    ///
+    /// Rewards created before the token program was recorded read it as the default key,
+    /// and always hold SPL Token mints.
+    pub fn token_program(token_program: &Pubkey) -> Pubkey {
+        if *token_program == Pubkey::default() {
+            anchor_spl::token::ID
+        } else {
+            *token_program
+        }
+    }
+}
+
 impl Reward {
     pub const MAX_URI_LENGTH: usize = 200;
     pub const MAX_NAME_LENGTH: usize = 32;
@@ -65,7 +140,32 @@ impl Reward {
     pub const SIZE: usize = 8 + // discriminator
         32 + // achievement
         8 +  // available
+        RewardKind::MAX_SIZE + // reward_kind
+        1 + // paused
+        1; // cancelled
+}
+
+impl RankReward {
+    /// Size of a borsh-serialized [RankReward].
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // leaderboard
+        4 + // min_rank
+        4 + // max_rank
+        1 + 8 + // season
         RewardKind::MAX_SIZE; // reward_kind
+
+    /// Whether `rank` falls within this reward's range.
+    pub fn covers(&self, rank: u32) -> bool {
+        rank >= self.min_rank && rank <= self.max_rank
+    }
+}
+
+impl RewardClaim {
+    pub const SIZE: usize = 8;
+}
+
+impl RankRewardClaim {
+    pub const SIZE: usize = 8 + 32 + 4;
 }
 
 impl RewardKind {
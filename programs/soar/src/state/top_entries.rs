@@ -1,20 +1,35 @@
-use super::ScoreEntry;
+use super::{LeaderBoard, ScoreEntry, TieBreak};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use std::cell::{Ref, RefMut};
 
 /// Keeps track of a sorted list of top scores for a leaderboard.
 ///
+/// Zero-copy: this header is followed in the account data by `capacity` [LeaderBoardScore]
+/// slots, sorted from best to worst under the [LeaderBoard]'s order and tie-break policy.
+/// Use [LeaderTopEntries::load_scores] and [LeaderTopEntries::load_scores_mut] to access them.
+///
 /// Seeds = [b"top-scores", leaderboard.key().as_ref()]
-#[account]
+#[account(zero_copy)]
 #[derive(Debug)]
 pub struct LeaderTopEntries {
-    /// Arrangement order.
-    pub is_ascending: bool,
+    /// The leaderboard season these top scores are for.
+    pub season: u64,
 
-    /// Top scores.
-    pub top_scores: Vec<LeaderBoardScore>,
+    /// Number of top scores to keep once fully allocated.
+    pub scores_to_retain: u16,
+
+    /// Number of score slots currently allocated.
+    pub capacity: u16,
+
+    /// Bumped whenever entries are moved other than by inserting a submitted score, which
+    /// invalidates the top entry recorded in each [PlayerScoresList][super::PlayerScoresList].
+    /// Never `0`, which lists use before recording one.
+    pub generation: u32,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug)]
 /// An single entry to a [LeaderTopEntries].
 pub struct LeaderBoardScore {
     /// The player
@@ -25,11 +40,303 @@ pub struct LeaderBoardScore {
 }
 
 impl LeaderTopEntries {
+    /// Offset of the first score slot in the account data.
+    pub const SCORES_OFFSET: usize = 8 + std::mem::size_of::<LeaderTopEntries>();
+
+    /// Calculate the size of an account holding `capacity` score slots.
+    pub fn size(capacity: usize) -> usize {
+        Self::SCORES_OFFSET + capacity * LeaderBoardScore::SIZE
+    }
+
+    /// Number of slots allocated on creation, limited by the space an account can be
+    /// created with from within a program.
+    pub fn initial_capacity(scores_to_retain: u16) -> u16 {
+        let max = (MAX_PERMITTED_DATA_INCREASE - Self::SCORES_OFFSET) / LeaderBoardScore::SIZE;
+        scores_to_retain.min(max as u16)
+    }
+
+    /// Number of slots to allocate on the next resize, limited by how much an account can
+    /// grow in a single instruction. Returns [None] once fully allocated.
+    pub fn next_capacity(&self) -> Option<u16> {
+        if self.capacity >= self.scores_to_retain {
+            return None;
+        }
+
+        let step = (MAX_PERMITTED_DATA_INCREASE / LeaderBoardScore::SIZE) as u16;
+        Some(
+            self.capacity
+                .saturating_add(step)
+                .min(self.scores_to_retain),
+        )
+    }
+
+    /// Create a new instance of Self.
+    pub fn new(season: u64, scores_to_retain: u16, capacity: u16) -> Self {
+        LeaderTopEntries {
+            season,
+            scores_to_retain,
+            capacity,
+            generation: 1,
+        }
+    }
+
+    /// Invalidate the top entries recorded in players' lists.
+    pub fn bump_generation(&mut self) {
+        self.generation = self.generation.wrapping_add(1).max(1);
+    }
+
+    /// Immutably load the header of a [LeaderTopEntries] account along with its score slots.
+    pub fn load_scores<'a>(
+        loader: &'a AccountLoader<'_, LeaderTopEntries>,
+    ) -> Result<(Ref<'a, LeaderTopEntries>, Ref<'a, [LeaderBoardScore]>)> {
+        // Run the loader's own checks before borrowing the data directly.
+        drop(loader.load()?);
+
+        let data = loader.as_ref().try_borrow_data()?;
+        let (header, scores) =
+            Ref::map_split(data, |data| data[8..].split_at(Self::SCORES_OFFSET - 8));
+        let header: Ref<LeaderTopEntries> = Ref::map(header, bytemuck::from_bytes);
+
+        let len = header.capacity as usize * LeaderBoardScore::SIZE;
+        let scores = Ref::map(scores, |scores| bytemuck::cast_slice(&scores[..len]));
+        Ok((header, scores))
+    }
+
+    /// Mutably load the header of a [LeaderTopEntries] account along with its score slots.
+    pub fn load_scores_mut<'a>(
+        loader: &'a AccountLoader<'_, LeaderTopEntries>,
+    ) -> Result<(RefMut<'a, LeaderTopEntries>, RefMut<'a, [LeaderBoardScore]>)> {
+        // Run the loader's own checks before borrowing the data directly.
+        drop(loader.load_mut()?);
+        Self::split_mut(loader.as_ref())
+    }
+
+    /// Like [LeaderTopEntries::load_scores_mut], for an account being initialized.
+    pub fn load_init_scores<'a>(
+        loader: &'a AccountLoader<'_, LeaderTopEntries>,
+    ) -> Result<(RefMut<'a, LeaderTopEntries>, RefMut<'a, [LeaderBoardScore]>)> {
+        drop(loader.load_init()?);
+        Self::split_mut(loader.as_ref())
+    }
+
+    /// Borrow the header and score slots of a [LeaderTopEntries] account's data, without
+    /// checking its owner or discriminator.
+    pub(crate) fn split_mut<'a>(
+        info: &'a AccountInfo<'_>,
+    ) -> Result<(RefMut<'a, LeaderTopEntries>, RefMut<'a, [LeaderBoardScore]>)> {
+        let data = info.try_borrow_mut_data()?;
+        let (header, scores) =
+            RefMut::map_split(data, |data| data[8..].split_at_mut(Self::SCORES_OFFSET - 8));
+        let header: RefMut<LeaderTopEntries> = RefMut::map(header, bytemuck::from_bytes_mut);
+
+        let len = header.capacity as usize * LeaderBoardScore::SIZE;
+        let scores = RefMut::map(scores, |scores| {
+            bytemuck::cast_slice_mut(&mut scores[..len])
+        });
+        Ok((header, scores))
+    }
+
+    /// Clear all entries and start tracking a new `season`.
+    pub fn reset(
+        &mut self,
+        scores: &mut [LeaderBoardScore],
+        season: u64,
+        leaderboard: &LeaderBoard,
+    ) {
+        scores.fill(LeaderBoardScore::placeholder(
+            leaderboard.is_ascending,
+            leaderboard.max_score,
+        ));
+        self.season = season;
+        self.bump_generation();
+    }
+
+    /// Find the index of `player`'s best entry in `scores`.
+    ///
+    /// `hint` is the player's best entry as recorded in the current generation, which is
+    /// looked up by its rank. The default entry records that the player has none. Without
+    /// a hint the whole list is searched.
+    pub fn find_best(
+        &self,
+        scores: &[LeaderBoardScore],
+        player: &Pubkey,
+        hint: Option<&ScoreEntry>,
+        leaderboard: &LeaderBoard,
+    ) -> Option<usize> {
+        let hint = match hint {
+            Some(hint) => LeaderBoardScore::new(*player, *hint),
+            None => return scores.iter().position(|s| s.player == *player),
+        };
+
+        let (is_ascending, tie_break) = (leaderboard.is_ascending, leaderboard.tie_break);
+        let start = scores.partition_point(|s| s.beats(&hint, is_ascending, tie_break));
+        scores[start..]
+            .iter()
+            .take_while(|s| !hint.beats(s, is_ascending, tie_break))
+            .position(|s| s.matches(player, &hint.entry))
+            .map(|offset| start + offset)
+    }
+
+    /// Insert `score` into its ranked position in `scores`, shifting lower entries down
+    /// and dropping the last one.
+    ///
+    /// `existing` is the index of the player's best entry, from [LeaderTopEntries::find_best].
+    /// Equal scores are ranked according to the leaderboard's tie-break policy. Unless the
+    /// leaderboard allows multiple scores, the player's existing entry is replaced only if
+    /// `score` beats it. Returns the index of the player's best entry afterwards.
+    pub fn insert(
+        &self,
+        scores: &mut [LeaderBoardScore],
+        score: LeaderBoardScore,
+        existing: Option<usize>,
+        leaderboard: &LeaderBoard,
+    ) -> Option<usize> {
+        let is_ascending = leaderboard.is_ascending;
+        let tie_break = leaderboard.tie_break;
+        let last = match scores.len().checked_sub(1) {
+            Some(last) if score.beats(&scores[last], is_ascending, tie_break) => last,
+            _ => return existing,
+        };
+
+        let index = scores.partition_point(|s| !score.beats(s, is_ascending, tie_break));
+        let mut evicted = last;
+        if !leaderboard.allow_multiple_scores {
+            match existing {
+                Some(existing) if existing < index => return Some(existing),
+                Some(existing) => evicted = existing,
+                None => {}
+            }
+        }
+
+        scores.copy_within(index..evicted, index + 1);
+        scores[index] = score;
+        Some(existing.map_or(index, |existing| existing.min(index)))
+    }
+
+    /// Remove every entry held by `player`, refilling the freed slots at the end of the list
+    /// with placeholders. Returns the number of removed entries.
+    pub fn evict_player(
+        &mut self,
+        scores: &mut [LeaderBoardScore],
+        player: &Pubkey,
+        leaderboard: &LeaderBoard,
+    ) -> usize {
+        self.retain(scores, leaderboard, |s| s.player != *player)
+    }
+
+    /// Remove a specific `entry` held by `player`, refilling the freed slot at the end of the
+    /// list with a placeholder. Returns the number of removed entries.
+    pub fn evict_entry(
+        &mut self,
+        scores: &mut [LeaderBoardScore],
+        player: &Pubkey,
+        entry: &ScoreEntry,
+        leaderboard: &LeaderBoard,
+    ) -> usize {
+        self.retain(scores, leaderboard, |s| !s.matches(player, entry))
+    }
+
+    /// Re-rank `scores` under `leaderboard`'s current order, score bounds and multiple-score
+    /// setting, after any of them changed.
+    ///
+    /// Entries now outside the score bounds are dropped, as are all but each player's best
+    /// entry if multiple scores are no longer allowed. Freed slots are refilled with
+    /// placeholders for the new order. Returns the number of removed entries.
+    pub fn rerank(&mut self, scores: &mut [LeaderBoardScore], leaderboard: &LeaderBoard) -> usize {
+        let is_ascending = leaderboard.is_ascending;
+        let (min_score, max_score) = (leaderboard.min_score, leaderboard.max_score);
+
+        let occupied = scores.iter().filter(|s| !s.is_placeholder()).count();
+        let removed = self.retain(scores, leaderboard, |s| {
+            !s.is_placeholder() && s.entry.score >= min_score && s.entry.score <= max_score
+        });
+        let mut kept = scores.len() - removed;
+
+        // Sorted in place, as the slots can outgrow what the heap could copy.
+        let rank_cmp = |a: &LeaderBoardScore, b: &LeaderBoardScore| {
+            a.entry
+                .rank_cmp(&b.entry, is_ascending, leaderboard.tie_break)
+        };
+
+        if !leaderboard.allow_multiple_scores {
+            // Group each player's entries best first and keep the first of every group, then
+            // restore the ranking below.
+            scores[..kept]
+                .sort_unstable_by(|a, b| a.player.cmp(&b.player).then_with(|| rank_cmp(a, b)));
+            let mut unique = 0;
+            for index in 0..kept {
+                if unique == 0 || scores[unique - 1].player != scores[index].player {
+                    scores[unique] = scores[index];
+                    unique += 1;
+                }
+            }
+            scores[unique..kept].fill(LeaderBoardScore::placeholder(is_ascending, max_score));
+            kept = unique;
+        }
+
+        scores[..kept].sort_unstable_by(rank_cmp);
+
+        occupied - kept
+    }
+
+    /// Keep only the entries matching `keep` in their current order, refilling the freed
+    /// slots at the end of the list with placeholders. Returns the number of removed entries.
+    pub fn retain(
+        &mut self,
+        scores: &mut [LeaderBoardScore],
+        leaderboard: &LeaderBoard,
+        mut keep: impl FnMut(&LeaderBoardScore) -> bool,
+    ) -> usize {
+        let mut kept = 0;
+        for index in 0..scores.len() {
+            if keep(&scores[index]) {
+                scores[kept] = scores[index];
+                kept += 1;
+            }
+        }
+
+        scores[kept..].fill(LeaderBoardScore::placeholder(
+            leaderboard.is_ascending,
+            leaderboard.max_score,
+        ));
+        self.bump_generation();
+        scores.len() - kept
+    }
+}
+
+/// A snapshot of a [LeaderTopEntries] account taken at the end of a leaderboard season.
+///
+/// Seeds = [b"season-top-scores", leaderboard.key().as_ref(), &season.to_le_bytes()]
+#[account]
+#[derive(Debug)]
+pub struct SeasonTopEntries {
+    /// The leaderboard these top scores were recorded for.
+    pub leaderboard: Pubkey,
+
+    /// The archived season.
+    pub season: u64,
+
+    /// Arrangement order.
+    pub is_ascending: bool,
+
+    /// Top scores at the end of the season.
+    pub top_scores: Vec<LeaderBoardScore>,
+}
+
+impl SeasonTopEntries {
+    /// Maximum number of top scores an archive holds, which bounds the scores a seasonal
+    /// leaderboard can retain. Archives are created from within the program, so this is
+    /// limited by the space an account can be created with in a single instruction.
+    pub const MAX_SCORES: usize =
+        (MAX_PERMITTED_DATA_INCREASE - Self::size(0)) / LeaderBoardScore::SIZE;
+
     /// Calculate the size for a given `top_scores` vector length.
-    pub fn size(scores_to_retain: usize) -> usize {
+    pub const fn size(scores_len: usize) -> usize {
         8 + // discriminator
+        32 + // leaderboard
+        8 + // season
         1 + // is_ascending
-        4 + (scores_to_retain * LeaderBoardScore::SIZE) // top_scores vec
+        4 + (scores_len * LeaderBoardScore::SIZE) // top_scores vec
     }
 }
 
@@ -42,4 +349,102 @@ impl LeaderBoardScore {
     pub fn new(player: Pubkey, entry: ScoreEntry) -> Self {
         LeaderBoardScore { player, entry }
     }
+
+    /// An entry used to fill unoccupied `top_scores` slots, ranked below any valid score.
+    pub fn placeholder(is_ascending: bool, max_score: u64) -> Self {
+        let mut placeholder = LeaderBoardScore::default();
+        if is_ascending {
+            placeholder.entry.score = max_score;
+        }
+        placeholder
+    }
+
+    /// Whether this is `player`'s exact `entry`.
+    pub fn matches(&self, player: &Pubkey, entry: &ScoreEntry) -> bool {
+        self.player == *player
+            && self.entry.score == entry.score
+            && self.entry.timestamp == entry.timestamp
+            && self.entry.tiebreak == entry.tiebreak
+    }
+
+    /// Whether this entry fills an unoccupied slot.
+    pub fn is_placeholder(&self) -> bool {
+        self.player == Pubkey::default()
+    }
+
+    /// Whether this entry ranks strictly higher than `other`, with equal scores ranked
+    /// according to `tie_break`. Any valid score beats a placeholder.
+    pub fn beats(&self, other: &LeaderBoardScore, is_ascending: bool, tie_break: TieBreak) -> bool {
+        if other.is_placeholder() {
+            return !self.is_placeholder();
+        }
+
+        self.entry
+            .rank_cmp(&other.entry, is_ascending, tie_break)
+            .is_lt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn season_archives_fit_in_a_single_allocation() {
+        assert_eq!(SeasonTopEntries::MAX_SCORES, 181);
+        assert!(
+            SeasonTopEntries::size(SeasonTopEntries::MAX_SCORES) <= MAX_PERMITTED_DATA_INCREASE
+        );
+        assert!(
+            SeasonTopEntries::size(SeasonTopEntries::MAX_SCORES + 1) > MAX_PERMITTED_DATA_INCREASE
+        );
+        // Top entries of a seasonal board are created at their full size.
+        assert_eq!(
+            LeaderTopEntries::initial_capacity(SeasonTopEntries::MAX_SCORES as u16) as usize,
+            SeasonTopEntries::MAX_SCORES
+        );
+    }
+
+    #[test]
+    fn rerank_keeps_each_players_best_entry() {
+        let leaderboard = LeaderBoard::new(
+            0,
+            Pubkey::default(),
+            String::new(),
+            Pubkey::default(),
+            None,
+            None,
+            None,
+        );
+        let player = |n: u8| Pubkey::new_from_array([n; 32]);
+        let score =
+            |n: u8, score: u64| LeaderBoardScore::new(player(n), ScoreEntry::new(score, 0, 0));
+        let placeholder = LeaderBoardScore::placeholder(false, u64::MAX);
+
+        let mut scores = [
+            score(1, 10),
+            score(2, 30),
+            score(1, 50),
+            score(3, 20),
+            score(2, 40),
+            score(1, 5),
+            placeholder,
+        ];
+        let mut top_entries = LeaderTopEntries::new(0, 7, 7);
+        assert_eq!(top_entries.rerank(&mut scores, &leaderboard), 3);
+
+        let ranked: Vec<(Pubkey, u64)> = scores.iter().map(|s| (s.player, s.entry.score)).collect();
+        assert_eq!(
+            ranked,
+            [
+                (player(1), 50),
+                (player(2), 40),
+                (player(3), 20),
+                (Pubkey::default(), 0),
+                (Pubkey::default(), 0),
+                (Pubkey::default(), 0),
+                (Pubkey::default(), 0),
+            ]
+        );
+    }
 }
@@ -1,14 +1,45 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::AccountMeta,
     program::{invoke, invoke_signed},
     system_instruction,
-    sysvar::rent::Rent,
+    sysvar::{instructions as ix_sysvar, rent::Rent},
 };
 use anchor_spl::token;
+use anchor_spl::token_interface::spl_token_2022;
 use mpl_token_metadata::instruction::{create_master_edition_v3, create_metadata_accounts_v3};
 use mpl_token_metadata::state::{DataV2, Metadata, TokenMetadataAccount};
 
+use crate::error::SoarError;
+use crate::state::Authority;
+
+/// Check that a game's authority list keeps at least one admin, so it can still be managed.
+pub fn check_auth(auth: &[Authority]) -> Result<()> {
+    require!(
+        auth.iter()
+            .any(|authority| authority.has_roles(Authority::ADMIN)),
+        SoarError::MissingAdmin
+    );
+    Ok(())
+}
+
+/// The keys holding the admin role in `auth`, sorted and without duplicates.
+pub fn admin_keys(auth: &[Authority]) -> Vec<Pubkey> {
+    let mut keys: Vec<Pubkey> = auth
+        .iter()
+        .filter(|authority| authority.has_roles(Authority::ADMIN))
+        .map(|authority| authority.key)
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
 // https://solanacookbook.com/references/programs.html#how-to-change-account-size
+//
+// Accounts that shrink refund their excess rent to `funding_account`, so they must be
+// owned by this program.
 pub fn resize_account<'a>(
     target_account: &AccountInfo<'a>,
     funding_account: &AccountInfo<'a>,
@@ -18,21 +49,60 @@ pub fn resize_account<'a>(
     let rent = Rent::get()?;
     let new_minimum_balance = rent.minimum_balance(new_size);
 
-    let lamports_diff = new_minimum_balance.saturating_sub(target_account.lamports());
-    invoke(
-        &system_instruction::transfer(funding_account.key, target_account.key, lamports_diff),
-        &[
-            funding_account.clone(),
-            target_account.clone(),
-            system_program.clone(),
-        ],
-    )?;
+    if new_size < target_account.data_len() {
+        let refund = target_account
+            .lamports()
+            .saturating_sub(new_minimum_balance);
+        **target_account.try_borrow_mut_lamports()? -= refund;
+        **funding_account.try_borrow_mut_lamports()? += refund;
+    } else {
+        let lamports_diff = new_minimum_balance.saturating_sub(target_account.lamports());
+        invoke(
+            &system_instruction::transfer(funding_account.key, target_account.key, lamports_diff),
+            &[
+                funding_account.clone(),
+                target_account.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    }
 
     target_account.realloc(new_size, false)?;
 
     Ok(())
 }
 
+/// Create `target_account`, a PDA of `owner` derived from `signer_seeds`, with `space` bytes
+/// of zeroed data.
+///
+/// Fails if the account already holds lamports.
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    target_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            target_account.key,
+            lamports,
+            space as u64,
+            owner,
+        ),
+        &[
+            payer.clone(),
+            target_account.clone(),
+            system_program.clone(),
+        ],
+        &[signer_seeds],
+    )
+    .map_err(Into::into)
+}
+
 pub fn decode_mpl_metadata_account(account: &AccountInfo<'_>) -> Result<Metadata> {
     if account.owner != &mpl_token_metadata::ID {
         return Err(ProgramError::IllegalOwner.into());
@@ -328,3 +398,257 @@ pub fn verify_nft<'a>(
 
     Ok(())
 }
+
+/// Check that the instruction right before the current one is an ed25519 program instruction
+/// verifying a single signature over `message`, and return the signer's pubkey.
+///
+/// The signature, pubkey and message must all be contained in the ed25519 instruction's data.
+pub fn verify_ed25519_signature(instructions: &AccountInfo<'_>, message: &[u8]) -> Result<Pubkey> {
+    // https://docs.solana.com/developing/runtime-facilities/programs#ed25519-program
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_SIZE: usize = 14;
+    const PUBKEY_SIZE: usize = 32;
+
+    let current_index = ix_sysvar::load_current_index_checked(instructions)? as usize;
+    let index = current_index
+        .checked_sub(1)
+        .ok_or(SoarError::InvalidAttestation)?;
+    let ix = ix_sysvar::load_instruction_at_checked(index, instructions)?;
+
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        SoarError::InvalidAttestation
+    );
+    require!(ix.accounts.is_empty(), SoarError::InvalidAttestation);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= OFFSETS_START + OFFSETS_SIZE && data[0] == 1,
+        SoarError::InvalidAttestation
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let signature_ix_index = read_u16(OFFSETS_START + 2);
+    let pubkey_offset = read_u16(OFFSETS_START + 4) as usize;
+    let pubkey_ix_index = read_u16(OFFSETS_START + 6);
+    let message_offset = read_u16(OFFSETS_START + 8) as usize;
+    let message_size = read_u16(OFFSETS_START + 10) as usize;
+    let message_ix_index = read_u16(OFFSETS_START + 12);
+
+    // `u16::MAX` refers to the ed25519 instruction itself.
+    require!(
+        signature_ix_index == u16::MAX
+            && pubkey_ix_index == u16::MAX
+            && message_ix_index == u16::MAX,
+        SoarError::InvalidAttestation
+    );
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + PUBKEY_SIZE)
+        .ok_or(SoarError::InvalidAttestation)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(SoarError::InvalidAttestation)?;
+    require!(signed_message == message, SoarError::InvalidAttestation);
+
+    Ok(Pubkey::try_from(pubkey).unwrap())
+}
+
+/// Transfer `amount` tokens of `mint` from `source_token_account`, which `authority` owns or
+/// is approved to spend from, to `destination_token_account`.
+///
+/// Works with both SPL Token and Token-2022 mints. `extra_accounts` are appended to the
+/// transfer so Token-2022 can resolve the accounts of a mint's transfer hook. Transfer fees
+/// are withheld from `amount` as configured on the mint.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked<'info>(
+    source_token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    destination_token_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    extra_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        source_token_account.key,
+        mint.key,
+        destination_token_account.key,
+        authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![
+        source_token_account.clone(),
+        mint.clone(),
+        destination_token_account.clone(),
+        authority.clone(),
+    ];
+    for account in extra_accounts {
+        ix.accounts.push(AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account.clone());
+    }
+
+    invoke_signed(&ix, &account_infos, signer).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::system_program;
+    use ix_sysvar::BorrowedInstruction;
+
+    const MESSAGE: &[u8] = b"soar:score-attestation:v1";
+    const SIGNER: Pubkey = Pubkey::new_from_array([7; 32]);
+    const SELF: u16 = u16::MAX;
+
+    /// Data of an ed25519 program instruction verifying one signature by [SIGNER] over
+    /// `message`, with every offset referring to `ix_index`.
+    fn ed25519_data(message: &[u8], ix_index: u16) -> Vec<u8> {
+        const DATA_START: u16 = 2 + 14;
+        let pubkey_offset = DATA_START;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            ix_index,
+            pubkey_offset,
+            ix_index,
+            message_offset,
+            message.len() as u16,
+            ix_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(SIGNER.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    /// Run [verify_ed25519_signature] for `message` from the last of `instructions`, given
+    /// as `(program_id, data)` pairs.
+    fn verify(instructions: &[(Pubkey, Vec<u8>)], message: &[u8]) -> Result<Pubkey> {
+        let borrowed: Vec<_> = instructions
+            .iter()
+            .map(|(program_id, data)| BorrowedInstruction {
+                program_id,
+                accounts: vec![],
+                data,
+            })
+            .collect();
+        let mut data = ix_sysvar::construct_instructions_data(&borrowed);
+        ix_sysvar::store_current_index(&mut data, instructions.len() as u16 - 1);
+
+        let key = ix_sysvar::ID;
+        let owner = Pubkey::default();
+        let mut lamports = 0;
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        verify_ed25519_signature(&info, message)
+    }
+
+    fn invalid() -> Error {
+        SoarError::InvalidAttestation.into()
+    }
+
+    #[test]
+    fn returns_the_signer_of_the_preceding_instruction() {
+        let ixs = [
+            (ed25519_program::ID, ed25519_data(MESSAGE, SELF)),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap(), SIGNER);
+    }
+
+    #[test]
+    fn rejects_a_missing_attestation() {
+        assert_eq!(
+            verify(&[(crate::ID, vec![])], MESSAGE).unwrap_err(),
+            invalid()
+        );
+    }
+
+    #[test]
+    fn rejects_an_attestation_that_is_not_right_before() {
+        let ixs = [
+            (ed25519_program::ID, ed25519_data(MESSAGE, SELF)),
+            (system_program::ID, vec![]),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_the_wrong_program() {
+        let ixs = [
+            (system_program::ID, ed25519_data(MESSAGE, SELF)),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_multiple_signatures() {
+        let mut data = ed25519_data(MESSAGE, SELF);
+        data[0] = 2;
+        let ixs = [(ed25519_program::ID, data), (crate::ID, vec![])];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_offsets_into_other_instructions() {
+        for ix_index in [0, 1] {
+            let ixs = [
+                (ed25519_program::ID, ed25519_data(MESSAGE, ix_index)),
+                (crate::ID, vec![]),
+            ];
+            assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+        }
+
+        // Even when only the message is read from another instruction.
+        let mut data = ed25519_data(MESSAGE, SELF);
+        data[14..16].copy_from_slice(&1u16.to_le_bytes());
+        let ixs = [(ed25519_program::ID, data), (crate::ID, MESSAGE.to_vec())];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_offsets_out_of_bounds() {
+        let mut data = ed25519_data(MESSAGE, SELF);
+        data[12..14].copy_from_slice(&u16::MAX.to_le_bytes());
+        let ixs = [(ed25519_program::ID, data), (crate::ID, vec![])];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_a_different_message() {
+        let ixs = [
+            (
+                ed25519_program::ID,
+                ed25519_data(b"soar:claim-voucher:v1", SELF),
+            ),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(verify(&ixs, MESSAGE).unwrap_err(), invalid());
+    }
+}